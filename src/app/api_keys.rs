@@ -0,0 +1,317 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{data_readers::matches_pattern, db_operations::DbOperationError};
+
+/// What a key lets its holder do. Every role includes the ones below it: a writer
+/// may read, an admin may write.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ApiRole {
+    Reader,
+    Writer,
+    Admin,
+}
+
+impl ApiRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiRole::Reader => "reader",
+            ApiRole::Writer => "writer",
+            ApiRole::Admin => "admin",
+        }
+    }
+}
+
+/// What a request touches - the part of the data the key has to cover.
+pub enum ApiScope<'s> {
+    /// Nothing of any table.
+    Any,
+    /// Something of the namespace, with no table of it in particular.
+    Namespace(&'s str),
+    /// One table of the namespace.
+    Table {
+        namespace: &'s str,
+        table_name: &'s str,
+    },
+    /// Any table of the namespace - the request names its tables somewhere the
+    /// check can not see.
+    AllTables(&'s str),
+    /// Every table of every namespace.
+    Server,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ApiKeyNamespaceModel {
+    #[serde(rename = "Namespace")]
+    pub namespace: String,
+    #[serde(rename = "Tables")]
+    pub tables: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ApiKeyModel {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "Roles")]
+    pub roles: Vec<ApiRole>,
+    #[serde(rename = "Namespaces")]
+    pub namespaces: Vec<ApiKeyNamespaceModel>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ApiKeysModel {
+    #[serde(rename = "Keys")]
+    pub keys: Vec<ApiKeyModel>,
+}
+
+/// Roles of one key and the namespaces and tables they apply to. Namespaces and
+/// tables are patterns, where `*` stands for any number of any characters.
+pub struct ApiKey {
+    pub name: String,
+    roles: Vec<ApiRole>,
+    namespaces: Vec<ApiKeyNamespaceModel>,
+}
+
+impl ApiKey {
+    pub fn has_role(&self, role: ApiRole) -> bool {
+        self.roles.iter().any(|itm| *itm >= role)
+    }
+
+    pub fn covers(&self, scope: &ApiScope) -> bool {
+        match scope {
+            ApiScope::Any => true,
+            ApiScope::Namespace(namespace) => self
+                .namespaces
+                .iter()
+                .any(|itm| matches_pattern(itm.namespace.as_str(), namespace)),
+            ApiScope::Table {
+                namespace,
+                table_name,
+            } => self.covers_tables(namespace, |pattern| matches_pattern(pattern, table_name)),
+            ApiScope::AllTables(namespace) => {
+                self.covers_tables(namespace, |pattern| pattern == "*")
+            }
+            ApiScope::Server => self
+                .namespaces
+                .iter()
+                .any(|itm| itm.namespace == "*" && itm.tables.iter().any(|table| table == "*")),
+        }
+    }
+
+    fn covers_tables(&self, namespace: &str, matches: impl Fn(&str) -> bool) -> bool {
+        self.namespaces.iter().any(|itm| {
+            matches_pattern(itm.namespace.as_str(), namespace)
+                && itm.tables.iter().any(|pattern| matches(pattern.as_str()))
+        })
+    }
+}
+
+/// Keys the writers, the admins and the MCP clients present - read from
+/// `ApiKeysFile`.
+pub struct ApiKeys {
+    keys: HashMap<String, Arc<ApiKey>>,
+}
+
+impl ApiKeys {
+    pub fn new(model: ApiKeysModel) -> Self {
+        let mut keys = HashMap::new();
+
+        for key in model.keys {
+            keys.insert(
+                key.key,
+                Arc::new(ApiKey {
+                    name: key.name,
+                    roles: key.roles,
+                    namespaces: key.namespaces,
+                }),
+            );
+        }
+
+        Self { keys }
+    }
+
+    pub async fn load(file_name: &str) -> Result<Self, String> {
+        let file_name = my_no_sql_sdk::core::rust_extensions::file_utils::format_path(file_name);
+
+        let file_content = match tokio::fs::read(file_name.as_str()).await {
+            Ok(file_content) => file_content,
+            Err(err) => {
+                return Err(format!(
+                    "Can't open API keys file [{}]. Err: {}",
+                    file_name.as_str(),
+                    err
+                ))
+            }
+        };
+
+        let model: ApiKeysModel = match serde_yaml::from_slice(file_content.as_slice()) {
+            Ok(model) => model,
+            Err(err) => {
+                return Err(format!(
+                    "Invalid API keys file [{}]. Err: {}",
+                    file_name.as_str(),
+                    err
+                ))
+            }
+        };
+
+        Ok(Self::new(model))
+    }
+
+    pub fn authenticate(&self, key: Option<&str>) -> Result<Arc<ApiKey>, DbOperationError> {
+        let Some(key) = key else {
+            return Err(DbOperationError::Unauthorized(
+                "API key is required".to_string(),
+            ));
+        };
+
+        match self.keys.get(key) {
+            Some(api_key) => Ok(api_key.clone()),
+            None => Err(DbOperationError::Unauthorized(
+                "API key is not valid".to_string(),
+            )),
+        }
+    }
+}
+
+/// Whether the key has the role the operation requires, over what it touches.
+pub fn check_api_access(
+    api_key: &ApiKey,
+    role: ApiRole,
+    scope: &ApiScope,
+) -> Result<(), DbOperationError> {
+    if !api_key.has_role(role) {
+        return Err(DbOperationError::AccessDenied(format!(
+            "API key '{}' has no {} role",
+            api_key.name,
+            role.as_str()
+        )));
+    }
+
+    if api_key.covers(scope) {
+        return Ok(());
+    }
+
+    let message = match scope {
+        ApiScope::Any => unreachable!("Every key covers ApiScope::Any"),
+        ApiScope::Namespace(namespace) => format!(
+            "API key '{}' does not cover namespace '{}'",
+            api_key.name, namespace
+        ),
+        ApiScope::Table {
+            namespace,
+            table_name,
+        } => format!(
+            "API key '{}' does not cover table '{}' of namespace '{}'",
+            api_key.name, table_name, namespace
+        ),
+        ApiScope::AllTables(namespace) => format!(
+            "API key '{}' does not cover every table of namespace '{}'",
+            api_key.name, namespace
+        ),
+        ApiScope::Server => format!("API key '{}' does not cover every namespace", api_key.name),
+    };
+
+    Err(DbOperationError::AccessDenied(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_keys() -> ApiKeys {
+        ApiKeys::new(ApiKeysModel {
+            keys: vec![
+                ApiKeyModel {
+                    name: "billing".to_string(),
+                    key: "billing-key".to_string(),
+                    roles: vec![ApiRole::Writer],
+                    namespaces: vec![ApiKeyNamespaceModel {
+                        namespace: "billing".to_string(),
+                        tables: vec!["invoice-*".to_string()],
+                    }],
+                },
+                ApiKeyModel {
+                    name: "ops".to_string(),
+                    key: "ops-key".to_string(),
+                    roles: vec![ApiRole::Admin],
+                    namespaces: vec![ApiKeyNamespaceModel {
+                        namespace: "*".to_string(),
+                        tables: vec!["*".to_string()],
+                    }],
+                },
+            ],
+        })
+    }
+
+    #[test]
+    fn test_unknown_or_missing_key_is_unauthorized() {
+        let keys = create_keys();
+
+        assert!(matches!(
+            keys.authenticate(None),
+            Err(DbOperationError::Unauthorized(_))
+        ));
+
+        assert!(matches!(
+            keys.authenticate(Some("wrong")),
+            Err(DbOperationError::Unauthorized(_))
+        ));
+
+        assert_eq!("ops", keys.authenticate(Some("ops-key")).unwrap().name);
+    }
+
+    #[test]
+    fn test_roles_include_the_ones_below() {
+        let keys = create_keys();
+        let billing = keys.authenticate(Some("billing-key")).unwrap();
+        let ops = keys.authenticate(Some("ops-key")).unwrap();
+
+        assert!(billing.has_role(ApiRole::Reader));
+        assert!(billing.has_role(ApiRole::Writer));
+        assert!(!billing.has_role(ApiRole::Admin));
+
+        assert!(ops.has_role(ApiRole::Reader));
+        assert!(ops.has_role(ApiRole::Admin));
+    }
+
+    #[test]
+    fn test_key_covers_its_namespaces_and_tables_only() {
+        let keys = create_keys();
+        let billing = keys.authenticate(Some("billing-key")).unwrap();
+        let ops = keys.authenticate(Some("ops-key")).unwrap();
+
+        let table = ApiScope::Table {
+            namespace: "billing",
+            table_name: "invoice-2024",
+        };
+
+        assert!(check_api_access(&billing, ApiRole::Writer, &table).is_ok());
+
+        assert!(matches!(
+            check_api_access(&billing, ApiRole::Admin, &table),
+            Err(DbOperationError::AccessDenied(_))
+        ));
+
+        let other_table = ApiScope::Table {
+            namespace: "billing",
+            table_name: "clients",
+        };
+
+        assert!(check_api_access(&billing, ApiRole::Writer, &other_table).is_err());
+        assert!(
+            check_api_access(&billing, ApiRole::Writer, &ApiScope::Namespace("billing")).is_ok()
+        );
+        assert!(
+            check_api_access(&billing, ApiRole::Writer, &ApiScope::AllTables("billing")).is_err()
+        );
+        assert!(check_api_access(&billing, ApiRole::Reader, &ApiScope::Server).is_err());
+        assert!(check_api_access(&billing, ApiRole::Reader, &ApiScope::Any).is_ok());
+
+        assert!(check_api_access(&ops, ApiRole::Admin, &ApiScope::AllTables("billing")).is_ok());
+        assert!(check_api_access(&ops, ApiRole::Admin, &ApiScope::Server).is_ok());
+    }
+}
@@ -1,24 +1,34 @@
 use std::{
     sync::{
         atomic::{AtomicI64, AtomicUsize},
-        Arc,
+        Arc, Mutex,
     },
     time::Duration,
 };
 
+use arc_swap::ArcSwap;
+
 use my_no_sql_sdk::core::rust_extensions::{
     date_time::DateTimeAsMicroseconds, events_loop::EventsLoop, file_utils::FilePath, AppStates,
 };
+use my_no_sql_sdk::tcp_contracts::MyNoSqlTcpContract;
 
 use crate::{
-    data_readers::DataReadersList, db_operations::bulk_processes::ActiveBulkProcesses,
-    db_operations::multipart::MultipartList, db_sync::NamespaceSyncEvent,
-    db_transactions::ActiveTransactions, operations::init::InitState,
+    audit_log::{AuditLog, AuditRecord},
+    data_readers::{DataReadersList, InitTableCache, ReaderAcl},
+    db_operations::bulk_processes::ActiveBulkProcesses,
+    db_operations::multipart::MultipartList,
+    db_sync::{NamespaceSyncEvent, SyncBatches, SyncHistory},
+    db_transactions::ActiveTransactions,
+    operations::init::InitState,
     settings_reader::SettingsModel,
+    settings_sources::SettingsSources,
+    tls::TlsConfig,
 };
 
 use super::{
-    DbNamespace, DbNamespaces, HttpWriters, OneSecondCounter, PrometheusMetrics, WritersTraffic,
+    logs::Logs, ApiKeys, DbNamespace, DbNamespaces, HttpWriters, OneSecondCounter,
+    PrometheusMetrics, SlowOperations, WritersTraffic,
 };
 
 pub const APP_VERSION: &'static str = env!("CARGO_PKG_VERSION");
@@ -47,6 +57,9 @@ pub struct AppContext {
     pub namespaces: DbNamespaces,
 
     pub metrics: PrometheusMetrics,
+    /// Latest entries of the persistence, backup, GC, vacuum and sync code.
+    pub logs: Arc<Logs>,
+    pub slow_operations: SlowOperations,
 
     pub active_transactions: ActiveTransactions,
 
@@ -60,8 +73,33 @@ pub struct AppContext {
     //pub persist_io: PersistIoOperations,
     pub init_state: InitState,
 
-    pub settings: Arc<SettingsModel>,
+    /// Swapped by a reload of the settings - the runtime ones of them, that is. The
+    /// rest stay as they were read on start.
+    pub settings: ArcSwap<SettingsModel>,
+    /// Where the settings were read from on start; a reload reads them from there
+    /// again.
+    pub settings_sources: SettingsSources,
+    /// Settings changed since the start which only a restart applies - as of the
+    /// last reload.
+    pub settings_restart_required: Mutex<Vec<String>>,
     pub sync: EventsLoop<NamespaceSyncEvent>,
+    /// Change sequence and recent events of every table, for readers which resume.
+    pub sync_history: SyncHistory,
+    /// Changes held back to be merged, for the tables which have a `SyncBatching`
+    /// window.
+    pub sync_batches: SyncBatches,
+    /// `InitTable` payloads readers which subscribe between two changes of a table share.
+    pub init_table_cache: InitTableCache<MyNoSqlTcpContract>,
+    /// Credentials readers have to present, when `ReaderAclFile` is set. Without it
+    /// any reader may subscribe to anything.
+    pub reader_acl: Option<ReaderAcl>,
+    /// Keys the HTTP, gRPC and MCP callers have to present, when `ApiKeysFile` is
+    /// set. Without it every call is let through.
+    pub api_keys: Option<ApiKeys>,
+    /// Certificates of the listeners, when `Tls` is set.
+    pub tls: Option<TlsConfig>,
+    /// Destructive and administrative operations, when `AuditLog` is set.
+    pub audit_log: Option<AuditLog>,
     pub states: Arc<AppStates>,
     /// Serializes `operations::persist` across its entry points (persist timer,
     /// Force-Persist HTTP action, shutdown drain): delete-table cleanup relies
@@ -88,13 +126,42 @@ pub struct AppContext {
 }
 
 impl AppContext {
-    pub async fn new(settings: Arc<SettingsModel>) -> Self {
-        let namespaces = Self::open_namespaces(&settings).await;
+    /// `Err` - a file the settings point to could not be loaded.
+    pub async fn new(
+        settings: Arc<SettingsModel>,
+        settings_sources: SettingsSources,
+    ) -> Result<Self, String> {
+        // Before the namespaces: their persistence writes to it while it opens.
+        let logs = Arc::new(Logs::new());
+
+        let namespaces = Self::open_namespaces(&settings, &logs).await;
+
+        let reader_acl = match settings.reader_acl_file.as_ref() {
+            Some(file_name) => Some(ReaderAcl::load(file_name).await?),
+            None => None,
+        };
+
+        let api_keys = match settings.api_keys_file.as_ref() {
+            Some(file_name) => Some(ApiKeys::load(file_name).await?),
+            None => None,
+        };
+
+        let tls = match settings.tls.as_ref() {
+            Some(tls_settings) => Some(TlsConfig::load(tls_settings.clone()).await?),
+            None => None,
+        };
 
-        Self {
+        let audit_log = match settings.audit_log.as_ref() {
+            Some(audit_log_settings) => Some(AuditLog::new(audit_log_settings.clone()).await?),
+            None => None,
+        };
+
+        Ok(Self {
             created: DateTimeAsMicroseconds::now(),
             namespaces,
             metrics: PrometheusMetrics::new(),
+            logs,
+            slow_operations: SlowOperations::new(settings.get_slow_operations_max_records()),
             active_transactions: ActiveTransactions::new(),
             active_bulk_processes: ActiveBulkProcesses::new(),
             states: Arc::new(AppStates::create_un_initialized()),
@@ -102,7 +169,19 @@ impl AppContext {
             data_readers: DataReadersList::new(Duration::from_secs(30)),
             multipart_list: MultipartList::new(),
             persist_call_lock: tokio::sync::Mutex::new(()),
-            settings,
+            sync_history: SyncHistory::new(
+                settings.get_sync_history_size(),
+                settings.get_sync_history_max_size(),
+            ),
+            sync_batches: SyncBatches::new(),
+            init_table_cache: InitTableCache::new(settings.get_init_table_cache_max_size()),
+            reader_acl,
+            api_keys,
+            tls,
+            audit_log,
+            settings: ArcSwap::new(settings),
+            settings_sources,
+            settings_restart_required: Mutex::new(Vec::new()),
             persist_amount: AtomicUsize::new(0),
             sync: EventsLoop::new("Sync"),
             http_writers: HttpWriters::new(),
@@ -116,13 +195,13 @@ impl AppContext {
             },
             mcp_writes_enabled_until: AtomicI64::new(0),
             ui_writes_enabled_until: AtomicI64::new(0),
-        }
+        })
     }
 
     /// Brings up every namespace this server already has on disk, plus the
     /// default one, which exists even when nothing was ever persisted into it.
-    async fn open_namespaces(settings: &Arc<SettingsModel>) -> DbNamespaces {
-        let namespaces = DbNamespaces::new(settings.clone());
+    async fn open_namespaces(settings: &Arc<SettingsModel>, logs: &Arc<Logs>) -> DbNamespaces {
+        let namespaces = DbNamespaces::new(settings.clone(), logs.clone());
 
         let root = settings.get_persistence_dest();
 
@@ -173,7 +252,7 @@ impl AppContext {
         &self,
         name: Option<&str>,
     ) -> Result<Option<Arc<DbNamespace>>, crate::db_operations::DbOperationError> {
-        if self.settings.auto_create_table_on_reader_subscribe {
+        if self.settings.load().auto_create_table_on_reader_subscribe {
             return Ok(Some(self.get_or_create_namespace(name).await?));
         }
 
@@ -338,6 +417,13 @@ impl AppContext {
         Some((micros_left.max(0) / 1_000_000) as u64)
     }
 
+    /// Records an operation in the audit log. Without `AuditLog` nothing is kept.
+    pub async fn write_audit(&self, record: AuditRecord) {
+        if let Some(audit_log) = self.audit_log.as_ref() {
+            audit_log.write(record).await;
+        }
+    }
+
     pub fn update_persist_amount(&self, value: usize) {
         self.persist_amount
             .store(value, std::sync::atomic::Ordering::SeqCst);
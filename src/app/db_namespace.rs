@@ -5,6 +5,7 @@ use arc_swap::ArcSwap;
 use my_no_sql_sdk::core::db::DbNamespaceName;
 use my_no_sql_sdk::server::DbInstance;
 
+use crate::app::logs::Logs;
 use crate::persist_markers::PersistMarkers;
 use crate::persist_repo::PersistRepo;
 use crate::settings_reader::SettingsModel;
@@ -21,8 +22,8 @@ pub struct DbNamespace {
 }
 
 impl DbNamespace {
-    pub async fn open(name: DbNamespaceName, settings: &SettingsModel) -> Self {
-        let repo = settings.open_persist_repo(name.as_str()).await;
+    pub async fn open(name: DbNamespaceName, settings: &SettingsModel, logs: Arc<Logs>) -> Self {
+        let repo = settings.open_persist_repo(name.as_str(), logs).await;
 
         Self {
             name,
@@ -69,14 +70,16 @@ pub struct DbNamespaces {
     /// racing on the same new namespace would open its folder twice.
     write_lock: tokio::sync::Mutex<()>,
     settings: Arc<SettingsModel>,
+    logs: Arc<Logs>,
 }
 
 impl DbNamespaces {
-    pub fn new(settings: Arc<SettingsModel>) -> Self {
+    pub fn new(settings: Arc<SettingsModel>, logs: Arc<Logs>) -> Self {
         Self {
             items: ArcSwap::from_pointee(Vec::new()),
             write_lock: tokio::sync::Mutex::new(()),
             settings,
+            logs,
         }
     }
 
@@ -118,7 +121,9 @@ impl DbNamespaces {
         }
 
         // Only here, on the rare creation path, is the owned name worth building.
-        let namespace = Arc::new(DbNamespace::open(name.into(), self.settings.as_ref()).await);
+        let namespace = Arc::new(
+            DbNamespace::open(name.into(), self.settings.as_ref(), self.logs.clone()).await,
+        );
 
         let mut new_items = self.items.load().as_ref().clone();
         new_items.push(namespace.clone());
@@ -98,6 +98,12 @@ impl HttpWriters {
         result
     }
 
+    /// Name the writer of `session` pinged with.
+    pub async fn get_name(&self, session: &str) -> Option<String> {
+        let data = self.data.lock().await;
+        data.get(session).map(|itm| itm.name.clone())
+    }
+
     pub async fn gc(&self, now: DateTimeAsMicroseconds) {
         let mut data = self.data.lock().await;
         let mut to_remove = Vec::new();
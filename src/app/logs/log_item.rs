@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Error,
+    /// Something which should never happen and leaves data behind: counted in
+    /// `fatal_errors_count`.
+    FatalError,
+}
+
+impl LogLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Info => "Info",
+            LogLevel::Error => "Error",
+            LogLevel::FatalError => "FatalError",
+        }
+    }
+
+    pub fn parse(src: &str) -> Option<Self> {
+        [LogLevel::Info, LogLevel::Error, LogLevel::FatalError]
+            .into_iter()
+            .find(|itm| itm.as_str().eq_ignore_ascii_case(src))
+    }
+}
+
+/// The part of the server an entry comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SystemProcess {
+    Init,
+    Persist,
+    Backup,
+    Gc,
+    Vacuum,
+    Sync,
+    DataReaders,
+    Tls,
+    Settings,
+}
+
+impl SystemProcess {
+    pub const ALL: [SystemProcess; 9] = [
+        SystemProcess::Init,
+        SystemProcess::Persist,
+        SystemProcess::Backup,
+        SystemProcess::Gc,
+        SystemProcess::Vacuum,
+        SystemProcess::Sync,
+        SystemProcess::DataReaders,
+        SystemProcess::Tls,
+        SystemProcess::Settings,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SystemProcess::Init => "Init",
+            SystemProcess::Persist => "Persist",
+            SystemProcess::Backup => "Backup",
+            SystemProcess::Gc => "Gc",
+            SystemProcess::Vacuum => "Vacuum",
+            SystemProcess::Sync => "Sync",
+            SystemProcess::DataReaders => "DataReaders",
+            SystemProcess::Tls => "Tls",
+            SystemProcess::Settings => "Settings",
+        }
+    }
+
+    pub fn parse(src: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|itm| itm.as_str().eq_ignore_ascii_case(src))
+    }
+}
+
+/// One entry of the in-memory log.
+#[derive(Debug, Clone)]
+pub struct LogItem {
+    pub date: DateTimeAsMicroseconds,
+    pub level: LogLevel,
+    pub process: SystemProcess,
+    pub message: String,
+    pub namespace: Option<String>,
+    pub table_name: Option<String>,
+    pub context: BTreeMap<String, String>,
+}
+
+impl LogItem {
+    pub fn new(level: LogLevel, process: SystemProcess, message: impl Into<String>) -> Self {
+        Self {
+            date: DateTimeAsMicroseconds::now(),
+            level,
+            process,
+            message: message.into(),
+            namespace: None,
+            table_name: None,
+            context: BTreeMap::new(),
+        }
+    }
+
+    pub fn info(process: SystemProcess, message: impl Into<String>) -> Self {
+        Self::new(LogLevel::Info, process, message)
+    }
+
+    pub fn error(process: SystemProcess, message: impl Into<String>) -> Self {
+        Self::new(LogLevel::Error, process, message)
+    }
+
+    pub fn fatal_error(process: SystemProcess, message: impl Into<String>) -> Self {
+        Self::new(LogLevel::FatalError, process, message)
+    }
+
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        self.namespace = Some(namespace.to_string());
+        self
+    }
+
+    pub fn with_table(mut self, namespace: &str, table_name: &str) -> Self {
+        self.namespace = Some(namespace.to_string());
+        self.table_name = Some(table_name.to_string());
+        self
+    }
+
+    pub fn with_context(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.context.insert(key.to_string(), value.into());
+        self
+    }
+}
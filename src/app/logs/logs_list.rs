@@ -0,0 +1,205 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use super::{LogItem, LogLevel, SystemProcess};
+
+/// How many of the latest entries are kept.
+pub const MAX_LOG_ITEMS: usize = 1000;
+
+/// How many of the latest entries are kept for every table, for every process and
+/// of the fatal errors - so a noisy table does not push the rare entries of the
+/// others out of sight.
+pub const MAX_LOG_ITEMS_PER_VIEW: usize = 100;
+
+struct LogsData {
+    items: VecDeque<Arc<LogItem>>,
+    by_table: HashMap<(String, String), VecDeque<Arc<LogItem>>>,
+    by_process: HashMap<SystemProcess, VecDeque<Arc<LogItem>>>,
+    fatal_errors: VecDeque<Arc<LogItem>>,
+    fatal_errors_count: usize,
+}
+
+/// Latest entries of the persistence, backup, GC, vacuum and sync code, kept in
+/// memory for `/api/Logs` and the UI. Every entry goes to the process log as well.
+pub struct Logs {
+    data: Mutex<LogsData>,
+}
+
+impl Logs {
+    pub fn new() -> Self {
+        Self {
+            data: Mutex::new(LogsData {
+                items: VecDeque::new(),
+                by_table: HashMap::new(),
+                by_process: HashMap::new(),
+                fatal_errors: VecDeque::new(),
+                fatal_errors_count: 0,
+            }),
+        }
+    }
+
+    pub fn write(&self, item: LogItem) {
+        write_to_logger(&item);
+
+        let item = Arc::new(item);
+
+        let mut data = self.data.lock().unwrap();
+
+        push(&mut data.items, item.clone(), MAX_LOG_ITEMS);
+
+        if let (Some(namespace), Some(table_name)) =
+            (item.namespace.as_ref(), item.table_name.as_ref())
+        {
+            let by_table = data
+                .by_table
+                .entry((namespace.to_string(), table_name.to_string()))
+                .or_default();
+
+            push(by_table, item.clone(), MAX_LOG_ITEMS_PER_VIEW);
+        }
+
+        let by_process = data.by_process.entry(item.process).or_default();
+        push(by_process, item.clone(), MAX_LOG_ITEMS_PER_VIEW);
+
+        if item.level == LogLevel::FatalError {
+            push(&mut data.fatal_errors, item, MAX_LOG_ITEMS_PER_VIEW);
+            data.fatal_errors_count += 1;
+        }
+    }
+
+    /// Latest entries, the newest first - of the given level only, when one is given.
+    pub fn get(&self, level: Option<LogLevel>, limit: usize) -> Vec<Arc<LogItem>> {
+        let data = self.data.lock().unwrap();
+
+        data.items
+            .iter()
+            .rev()
+            .filter(|itm| level.is_none() || level == Some(itm.level))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_by_table(
+        &self,
+        namespace: &str,
+        table_name: &str,
+        limit: usize,
+    ) -> Vec<Arc<LogItem>> {
+        let data = self.data.lock().unwrap();
+
+        match data
+            .by_table
+            .get(&(namespace.to_string(), table_name.to_string()))
+        {
+            Some(items) => get_latest(items, limit),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn get_by_process(&self, process: SystemProcess, limit: usize) -> Vec<Arc<LogItem>> {
+        let data = self.data.lock().unwrap();
+
+        match data.by_process.get(&process) {
+            Some(items) => get_latest(items, limit),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn get_fatal_errors(&self, limit: usize) -> Vec<Arc<LogItem>> {
+        let data = self.data.lock().unwrap();
+        get_latest(&data.fatal_errors, limit)
+    }
+
+    /// Every fatal error since the start - not only the ones still kept.
+    pub fn get_fatal_errors_count(&self) -> usize {
+        self.data.lock().unwrap().fatal_errors_count
+    }
+
+    /// Forgets the entries of a table which is gone.
+    pub fn remove_table(&self, namespace: &str, table_name: &str) {
+        self.data
+            .lock()
+            .unwrap()
+            .by_table
+            .remove(&(namespace.to_string(), table_name.to_string()));
+    }
+}
+
+fn push(items: &mut VecDeque<Arc<LogItem>>, item: Arc<LogItem>, max_items: usize) {
+    items.push_back(item);
+
+    while items.len() > max_items {
+        items.pop_front();
+    }
+}
+
+fn get_latest(items: &VecDeque<Arc<LogItem>>, limit: usize) -> Vec<Arc<LogItem>> {
+    items.iter().rev().take(limit).cloned().collect()
+}
+
+fn write_to_logger(item: &LogItem) {
+    let mut ctx = my_logger::LogEventCtx::new();
+
+    if let Some(namespace) = item.namespace.as_ref() {
+        ctx = ctx.add("namespace", namespace.to_string());
+    }
+
+    if let Some(table_name) = item.table_name.as_ref() {
+        ctx = ctx.add("tableName", table_name.to_string());
+    }
+
+    for (key, value) in item.context.iter() {
+        ctx = ctx.add(key.as_str(), value.to_string());
+    }
+
+    match item.level {
+        LogLevel::Info => {
+            my_logger::LOGGER.write_info(item.process.as_str(), item.message.as_str(), ctx)
+        }
+        LogLevel::Error => {
+            my_logger::LOGGER.write_error(item.process.as_str(), item.message.as_str(), ctx)
+        }
+        LogLevel::FatalError => {
+            my_logger::LOGGER.write_fatal_error(item.process.as_str(), item.message.as_str(), ctx)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_views_keep_latest_entries_newest_first() {
+        let logs = Logs::new();
+
+        for i in 0..MAX_LOG_ITEMS_PER_VIEW + 5 {
+            logs.write(
+                LogItem::error(SystemProcess::Persist, format!("{}", i))
+                    .with_table("default", "orders"),
+            );
+        }
+
+        logs.write(LogItem::fatal_error(SystemProcess::Backup, "broken"));
+
+        let by_table = logs.get_by_table("default", "orders", 1000);
+        assert_eq!(MAX_LOG_ITEMS_PER_VIEW, by_table.len());
+        assert_eq!("104", by_table[0].message);
+
+        assert!(logs.get_by_table("other", "orders", 10).is_empty());
+
+        let latest = logs.get(None, 2);
+        assert_eq!("broken", latest[0].message);
+        assert_eq!("104", latest[1].message);
+
+        let errors = logs.get(Some(LogLevel::Error), 1000);
+        assert_eq!(MAX_LOG_ITEMS_PER_VIEW + 5, errors.len());
+
+        assert_eq!(1, logs.get_by_process(SystemProcess::Backup, 10).len());
+        assert_eq!(1, logs.get_fatal_errors(10).len());
+        assert_eq!(1, logs.get_fatal_errors_count());
+    }
+}
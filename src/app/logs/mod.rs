@@ -0,0 +1,4 @@
+mod log_item;
+pub use log_item::*;
+mod logs_list;
+pub use logs_list::*;
@@ -1,7 +1,16 @@
 use my_tcp_sockets::ThreadsStatistics;
-use prometheus::{Encoder, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder};
+use std::time::Duration;
 
-use crate::operations::DbTableMetrics;
+use prometheus::{
+    Encoder, Gauge, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec,
+    Opts, Registry, TextEncoder,
+};
+
+use crate::data_readers::SlowReaderAction;
+
+use super::{OperationKind, OperationStats, OperationsTotals};
+
+use crate::operations::{init::InitState, DbTableMetrics};
 
 pub trait UpdatePendingToSyncModel {
     fn get_name(&self) -> &str;
@@ -17,8 +26,21 @@ pub struct PrometheusMetrics {
     unix_connections: IntGaugeVec,
     tcp_connections_changes: IntGaugeVec,
     http_connections_count: IntGauge,
+    web_socket_connections_count: IntGauge,
+    slow_reader_actions: IntCounterVec,
+    sync_events_merged: IntCounterVec,
+    init_table_cache_hits: IntCounter,
+    init_table_cache_misses: IntCounter,
+    init_table_cache_size: IntGauge,
+    dead_readers_disconnected: IntCounter,
     persist_delay_in_seconds: IntGaugeVec,
     pending_to_sync: IntGaugeVec,
+    fatal_errors_count: IntGauge,
+    operation_duration_sec: HistogramVec,
+    operations_count: IntCounterVec,
+    operations_totals: OperationsTotals,
+    init_progress: Gauge,
+    init_skipped_partitions: IntGauge,
 }
 
 const TABLE_NAME: &str = "table_name";
@@ -26,6 +48,17 @@ const TABLE_NAME: &str = "table_name";
 /// namespace reports itself as "default" rather than as an absent label.
 const NAMESPACE: &str = "ns";
 const TCP_METRIC: &str = "tcp_metric";
+const KIND: &str = "kind";
+const OPERATION: &str = "operation";
+/// `ok` or `error`.
+const RESULT: &str = "result";
+
+/// From half a millisecond - a read of one row - to a minute - a backup of a big
+/// namespace.
+const OPERATION_DURATION_BUCKETS: &[f64] = &[
+    0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+    60.0,
+];
 
 impl PrometheusMetrics {
     pub fn new() -> Self {
@@ -38,12 +71,100 @@ impl PrometheusMetrics {
         let tcp_connections_changes = create_tcp_connections_changes();
         let fatal_errors_count = create_fatal_errors_count();
 
+        let operation_duration_sec = create_operation_duration_sec();
+
+        let operations_count = create_operations_count();
+
         let pending_to_sync = create_pending_to_sync();
 
         let persist_delay_in_seconds = create_persist_delay_in_seconds();
 
         let http_connections_count = create_http_connections_count();
 
+        let web_socket_connections_count = create_web_socket_connections_count();
+
+        let slow_reader_actions = create_slow_reader_actions();
+
+        let sync_events_merged = create_sync_events_merged();
+
+        let init_table_cache_hits = IntCounter::new(
+            "init_table_cache_hits",
+            "InitTable payloads a subscribing reader got from the cache",
+        )
+        .unwrap();
+
+        let init_table_cache_misses = IntCounter::new(
+            "init_table_cache_misses",
+            "InitTable payloads built for a subscribing reader",
+        )
+        .unwrap();
+
+        let init_table_cache_size = IntGauge::new(
+            "init_table_cache_size",
+            "Bytes of the InitTable payloads in the cache",
+        )
+        .unwrap();
+
+        let dead_readers_disconnected = IntCounter::new(
+            "dead_readers_disconnected",
+            "TCP readers disconnected for being silent longer than DeadReaderTimeoutSec",
+        )
+        .unwrap();
+
+        let init_progress = Gauge::new(
+            "init_progress",
+            "Share of the persisted data loaded on start up, 0 to 1",
+        )
+        .unwrap();
+
+        let init_skipped_partitions = IntGauge::new(
+            "init_skipped_partitions",
+            "Broken partitions skipped on start up under SkipBrokenPartitions",
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(dead_readers_disconnected.clone()))
+            .unwrap();
+
+        registry.register(Box::new(init_progress.clone())).unwrap();
+
+        registry
+            .register(Box::new(init_skipped_partitions.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(operation_duration_sec.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(operations_count.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(init_table_cache_hits.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(init_table_cache_misses.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(init_table_cache_size.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(sync_events_merged.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(slow_reader_actions.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(web_socket_connections_count.clone()))
+            .unwrap();
+
         registry
             .register(Box::new(http_connections_count.clone()))
             .unwrap();
@@ -84,7 +205,20 @@ impl PrometheusMetrics {
             persist_delay_in_seconds,
             pending_to_sync,
             http_connections_count,
+            web_socket_connections_count,
+            slow_reader_actions,
+            sync_events_merged,
+            init_table_cache_hits,
+            init_table_cache_misses,
+            init_table_cache_size,
+            dead_readers_disconnected,
             unix_connections,
+            fatal_errors_count,
+            operation_duration_sec,
+            operations_count,
+            operations_totals: OperationsTotals::new(),
+            init_progress,
+            init_skipped_partitions,
         };
     }
 
@@ -193,6 +327,87 @@ impl PrometheusMetrics {
             .inc();
     }
 
+    pub fn mark_new_web_socket_connection(&self) {
+        self.web_socket_connections_count.inc();
+    }
+
+    pub fn mark_new_web_socket_disconnection(&self) {
+        self.web_socket_connections_count.dec();
+    }
+
+    pub fn mark_slow_reader_action(&self, action: SlowReaderAction) {
+        self.slow_reader_actions
+            .with_label_values(&[action.as_str()])
+            .inc();
+    }
+
+    pub fn get_slow_reader_actions(&self, action: SlowReaderAction) -> u64 {
+        self.slow_reader_actions
+            .with_label_values(&[action.as_str()])
+            .get()
+    }
+
+    pub fn mark_sync_events_merged(&self, namespace: &str, table_name: &str, amount: usize) {
+        self.sync_events_merged
+            .with_label_values(&[namespace, table_name])
+            .inc_by(amount as u64);
+    }
+
+    pub fn mark_init_table_cache_hit(&self) {
+        self.init_table_cache_hits.inc();
+    }
+
+    pub fn mark_init_table_cache_miss(&self, cache_size: usize) {
+        self.init_table_cache_misses.inc();
+        self.init_table_cache_size.set(cache_size as i64);
+    }
+
+    pub fn update_init_table_cache_size(&self, cache_size: usize) {
+        self.init_table_cache_size.set(cache_size as i64);
+    }
+
+    pub fn update_init_progress(&self, init_state: &InitState) {
+        self.init_progress.set(init_state.get_progress());
+        self.init_skipped_partitions
+            .set(init_state.get_skipped_partitions_amount() as i64);
+    }
+
+    pub fn mark_dead_reader_disconnected(&self) {
+        self.dead_readers_disconnected.inc();
+    }
+
+    pub fn update_fatal_errors_count(&self, value: usize) {
+        self.fatal_errors_count.set(value as i64);
+    }
+
+    /// `table_name` is empty when the operation is not about one table - or names
+    /// it only in its body.
+    pub fn observe_operation(
+        &self,
+        kind: OperationKind,
+        operation: &str,
+        namespace: &str,
+        table_name: &str,
+        duration: Duration,
+        succeeded: bool,
+    ) {
+        self.operation_duration_sec
+            .with_label_values(&[kind.as_str(), operation, namespace, table_name])
+            .observe(duration.as_secs_f64());
+
+        let result = if succeeded { "ok" } else { "error" };
+
+        self.operations_count
+            .with_label_values(&[kind.as_str(), operation, namespace, table_name, result])
+            .inc();
+
+        self.operations_totals.add(kind, duration, succeeded);
+    }
+
+    pub fn get_operations_stats(&self) -> Vec<OperationStats> {
+        self.operations_totals.get()
+    }
+
     pub fn build(&self) -> String {
         let mut buffer = vec![];
         let encoder = TextEncoder::new();
@@ -242,10 +457,58 @@ fn create_fatal_errors_count() -> IntGauge {
     IntGauge::new("fatal_errors_count", "Fatal errors count").unwrap()
 }
 
+fn create_operation_duration_sec() -> HistogramVec {
+    let histogram_opts = HistogramOpts::new(
+        "operation_duration_sec",
+        "Duration of HTTP actions, gRPC methods, reader packets, persist tasks, backups and vacuum passes",
+    )
+    .buckets(OPERATION_DURATION_BUCKETS.to_vec());
+
+    let labels = &[KIND, OPERATION, NAMESPACE, TABLE_NAME];
+    HistogramVec::new(histogram_opts, labels).unwrap()
+}
+
+fn create_operations_count() -> IntCounterVec {
+    let counter_opts = Opts::new(
+        "operations_count",
+        "HTTP actions, gRPC methods, reader packets, persist tasks, backups and vacuum passes, by result",
+    );
+
+    let labels = &[KIND, OPERATION, NAMESPACE, TABLE_NAME, RESULT];
+    IntCounterVec::new(counter_opts, labels).unwrap()
+}
+
 fn create_http_connections_count() -> IntGauge {
     IntGauge::new("http_connections_count", "Http connections count").unwrap()
 }
 
+fn create_slow_reader_actions() -> IntCounterVec {
+    let counter_opts = Opts::new(
+        "slow_reader_actions",
+        "Readers found further behind than the SlowReader limits allow, by the action taken",
+    );
+
+    IntCounterVec::new(counter_opts, &["action"]).unwrap()
+}
+
+fn create_sync_events_merged() -> IntCounterVec {
+    let counter_opts = Opts::new(
+        "sync_events_merged",
+        "Changes which did not go to the readers as events of their own, merged into others by the SyncBatching window",
+    );
+
+    let labels = &[NAMESPACE, TABLE_NAME];
+    IntCounterVec::new(counter_opts, labels).unwrap()
+}
+
+fn create_web_socket_connections_count() -> IntGauge {
+    IntGauge::new(
+        "web_socket_connections_count",
+        "Data readers connected over WebSocket",
+    )
+    .unwrap()
+}
+
 fn create_persist_delay_in_seconds() -> IntGaugeVec {
     let gauge_opts = Opts::new(
         format!("persist_delay_sec"),
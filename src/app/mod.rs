@@ -1,10 +1,20 @@
+mod api_keys;
+pub use api_keys::*;
 mod app_ctx;
 
+pub mod logs;
+
 mod db_namespace;
 pub use db_namespace::*;
 
 mod metrics;
 
+mod operation_metrics;
+pub use operation_metrics::*;
+
+mod slow_operations;
+pub use slow_operations::*;
+
 pub use app_ctx::{AppContext, APP_NAME, APP_VERSION, DEFAULT_PERSIST_PERIOD};
 pub use metrics::PrometheusMetrics;
 pub use metrics::UpdatePendingToSyncModel;
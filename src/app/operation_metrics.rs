@@ -0,0 +1,317 @@
+use std::{
+    cell::Cell,
+    future::Future,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use super::{AppContext, SlowOperation, SlowOperationDetails};
+
+/// Where an operation of `operation_duration_sec` and `operations_count` comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OperationKind {
+    Http,
+    Grpc,
+    /// A packet of a reader - over TCP, WebSocket or gRPC.
+    Reader,
+    Persist,
+    Backup,
+    Vacuum,
+    /// A change sent out to the readers of its table.
+    Sync,
+}
+
+impl OperationKind {
+    pub const ALL: [OperationKind; 7] = [
+        OperationKind::Http,
+        OperationKind::Grpc,
+        OperationKind::Reader,
+        OperationKind::Persist,
+        OperationKind::Backup,
+        OperationKind::Vacuum,
+        OperationKind::Sync,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperationKind::Http => "http",
+            OperationKind::Grpc => "grpc",
+            OperationKind::Reader => "reader",
+            OperationKind::Persist => "persist",
+            OperationKind::Backup => "backup",
+            OperationKind::Vacuum => "vacuum",
+            OperationKind::Sync => "sync",
+        }
+    }
+
+    pub fn parse(src: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|itm| itm.as_str().eq_ignore_ascii_case(src))
+    }
+
+    fn get_index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// What the operations of one kind added up to since the start. Kept next to the
+/// Prometheus metrics for the overview of the UI, which shows a kind as a whole
+/// rather than every operation, namespace and table of it.
+#[derive(Default)]
+struct OperationTotals {
+    count: AtomicU64,
+    errors: AtomicU64,
+    duration_micros: AtomicU64,
+}
+
+impl OperationTotals {
+    fn add(&self, duration: Duration, succeeded: bool) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+
+        if !succeeded {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+
+        self.duration_micros
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+    }
+}
+
+pub struct OperationStats {
+    pub kind: OperationKind,
+    pub count: u64,
+    pub errors: u64,
+    pub duration_micros: u64,
+}
+
+pub struct OperationsTotals {
+    items: [OperationTotals; OperationKind::ALL.len()],
+}
+
+impl OperationsTotals {
+    pub fn new() -> Self {
+        Self {
+            items: Default::default(),
+        }
+    }
+
+    pub fn add(&self, kind: OperationKind, duration: Duration, succeeded: bool) {
+        self.items[kind.get_index()].add(duration, succeeded);
+    }
+
+    pub fn get(&self) -> Vec<OperationStats> {
+        OperationKind::ALL
+            .into_iter()
+            .map(|kind| {
+                let totals = &self.items[kind.get_index()];
+
+                OperationStats {
+                    kind,
+                    count: totals.count.load(Ordering::Relaxed),
+                    errors: totals.errors.load(Ordering::Relaxed),
+                    duration_micros: totals.duration_micros.load(Ordering::Relaxed),
+                }
+            })
+            .collect()
+    }
+}
+
+tokio::task_local! {
+    /// The rows `report_rows` was given by the operation `OperationTimer::run` runs.
+    static OPERATION_ROWS: Cell<Option<usize>>;
+}
+
+/// How many rows the operation being timed read or wrote, from wherever they are
+/// known - an HTTP action has no hold of the timer the middleware keeps around it.
+/// Does nothing outside `OperationTimer::run`.
+pub fn report_rows(rows: usize) {
+    let _ = OPERATION_ROWS.try_with(|reported| reported.set(Some(rows)));
+}
+
+/// Times an operation and records it once dropped - as failed, unless
+/// `succeeded` was called. Which is what an early return with `?` is. One which
+/// took longer than its threshold goes to the slow operations as well.
+pub struct OperationTimer<'s> {
+    app: &'s AppContext,
+    kind: OperationKind,
+    operation: String,
+    namespace: String,
+    table_name: String,
+    details: SlowOperationDetails,
+    started: Instant,
+    succeeded: bool,
+    skipped: bool,
+}
+
+impl<'s> OperationTimer<'s> {
+    pub fn new(
+        app: &'s AppContext,
+        kind: OperationKind,
+        operation: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> Self {
+        Self {
+            app,
+            kind,
+            operation: operation.to_string(),
+            namespace: namespace.to_string(),
+            table_name: table_name.to_string(),
+            details: SlowOperationDetails::default(),
+            started: Instant::now(),
+            succeeded: false,
+            skipped: false,
+        }
+    }
+
+    pub fn with_partition_key(mut self, partition_key: Option<&str>) -> Self {
+        self.details.partition_key = partition_key.map(|itm| itm.to_string());
+        self
+    }
+
+    pub fn with_payload_size(mut self, payload_size: usize) -> Self {
+        self.details.payload_size = Some(payload_size);
+        self
+    }
+
+    pub fn with_session(mut self, session: Option<&str>) -> Self {
+        self.details.session = session.map(|itm| itm.to_string());
+        self
+    }
+
+    /// The namespace and the table of the labels, once the operation has resolved
+    /// them.
+    pub fn set_table(&mut self, namespace: &str, table_name: &str) {
+        self.namespace = namespace.to_string();
+        self.table_name = table_name.to_string();
+    }
+
+    /// How many rows the operation read or wrote, once it is known.
+    pub fn set_rows(&mut self, rows: usize) {
+        self.details.rows = Some(rows);
+    }
+
+    /// Awaits `operation`, taking the rows it reports with `report_rows`.
+    pub async fn run<TResult>(&mut self, operation: impl Future<Output = TResult>) -> TResult {
+        let (result, rows) = OPERATION_ROWS
+            .scope(Cell::new(None), async {
+                let result = operation.await;
+                (result, OPERATION_ROWS.with(|reported| reported.get()))
+            })
+            .await;
+
+        if let Some(rows) = rows {
+            self.set_rows(rows);
+        }
+
+        result
+    }
+
+    pub fn succeeded(mut self) {
+        self.succeeded = true;
+    }
+
+    /// Drops the timer without recording anything - for what turned out not to
+    /// be an operation at all.
+    pub fn skip(mut self) {
+        self.skipped = true;
+    }
+}
+
+impl Drop for OperationTimer<'_> {
+    fn drop(&mut self) {
+        if self.skipped {
+            return;
+        }
+
+        let duration = self.started.elapsed();
+
+        self.app.metrics.observe_operation(
+            self.kind,
+            self.operation.as_str(),
+            self.namespace.as_str(),
+            self.table_name.as_str(),
+            duration,
+            self.succeeded,
+        );
+
+        let Some(threshold) = self
+            .app
+            .settings
+            .load()
+            .get_slow_operation_threshold(self.kind)
+        else {
+            return;
+        };
+
+        if duration < threshold {
+            return;
+        }
+
+        self.app.slow_operations.add(SlowOperation {
+            date: DateTimeAsMicroseconds::now(),
+            kind: self.kind,
+            operation: std::mem::take(&mut self.operation),
+            namespace: std::mem::take(&mut self.namespace),
+            table_name: std::mem::take(&mut self.table_name),
+            duration,
+            succeeded: self.succeeded,
+            details: std::mem::take(&mut self.details),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reported_rows_are_scoped_to_the_operation() {
+        report_rows(5);
+
+        let (result, rows) = OPERATION_ROWS
+            .scope(Cell::new(None), async {
+                report_rows(3);
+                report_rows(7);
+                ("done", OPERATION_ROWS.with(|reported| reported.get()))
+            })
+            .await;
+
+        assert_eq!("done", result);
+        assert_eq!(Some(7), rows);
+    }
+
+    #[test]
+    fn test_totals_are_kept_by_kind() {
+        let totals = OperationsTotals::new();
+
+        totals.add(OperationKind::Http, Duration::from_millis(2), true);
+        totals.add(OperationKind::Http, Duration::from_millis(3), false);
+        totals.add(OperationKind::Vacuum, Duration::from_secs(1), true);
+
+        let stats = totals.get();
+
+        let http = stats
+            .iter()
+            .find(|itm| itm.kind == OperationKind::Http)
+            .unwrap();
+        assert_eq!(2, http.count);
+        assert_eq!(1, http.errors);
+        assert_eq!(5_000, http.duration_micros);
+
+        let grpc = stats
+            .iter()
+            .find(|itm| itm.kind == OperationKind::Grpc)
+            .unwrap();
+        assert_eq!(0, grpc.count);
+
+        let vacuum = stats
+            .iter()
+            .find(|itm| itm.kind == OperationKind::Vacuum)
+            .unwrap();
+        assert_eq!(1_000_000, vacuum.duration_micros);
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use serde::{Deserialize, Serialize};
+
+use super::OperationKind;
+
+/// An HTTP action, a gRPC call, a persist task or a sync dispatch which takes
+/// longer than this is recorded, unless `SlowOperations` says otherwise.
+pub const DEFAULT_SLOW_OPERATION_THRESHOLD_MS: u64 = 1000;
+
+pub const DEFAULT_SLOW_OPERATIONS_MAX_RECORDS: usize = 100;
+
+/// `SlowOperations` of the settings file.
+///
+/// ```yaml
+/// SlowOperations:
+///   ThresholdMs: 500
+///   Kinds:
+///     persist: 2000
+///     backup: 60000
+///   MaxRecords: 200
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SlowOperationsSettings {
+    /// Threshold of the kinds which are recorded without being named in `Kinds`.
+    #[serde(rename = "ThresholdMs", default)]
+    pub threshold_ms: Option<u64>,
+    /// Threshold of a kind, by the `kind` label of `operation_duration_sec`. 0 -
+    /// the kind is not recorded.
+    #[serde(rename = "Kinds", default)]
+    pub kinds: HashMap<String, u64>,
+    #[serde(rename = "MaxRecords", default)]
+    pub max_records: Option<usize>,
+}
+
+impl SlowOperationsSettings {
+    pub fn get_threshold(&self, kind: OperationKind) -> Option<Duration> {
+        let threshold_ms = match self.kinds.get(kind.as_str()) {
+            Some(threshold_ms) => *threshold_ms,
+            None => get_default_threshold_ms(kind, self.threshold_ms)?,
+        };
+
+        if threshold_ms == 0 {
+            return None;
+        }
+
+        Some(Duration::from_millis(threshold_ms))
+    }
+}
+
+/// Reader packets, backups and vacuum passes are only recorded when `Kinds`
+/// asks for them: a backup taking a minute is what a backup does.
+fn get_default_threshold_ms(kind: OperationKind, threshold_ms: Option<u64>) -> Option<u64> {
+    match kind {
+        OperationKind::Http
+        | OperationKind::Grpc
+        | OperationKind::Persist
+        | OperationKind::Sync => Some(threshold_ms.unwrap_or(DEFAULT_SLOW_OPERATION_THRESHOLD_MS)),
+        OperationKind::Reader | OperationKind::Backup | OperationKind::Vacuum => None,
+    }
+}
+
+/// What is known about an operation besides its name and its table - whatever
+/// the code timing it could tell.
+#[derive(Debug, Clone, Default)]
+pub struct SlowOperationDetails {
+    pub partition_key: Option<String>,
+    pub rows: Option<usize>,
+    pub payload_size: Option<usize>,
+    /// The `session` of the HTTP writer which sent the request.
+    pub session: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SlowOperation {
+    pub date: DateTimeAsMicroseconds,
+    pub kind: OperationKind,
+    pub operation: String,
+    pub namespace: String,
+    pub table_name: String,
+    pub duration: Duration,
+    pub succeeded: bool,
+    pub details: SlowOperationDetails,
+}
+
+/// Latest operations which took longer than their threshold, kept in memory for
+/// `/api/Debug/SlowOperations` and the UI.
+pub struct SlowOperations {
+    items: Mutex<VecDeque<Arc<SlowOperation>>>,
+    max_records: usize,
+}
+
+impl SlowOperations {
+    pub fn new(max_records: usize) -> Self {
+        Self {
+            items: Mutex::new(VecDeque::new()),
+            max_records,
+        }
+    }
+
+    pub fn add(&self, item: SlowOperation) {
+        let mut items = self.items.lock().unwrap();
+
+        items.push_back(Arc::new(item));
+
+        while items.len() > self.max_records {
+            items.pop_front();
+        }
+    }
+
+    /// Latest records, the newest first - of the given kind only, when one is given.
+    pub fn get(&self, kind: Option<OperationKind>, limit: usize) -> Vec<Arc<SlowOperation>> {
+        let items = self.items.lock().unwrap();
+
+        items
+            .iter()
+            .rev()
+            .filter(|itm| kind.is_none() || kind == Some(itm.kind))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thresholds_by_kind() {
+        let settings: SlowOperationsSettings =
+            serde_yaml::from_str("ThresholdMs: 500\nKinds:\n  persist: 0\n  backup: 60000\n")
+                .unwrap();
+
+        assert_eq!(
+            Some(Duration::from_millis(500)),
+            settings.get_threshold(OperationKind::Http)
+        );
+        assert_eq!(None, settings.get_threshold(OperationKind::Persist));
+        assert_eq!(
+            Some(Duration::from_secs(60)),
+            settings.get_threshold(OperationKind::Backup)
+        );
+        assert_eq!(None, settings.get_threshold(OperationKind::Vacuum));
+    }
+
+    #[test]
+    fn test_keeps_latest_records_newest_first() {
+        let slow_operations = SlowOperations::new(2);
+
+        for (kind, operation) in [
+            (OperationKind::Http, "GET /api/Row"),
+            (OperationKind::Persist, "SyncTable"),
+            (OperationKind::Http, "POST /api/Bulk/CleanAndBulkInsert"),
+        ] {
+            slow_operations.add(SlowOperation {
+                date: DateTimeAsMicroseconds::now(),
+                kind,
+                operation: operation.to_string(),
+                namespace: "default".to_string(),
+                table_name: "orders".to_string(),
+                duration: Duration::from_secs(2),
+                succeeded: true,
+                details: SlowOperationDetails::default(),
+            });
+        }
+
+        let items = slow_operations.get(None, 10);
+        assert_eq!(2, items.len());
+        assert_eq!("POST /api/Bulk/CleanAndBulkInsert", items[0].operation);
+        assert_eq!("SyncTable", items[1].operation);
+
+        let http = slow_operations.get(Some(OperationKind::Http), 10);
+        assert_eq!(1, http.len());
+    }
+}
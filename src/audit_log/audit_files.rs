@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_AUDIT_MAX_FILE_SIZE_MB: u64 = 64;
+pub const DEFAULT_AUDIT_RETENTION_DAYS: u64 = 90;
+
+const AUDIT_FILE_PREFIX: &str = "audit-";
+const AUDIT_FILE_EXTENSION: &str = ".jsonl";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuditLogSettings {
+    #[serde(rename = "Folder")]
+    pub folder: String,
+    /// Size the current file grows to before the next one is started.
+    #[serde(rename = "MaxFileSizeMb", default)]
+    pub max_file_size_mb: Option<u64>,
+    /// How long a file is kept once the next one is started. `0` - forever.
+    #[serde(rename = "RetentionDays", default)]
+    pub retention_days: Option<u64>,
+}
+
+impl AuditLogSettings {
+    pub fn get_folder(&self) -> String {
+        my_no_sql_sdk::core::rust_extensions::file_utils::format_path(self.folder.as_str())
+            .to_string()
+    }
+
+    pub fn get_max_file_size(&self) -> u64 {
+        self.max_file_size_mb
+            .unwrap_or(DEFAULT_AUDIT_MAX_FILE_SIZE_MB)
+            * 1024
+            * 1024
+    }
+
+    pub fn get_retention_microseconds(&self) -> Option<i64> {
+        match self.retention_days.unwrap_or(DEFAULT_AUDIT_RETENTION_DAYS) {
+            0 => None,
+            days => Some(days as i64 * 24 * 60 * 60 * 1_000_000),
+        }
+    }
+}
+
+/// Name of the file started at `unix_microseconds`. Zero-padded, so the names sort
+/// the way the files were started.
+pub fn get_audit_file_name(unix_microseconds: i64) -> String {
+    format!(
+        "{}{:020}{}",
+        AUDIT_FILE_PREFIX, unix_microseconds, AUDIT_FILE_EXTENSION
+    )
+}
+
+/// When the file was started. `None` - it is not an audit file.
+pub fn parse_audit_file_name(file_name: &str) -> Option<i64> {
+    file_name
+        .strip_prefix(AUDIT_FILE_PREFIX)?
+        .strip_suffix(AUDIT_FILE_EXTENSION)?
+        .parse()
+        .ok()
+}
+
+/// Files which hold nothing newer than the retention. A file is written until the
+/// next one is started, so it is the start of the next one which tells how fresh
+/// its last record is - and the newest file is never expired.
+pub fn get_expired_audit_files(
+    file_names: &[String],
+    now_microseconds: i64,
+    retention_microseconds: i64,
+) -> Vec<String> {
+    let mut started: Vec<(i64, &String)> = file_names
+        .iter()
+        .filter_map(|name| parse_audit_file_name(name).map(|started| (started, name)))
+        .collect();
+
+    started.sort();
+
+    let mut result = Vec::new();
+
+    for pair in started.windows(2) {
+        let (_, file_name) = pair[0];
+        let (next_started, _) = pair[1];
+
+        if now_microseconds - next_started > retention_microseconds {
+            result.push(file_name.clone());
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_names_round_trip_and_sort() {
+        let older = get_audit_file_name(999);
+        let newer = get_audit_file_name(1_000);
+
+        assert_eq!(Some(999), parse_audit_file_name(older.as_str()));
+        assert!(older < newer);
+        assert_eq!(None, parse_audit_file_name("audit-abc.jsonl"));
+        assert_eq!(None, parse_audit_file_name("other.txt"));
+    }
+
+    #[test]
+    fn test_newest_file_is_never_expired() {
+        let files = vec![
+            get_audit_file_name(300),
+            get_audit_file_name(100),
+            get_audit_file_name(200),
+            "readme.txt".to_string(),
+        ];
+
+        assert_eq!(
+            vec![get_audit_file_name(100)],
+            get_expired_audit_files(&files, 1_000, 750)
+        );
+
+        assert_eq!(
+            vec![get_audit_file_name(100), get_audit_file_name(200)],
+            get_expired_audit_files(&files, 1_000, 10)
+        );
+    }
+}
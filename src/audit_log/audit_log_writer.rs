@@ -0,0 +1,220 @@
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use tokio::{fs::File, io::AsyncWriteExt, sync::Mutex};
+
+use super::*;
+
+struct CurrentAuditFile {
+    file: File,
+    size: u64,
+}
+
+/// Append-only log of the destructive and the administrative operations, kept in
+/// the `AuditLog` folder as JSON lines. One file is written at a time; the next
+/// one is started once it reaches `MaxFileSizeMb`.
+pub struct AuditLog {
+    settings: AuditLogSettings,
+    folder: String,
+    current: Mutex<Option<CurrentAuditFile>>,
+}
+
+impl AuditLog {
+    pub async fn new(settings: AuditLogSettings) -> Result<Self, String> {
+        let folder = settings.get_folder();
+
+        if let Err(err) = tokio::fs::create_dir_all(folder.as_str()).await {
+            return Err(format!(
+                "Can't create audit log folder [{}]. Err: {}",
+                folder, err
+            ));
+        }
+
+        Ok(Self {
+            settings,
+            folder,
+            current: Mutex::new(None),
+        })
+    }
+
+    /// A record which can not be written is reported to the log - the operation it
+    /// describes has already happened.
+    pub async fn write(&self, record: AuditRecord) {
+        let mut line = match serde_json::to_vec(&record) {
+            Ok(line) => line,
+            Err(err) => {
+                write_error(format!("Can not serialize audit record. Err: {}", err));
+                return;
+            }
+        };
+
+        line.push(b'\n');
+
+        let mut current = self.current.lock().await;
+
+        if let Err(err) = self.write_line(&mut current, line.as_slice()).await {
+            write_error(format!("Can not write audit record. Err: {}", err));
+            // The next record starts over with a file it can open.
+            *current = None;
+        }
+    }
+
+    async fn write_line(
+        &self,
+        current: &mut Option<CurrentAuditFile>,
+        line: &[u8],
+    ) -> std::io::Result<()> {
+        let max_file_size = self.settings.get_max_file_size();
+
+        let is_full = match current.as_ref() {
+            Some(current) => current.size > 0 && current.size + line.len() as u64 > max_file_size,
+            None => true,
+        };
+
+        if is_full {
+            *current = Some(self.open_file(current.is_none(), max_file_size).await?);
+        }
+
+        let current = current.as_mut().unwrap();
+        current.file.write_all(line).await?;
+        current.file.flush().await?;
+        current.size += line.len() as u64;
+
+        Ok(())
+    }
+
+    /// After a restart the newest file is appended to, as long as there is room
+    /// in it.
+    async fn open_file(
+        &self,
+        after_restart: bool,
+        max_file_size: u64,
+    ) -> std::io::Result<CurrentAuditFile> {
+        if after_restart {
+            if let Some(file_name) = self.get_file_names().await?.pop() {
+                let path = self.get_path(file_name.as_str());
+                let size = tokio::fs::metadata(path.as_str()).await?.len();
+
+                if size < max_file_size {
+                    let file = tokio::fs::OpenOptions::new()
+                        .append(true)
+                        .open(path.as_str())
+                        .await?;
+
+                    return Ok(CurrentAuditFile { file, size });
+                }
+            }
+        }
+
+        let file_name = get_audit_file_name(DateTimeAsMicroseconds::now().unix_microseconds);
+
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.get_path(file_name.as_str()))
+            .await?;
+
+        Ok(CurrentAuditFile { file, size: 0 })
+    }
+
+    /// Records which match `filter`, the newest first.
+    pub async fn read(
+        &self,
+        filter: &AuditFilter,
+        skip: usize,
+        limit: usize,
+    ) -> std::io::Result<Vec<AuditRecord>> {
+        let mut result = Vec::new();
+        let mut to_skip = skip;
+
+        for file_name in self.get_file_names().await?.into_iter().rev() {
+            let content = match tokio::fs::read(self.get_path(file_name.as_str())).await {
+                Ok(content) => content,
+                // Removed by the GC since it was listed.
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
+            };
+
+            for line in content.split(|b| *b == b'\n').rev() {
+                if line.is_empty() {
+                    continue;
+                }
+
+                // A line which is being written right now is not a record yet.
+                let Ok(record) = serde_json::from_slice::<AuditRecord>(line) else {
+                    continue;
+                };
+
+                if !filter.matches(&record) {
+                    continue;
+                }
+
+                if to_skip > 0 {
+                    to_skip -= 1;
+                    continue;
+                }
+
+                result.push(record);
+
+                if result.len() >= limit {
+                    return Ok(result);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Removes the files older than `RetentionDays`.
+    pub async fn gc(&self, now: DateTimeAsMicroseconds) {
+        let Some(retention) = self.settings.get_retention_microseconds() else {
+            return;
+        };
+
+        let file_names = match self.get_file_names().await {
+            Ok(file_names) => file_names,
+            Err(err) => {
+                write_error(format!("Can not list audit files. Err: {}", err));
+                return;
+            }
+        };
+
+        for file_name in get_expired_audit_files(&file_names, now.unix_microseconds, retention) {
+            if let Err(err) = tokio::fs::remove_file(self.get_path(file_name.as_str())).await {
+                write_error(format!(
+                    "Can not remove audit file {}. Err: {}",
+                    file_name, err
+                ));
+            }
+        }
+    }
+
+    /// Audit files of the folder, the oldest first.
+    async fn get_file_names(&self) -> std::io::Result<Vec<String>> {
+        let mut result = Vec::new();
+
+        let mut read_dir = tokio::fs::read_dir(self.folder.as_str()).await?;
+
+        while let Some(entry) = read_dir.next_entry().await? {
+            if let Some(file_name) = entry.file_name().to_str() {
+                if parse_audit_file_name(file_name).is_some() {
+                    result.push(file_name.to_string());
+                }
+            }
+        }
+
+        result.sort();
+
+        Ok(result)
+    }
+
+    fn get_path(&self, file_name: &str) -> String {
+        if self.folder.ends_with(std::path::MAIN_SEPARATOR) {
+            format!("{}{}", self.folder, file_name)
+        } else {
+            format!("{}{}{}", self.folder, std::path::MAIN_SEPARATOR, file_name)
+        }
+    }
+}
+
+fn write_error(message: String) {
+    my_logger::LOGGER.write_error("AuditLog", message, my_logger::LogEventCtx::new());
+}
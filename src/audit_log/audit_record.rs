@@ -0,0 +1,217 @@
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use serde::{Deserialize, Serialize};
+
+/// Who did it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum AuditActorKind {
+    /// An HTTP writer, by the `session` it got with its ping.
+    Writer,
+    /// A caller which presented a key of `ApiKeysFile`.
+    ApiKey,
+    Mcp,
+    Ui,
+    /// Nothing told who the caller is.
+    Anonymous,
+}
+
+/// One destructive or administrative operation, as one line of the audit file.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRecord {
+    pub timestamp: String,
+    pub actor: AuditActorKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    pub operation: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub partition_keys: Vec<String>,
+    /// How many rows the operation removed or wrote, when it is known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rows: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+impl AuditRecord {
+    pub fn new(operation: &str) -> Self {
+        Self {
+            timestamp: DateTimeAsMicroseconds::now().to_rfc3339(),
+            actor: AuditActorKind::Anonymous,
+            actor_name: None,
+            ip: None,
+            operation: operation.to_string(),
+            namespace: None,
+            table_name: None,
+            partition_keys: Vec::new(),
+            rows: None,
+            details: None,
+        }
+    }
+
+    pub fn with_actor(mut self, actor: AuditActorKind, actor_name: Option<String>) -> Self {
+        self.actor = actor;
+        self.actor_name = actor_name;
+        self
+    }
+
+    pub fn with_ip(mut self, ip: String) -> Self {
+        self.ip = Some(ip);
+        self
+    }
+
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        self.namespace = Some(namespace.to_string());
+        self
+    }
+
+    pub fn with_table(mut self, namespace: &str, table_name: &str) -> Self {
+        self.namespace = Some(namespace.to_string());
+        self.table_name = Some(table_name.to_string());
+        self
+    }
+
+    pub fn with_partition_keys(mut self, partition_keys: impl Iterator<Item = String>) -> Self {
+        self.partition_keys = partition_keys.collect();
+        self
+    }
+
+    pub fn with_rows(mut self, rows: usize) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    pub fn with_details(mut self, details: String) -> Self {
+        self.details = Some(details);
+        self
+    }
+}
+
+/// What `GET /api/Audit` asks for. Every field which is set has to match.
+#[derive(Default)]
+pub struct AuditFilter {
+    /// The kind of the actor or its name.
+    pub actor: Option<String>,
+    pub operation: Option<String>,
+    pub namespace: Option<String>,
+    pub table_name: Option<String>,
+    pub from: Option<DateTimeAsMicroseconds>,
+    pub to: Option<DateTimeAsMicroseconds>,
+}
+
+impl AuditFilter {
+    pub fn matches(&self, record: &AuditRecord) -> bool {
+        if let Some(actor) = self.actor.as_ref() {
+            let kind_matches = serde_json::to_value(&record.actor)
+                .ok()
+                .and_then(|value| value.as_str().map(|kind| kind.eq_ignore_ascii_case(actor)))
+                .unwrap_or(false);
+
+            let name_matches = record
+                .actor_name
+                .as_ref()
+                .map(|name| name.eq_ignore_ascii_case(actor))
+                .unwrap_or(false);
+
+            if !kind_matches && !name_matches {
+                return false;
+            }
+        }
+
+        if !matches_optional(self.operation.as_ref(), Some(&record.operation)) {
+            return false;
+        }
+
+        if !matches_optional(self.namespace.as_ref(), record.namespace.as_ref()) {
+            return false;
+        }
+
+        if !matches_optional(self.table_name.as_ref(), record.table_name.as_ref()) {
+            return false;
+        }
+
+        // Timestamps are written with `to_rfc3339`, in UTC, so they sort as text the
+        // way they sort in time.
+        if let Some(from) = self.from {
+            if record.timestamp.as_str() < from.to_rfc3339().as_str() {
+                return false;
+            }
+        }
+
+        if let Some(to) = self.to {
+            if record.timestamp.as_str() > to.to_rfc3339().as_str() {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+fn matches_optional(expected: Option<&String>, value: Option<&String>) -> bool {
+    match expected {
+        Some(expected) => match value {
+            Some(value) => value.eq_ignore_ascii_case(expected),
+            None => false,
+        },
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_matches_actor_by_kind_or_name() {
+        let record = AuditRecord::new("DeleteTable")
+            .with_actor(AuditActorKind::ApiKey, Some("ops".to_string()))
+            .with_table("default", "orders");
+
+        let by_kind = AuditFilter {
+            actor: Some("apiKey".to_string()),
+            ..Default::default()
+        };
+        assert!(by_kind.matches(&record));
+
+        let by_name = AuditFilter {
+            actor: Some("ops".to_string()),
+            table_name: Some("orders".to_string()),
+            ..Default::default()
+        };
+        assert!(by_name.matches(&record));
+
+        let other = AuditFilter {
+            actor: Some("mcp".to_string()),
+            ..Default::default()
+        };
+        assert!(!other.matches(&record));
+    }
+
+    #[test]
+    fn test_filter_by_time_range() {
+        let mut record = AuditRecord::new("CleanTable");
+        record.timestamp = DateTimeAsMicroseconds::from_str("2024-05-01T10:00:00")
+            .unwrap()
+            .to_rfc3339();
+
+        let inside = AuditFilter {
+            from: DateTimeAsMicroseconds::from_str("2024-05-01T00:00:00"),
+            to: DateTimeAsMicroseconds::from_str("2024-05-02T00:00:00"),
+            ..Default::default()
+        };
+        assert!(inside.matches(&record));
+
+        let after = AuditFilter {
+            from: DateTimeAsMicroseconds::from_str("2024-05-01T11:00:00"),
+            ..Default::default()
+        };
+        assert!(!after.matches(&record));
+    }
+}
@@ -0,0 +1,6 @@
+mod audit_log_writer;
+pub use audit_log_writer::*;
+mod audit_record;
+pub use audit_record::*;
+mod audit_files;
+pub use audit_files::*;
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::rust_extensions::{
+    date_time::DateTimeAsMicroseconds, MyTimerTick, RepeatTimerIteration,
+};
+
+use crate::app::AppContext;
+
+/// Removes the audit files older than `AuditLog.RetentionDays`.
+pub struct GcAuditLogTimer {
+    app: Arc<AppContext>,
+}
+
+impl GcAuditLogTimer {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+#[async_trait::async_trait]
+impl MyTimerTick for GcAuditLogTimer {
+    async fn tick(&self) -> RepeatTimerIteration {
+        if let Some(audit_log) = self.app.audit_log.as_ref() {
+            audit_log.gc(DateTimeAsMicroseconds::now()).await;
+        }
+
+        RepeatTimerIteration::WithInterval
+    }
+}
@@ -1,12 +1,14 @@
-use std::{collections::HashMap, sync::Arc};
+use std::sync::Arc;
 
-use my_logger::LogEventCtx;
 use my_no_sql_sdk::core::rust_extensions::{
     date_time::DateTimeAsMicroseconds, MyTimerTick, RepeatTimerIteration,
 };
 
 use crate::{
-    app::{AppContext, DbNamespace},
+    app::{
+        logs::{LogItem, SystemProcess},
+        AppContext, DbNamespace,
+    },
     db_sync::EventSource,
 };
 
@@ -74,16 +76,23 @@ async fn gc_namespace(app: &AppContext, db_namespace: &Arc<DbNamespace>) {
             )
             .await
             {
-                my_logger::LOGGER.write_error(
-                    "GcPartitions",
-                    format!("{:?}", err),
-                    LogEventCtx::new().add("tableName", table.name.as_str()),
+                app.logs.write(
+                    LogItem::error(
+                        SystemProcess::Gc,
+                        format!("Can not delete expired partitions. Err: {:?}", err),
+                    )
+                    .with_table(db_namespace.name.as_str(), table.name.as_str()),
                 );
             }
         }
 
         if data_to_gc.db_rows.len() > 0 {
-            println!("GcRows: {}", data_to_gc.db_rows.len());
+            app.logs.write(
+                LogItem::info(SystemProcess::Gc, "Deleting expired rows")
+                    .with_table(db_namespace.name.as_str(), table.name.as_str())
+                    .with_context("partitions", data_to_gc.db_rows.len().to_string()),
+            );
+
             if let Err(err) = crate::db_operations::write::bulk_delete(
                 app,
                 db_namespace,
@@ -103,15 +112,13 @@ async fn gc_namespace(app: &AppContext, db_namespace: &Arc<DbNamespace>) {
             )
             .await
             {
-                let mut ctx = HashMap::new();
-
-                ctx.insert("TableName".to_string(), table.name.to_string());
-
-                my_logger::LOGGER.write_error(
-                    "GcRows",
-                    format!("{:?}", err),
-                    LogEventCtx::new().add("tableName", table.name.as_str()),
-                )
+                app.logs.write(
+                    LogItem::error(
+                        SystemProcess::Gc,
+                        format!("Can not delete expired rows. Err: {:?}", err),
+                    )
+                    .with_table(db_namespace.name.as_str(), table.name.as_str()),
+                );
             }
         }
     }
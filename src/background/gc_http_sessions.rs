@@ -4,7 +4,10 @@ use my_no_sql_sdk::core::rust_extensions::{
     date_time::DateTimeAsMicroseconds, MyTimerTick, RepeatTimerIteration,
 };
 
-use crate::app::AppContext;
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext,
+};
 
 pub struct GcHttpSessionsTimer {
     app: Arc<AppContext>,
@@ -22,7 +25,12 @@ impl MyTimerTick for GcHttpSessionsTimer {
         let now = DateTimeAsMicroseconds::now();
 
         for data_reader in self.app.data_readers.get_all().await {
-            data_reader.ping_http_servers(now).await;
+            if let Err(err) = data_reader.ping_http_servers(now).await {
+                self.app.logs.write(
+                    LogItem::error(SystemProcess::Sync, err)
+                        .with_context("name", data_reader.get_name().to_string()),
+                );
+            }
         }
         if let Some(data_readers) = self.app.data_readers.gc_http_sessions(now).await {
             for data_reader in data_readers {
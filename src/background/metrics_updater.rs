@@ -89,6 +89,10 @@ impl MyTimerTick for MetricsUpdater {
 
         self.app.update_persist_amount(persist_amount);
 
+        self.app
+            .metrics
+            .update_fatal_errors_count(self.app.logs.get_fatal_errors_count());
+
         self.app.write_payloads_per_second.one_second_tick();
         self.app.write_bytes_per_second.one_second_tick();
         self.app.writers_traffic.one_second_tick();
@@ -97,6 +101,8 @@ impl MyTimerTick for MetricsUpdater {
             self.app.metrics.update_pending_to_sync(&reader.connection);
 
             reader.connection.one_sec_tick().await;
+
+            crate::operations::data_readers::reinit_caught_up_tables(&self.app, &reader).await;
         }
 
         RepeatTimerIteration::WithInterval
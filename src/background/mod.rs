@@ -1,4 +1,5 @@
 mod backup_timer;
+pub mod gc_audit_log_timer;
 mod gc_backups_timer;
 pub mod gc_bulk_processes;
 pub mod gc_db_rows;
@@ -6,7 +7,10 @@ pub mod gc_http_sessions;
 pub mod gc_multipart;
 pub mod metrics_updater;
 pub mod persist;
+pub mod settings_file_timer;
 pub mod sync;
+pub mod sync_batches_timer;
+pub mod tls_certificates_timer;
 mod vacuum_timer;
 pub use backup_timer::*;
 pub use gc_backups_timer::*;
@@ -0,0 +1,75 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+use my_no_sql_sdk::core::rust_extensions::{file_utils, MyTimerTick, RepeatTimerIteration};
+
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext,
+};
+
+/// Reloads the settings once their file is changed on disk. A file which does not
+/// read is reported once per change, and what was in effect stays so.
+pub struct SettingsFileTimer {
+    app: Arc<AppContext>,
+    file_name: String,
+    file_modified: Mutex<Option<SystemTime>>,
+}
+
+impl SettingsFileTimer {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        let file_name = file_utils::format_path(app.settings_sources.get_file_name()).to_string();
+
+        // As of the start - the settings in effect were read about then.
+        let file_modified = std::fs::metadata(file_name.as_str())
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        Self {
+            app,
+            file_name,
+            file_modified: Mutex::new(file_modified),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl MyTimerTick for SettingsFileTimer {
+    async fn tick(&self) -> RepeatTimerIteration {
+        let file_modified = get_file_modified(self.file_name.as_str()).await;
+
+        {
+            let mut last_modified = self.file_modified.lock().unwrap();
+
+            if *last_modified == file_modified {
+                return RepeatTimerIteration::WithInterval;
+            }
+
+            *last_modified = file_modified;
+        }
+
+        if let Err(err) = crate::operations::reload_settings(self.app.as_ref()).await {
+            self.app.logs.write(
+                LogItem::error(
+                    SystemProcess::Settings,
+                    format!(
+                        "Can not reload the settings, keeping the ones in effect. Err: {}",
+                        err
+                    ),
+                )
+                .with_context("file", self.file_name.clone()),
+            );
+        }
+
+        RepeatTimerIteration::WithInterval
+    }
+}
+
+async fn get_file_modified(file_name: &str) -> Option<SystemTime> {
+    match tokio::fs::metadata(file_name).await {
+        Ok(metadata) => metadata.modified().ok(),
+        Err(_) => None,
+    }
+}
@@ -1,5 +1,9 @@
-use crate::{app::AppContext, db_sync::NamespaceSyncEvent};
+use crate::{
+    app::{AppContext, OperationKind, OperationTimer},
+    db_sync::NamespaceSyncEvent,
+};
 use my_no_sql_sdk::core::rust_extensions::events_loop::EventsLoopTick;
+use opentelemetry::{context::FutureExt, trace::SpanKind};
 use std::sync::Arc;
 
 pub struct SyncEventLoop {
@@ -16,7 +20,30 @@ impl SyncEventLoop {
 impl EventsLoopTick<NamespaceSyncEvent> for SyncEventLoop {
     async fn started(&self) {}
     async fn tick(&self, model: NamespaceSyncEvent) {
-        crate::operations::sync::sync(&self.app, &model).await;
+        let model = self.app.sync_history.add(model);
+
+        let timer = OperationTimer::new(
+            &self.app,
+            OperationKind::Sync,
+            model.event.get_name(),
+            model.namespace.as_str(),
+            model.event.get_table_name(),
+        );
+
+        let span = crate::telemetry::start_span_with_parent(
+            "operations::sync",
+            &crate::telemetry::as_parent(model.trace_parent.as_ref()),
+            SpanKind::Consumer,
+        );
+        span.set_attribute("db.namespace", model.namespace.as_str().to_string());
+        span.set_attribute("db.table", model.event.get_table_name().to_string());
+        span.set_attribute("sync.event", model.event.get_name());
+
+        crate::operations::sync::sync(&self.app, &model)
+            .with_context(span.context())
+            .await;
+
+        timer.succeeded();
     }
 
     async fn finished(&self) {}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::rust_extensions::{MyTimerTick, RepeatTimerIteration};
+
+use crate::app::AppContext;
+
+pub struct SyncBatchesTimer {
+    app: Arc<AppContext>,
+}
+
+impl SyncBatchesTimer {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+#[async_trait::async_trait]
+impl MyTimerTick for SyncBatchesTimer {
+    async fn tick(&self) -> RepeatTimerIteration {
+        crate::operations::sync::flush_sync_batches(&self.app);
+        RepeatTimerIteration::WithInterval
+    }
+}
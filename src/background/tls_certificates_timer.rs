@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::rust_extensions::{MyTimerTick, RepeatTimerIteration};
+
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext,
+};
+
+/// Picks up the certificate, the key and the client CA once they are replaced on
+/// disk. Connections which are already open keep what they were accepted with.
+pub struct TlsCertificatesTimer {
+    app: Arc<AppContext>,
+}
+
+impl TlsCertificatesTimer {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+#[async_trait::async_trait]
+impl MyTimerTick for TlsCertificatesTimer {
+    async fn tick(&self) -> RepeatTimerIteration {
+        let Some(tls) = self.app.tls.as_ref() else {
+            return RepeatTimerIteration::WithInterval;
+        };
+
+        match tls.reload_if_changed().await {
+            Ok(true) => {
+                self.app.logs.write(LogItem::info(
+                    SystemProcess::Tls,
+                    "TLS certificates are reloaded",
+                ));
+            }
+            Ok(false) => {}
+            Err(err) => {
+                self.app.logs.write(LogItem::error(
+                    SystemProcess::Tls,
+                    format!(
+                        "Can not reload TLS certificates, keeping the loaded ones. Err: {}",
+                        err
+                    ),
+                ));
+            }
+        }
+
+        RepeatTimerIteration::WithInterval
+    }
+}
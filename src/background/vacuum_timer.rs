@@ -7,7 +7,10 @@ use my_no_sql_sdk::core::rust_extensions::{
     date_time::DateTimeAsMicroseconds, MyTimerTick, RepeatTimerIteration,
 };
 
-use crate::app::AppContext;
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext, OperationKind, OperationTimer,
+};
 
 /// How often the persistence backend is compacted.
 const VACUUM_INTERVAL_SECS: u64 = 60 * 60;
@@ -48,13 +51,31 @@ impl MyTimerTick for VacuumTimer {
             return RepeatTimerIteration::WithInterval;
         }
 
-        println!("Running persistence vacuum...");
         for db_namespace in self.app.namespaces.get_all() {
+            self.app.logs.write(
+                LogItem::info(SystemProcess::Vacuum, "Running persistence vacuum")
+                    .with_namespace(db_namespace.name.as_str()),
+            );
+
+            let timer = OperationTimer::new(
+                &self.app,
+                OperationKind::Vacuum,
+                "Vacuum",
+                db_namespace.name.as_str(),
+                "",
+            );
+
             db_namespace.repo.vacuum().await;
+
+            timer.succeeded();
         }
         self.last_vacuum_unix_micros
             .store(now.unix_microseconds, Ordering::Relaxed);
-        println!("Persistence vacuum completed");
+
+        self.app.logs.write(LogItem::info(
+            SystemProcess::Vacuum,
+            "Persistence vacuum completed",
+        ));
 
         RepeatTimerIteration::WithInterval
     }
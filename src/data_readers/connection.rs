@@ -1,10 +1,15 @@
 use std::sync::Arc;
 
-use super::{http_connection::HttpConnectionInfo, tcp_connection::TcpConnectionInfo};
+use super::{
+    grpc_connection::GrpcConnectionInfo, http_connection::HttpConnectionInfo,
+    tcp_connection::TcpConnectionInfo, web_socket_connection::WebSocketConnectionInfo,
+};
 
 pub enum DataReaderConnection {
     Tcp(Arc<TcpConnectionInfo>),
     Http(HttpConnectionInfo),
+    WebSocket(WebSocketConnectionInfo),
+    Grpc(GrpcConnectionInfo),
 }
 
 impl DataReaderConnection {
@@ -12,6 +17,8 @@ impl DataReaderConnection {
         match self {
             DataReaderConnection::Tcp(tcp_info) => tcp_info.get_name(),
             DataReaderConnection::Http(http_info) => http_info.get_name(),
+            DataReaderConnection::WebSocket(web_socket_info) => web_socket_info.get_name(),
+            DataReaderConnection::Grpc(grpc_info) => grpc_info.get_name(),
         }
     }
 
@@ -44,6 +51,10 @@ impl DataReaderConnection {
         match self {
             DataReaderConnection::Tcp(tcp_info) => tcp_info.timer_1sec_tick().await,
             DataReaderConnection::Http(_) => {}
+            DataReaderConnection::WebSocket(web_socket_info) => {
+                web_socket_info.timer_1sec_tick().await
+            }
+            DataReaderConnection::Grpc(grpc_info) => grpc_info.timer_1sec_tick().await,
         }
     }
 }
@@ -57,6 +68,10 @@ impl crate::app::UpdatePendingToSyncModel for DataReaderConnection {
         match self {
             DataReaderConnection::Tcp(tcp_info) => tcp_info.get_pending_to_send(),
             DataReaderConnection::Http(http_info) => http_info.get_pending_to_send(),
+            DataReaderConnection::WebSocket(web_socket_info) => {
+                web_socket_info.get_pending_to_send()
+            }
+            DataReaderConnection::Grpc(grpc_info) => grpc_info.get_pending_to_send(),
         }
     }
 }
@@ -1,5 +1,6 @@
+use std::collections::BTreeMap;
 use std::sync::{
-    atomic::{AtomicBool, Ordering},
+    atomic::{AtomicBool, AtomicUsize, Ordering},
     Arc,
 };
 
@@ -8,7 +9,7 @@ use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 use my_no_sql_sdk::server::DbTable;
 use tokio::sync::RwLock;
 
-use super::{DataReaderConnection, DataReaderUpdatableData};
+use super::{DataReaderConnection, DataReaderUpdatableData, PartitionScope, ReaderCredential};
 
 pub struct DataReadeMetrics {
     pub session_id: String,
@@ -29,6 +30,14 @@ pub struct DataReader {
     /// first subscription; a connection which never sends the packet stays in
     /// the default namespace, which is what every pre-namespace reader does.
     namespace: std::sync::RwLock<DbNamespaceName>,
+    /// Tables the reader is too far behind in to be sent their changes. The value
+    /// is whether the `InitTable` which catches it up is already dispatched.
+    tables_behind: std::sync::Mutex<BTreeMap<String, bool>>,
+    reinits: AtomicUsize,
+    disconnecting: AtomicBool,
+    /// What the reader authenticated with. `None` until it presents a credential
+    /// the `ReaderAclFile` knows.
+    credential: std::sync::RwLock<Option<Arc<ReaderCredential>>>,
 }
 
 impl DataReader {
@@ -39,6 +48,10 @@ impl DataReader {
             connection,
             has_first_init: AtomicBool::new(false),
             namespace: std::sync::RwLock::new(DbNamespaceName::default()),
+            tables_behind: std::sync::Mutex::new(BTreeMap::new()),
+            reinits: AtomicUsize::new(0),
+            disconnecting: AtomicBool::new(false),
+            credential: std::sync::RwLock::new(None),
         }
     }
 
@@ -70,6 +83,14 @@ impl DataReader {
         Ok(())
     }
 
+    pub fn get_credential(&self) -> Option<Arc<ReaderCredential>> {
+        self.credential.read().unwrap().clone()
+    }
+
+    pub fn set_credential(&self, credential: Arc<ReaderCredential>) {
+        *self.credential.write().unwrap() = Some(credential);
+    }
+
     pub fn has_first_init(&self) -> bool {
         self.has_first_init.load(Ordering::Relaxed)
     }
@@ -78,6 +99,57 @@ impl DataReader {
         self.has_first_init.store(true, Ordering::SeqCst);
     }
 
+    /// `true` for the first caller only - the one which is to disconnect the reader.
+    pub fn set_disconnecting(&self) -> bool {
+        !self.disconnecting.swap(true, Ordering::SeqCst)
+    }
+
+    pub fn is_disconnecting(&self) -> bool {
+        self.disconnecting.load(Ordering::Relaxed)
+    }
+
+    pub fn mark_table_behind(&self, table_name: &str) {
+        let mut tables_behind = self.tables_behind.lock().unwrap();
+
+        if !tables_behind.contains_key(table_name) {
+            tables_behind.insert(table_name.to_string(), false);
+            self.reinits.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    pub fn is_table_behind(&self, table_name: &str) -> bool {
+        self.tables_behind.lock().unwrap().contains_key(table_name)
+    }
+
+    /// Tables the reader is behind in and which have no `InitTable` dispatched yet.
+    /// They are marked as dispatched - whoever takes them dispatches it.
+    pub fn take_tables_to_reinit(&self) -> Vec<String> {
+        let mut result = Vec::new();
+
+        for (table_name, dispatched) in self.tables_behind.lock().unwrap().iter_mut() {
+            if !*dispatched {
+                *dispatched = true;
+                result.push(table_name.to_string());
+            }
+        }
+
+        result
+    }
+
+    pub fn get_tables_behind(&self) -> Vec<String> {
+        self.tables_behind.lock().unwrap().keys().cloned().collect()
+    }
+
+    /// The reader got the table in full and is up to date with it again.
+    pub fn set_table_caught_up(&self, table_name: &str) {
+        self.tables_behind.lock().unwrap().remove(table_name);
+    }
+
+    /// How many times the reader was found too far behind in a table.
+    pub fn get_reinits(&self) -> usize {
+        self.reinits.load(Ordering::Relaxed)
+    }
+
     pub async fn has_table(&self, table_name: &str) -> bool {
         let read_access = self.data.read().await;
         read_access.has_table(table_name)
@@ -98,9 +170,55 @@ impl DataReader {
         self.connection.get_name()
     }
 
-    pub async fn subscribe(&self, db_table: &Arc<DbTable>) {
+    pub async fn subscribe(
+        &self,
+        db_table: &Arc<DbTable>,
+        scope: Option<PartitionScope>,
+        sequenced: bool,
+    ) {
+        let mut write_access = self.data.write().await;
+        write_access.subscribe(db_table, scope, sequenced);
+    }
+
+    /// Whether the reader is sent the sequence of every event of the table - the
+    /// TCP contracts have no field for it, HTTP and gRPC readers always get it.
+    pub async fn is_sequenced(&self, table_name: &str) -> bool {
+        let read_access = self.data.read().await;
+        read_access.is_sequenced(table_name)
+    }
+
+    pub async fn get_table(&self, table_name: &str) -> Option<Arc<DbTable>> {
+        let read_access = self.data.read().await;
+        read_access.get_table(table_name)
+    }
+
+    /// `None` when the reader gets the whole table.
+    pub async fn get_partition_scope(&self, table_name: &str) -> Option<PartitionScope> {
+        let read_access = self.data.read().await;
+        read_access.get_partition_scope(table_name).cloned()
+    }
+
+    /// Changes the partitions of a subscribed table the reader gets. Adding to the
+    /// scope of a reader which gets the whole table narrows it down to just what is
+    /// added; `whole_table` drops the scope altogether. Returns the table, so the
+    /// reader can be re-initialized with what it is now supposed to hold.
+    pub async fn update_partition_scope(
+        &self,
+        table_name: &str,
+        update: impl FnOnce(&mut PartitionScope),
+        whole_table: bool,
+    ) -> Option<Arc<DbTable>> {
         let mut write_access = self.data.write().await;
-        write_access.subscribe(db_table);
+
+        let db_table = write_access.get_table(table_name)?;
+
+        if whole_table {
+            write_access.remove_partition_scope(table_name);
+        } else {
+            update(write_access.get_partition_scope_mut(table_name)?);
+        }
+
+        Some(db_table)
     }
 
     pub async fn unsubscribe(&self, table_name: &str) {
@@ -112,6 +230,8 @@ impl DataReader {
         match &self.connection {
             DataReaderConnection::Tcp(connection) => connection.get_ip(),
             DataReaderConnection::Http(connection) => connection.ip.to_string(),
+            DataReaderConnection::WebSocket(connection) => connection.get_ip(),
+            DataReaderConnection::Grpc(connection) => connection.ip.to_string(),
         }
     }
 
@@ -119,6 +239,8 @@ impl DataReader {
         match &self.connection {
             DataReaderConnection::Tcp(connection) => connection.connection_statistics().connected,
             DataReaderConnection::Http(connection) => connection.connected,
+            DataReaderConnection::WebSocket(connection) => connection.connected,
+            DataReaderConnection::Grpc(connection) => connection.connected,
         }
     }
 
@@ -131,6 +253,12 @@ impl DataReader {
             DataReaderConnection::Http(connection) => {
                 connection.last_incoming_moment.as_date_time()
             }
+            DataReaderConnection::WebSocket(connection) => {
+                connection.last_incoming_moment.as_date_time()
+            }
+            DataReaderConnection::Grpc(connection) => {
+                connection.last_incoming_moment.as_date_time()
+            }
         }
     }
 
@@ -161,6 +289,8 @@ impl DataReader {
         match &self.connection {
             DataReaderConnection::Tcp(tcp_connection) => tcp_connection.is_node(),
             DataReaderConnection::Http(_) => false,
+            DataReaderConnection::WebSocket(connection) => connection.is_node(),
+            DataReaderConnection::Grpc(_) => false,
         }
     }
 
@@ -168,24 +298,53 @@ impl DataReader {
         match &self.connection {
             DataReaderConnection::Tcp(connection) => connection.get_pending_to_send(),
             DataReaderConnection::Http(connection) => connection.get_pending_to_send(),
+            DataReaderConnection::WebSocket(connection) => connection.get_pending_to_send(),
+            DataReaderConnection::Grpc(connection) => connection.get_pending_to_send(),
+        }
+    }
+
+    /// Drops the payloads of a table queued for the reader, once it is to get the table
+    /// in full again. Only an HTTP reader's are still the server's to drop: the TCP and
+    /// WebSocket ones are in the buffer of the socket, and the gRPC ones in the channel
+    /// of the stream, in order with those of the other tables.
+    pub async fn drop_pending_of_table(&self, table_name: &str) {
+        if let DataReaderConnection::Http(connection) = &self.connection {
+            connection.drop_pending_of_table(table_name).await;
         }
     }
 
-    pub async fn ping_http_servers(&self, now: DateTimeAsMicroseconds) {
+    /// Payloads queued for the reader and not delivered yet.
+    pub fn get_pending_events(&self) -> usize {
+        match &self.connection {
+            DataReaderConnection::Tcp(connection) => connection.get_pending_events(),
+            DataReaderConnection::Http(connection) => connection.get_pending_events(),
+            DataReaderConnection::WebSocket(connection) => connection.get_pending_events(),
+            DataReaderConnection::Grpc(connection) => connection.get_pending_events(),
+        }
+    }
+
+    pub async fn ping_http_servers(&self, now: DateTimeAsMicroseconds) -> Result<(), String> {
         if let DataReaderConnection::Http(info) = &self.connection {
-            info.ping(now).await;
+            return info.ping(now).await;
         }
+
+        Ok(())
     }
 
     pub async fn get_sent_per_second(&self) -> Vec<usize> {
         match &self.connection {
             DataReaderConnection::Tcp(tcp) => tcp.sent_per_second.get_snapshot().await,
             DataReaderConnection::Http(_) => vec![],
+            DataReaderConnection::WebSocket(web_socket) => {
+                web_socket.sent_per_second.get_snapshot().await
+            }
+            DataReaderConnection::Grpc(grpc) => grpc.sent_per_second.get_snapshot().await,
         }
     }
 
     // Returns current (incoming, outgoing) bytes-per-second rates.
-    // HTTP readers do not track traffic rate, so they report (0, 0).
+    // HTTP readers do not track traffic rate, so they report (0, 0); gRPC readers
+    // report the outgoing rate only.
     pub fn get_traffic_per_second(&self) -> (usize, usize) {
         match &self.connection {
             DataReaderConnection::Tcp(tcp) => {
@@ -196,6 +355,8 @@ impl DataReader {
                 )
             }
             DataReaderConnection::Http(_) => (0, 0),
+            DataReaderConnection::WebSocket(web_socket) => web_socket.get_traffic_per_second(),
+            DataReaderConnection::Grpc(grpc) => (0, grpc.get_last_sent_per_second()),
         }
     }
 }
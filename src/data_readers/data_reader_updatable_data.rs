@@ -1,31 +1,72 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
 use my_no_sql_sdk::server::DbTable;
 
+use super::PartitionScope;
+
 pub struct DataReaderUpdatableData {
     tables: BTreeMap<String, Arc<DbTable>>,
+    partition_scopes: BTreeMap<String, PartitionScope>,
+    /// Tables subscribed to with `fromSequence` - their events carry the sequence.
+    sequenced: BTreeSet<String>,
 }
 
 impl DataReaderUpdatableData {
     pub fn new() -> Self {
         Self {
             tables: BTreeMap::new(),
+            partition_scopes: BTreeMap::new(),
+            sequenced: BTreeSet::new(),
         }
     }
 
-    pub fn subscribe(&mut self, db_table_wrapper: &Arc<DbTable>) {
-        self.tables
-            .insert(db_table_wrapper.name.to_string(), db_table_wrapper.clone());
+    pub fn subscribe(
+        &mut self,
+        db_table_wrapper: &Arc<DbTable>,
+        scope: Option<PartitionScope>,
+        sequenced: bool,
+    ) {
+        let table_name = db_table_wrapper.name.to_string();
+
+        if sequenced {
+            self.sequenced.insert(table_name.clone());
+        } else {
+            self.sequenced.remove(table_name.as_str());
+        }
+
+        match scope {
+            Some(scope) => {
+                self.partition_scopes.insert(table_name.clone(), scope);
+            }
+            None => {
+                self.partition_scopes.remove(table_name.as_str());
+            }
+        }
+
+        self.tables.insert(table_name, db_table_wrapper.clone());
     }
 
     pub fn unsubscribe(&mut self, table_name: &str) {
         self.tables.remove(table_name);
+        self.partition_scopes.remove(table_name);
+        self.sequenced.remove(table_name);
+    }
+
+    pub fn is_sequenced(&self, table_name: &str) -> bool {
+        self.sequenced.contains(table_name)
     }
 
     pub fn has_table(&self, table_name: &str) -> bool {
         self.tables.contains_key(table_name)
     }
 
+    pub fn get_table(&self, table_name: &str) -> Option<Arc<DbTable>> {
+        self.tables.get(table_name).cloned()
+    }
+
     pub fn has_any_subscription(&self) -> bool {
         !self.tables.is_empty()
     }
@@ -33,4 +74,27 @@ impl DataReaderUpdatableData {
     pub fn get_table_names(&self) -> Vec<String> {
         self.tables.keys().map(|id| id.to_string()).collect()
     }
+
+    pub fn get_partition_scope(&self, table_name: &str) -> Option<&PartitionScope> {
+        self.partition_scopes.get(table_name)
+    }
+
+    /// Scope of a table the reader is subscribed to, created empty when the reader
+    /// used to get the whole table.
+    pub fn get_partition_scope_mut(&mut self, table_name: &str) -> Option<&mut PartitionScope> {
+        if !self.tables.contains_key(table_name) {
+            return None;
+        }
+
+        if !self.partition_scopes.contains_key(table_name) {
+            self.partition_scopes
+                .insert(table_name.to_string(), PartitionScope::new());
+        }
+
+        self.partition_scopes.get_mut(table_name)
+    }
+
+    pub fn remove_partition_scope(&mut self, table_name: &str) {
+        self.partition_scopes.remove(table_name);
+    }
 }
@@ -8,6 +8,8 @@ use super::{DataReader, DataReaderConnection};
 pub struct DataReadersData {
     tcp: BTreeMap<i32, Arc<DataReader>>,
     http: BTreeMap<String, Arc<DataReader>>,
+    web_socket: BTreeMap<i64, Arc<DataReader>>,
+    grpc: BTreeMap<i64, Arc<DataReader>>,
     all: BTreeMap<String, Arc<DataReader>>,
     id: usize,
 }
@@ -18,6 +20,8 @@ impl DataReadersData {
             tcp: BTreeMap::new(),
             all: BTreeMap::new(),
             http: BTreeMap::new(),
+            web_socket: BTreeMap::new(),
+            grpc: BTreeMap::new(),
             id: 0,
         }
     }
@@ -40,6 +44,14 @@ impl DataReadersData {
             DataReaderConnection::Http(connection) => {
                 self.http.insert(connection.id.to_string(), data_reader);
             }
+
+            DataReaderConnection::WebSocket(connection) => {
+                self.web_socket.insert(connection.get_id(), data_reader);
+            }
+
+            DataReaderConnection::Grpc(connection) => {
+                self.grpc.insert(connection.get_id(), data_reader);
+            }
         }
     }
 
@@ -53,6 +65,16 @@ impl DataReadersData {
         result.clone().into()
     }
 
+    pub fn get_web_socket(&self, web_socket_id: i64) -> Option<Arc<DataReader>> {
+        let result = self.web_socket.get(&web_socket_id)?;
+        result.clone().into()
+    }
+
+    pub fn get_grpc(&self, connection_id: i64) -> Option<Arc<DataReader>> {
+        let result = self.grpc.get(&connection_id)?;
+        result.clone().into()
+    }
+
     pub fn remove_tcp(&mut self, connection_id: i32) -> Option<Arc<DataReader>> {
         if let Some(removed_connection) = self.tcp.remove(&connection_id) {
             return self.all.remove(&removed_connection.id);
@@ -61,6 +83,22 @@ impl DataReadersData {
         None
     }
 
+    pub fn remove_web_socket(&mut self, web_socket_id: i64) -> Option<Arc<DataReader>> {
+        if let Some(removed_connection) = self.web_socket.remove(&web_socket_id) {
+            return self.all.remove(&removed_connection.id);
+        }
+
+        None
+    }
+
+    pub fn remove_grpc(&mut self, connection_id: i64) -> Option<Arc<DataReader>> {
+        if let Some(removed_connection) = self.grpc.remove(&connection_id) {
+            return self.all.remove(&removed_connection.id);
+        }
+
+        None
+    }
+
     pub fn remove_http(&mut self, data_reader: &DataReader) {
         if let DataReaderConnection::Http(connection) = &data_reader.connection {
             if let Some(removed_connection) = self.http.remove(connection.id.as_str()) {
@@ -97,6 +135,24 @@ impl DataReadersData {
         result
     }
 
+    /// TCP readers nothing came from for `timeout` - the client library pings every
+    /// few seconds, so such a connection is half-open.
+    pub fn get_dead_tcp_connections(
+        &self,
+        now: DateTimeAsMicroseconds,
+        timeout: Duration,
+    ) -> Vec<Arc<DataReader>> {
+        self.tcp
+            .values()
+            .filter(|data_reader| {
+                now.duration_since(data_reader.get_last_incoming_moment())
+                    .as_positive_or_zero()
+                    >= timeout
+            })
+            .cloned()
+            .collect()
+    }
+
     pub fn gc_http_sessions(
         &mut self,
         now: DateTimeAsMicroseconds,
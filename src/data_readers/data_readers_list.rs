@@ -4,11 +4,15 @@ use my_no_sql_sdk::core::db::DbNamespaceName;
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 use tokio::sync::RwLock;
 
+use my_http_server::web_sockets::MyWebSocket;
+
 use crate::tcp::MyNoSqlTcpConnection;
 
 use super::{
+    grpc_connection::{GrpcConnectionInfo, GrpcEventsSender},
     http_connection::HttpConnectionInfo,
     tcp_connection::{ReaderName, TcpConnectionInfo},
+    web_socket_connection::WebSocketConnectionInfo,
     DataReader, DataReaderConnection, DataReadersData,
 };
 
@@ -29,11 +33,12 @@ impl DataReadersList {
         &self,
         tcp_connection: Arc<MyNoSqlTcpConnection>,
         name: ReaderName,
+        ip: String,
         compress_data: bool,
     ) {
         let id = format!("Tcp-{}", tcp_connection.id);
 
-        let connection_info = TcpConnectionInfo::new(tcp_connection, name, compress_data);
+        let connection_info = TcpConnectionInfo::new(tcp_connection, name, ip, compress_data);
 
         let connection_info = Arc::new(connection_info);
 
@@ -43,6 +48,39 @@ impl DataReadersList {
         write_lock.insert(Arc::new(data_reader));
     }
 
+    pub async fn add_web_socket(
+        &self,
+        web_socket: Arc<MyWebSocket>,
+        name: ReaderName,
+        compress_data: bool,
+    ) {
+        let id = format!("Ws-{}", web_socket.id);
+
+        let connection_info = WebSocketConnectionInfo::new(web_socket, name, compress_data);
+
+        let mut write_lock = self.data.write().await;
+
+        let data_reader = DataReader::new(id, DataReaderConnection::WebSocket(connection_info));
+        write_lock.insert(Arc::new(data_reader));
+    }
+
+    pub async fn add_grpc(
+        &self,
+        connection_id: i64,
+        name: ReaderName,
+        ip: String,
+        sender: GrpcEventsSender,
+    ) {
+        let id = format!("Grpc-{}", connection_id);
+
+        let connection_info = GrpcConnectionInfo::new(connection_id, name, ip, sender);
+
+        let mut write_lock = self.data.write().await;
+
+        let data_reader = DataReader::new(id, DataReaderConnection::Grpc(connection_info));
+        write_lock.insert(Arc::new(data_reader));
+    }
+
     pub async fn add_http(&self, name: String, version: String, ip: String) -> Arc<DataReader> {
         let mut write_lock = self.data.write().await;
         let id = format!("Http-{}", write_lock.get_next_id());
@@ -64,6 +102,16 @@ impl DataReadersList {
         read_lock.get_tcp(tcp_connection.id)
     }
 
+    pub async fn get_web_socket(&self, web_socket_id: i64) -> Option<Arc<DataReader>> {
+        let read_lock = self.data.read().await;
+        read_lock.get_web_socket(web_socket_id)
+    }
+
+    pub async fn get_grpc(&self, connection_id: i64) -> Option<Arc<DataReader>> {
+        let read_lock = self.data.read().await;
+        read_lock.get_grpc(connection_id)
+    }
+
     pub async fn get_http(&self, session_id: &str) -> Option<Arc<DataReader>> {
         let read_lock = self.data.read().await;
         read_lock.get_http(session_id)
@@ -78,6 +126,21 @@ impl DataReadersList {
         write_lock.remove_tcp(tcp_connection.id)
     }
 
+    pub async fn remove_web_socket(&self, web_socket_id: i64) -> Option<Arc<DataReader>> {
+        let mut write_lock = self.data.write().await;
+        write_lock.remove_web_socket(web_socket_id)
+    }
+
+    pub async fn remove_grpc(&self, connection_id: i64) -> Option<Arc<DataReader>> {
+        let mut write_lock = self.data.write().await;
+        write_lock.remove_grpc(connection_id)
+    }
+
+    pub async fn remove_http(&self, data_reader: &DataReader) {
+        let mut write_lock = self.data.write().await;
+        write_lock.remove_http(data_reader);
+    }
+
     pub async fn get_all(&self) -> Vec<Arc<DataReader>> {
         let read_lock = self.data.read().await;
         read_lock.get_all()
@@ -94,6 +157,15 @@ impl DataReadersList {
             .await
     }
 
+    pub async fn get_dead_connections(
+        &self,
+        now: DateTimeAsMicroseconds,
+        timeout: Duration,
+    ) -> Vec<Arc<DataReader>> {
+        let read_access = self.data.read().await;
+        read_access.get_dead_tcp_connections(now, timeout)
+    }
+
     pub async fn gc_http_sessions(
         &self,
         now: DateTimeAsMicroseconds,
@@ -0,0 +1,141 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use my_no_sql_sdk::core::rust_extensions::date_time::{
+    AtomicDateTimeAsMicroseconds, DateTimeAsMicroseconds,
+};
+use prost::Message;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::data_readers::tcp_connection::{ReaderName, SendPerSecond};
+use crate::data_readers::PendingToSend;
+use crate::mynosqlserver_grpc::ReaderGrpcEvent;
+
+/// Sending half of a `Reader.Subscribe` response stream. Counts what is queued
+/// and not yet taken by the stream, which is what the reader is behind by.
+///
+/// `None` in the channel is the end of the stream.
+#[derive(Clone)]
+pub struct GrpcEventsSender {
+    sender: UnboundedSender<Option<ReaderGrpcEvent>>,
+    pending_to_send: Arc<PendingToSend>,
+}
+
+impl GrpcEventsSender {
+    pub fn new(sender: UnboundedSender<Option<ReaderGrpcEvent>>) -> Self {
+        Self {
+            sender,
+            pending_to_send: Arc::new(PendingToSend::new()),
+        }
+    }
+
+    /// `false` once the client is gone.
+    pub fn send(&self, event: ReaderGrpcEvent) -> bool {
+        let size = event.encoded_len();
+        self.pending_to_send.add(size);
+
+        if self.sender.send(Some(event)).is_err() {
+            self.pending_to_send.remove(size);
+            return false;
+        }
+
+        true
+    }
+
+    /// Ends the stream after what is already queued, and the error saying why.
+    pub fn close(&self, message: String) {
+        self.send(super::grpc_payload_to_send::new_error(message));
+        let _ = self.sender.send(None);
+    }
+
+    /// Called by the response stream for every event it takes.
+    pub fn mark_sent(&self, event: &ReaderGrpcEvent) {
+        self.pending_to_send.remove(event.encoded_len());
+    }
+
+    pub fn get_pending_to_send(&self) -> usize {
+        self.pending_to_send.get_size()
+    }
+
+    pub fn get_pending_events(&self) -> usize {
+        self.pending_to_send.get_amount()
+    }
+}
+
+/// Reader subscribed over the `Reader` gRPC service.
+pub struct GrpcConnectionInfo {
+    id: i64,
+    pub name: ReaderName,
+    pub ip: String,
+    pub connected: DateTimeAsMicroseconds,
+    pub last_incoming_moment: AtomicDateTimeAsMicroseconds,
+    sender: GrpcEventsSender,
+    sent_per_second_accumulator: AtomicUsize,
+    last_sent_per_second: AtomicUsize,
+    pub sent_per_second: SendPerSecond,
+}
+
+impl GrpcConnectionInfo {
+    pub fn new(id: i64, name: ReaderName, ip: String, sender: GrpcEventsSender) -> Self {
+        Self {
+            id,
+            name,
+            ip,
+            connected: DateTimeAsMicroseconds::now(),
+            last_incoming_moment: AtomicDateTimeAsMicroseconds::now(),
+            sender,
+            sent_per_second_accumulator: AtomicUsize::new(0),
+            last_sent_per_second: AtomicUsize::new(0),
+            sent_per_second: SendPerSecond::new(),
+        }
+    }
+
+    pub fn get_id(&self) -> i64 {
+        self.id
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name.get_name()
+    }
+
+    pub fn send(&self, events: Vec<ReaderGrpcEvent>) {
+        let mut sent_amount = 0;
+
+        for event in events {
+            let size = event.encoded_len();
+
+            if !self.sender.send(event) {
+                break;
+            }
+
+            sent_amount += size;
+        }
+
+        self.sent_per_second_accumulator
+            .fetch_add(sent_amount, Ordering::SeqCst);
+    }
+
+    pub fn disconnect(&self, reason: String) {
+        self.sender.close(reason);
+    }
+
+    pub async fn timer_1sec_tick(&self) {
+        let value = self.sent_per_second_accumulator.swap(0, Ordering::SeqCst);
+        self.last_sent_per_second.store(value, Ordering::Relaxed);
+        self.sent_per_second.add(value).await;
+    }
+
+    pub fn get_last_sent_per_second(&self) -> usize {
+        self.last_sent_per_second.load(Ordering::Relaxed)
+    }
+
+    pub fn get_pending_to_send(&self) -> usize {
+        self.sender.get_pending_to_send()
+    }
+
+    pub fn get_pending_events(&self) -> usize {
+        self.sender.get_pending_events()
+    }
+}
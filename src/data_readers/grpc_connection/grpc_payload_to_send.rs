@@ -0,0 +1,164 @@
+use my_no_sql_sdk::core::my_json::consts::EMPTY_ARRAY;
+
+use crate::data_readers::PartitionScope;
+use crate::db_sync::SyncEvent;
+use crate::mynosqlserver_grpc::{DeletedRowGrpcModel, ReaderEventType, ReaderGrpcEvent};
+
+/// The events a `Reader.Subscribe` stream carries for one sync event - the same
+/// split the TCP contracts have. With a `scope` only the partitions in it are sent.
+/// Every one of them carries the `sequence` of the sync event.
+pub fn serialize(
+    sync_event: &SyncEvent,
+    scope: Option<&PartitionScope>,
+    sequence: Option<u64>,
+) -> Vec<ReaderGrpcEvent> {
+    let mut result = serialize_events(sync_event, scope);
+
+    for event in result.iter_mut() {
+        event.sequence = sequence;
+    }
+
+    result
+}
+
+fn serialize_events(
+    sync_event: &SyncEvent,
+    scope: Option<&PartitionScope>,
+) -> Vec<ReaderGrpcEvent> {
+    match sync_event {
+        SyncEvent::TableFirstInit(sync_data) => {
+            let table_snapshot = sync_data.db_table.get_table_snapshot();
+
+            vec![new_event(
+                ReaderEventType::InitTable,
+                sync_data.db_table.name.as_str(),
+                crate::data_readers::table_snapshot_as_json(&table_snapshot, scope).into_bytes(),
+            )]
+        }
+        SyncEvent::UpdateTableAttributes(_) => vec![],
+        SyncEvent::InitTable(sync_data) => vec![new_event(
+            ReaderEventType::InitTable,
+            sync_data.table_data.table_name.as_str(),
+            crate::data_readers::table_snapshot_as_json(&sync_data.table_snapshot, scope)
+                .into_bytes(),
+        )],
+        SyncEvent::InitPartitions(data) => {
+            let mut result = Vec::with_capacity(data.partitions_to_update.len());
+
+            for partition in data.partitions_to_update.iter() {
+                if let Some(scope) = scope {
+                    if !scope.contains(partition.partition_key.as_str()) {
+                        continue;
+                    }
+                }
+
+                let content = match &partition.snapshot {
+                    Some(db_partition_snapshot) => db_partition_snapshot
+                        .db_rows_snapshot
+                        .as_json_array()
+                        .build()
+                        .into_bytes(),
+                    None => EMPTY_ARRAY.to_vec(),
+                };
+
+                let mut event = new_event(
+                    ReaderEventType::InitPartition,
+                    data.table_data.table_name.as_str(),
+                    content,
+                );
+                event.partition_key = Some(partition.partition_key.to_string());
+
+                result.push(event);
+            }
+
+            result
+        }
+        SyncEvent::UpdateRows(data) => {
+            let db_rows = data.rows_by_partition.get_rows_snapshot(scope);
+
+            if db_rows.len() == 0 {
+                return vec![];
+            }
+
+            vec![new_event(
+                ReaderEventType::UpdateRows,
+                data.table_data.table_name.as_str(),
+                db_rows.as_json_array().build().into_bytes(),
+            )]
+        }
+        SyncEvent::DeleteRows(data) => {
+            let mut result = Vec::new();
+
+            if let Some(deleted_partitions) = &data.deleted_partitions {
+                for partition_key in deleted_partitions.iter() {
+                    if let Some(scope) = scope {
+                        if !scope.contains(partition_key.as_str()) {
+                            continue;
+                        }
+                    }
+
+                    let mut event = new_event(
+                        ReaderEventType::InitPartition,
+                        data.table_data.table_name.as_str(),
+                        EMPTY_ARRAY.to_vec(),
+                    );
+                    event.partition_key = Some(partition_key.to_string());
+
+                    result.push(event);
+                }
+            }
+
+            if let Some(deleted_rows) = &data.deleted_rows {
+                let mut event = new_event(
+                    ReaderEventType::DeleteRows,
+                    data.table_data.table_name.as_str(),
+                    vec![],
+                );
+
+                for deleted_row in deleted_rows.iter() {
+                    if let Some(scope) = scope {
+                        if !scope.contains(deleted_row.partition_key.as_str()) {
+                            continue;
+                        }
+                    }
+
+                    for db_row in deleted_row.db_rows.iter() {
+                        event.deleted_rows.push(DeletedRowGrpcModel {
+                            partition_key: deleted_row.partition_key.to_string(),
+                            row_key: db_row.get_row_key().to_string(),
+                        });
+                    }
+                }
+
+                if event.deleted_rows.len() > 0 {
+                    result.push(event);
+                }
+            }
+
+            result
+        }
+        SyncEvent::DeleteTable(data) => vec![new_event(
+            ReaderEventType::InitTable,
+            data.table_data.table_name.as_str(),
+            EMPTY_ARRAY.to_vec(),
+        )],
+    }
+}
+
+pub fn new_error(message: String) -> ReaderGrpcEvent {
+    let mut result = new_event(ReaderEventType::Error, "", vec![]);
+    result.message = Some(message);
+    result
+}
+
+fn new_event(event_type: ReaderEventType, table_name: &str, content: Vec<u8>) -> ReaderGrpcEvent {
+    ReaderGrpcEvent {
+        event_type: event_type as i32,
+        table_name: table_name.to_string(),
+        partition_key: None,
+        content,
+        deleted_rows: vec![],
+        message: None,
+        sequence: None,
+    }
+}
@@ -0,0 +1,3 @@
+mod grpc_connection_info;
+pub use grpc_connection_info::*;
+pub mod grpc_payload_to_send;
@@ -17,7 +17,8 @@ pub struct AwaitingResponse {
 
 pub struct HttpConnectionDeliveryInfo {
     awaiting_response: Option<AwaitingResponse>,
-    payload_to_deliver: VecDeque<Vec<u8>>,
+    /// Payloads uploaded and not delivered yet, each with the table it is of.
+    payload_to_deliver: VecDeque<(String, Vec<u8>)>,
     id: String,
 }
 static MIN_PING_TIMEOUT: Duration = Duration::from_secs(3);
@@ -31,23 +32,30 @@ impl HttpConnectionDeliveryInfo {
         }
     }
 
-    pub fn upload(&mut self, payload: Vec<u8>) {
-        match self.payload_to_deliver.pop_back() {
-            Some(mut last_one) => {
-                last_one.extend(payload);
-                self.payload_to_deliver.push_back(last_one);
-            }
-            None => {
-                self.payload_to_deliver.push_back(payload);
-            }
-        }
+    pub fn upload(&mut self, table_name: &str, payload: Vec<u8>) {
+        self.payload_to_deliver
+            .push_back((table_name.to_string(), payload));
     }
 
+    /// Everything uploaded so far goes out as one payload.
     pub fn get_payload_to_deliver(&mut self) -> Option<Vec<u8>> {
-        self.payload_to_deliver.pop_front()
+        let (_, mut result) = self.payload_to_deliver.pop_front()?;
+
+        for (_, payload) in self.payload_to_deliver.drain(..) {
+            result.extend(payload);
+        }
+
+        Some(result)
     }
 
-    pub fn ping(&mut self, now: DateTimeAsMicroseconds) {
+    /// Drops what is uploaded of a table the reader is to get in full again - the
+    /// `InitTable` it gets makes all of it stale.
+    pub fn drop_table(&mut self, table_name: &str) {
+        self.payload_to_deliver
+            .retain(|(payload_table_name, _)| payload_table_name != table_name);
+    }
+
+    pub fn ping(&mut self, now: DateTimeAsMicroseconds) -> Result<(), String> {
         let ping_me = if let Some(item) = &self.awaiting_response {
             now.duration_since(item.created).as_positive_or_zero() >= MIN_PING_TIMEOUT
         } else {
@@ -55,17 +63,19 @@ impl HttpConnectionDeliveryInfo {
         };
 
         if !ping_me {
-            return;
+            return Ok(());
         }
 
         if let Some(mut task) = self.get_task_to_write_response() {
             if let Err(err) = task.try_set_ok(HttpPayload::Ping) {
-                println!(
+                return Err(format!(
                     "Could not set ping result to http connection {}. Err:{:?}",
                     self.id, err
-                );
+                ));
             }
         }
+
+        Ok(())
     }
 
     pub fn get_task_to_write_response(
@@ -101,13 +111,48 @@ impl HttpConnectionDeliveryInfo {
         result
     }
 
+    pub fn get_amount(&self) -> usize {
+        self.payload_to_deliver.len()
+    }
+
     pub fn get_size(&self) -> usize {
         let mut result = 0;
 
-        for delivery_info in &self.payload_to_deliver {
-            result += delivery_info.len();
+        for (_, payload) in &self.payload_to_deliver {
+            result += payload.len();
         }
 
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_table() {
+        let mut delivery_info = HttpConnectionDeliveryInfo::new("test".to_string());
+
+        delivery_info.upload("orders", vec![0; 100]);
+        delivery_info.upload("clients", vec![1; 10]);
+        delivery_info.upload("orders", vec![0; 50]);
+
+        assert_eq!(160, delivery_info.get_size());
+        assert_eq!(3, delivery_info.get_amount());
+
+        delivery_info.drop_table("orders");
+
+        assert_eq!(10, delivery_info.get_size());
+        assert_eq!(1, delivery_info.get_amount());
+
+        delivery_info.upload("orders", vec![0; 20]);
+
+        assert_eq!(
+            [vec![1; 10], vec![0; 20]].concat(),
+            delivery_info.get_payload_to_deliver().unwrap()
+        );
+        assert_eq!(0, delivery_info.get_size());
+        assert_eq!(0, delivery_info.get_amount());
+    }
+}
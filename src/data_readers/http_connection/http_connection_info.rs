@@ -1,4 +1,4 @@
-use std::sync::atomic::AtomicUsize;
+use std::sync::{atomic::AtomicUsize, Arc};
 
 use my_http_server::HttpFailResult;
 use my_no_sql_sdk::core::rust_extensions::date_time::{
@@ -7,10 +7,13 @@ use my_no_sql_sdk::core::rust_extensions::date_time::{
 use tokio::sync::Mutex;
 
 use crate::{
-    data_readers::http_connection::connection_delivery_info::HttpPayload, db_sync::SyncEvent,
+    data_readers::{
+        http_connection::connection_delivery_info::HttpPayload, PartitionScope, PendingToSend,
+    },
+    db_sync::SyncEvent,
 };
 
-use super::{into_http_payload, HttpConnectionDeliveryInfo};
+use super::{into_http_payload, HttpConnectionDeliveryInfo, SseStream};
 
 pub struct HttpConnectionInfo {
     pub id: String,
@@ -18,7 +21,12 @@ pub struct HttpConnectionInfo {
     pub connected: DateTimeAsMicroseconds,
     pub last_incoming_moment: AtomicDateTimeAsMicroseconds,
     pub delivery_info: Mutex<HttpConnectionDeliveryInfo>,
+    /// Set while the session is delivered over `GET /api/DataReader/Stream`.
+    stream: Mutex<Option<SseStream>>,
+    /// What the stream has queued and its response has not taken yet.
+    stream_pending_to_send: Arc<PendingToSend>,
     pending_to_send: AtomicUsize,
+    pending_events: AtomicUsize,
     name: String,
     #[allow(dead_code)]
     version: String,
@@ -32,7 +40,10 @@ impl HttpConnectionInfo {
             connected: DateTimeAsMicroseconds::now(),
             last_incoming_moment: AtomicDateTimeAsMicroseconds::now(),
             delivery_info: Mutex::new(HttpConnectionDeliveryInfo::new(id)),
+            stream: Mutex::new(None),
+            stream_pending_to_send: Arc::new(PendingToSend::new()),
             pending_to_send: AtomicUsize::new(0),
+            pending_events: AtomicUsize::new(0),
             name,
             version,
         }
@@ -42,31 +53,85 @@ impl HttpConnectionInfo {
         self.name.as_str()
     }
 
-    pub async fn ping(&self, now: DateTimeAsMicroseconds) {
+    /// For the stream of the session to count what it queues in.
+    pub fn get_stream_pending_to_send(&self) -> Arc<PendingToSend> {
+        self.stream_pending_to_send.clone()
+    }
+
+    pub async fn attach_stream(&self, stream: SseStream) {
+        *self.stream.lock().await = Some(stream);
+    }
+
+    /// Closes the Server-Sent Events stream, if the session has one.
+    pub async fn disconnect(&self) {
+        *self.stream.lock().await = None;
+    }
+
+    /// `Err` - the ping could not be handed to the request waiting for it.
+    pub async fn ping(&self, now: DateTimeAsMicroseconds) -> Result<(), String> {
+        {
+            let mut stream = self.stream.lock().await;
+
+            if let Some(sse_stream) = stream.as_ref() {
+                // An open stream is the session being alive - nobody calls in to say so.
+                // Once the client is gone the session is left to the GC.
+                if sse_stream.send_heartbeat() {
+                    self.last_incoming_moment.update(now);
+                } else {
+                    *stream = None;
+                }
+
+                return Ok(());
+            }
+        }
+
         let mut delivery_info = self.delivery_info.lock().await;
         delivery_info.ping(now)
     }
 
-    pub async fn send(&self, sync_event: &SyncEvent) {
-        if let Some(payload) = into_http_payload::convert(sync_event).await {
+    /// `Err` - the payload could not be handed to the request waiting for it.
+    pub async fn send(
+        &self,
+        sync_event: &SyncEvent,
+        sequence: Option<u64>,
+        scope: Option<&PartitionScope>,
+    ) -> Result<(), String> {
+        {
+            let mut stream = self.stream.lock().await;
+
+            if let Some(sse_stream) = stream.as_mut() {
+                if let Some(parts) = into_http_payload::get_parts(sync_event, scope) {
+                    if !sse_stream.send(parts, sequence) {
+                        *stream = None;
+                    }
+                }
+
+                return Ok(());
+            }
+        }
+
+        if let Some(payload) = into_http_payload::convert(sync_event, sequence, scope) {
             let mut delivery_info_write_access = self.delivery_info.lock().await;
-            delivery_info_write_access.upload(payload);
-            self.pending_to_send.store(
-                delivery_info_write_access.get_size(),
-                std::sync::atomic::Ordering::SeqCst,
-            );
+            delivery_info_write_access.upload(sync_event.get_table_name(), payload);
 
-            if let Some(mut task) = delivery_info_write_access.get_task_to_write_response() {
-                let payload = delivery_info_write_access.get_payload_to_deliver().unwrap();
+            let task = delivery_info_write_access.get_task_to_write_response();
+            let payload = task
+                .as_ref()
+                .and_then(|_| delivery_info_write_access.get_payload_to_deliver());
 
+            self.update_pending(&delivery_info_write_access);
+
+            if let (Some(mut task), Some(payload)) = (task, payload) {
                 if let Err(err) = task.try_set_ok(HttpPayload::Payload(payload)) {
-                    println!(
+                    return Err(format!(
                         "Sending payload Error for the session: {}. Reason:{:?}",
                         self.id, err
-                    );
+                    ));
                 }
             }
         }
+
+        Ok(())
     }
 
     pub async fn new_request(&self) -> Result<HttpPayload, HttpFailResult> {
@@ -74,6 +139,7 @@ impl HttpConnectionInfo {
             let mut write_access = self.delivery_info.lock().await;
 
             if let Some(payload) = write_access.get_payload_to_deliver() {
+                self.update_pending(&write_access);
                 return Ok(HttpPayload::Payload(payload));
             }
 
@@ -83,8 +149,35 @@ impl HttpConnectionInfo {
         task_completion.get_result().await
     }
 
+    /// Drops what is queued of a table the reader is to get in full again. The
+    /// Server-Sent Events stream has handed its events to the response already, so
+    /// only what waits for a long-poll request is dropped.
+    pub async fn drop_pending_of_table(&self, table_name: &str) {
+        let mut delivery_info = self.delivery_info.lock().await;
+        delivery_info.drop_table(table_name);
+        self.update_pending(&delivery_info);
+    }
+
+    fn update_pending(&self, delivery_info: &HttpConnectionDeliveryInfo) {
+        self.pending_to_send.store(
+            delivery_info.get_size(),
+            std::sync::atomic::Ordering::SeqCst,
+        );
+        self.pending_events.store(
+            delivery_info.get_amount(),
+            std::sync::atomic::Ordering::SeqCst,
+        );
+    }
+
     pub fn get_pending_to_send(&self) -> usize {
         self.pending_to_send
             .load(std::sync::atomic::Ordering::Relaxed)
+            + self.stream_pending_to_send.get_size()
+    }
+
+    pub fn get_pending_events(&self) -> usize {
+        self.pending_events
+            .load(std::sync::atomic::Ordering::Relaxed)
+            + self.stream_pending_to_send.get_amount()
     }
 }
@@ -1,92 +1,120 @@
-use my_no_sql_sdk::core::my_json::json_writer::{JsonArrayWriter, JsonObjectWriter};
-
-use crate::db_sync::{
-    states::{DeleteRowsEventSyncData, InitPartitionsSyncEventData, UpdateRowsSyncData},
-    SyncEvent,
-};
+use crate::{data_readers::PartitionScope, db_sync::SyncEvent};
+
+/// One event as HTTP readers get it: what happened, to which table, and the JSON
+/// content - rows, partitions or deleted keys.
+pub struct HttpPayloadParts<'s> {
+    pub kind: &'static str,
+    pub table_name: &'s str,
+    pub content: Vec<u8>,
+}
 
-pub async fn convert(sync_event: &SyncEvent) -> Option<Vec<u8>> {
+/// With a `scope` only the partitions in it are sent; an event which touches none of
+/// them converts to nothing.
+pub fn get_parts<'s>(
+    sync_event: &'s SyncEvent,
+    scope: Option<&PartitionScope>,
+) -> Option<HttpPayloadParts<'s>> {
     match sync_event {
         SyncEvent::TableFirstInit(sync_data) => {
-            let content = sync_data.db_table.get_table_as_json_array();
-            write_init_table_result(sync_data.db_table.name.as_str(), content).into()
+            let content = match scope {
+                Some(scope) => {
+                    let table_snapshot = sync_data.db_table.get_table_snapshot();
+                    crate::data_readers::table_snapshot_as_json(&table_snapshot, Some(scope))
+                }
+                None => sync_data.db_table.get_table_as_json_array().build(),
+            };
+
+            HttpPayloadParts {
+                kind: "initTable",
+                table_name: sync_data.db_table.name.as_str(),
+                content: content.into_bytes(),
+            }
+            .into()
+        }
+        SyncEvent::InitTable(sync_data) => HttpPayloadParts {
+            kind: "initTable",
+            table_name: sync_data.table_data.table_name.as_str(),
+            content: crate::data_readers::table_snapshot_as_json(&sync_data.table_snapshot, scope)
+                .into_bytes(),
+        }
+        .into(),
+        SyncEvent::InitPartitions(sync_data) => {
+            if !sync_data.has_partitions_in_scope(scope) {
+                return None;
+            }
+
+            HttpPayloadParts {
+                kind: "initPartitions",
+                table_name: sync_data.table_data.table_name.as_str(),
+                content: sync_data.as_json(scope).build().into_bytes(),
+            }
+            .into()
+        }
+        SyncEvent::UpdateRows(sync_data) => {
+            let db_rows = sync_data.rows_by_partition.get_rows_snapshot(scope);
+
+            if scope.is_some() && db_rows.len() == 0 {
+                return None;
+            }
+
+            HttpPayloadParts {
+                kind: "updateRows",
+                table_name: sync_data.table_data.table_name.as_str(),
+                content: db_rows.as_json_array().build().into_bytes(),
+            }
+            .into()
+        }
+        SyncEvent::DeleteRows(sync_data) => {
+            if !sync_data.has_partitions_in_scope(scope) {
+                return None;
+            }
+
+            HttpPayloadParts {
+                kind: "deleteRows",
+                table_name: sync_data.table_data.table_name.as_str(),
+                content: sync_data.as_vec(scope),
+            }
+            .into()
         }
-        SyncEvent::InitTable(sync_data) => {
-            let content = sync_data.table_snapshot.as_json_array();
-            write_init_table_result(sync_data.table_data.table_name.as_str(), content).into()
+        SyncEvent::DeleteTable(sync_data) => HttpPayloadParts {
+            kind: "initTable",
+            table_name: sync_data.table_data.table_name.as_str(),
+            content: b"[]".to_vec(),
         }
-        SyncEvent::InitPartitions(sync_data) => write_init_partitions_result(sync_data).into(),
-        SyncEvent::UpdateRows(sync_data) => compile_update_rows_result(sync_data).into(),
-        SyncEvent::DeleteRows(sync_data) => compile_delete_rows_result(sync_data).into(),
-        SyncEvent::DeleteTable(sync_data) => write_init_table_result(
-            sync_data.table_data.table_name.as_str(),
-            JsonArrayWriter::new(),
-        )
         .into(),
         SyncEvent::UpdateTableAttributes(_) => None,
     }
 }
 
-fn write_init_table_result(table_name: &str, content: JsonArrayWriter) -> Vec<u8> {
-    let mut result = Vec::new();
-
-    let header_json = JsonObjectWriter::new()
-        .write("tableName", table_name)
-        .build();
-
-    let header = format!("initTable:{}", header_json);
-
-    write_pascal_string(header.as_str(), &mut result);
-
-    let content = content.build();
-    write_byte_array(content.as_bytes(), &mut result);
-    result
-}
-
-fn write_init_partitions_result(sync_data: &InitPartitionsSyncEventData) -> Vec<u8> {
-    let mut result = Vec::new();
-
-    let header_json = JsonObjectWriter::new()
-        .write("tableName", sync_data.table_data.table_name.as_str())
-        .build();
-
-    let header = format!("initPartitions:{}", header_json);
-
-    write_pascal_string(header.as_str(), &mut result);
-
-    let content = sync_data.as_json().build();
-    write_byte_array(content.as_bytes(), &mut result);
-    result
-}
+/// The binary frame `GetChanges` answers with. `sequence` goes into the header, so
+/// the reader knows the position to resume from.
+pub fn convert(
+    sync_event: &SyncEvent,
+    sequence: Option<u64>,
+    scope: Option<&PartitionScope>,
+) -> Option<Vec<u8>> {
+    let parts = get_parts(sync_event, scope)?;
 
-pub fn compile_update_rows_result(sync_data: &UpdateRowsSyncData) -> Vec<u8> {
     let mut result = Vec::new();
-    let header_json = JsonObjectWriter::new()
-        .write("tableName", sync_data.table_data.table_name.as_str())
-        .build();
 
-    let header = format!("updateRows:{}", header_json);
+    let header = format!(
+        "{}:{}",
+        parts.kind,
+        get_header_json(parts.table_name, sequence)
+    );
 
     write_pascal_string(header.as_str(), &mut result);
+    write_byte_array(parts.content.as_slice(), &mut result);
 
-    let content = sync_data.rows_by_partition.as_json_array().build();
-    write_byte_array(content.as_bytes(), &mut result);
-    result
+    Some(result)
 }
 
-pub fn compile_delete_rows_result(sync_data: &DeleteRowsEventSyncData) -> Vec<u8> {
-    let mut result = Vec::new();
-    let header_json = JsonObjectWriter::new()
-        .write("tableName", sync_data.table_data.table_name.as_str())
-        .build();
-
-    let header = format!("deleteRows:{}", header_json);
-
-    write_pascal_string(header.as_str(), &mut result);
-
-    let content = sync_data.as_vec();
-    write_byte_array(content.as_slice(), &mut result);
-    result
+fn get_header_json(table_name: &str, sequence: Option<u64>) -> String {
+    match sequence {
+        Some(sequence) => serde_json::json!({"tableName": table_name, "sequence": sequence}),
+        None => serde_json::json!({ "tableName": table_name }),
+    }
+    .to_string()
 }
 
 fn write_pascal_string(src: &str, dest: &mut Vec<u8>) {
@@ -1,5 +1,7 @@
 mod connection_delivery_info;
 mod http_connection_info;
 mod into_http_payload;
+mod sse_stream;
 pub use connection_delivery_info::{HttpConnectionDeliveryInfo, HttpPayload};
 pub use http_connection_info::HttpConnectionInfo;
+pub use sse_stream::*;
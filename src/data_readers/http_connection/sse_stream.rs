@@ -0,0 +1,194 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::data_readers::PendingToSend;
+
+use super::into_http_payload::HttpPayloadParts;
+
+pub const SSE_HEARTBEAT: &[u8] = b": ping\n\n";
+
+/// Server-Sent Events stream a session delivers to instead of waiting for
+/// `GetChanges` to come and pick the payloads up.
+///
+/// The id of every event is the position of the stream in each of its tables -
+/// `orders:1718000000000123;clients:1718000000000042` - so the `Last-Event-ID` a
+/// browser sends on reconnect is enough to resume every table at once.
+///
+/// Every frame is counted in `pending_to_send` until the body of the response takes
+/// it, so a client which reads slower than its tables change shows up - and is
+/// dealt with - like any other slow reader.
+pub struct SseStream {
+    sender: UnboundedSender<Vec<u8>>,
+    pending_to_send: Arc<PendingToSend>,
+    positions: BTreeMap<String, u64>,
+}
+
+impl SseStream {
+    pub fn new(sender: UnboundedSender<Vec<u8>>, pending_to_send: Arc<PendingToSend>) -> Self {
+        Self {
+            sender,
+            pending_to_send,
+            positions: BTreeMap::new(),
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+
+    /// `false` once the client is gone.
+    pub fn send(&mut self, parts: HttpPayloadParts, sequence: Option<u64>) -> bool {
+        if let Some(sequence) = sequence {
+            self.positions
+                .insert(parts.table_name.to_string(), sequence);
+        }
+
+        let frame = compile_event(&parts, sequence, self.get_event_id().as_str());
+        self.send_frame(frame)
+    }
+
+    pub fn send_heartbeat(&self) -> bool {
+        self.send_frame(SSE_HEARTBEAT.to_vec())
+    }
+
+    /// The first event of the stream: the id of the session, so the client can change
+    /// the partitions it gets through `UpdatePartitions` without a Greeting of its own.
+    pub fn send_session(&self, session_id: &str) -> bool {
+        let frame = format!(
+            "event: session\ndata: {}\n\n",
+            serde_json::json!({ "session": session_id })
+        );
+
+        self.send_frame(frame.into_bytes())
+    }
+
+    fn send_frame(&self, frame: Vec<u8>) -> bool {
+        let size = frame.len();
+        self.pending_to_send.add(size);
+
+        if self.sender.send(frame).is_err() {
+            self.pending_to_send.remove(size);
+            return false;
+        }
+
+        true
+    }
+
+    fn get_event_id(&self) -> String {
+        let mut result = String::new();
+
+        for (table_name, sequence) in self.positions.iter() {
+            if !result.is_empty() {
+                result.push(';');
+            }
+
+            result.push_str(table_name);
+            result.push(':');
+            result.push_str(sequence.to_string().as_str());
+        }
+
+        result
+    }
+}
+
+/// `event: updateRows` with the table, its sequence and the JSON content in `data`.
+pub fn compile_event(parts: &HttpPayloadParts, sequence: Option<u64>, event_id: &str) -> Vec<u8> {
+    let mut result = Vec::with_capacity(parts.content.len() + 128);
+
+    result.extend_from_slice(b"event: ");
+    result.extend_from_slice(parts.kind.as_bytes());
+    result.push(b'\n');
+
+    if !event_id.is_empty() {
+        result.extend_from_slice(b"id: ");
+        result.extend_from_slice(event_id.as_bytes());
+        result.push(b'\n');
+    }
+
+    result.extend_from_slice(b"data: {\"tableName\":");
+    result.extend_from_slice(serde_json::to_string(parts.table_name).unwrap().as_bytes());
+
+    if let Some(sequence) = sequence {
+        result.extend_from_slice(b",\"sequence\":");
+        result.extend_from_slice(sequence.to_string().as_bytes());
+    }
+
+    result.extend_from_slice(b",\"data\":");
+
+    // Rows are written as compact JSON, but a row stored with line breaks in it would
+    // end the `data:` line early - they are whitespace to JSON, so they go.
+    for byte in parts.content.iter() {
+        if *byte != b'\n' && *byte != b'\r' {
+            result.push(*byte);
+        }
+    }
+
+    result.extend_from_slice(b"}\n\n");
+
+    result
+}
+
+/// Position per table out of a `Last-Event-ID`. Anything which does not parse is
+/// skipped - that table is then initialized in full.
+pub fn parse_event_id(event_id: &str) -> BTreeMap<String, u64> {
+    let mut result = BTreeMap::new();
+
+    for item in event_id.split(';') {
+        let Some((table_name, sequence)) = item.trim().rsplit_once(':') else {
+            continue;
+        };
+
+        if let Ok(sequence) = sequence.parse::<u64>() {
+            result.insert(table_name.to_string(), sequence);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_is_compiled() {
+        let parts = HttpPayloadParts {
+            kind: "updateRows",
+            table_name: "orders",
+            content: b"[{\"PartitionKey\":\"pk\",\n\"RowKey\":\"rk\"}]".to_vec(),
+        };
+
+        let frame = compile_event(&parts, Some(5), "orders:5");
+
+        assert_eq!(
+            "event: updateRows\nid: orders:5\ndata: {\"tableName\":\"orders\",\"sequence\":5,\"data\":[{\"PartitionKey\":\"pk\",\"RowKey\":\"rk\"}]}\n\n",
+            String::from_utf8(frame).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_event_id_round_trip() {
+        let (sender, _receiver) = tokio::sync::mpsc::unbounded_channel();
+        let pending_to_send = Arc::new(PendingToSend::new());
+        let mut stream = SseStream::new(sender, pending_to_send.clone());
+
+        for (table_name, sequence) in [("orders", 10), ("clients", 7), ("orders", 11)] {
+            let parts = HttpPayloadParts {
+                kind: "updateRows",
+                table_name,
+                content: b"[]".to_vec(),
+            };
+            assert!(stream.send(parts, Some(sequence)));
+        }
+
+        assert_eq!("clients:7;orders:11", stream.get_event_id());
+        assert_eq!(3, pending_to_send.get_amount());
+
+        let positions = parse_event_id(stream.get_event_id().as_str());
+        assert_eq!(Some(&11), positions.get("orders"));
+        assert_eq!(Some(&7), positions.get("clients"));
+
+        assert!(parse_event_id("garbage;orders:x").is_empty());
+    }
+}
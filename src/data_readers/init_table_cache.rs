@@ -0,0 +1,232 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+pub const DEFAULT_INIT_TABLE_CACHE_MAX_SIZE_MB: usize = 256;
+
+struct CachedPayload<TPayload> {
+    payload: Arc<TPayload>,
+    size: usize,
+}
+
+/// `InitTable` of one table as of one change sequence, compressed and not.
+struct CachedInitTable<TPayload> {
+    sequence: u64,
+    compressed: Option<CachedPayload<TPayload>>,
+    non_compressed: Option<CachedPayload<TPayload>>,
+    last_used: u64,
+}
+
+impl<TPayload> CachedInitTable<TPayload> {
+    fn get_size(&self) -> usize {
+        self.compressed.as_ref().map(|itm| itm.size).unwrap_or(0)
+            + self
+                .non_compressed
+                .as_ref()
+                .map(|itm| itm.size)
+                .unwrap_or(0)
+    }
+}
+
+struct InitTableCacheInner<TPayload> {
+    tables: HashMap<(String, String), CachedInitTable<TPayload>>,
+    size: usize,
+    last_used: u64,
+}
+
+impl<TPayload> InitTableCacheInner<TPayload> {
+    fn remove(&mut self, key: &(String, String)) {
+        if let Some(removed) = self.tables.remove(key) {
+            self.size -= removed.get_size();
+        }
+    }
+}
+
+/// `InitTable` payloads the readers which subscribe one after another share, keyed
+/// by (namespace, table) and the change sequence the table was at. A reader which
+/// subscribes before the next change of the table gets the very payload the previous
+/// one got - serialized and compressed once.
+///
+/// A table keeps one sequence only: a change makes the payload of every earlier one
+/// useless, so it is dropped right away. Above `max_size` bytes the tables used the
+/// longest ago go first.
+pub struct InitTableCache<TPayload> {
+    max_size: usize,
+    inner: Mutex<InitTableCacheInner<TPayload>>,
+}
+
+impl<TPayload> InitTableCache<TPayload> {
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            max_size,
+            inner: Mutex::new(InitTableCacheInner {
+                tables: HashMap::new(),
+                size: 0,
+                last_used: 0,
+            }),
+        }
+    }
+
+    pub fn get(
+        &self,
+        namespace: &str,
+        table_name: &str,
+        sequence: u64,
+        compress: bool,
+    ) -> Option<Arc<TPayload>> {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.last_used += 1;
+        let last_used = inner.last_used;
+
+        let cached = inner.tables.get_mut(&get_key(namespace, table_name))?;
+
+        if cached.sequence != sequence {
+            return None;
+        }
+
+        let payload = if compress {
+            cached.compressed.as_ref()
+        } else {
+            cached.non_compressed.as_ref()
+        };
+
+        let payload = payload?.payload.clone();
+        cached.last_used = last_used;
+
+        Some(payload)
+    }
+
+    pub fn insert(
+        &self,
+        namespace: &str,
+        table_name: &str,
+        sequence: u64,
+        compress: bool,
+        payload: Arc<TPayload>,
+        size: usize,
+    ) {
+        if size > self.max_size {
+            return;
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+
+        let key = get_key(namespace, table_name);
+
+        let is_other_sequence = inner
+            .tables
+            .get(&key)
+            .map(|cached| cached.sequence != sequence)
+            .unwrap_or(false);
+
+        if is_other_sequence {
+            inner.remove(&key);
+        }
+
+        inner.last_used += 1;
+        let last_used = inner.last_used;
+
+        let cached = inner
+            .tables
+            .entry(key.clone())
+            .or_insert_with(|| CachedInitTable {
+                sequence,
+                compressed: None,
+                non_compressed: None,
+                last_used,
+            });
+
+        cached.last_used = last_used;
+
+        let slot = if compress {
+            &mut cached.compressed
+        } else {
+            &mut cached.non_compressed
+        };
+
+        let replaced_size = slot.as_ref().map(|itm| itm.size).unwrap_or(0);
+        *slot = Some(CachedPayload { payload, size });
+
+        inner.size = inner.size - replaced_size + size;
+
+        while inner.size > self.max_size {
+            let oldest = inner
+                .tables
+                .iter()
+                .filter(|(itm_key, _)| **itm_key != key)
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(itm_key, _)| itm_key.clone());
+
+            match oldest {
+                Some(oldest) => inner.remove(&oldest),
+                None => break,
+            }
+        }
+    }
+
+    /// The table changed - what is cached for it is not what it holds anymore.
+    pub fn remove(&self, namespace: &str, table_name: &str) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.remove(&get_key(namespace, table_name));
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.inner.lock().unwrap().size
+    }
+}
+
+fn get_key(namespace: &str, table_name: &str) -> (String, String) {
+    (namespace.to_string(), table_name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_is_shared_until_table_changes() {
+        let cache = InitTableCache::new(100);
+
+        assert_eq!(None, cache.get("ns", "table", 5, false));
+
+        cache.insert("ns", "table", 5, false, Arc::new("v5"), 10);
+        assert_eq!(Some(Arc::new("v5")), cache.get("ns", "table", 5, false));
+        assert_eq!(None, cache.get("ns", "table", 5, true));
+        assert_eq!(None, cache.get("other", "table", 5, false));
+
+        // The table is at the next sequence - what is cached is of no use to anybody.
+        assert_eq!(None, cache.get("ns", "table", 6, false));
+
+        cache.insert("ns", "table", 6, true, Arc::new("v6"), 20);
+        assert_eq!(None, cache.get("ns", "table", 5, false));
+        assert_eq!(20, cache.get_size());
+
+        cache.remove("ns", "table");
+        assert_eq!(None, cache.get("ns", "table", 6, true));
+        assert_eq!(0, cache.get_size());
+    }
+
+    #[test]
+    fn test_least_recently_used_are_evicted() {
+        let cache = InitTableCache::new(100);
+
+        cache.insert("ns", "a", 1, false, Arc::new("a"), 40);
+        cache.insert("ns", "b", 1, false, Arc::new("b"), 40);
+
+        assert!(cache.get("ns", "a", 1, false).is_some());
+
+        cache.insert("ns", "c", 1, false, Arc::new("c"), 40);
+
+        assert!(cache.get("ns", "a", 1, false).is_some());
+        assert_eq!(None, cache.get("ns", "b", 1, false));
+        assert!(cache.get("ns", "c", 1, false).is_some());
+        assert_eq!(80, cache.get_size());
+
+        // Bigger than the whole cache - not kept at all.
+        cache.insert("ns", "d", 1, false, Arc::new("d"), 101);
+        assert_eq!(None, cache.get("ns", "d", 1, false));
+        assert_eq!(80, cache.get_size());
+    }
+}
@@ -3,11 +3,25 @@ mod data_reader;
 mod data_reader_updatable_data;
 mod data_readers_data;
 mod data_readers_list;
+pub mod grpc_connection;
 pub mod http_connection;
+mod init_table_cache;
+mod partition_scope;
+mod pending_to_send;
+mod reader_acl;
+mod slow_reader_policy;
+mod subscribe_options;
 pub mod tcp_connection;
+pub mod web_socket_connection;
 
 pub use connection::DataReaderConnection;
 pub use data_reader::DataReader;
 pub use data_reader_updatable_data::DataReaderUpdatableData;
 pub use data_readers_data::DataReadersData;
 pub use data_readers_list::DataReadersList;
+pub use init_table_cache::*;
+pub use partition_scope::*;
+pub use pending_to_send::*;
+pub use reader_acl::*;
+pub use slow_reader_policy::*;
+pub use subscribe_options::*;
@@ -0,0 +1,139 @@
+use std::collections::BTreeSet;
+
+use my_no_sql_sdk::server::db_snapshots::DbTableSnapshot;
+
+/// The partitions of one table a reader is interested in: exact partition keys
+/// and/or key prefixes. A reader subscribed without a scope gets the whole table;
+/// a reader with a scope gets only the partitions matching it - an empty scope
+/// matches nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartitionScope {
+    partition_keys: BTreeSet<String>,
+    prefixes: BTreeSet<String>,
+}
+
+impl PartitionScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_partition_keys(&mut self, partition_keys: impl Iterator<Item = String>) {
+        self.partition_keys.extend(partition_keys);
+    }
+
+    pub fn add_prefixes(&mut self, prefixes: impl Iterator<Item = String>) {
+        self.prefixes.extend(prefixes);
+    }
+
+    pub fn remove_partition_keys<'s>(&mut self, partition_keys: impl Iterator<Item = &'s str>) {
+        for partition_key in partition_keys {
+            self.partition_keys.remove(partition_key);
+        }
+    }
+
+    pub fn remove_prefixes<'s>(&mut self, prefixes: impl Iterator<Item = &'s str>) {
+        for prefix in prefixes {
+            self.prefixes.remove(prefix);
+        }
+    }
+
+    pub fn contains(&self, partition_key: &str) -> bool {
+        if self.partition_keys.contains(partition_key) {
+            return true;
+        }
+
+        self.prefixes
+            .iter()
+            .any(|prefix| partition_key.starts_with(prefix.as_str()))
+    }
+
+    pub fn get_partition_keys(&self) -> Vec<String> {
+        self.partition_keys.iter().cloned().collect()
+    }
+
+    pub fn get_prefixes(&self) -> Vec<String> {
+        self.prefixes.iter().cloned().collect()
+    }
+}
+
+/// The table as a JSON array of rows, holding only the partitions in `scope`.
+pub fn table_snapshot_as_json(
+    table_snapshot: &DbTableSnapshot,
+    scope: Option<&PartitionScope>,
+) -> String {
+    let Some(scope) = scope else {
+        return table_snapshot.as_json_array().build();
+    };
+
+    let arrays: Vec<String> = table_snapshot
+        .by_partition
+        .iter()
+        .filter(|itm| scope.contains(itm.partition_key.as_str()))
+        .map(|itm| itm.db_rows_snapshot.as_json_array().build())
+        .collect();
+
+    merge_json_arrays(arrays.iter().map(|itm| itm.as_str()))
+}
+
+/// Serialized events carry partitions as JSON arrays of rows - one array per
+/// partition. A scoped reader gets only some of them, glued back into one array.
+pub fn merge_json_arrays<'s>(arrays: impl Iterator<Item = &'s str>) -> String {
+    let mut result = String::from("[");
+
+    for array in arrays {
+        let content = array.trim();
+        let content = content
+            .strip_prefix('[')
+            .and_then(|content| content.strip_suffix(']'))
+            .unwrap_or(content)
+            .trim();
+
+        if content.is_empty() {
+            continue;
+        }
+
+        if result.len() > 1 {
+            result.push(',');
+        }
+
+        result.push_str(content);
+    }
+
+    result.push(']');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_and_prefixes() {
+        let mut scope = PartitionScope::new();
+        assert!(!scope.contains("client-1"));
+
+        scope.add_partition_keys(["client-1".to_string()].into_iter());
+        scope.add_prefixes(["eu-".to_string()].into_iter());
+
+        assert!(scope.contains("client-1"));
+        assert!(!scope.contains("client-10"));
+        assert!(scope.contains("eu-west"));
+        assert!(!scope.contains("us-east"));
+
+        scope.remove_partition_keys(["client-1"].into_iter());
+        scope.remove_prefixes(["eu-"].into_iter());
+
+        assert!(!scope.contains("client-1"));
+        assert!(!scope.contains("eu-west"));
+    }
+
+    #[test]
+    fn test_merge_json_arrays() {
+        assert_eq!("[]", merge_json_arrays([].into_iter()));
+        assert_eq!("[]", merge_json_arrays(["[]", " [ ] "].into_iter()));
+        assert_eq!(
+            r#"[{"a":1},{"b":2},{"c":3}]"#,
+            merge_json_arrays([r#"[{"a":1},{"b":2}]"#, "[]", r#"[{"c":3}]"#].into_iter())
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// What a connection has queued and its writer has not delivered yet - in bytes and
+/// in payloads. Added to when a payload is queued, taken from by the writer once the
+/// payload is out.
+#[derive(Default)]
+pub struct PendingToSend {
+    size: AtomicUsize,
+    amount: AtomicUsize,
+}
+
+impl PendingToSend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&self, size: usize) {
+        self.size.fetch_add(size, Ordering::SeqCst);
+        self.amount.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn remove(&self, size: usize) {
+        self.size.fetch_sub(size, Ordering::SeqCst);
+        self.amount.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.size.load(Ordering::Relaxed)
+    }
+
+    pub fn get_amount(&self) -> usize {
+        self.amount.load(Ordering::Relaxed)
+    }
+}
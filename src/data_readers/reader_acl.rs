@@ -0,0 +1,260 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+/// Credential of a reader as it is presented with the `Greeting`: the reader name
+/// ends with `?token=<token>`. The name is kept without it.
+pub const READER_TOKEN_SEPARATOR: &str = "?token=";
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReaderAclNamespaceModel {
+    #[serde(rename = "Namespace")]
+    pub namespace: String,
+    #[serde(rename = "Tables")]
+    pub tables: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReaderCredentialModel {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Token")]
+    pub token: String,
+    #[serde(rename = "Namespaces")]
+    pub namespaces: Vec<ReaderAclNamespaceModel>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReaderAclModel {
+    #[serde(rename = "Credentials")]
+    pub credentials: Vec<ReaderCredentialModel>,
+}
+
+/// What one credential may subscribe to. Namespaces and tables are patterns, where
+/// `*` stands for any number of any characters.
+pub struct ReaderCredential {
+    pub name: String,
+    namespaces: Vec<ReaderAclNamespaceModel>,
+}
+
+impl ReaderCredential {
+    pub fn can_use_namespace(&self, namespace: &str) -> bool {
+        self.namespaces
+            .iter()
+            .any(|itm| matches_pattern(itm.namespace.as_str(), namespace))
+    }
+
+    pub fn can_subscribe(&self, namespace: &str, table_name: &str) -> bool {
+        self.namespaces.iter().any(|itm| {
+            matches_pattern(itm.namespace.as_str(), namespace)
+                && itm
+                    .tables
+                    .iter()
+                    .any(|pattern| matches_pattern(pattern.as_str(), table_name))
+        })
+    }
+}
+
+/// Credentials of the readers, by token - read from `ReaderAclFile`.
+pub struct ReaderAcl {
+    credentials: HashMap<String, Arc<ReaderCredential>>,
+}
+
+impl ReaderAcl {
+    pub fn new(model: ReaderAclModel) -> Self {
+        let mut credentials = HashMap::new();
+
+        for credential in model.credentials {
+            credentials.insert(
+                credential.token,
+                Arc::new(ReaderCredential {
+                    name: credential.name,
+                    namespaces: credential.namespaces,
+                }),
+            );
+        }
+
+        Self { credentials }
+    }
+
+    pub async fn load(file_name: &str) -> Result<Self, String> {
+        let file_name = my_no_sql_sdk::core::rust_extensions::file_utils::format_path(file_name);
+
+        let file_content = match tokio::fs::read(file_name.as_str()).await {
+            Ok(file_content) => file_content,
+            Err(err) => {
+                return Err(format!(
+                    "Can't open reader ACL file [{}]. Err: {}",
+                    file_name.as_str(),
+                    err
+                ))
+            }
+        };
+
+        let model: ReaderAclModel = match serde_yaml::from_slice(file_content.as_slice()) {
+            Ok(model) => model,
+            Err(err) => {
+                return Err(format!(
+                    "Invalid reader ACL file [{}]. Err: {}",
+                    file_name.as_str(),
+                    err
+                ))
+            }
+        };
+
+        Ok(Self::new(model))
+    }
+
+    pub fn authenticate(&self, token: &str) -> Option<Arc<ReaderCredential>> {
+        self.credentials.get(token).cloned()
+    }
+
+    /// Whether a reader which authenticated with `credential` may read the namespace -
+    /// or the table of it, when `table_name` is given. `Err` says why not.
+    pub fn check_read_access(
+        &self,
+        credential: Option<&ReaderCredential>,
+        namespace: &str,
+        table_name: Option<&str>,
+    ) -> Result<(), String> {
+        let Some(credential) = credential else {
+            return Err("Reader is not authenticated".to_string());
+        };
+
+        let allowed = match table_name {
+            Some(table_name) => credential.can_subscribe(namespace, table_name),
+            None => credential.can_use_namespace(namespace),
+        };
+
+        if allowed {
+            return Ok(());
+        }
+
+        match table_name {
+            Some(table_name) => Err(format!(
+                "Credential '{}' can not read table '{}' of namespace '{}'",
+                credential.name, table_name, namespace
+            )),
+            None => Err(format!(
+                "Credential '{}' can not read namespace '{}'",
+                credential.name, namespace
+            )),
+        }
+    }
+}
+
+/// Splits the credential off the name a reader greets with.
+pub fn split_reader_token(name: String) -> (String, Option<String>) {
+    match name.split_once(READER_TOKEN_SEPARATOR) {
+        Some((name, token)) => (name.to_string(), Some(token.to_string())),
+        None => (name, None),
+    }
+}
+
+/// Whether `value` matches `pattern`, where `*` stands for any number of any characters.
+pub fn matches_pattern(pattern: &str, value: &str) -> bool {
+    let Some((prefix, rest)) = pattern.split_once('*') else {
+        return pattern == value;
+    };
+
+    let Some(mut value) = value.strip_prefix(prefix) else {
+        return false;
+    };
+
+    let mut parts: Vec<&str> = rest.split('*').collect();
+    let suffix = parts.pop().unwrap_or("");
+
+    for part in parts {
+        match value.find(part) {
+            Some(index) => value = &value[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    value.ends_with(suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patterns() {
+        assert!(matches_pattern("orders", "orders"));
+        assert!(!matches_pattern("orders", "orders-archive"));
+        assert!(matches_pattern("*", "anything"));
+        assert!(matches_pattern("orders-*", "orders-archive"));
+        assert!(!matches_pattern("orders-*", "clients"));
+        assert!(matches_pattern("*-archive", "orders-archive"));
+        assert!(matches_pattern("a*b*c", "a-b-c"));
+        assert!(!matches_pattern("a*b*c", "a-c-b"));
+        assert!(!matches_pattern("ab*ba", "aba"));
+    }
+
+    #[test]
+    fn test_credential_covers_tables_of_its_namespaces_only() {
+        let acl = ReaderAcl::new(ReaderAclModel {
+            credentials: vec![ReaderCredentialModel {
+                name: "billing".to_string(),
+                token: "secret".to_string(),
+                namespaces: vec![ReaderAclNamespaceModel {
+                    namespace: "default".to_string(),
+                    tables: vec!["invoice-*".to_string(), "clients".to_string()],
+                }],
+            }],
+        });
+
+        assert!(acl.authenticate("wrong").is_none());
+
+        let credential = acl.authenticate("secret").unwrap();
+        assert_eq!("billing", credential.name);
+
+        assert!(credential.can_use_namespace("default"));
+        assert!(!credential.can_use_namespace("other"));
+
+        assert!(credential.can_subscribe("default", "invoice-2024"));
+        assert!(credential.can_subscribe("default", "clients"));
+        assert!(!credential.can_subscribe("default", "orders"));
+        assert!(!credential.can_subscribe("other", "clients"));
+    }
+
+    #[test]
+    fn test_expiration_of_a_table_outside_the_credential_is_refused() {
+        // What the maintenance packets - `UpdateRowsExpirationTime` and the like - are
+        // checked with before they touch the table.
+        let acl = ReaderAcl::new(ReaderAclModel {
+            credentials: vec![ReaderCredentialModel {
+                name: "billing".to_string(),
+                token: "secret".to_string(),
+                namespaces: vec![ReaderAclNamespaceModel {
+                    namespace: "default".to_string(),
+                    tables: vec!["invoices".to_string()],
+                }],
+            }],
+        });
+
+        let credential = acl.authenticate("secret").unwrap();
+
+        assert!(acl
+            .check_read_access(Some(&credential), "default", Some("invoices"))
+            .is_ok());
+        assert!(acl
+            .check_read_access(Some(&credential), "default", Some("orders"))
+            .is_err());
+        assert!(acl
+            .check_read_access(None, "default", Some("invoices"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_token_is_split_off_name() {
+        assert_eq!(
+            ("my-service:1.0".to_string(), Some("secret".to_string())),
+            split_reader_token("my-service:1.0?token=secret".to_string())
+        );
+        assert_eq!(
+            ("my-service".to_string(), None),
+            split_reader_token("my-service".to_string())
+        );
+    }
+}
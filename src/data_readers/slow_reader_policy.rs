@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+/// What happens to a reader which is further behind than the limits allow.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum SlowReaderAction {
+    /// Nothing more of the table is sent until the reader catches up; then it gets
+    /// one fresh `InitTable` instead of everything it was not sent.
+    Reinit,
+    /// The reader is disconnected and has to come back and subscribe again.
+    Disconnect,
+}
+
+impl SlowReaderAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SlowReaderAction::Reinit => "reinit",
+            SlowReaderAction::Disconnect => "disconnect",
+        }
+    }
+}
+
+/// Limits on what one reader may have queued and not yet delivered. A limit
+/// which is not set is not checked.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SlowReaderPolicy {
+    #[serde(rename = "MaxPendingBytes", default)]
+    pub max_pending_bytes: Option<usize>,
+    #[serde(rename = "MaxPendingEvents", default)]
+    pub max_pending_events: Option<usize>,
+    #[serde(rename = "Action")]
+    pub action: SlowReaderAction,
+}
+
+impl SlowReaderPolicy {
+    /// Why the reader is over the limits; `None` while it is within them.
+    pub fn get_exceeded(&self, pending_bytes: usize, pending_events: usize) -> Option<String> {
+        if let Some(max_pending_bytes) = self.max_pending_bytes {
+            if pending_bytes > max_pending_bytes {
+                return Some(format!(
+                    "{} bytes are pending to send, while the limit is {}",
+                    pending_bytes, max_pending_bytes
+                ));
+            }
+        }
+
+        if let Some(max_pending_events) = self.max_pending_events {
+            if pending_events > max_pending_events {
+                return Some(format!(
+                    "{} events are pending to send, while the limit is {}",
+                    pending_events, max_pending_events
+                ));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits_which_are_set_are_checked() {
+        let policy = SlowReaderPolicy {
+            max_pending_bytes: Some(1000),
+            max_pending_events: None,
+            action: SlowReaderAction::Reinit,
+        };
+
+        assert_eq!(None, policy.get_exceeded(1000, 1_000_000));
+        assert!(policy.get_exceeded(1001, 0).is_some());
+
+        let policy = SlowReaderPolicy {
+            max_pending_bytes: None,
+            max_pending_events: Some(10),
+            action: SlowReaderAction::Disconnect,
+        };
+
+        assert_eq!(None, policy.get_exceeded(usize::MAX, 10));
+        assert_eq!(
+            Some("11 events are pending to send, while the limit is 10".to_string()),
+            policy.get_exceeded(0, 11)
+        );
+    }
+}
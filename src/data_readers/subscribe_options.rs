@@ -0,0 +1,120 @@
+use super::PartitionScope;
+
+/// The TCP `Subscribe` has nothing but the table name - the contract is the one of
+/// my-no-sql-sdk, and the readers built with it send no other - so the options of the
+/// subscription ride in it, the way the token rides in the greeting name:
+/// `orders?partitionKeys=client-1&partitionKeys=client-2&partitionKeyPrefixes=eu-`.
+/// Values are percent-encoded, as in a query string. A gRPC reader has fields for them.
+pub const SUBSCRIBE_OPTIONS_SEPARATOR: char = '?';
+
+/// What a reader which subscribed with `fromSequence` gets as the table name of every
+/// event: `orders?sequence=1718000000000123` - the table name, `?`, `sequence=` and the
+/// sequence in decimal, nothing else. The contracts of my-no-sql-sdk have no field for
+/// it, so this is the format: a table name never has `?` in it, and a reader finds its
+/// table by what comes before the first one, as `get_subscribe_table_name` does.
+const SEQUENCE_OPTION: &str = "sequence";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct SubscribeOptions {
+    /// `None` - the whole table, as without options.
+    pub scope: Option<PartitionScope>,
+    /// The sequence of the last event the reader has. Set - the reader resumes from
+    /// it, and is sent the sequence of every event of the table.
+    pub from_sequence: Option<u64>,
+}
+
+/// The table name of a `Subscribe` without the options.
+pub fn get_subscribe_table_name(table_name: &str) -> &str {
+    match table_name.split_once(SUBSCRIBE_OPTIONS_SEPARATOR) {
+        Some((table_name, _)) => table_name,
+        None => table_name,
+    }
+}
+
+/// Splits the options off the table name of a `Subscribe`, percent-decoding the values.
+pub fn split_subscribe_options(table_name: String) -> Result<(String, SubscribeOptions), String> {
+    let Some((name, options)) = table_name.split_once(SUBSCRIBE_OPTIONS_SEPARATOR) else {
+        return Ok((table_name, SubscribeOptions::default()));
+    };
+
+    let mut result = SubscribeOptions::default();
+
+    for option in options.split('&').filter(|itm| !itm.is_empty()) {
+        let Some((key, value)) = option.split_once('=') else {
+            return Err(format!("Invalid subscribe option '{}'", option));
+        };
+
+        match key {
+            "partitionKeys" => result
+                .scope
+                .get_or_insert_with(PartitionScope::new)
+                .add_partition_keys([crate::http_server::decode_query_value(value)?].into_iter()),
+            "partitionKeyPrefixes" => result
+                .scope
+                .get_or_insert_with(PartitionScope::new)
+                .add_prefixes([crate::http_server::decode_query_value(value)?].into_iter()),
+            "fromSequence" => match value.parse() {
+                Ok(sequence) => result.from_sequence = Some(sequence),
+                Err(_) => return Err(format!("Invalid fromSequence '{}'", value)),
+            },
+            _ => return Err(format!("Unknown subscribe option '{}'", key)),
+        }
+    }
+
+    Ok((name.to_string(), result))
+}
+
+pub fn get_sequenced_table_name(table_name: &str, sequence: u64) -> String {
+    format!(
+        "{}{}{}={}",
+        table_name, SUBSCRIBE_OPTIONS_SEPARATOR, SEQUENCE_OPTION, sequence
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_subscribe_options() {
+        let (table_name, options) = split_subscribe_options("orders".to_string()).unwrap();
+        assert_eq!("orders", table_name);
+        assert_eq!(SubscribeOptions::default(), options);
+
+        let (table_name, options) = split_subscribe_options(
+            "orders?partitionKeys=client-1&partitionKeyPrefixes=eu-&fromSequence=42".to_string(),
+        )
+        .unwrap();
+        assert_eq!("orders", table_name);
+        assert_eq!(Some(42), options.from_sequence);
+
+        let scope = options.scope.unwrap();
+        assert!(scope.contains("client-1"));
+        assert!(scope.contains("eu-west"));
+        assert!(!scope.contains("client-2"));
+
+        assert!(split_subscribe_options("orders?partitionKey=client-1".to_string()).is_err());
+        assert!(split_subscribe_options("orders?client-1".to_string()).is_err());
+        assert!(split_subscribe_options("orders?fromSequence=last".to_string()).is_err());
+
+        let (_, options) =
+            split_subscribe_options("orders?partitionKeys=a%26b%3Dc&partitionKeys=d+e".to_string())
+                .unwrap();
+        let scope = options.scope.unwrap();
+        assert!(scope.contains("a&b=c"));
+        assert!(scope.contains("d e"));
+        assert!(!scope.contains("a%26b%3Dc"));
+
+        assert!(split_subscribe_options("orders?partitionKeys=a%2".to_string()).is_err());
+        assert!(split_subscribe_options("orders?partitionKeys=%ff".to_string()).is_err());
+
+        let (_, options) = split_subscribe_options("orders?fromSequence=42".to_string()).unwrap();
+        assert_eq!(None, options.scope);
+
+        assert_eq!(
+            "orders",
+            get_subscribe_table_name("orders?partitionKeys=client-1")
+        );
+        assert_eq!("orders?sequence=42", get_sequenced_table_name("orders", 42));
+    }
+}
@@ -1,4 +1,6 @@
-use std::sync::{atomic::AtomicUsize, Arc};
+use std::collections::VecDeque;
+use std::sync::{atomic::AtomicUsize, Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use my_no_sql_sdk::tcp_contracts::MyNoSqlTcpContract;
 use my_tcp_sockets::tcp_connection::ConnectionStatistics;
@@ -35,7 +37,12 @@ impl ReaderName {
 pub struct TcpConnectionInfo {
     connection: Arc<MyNoSqlTcpConnection>,
     pub name: ReaderName,
+    /// Of the client - behind the TLS terminator, when TLS is on.
+    ip: String,
     sent_per_second_accumulator: AtomicUsize,
+    /// (bytes, payloads, when) of every send the socket may not have written out
+    /// yet, oldest first.
+    pending_sends: Mutex<VecDeque<(usize, usize, Instant)>>,
     pub sent_per_second: SendPerSecond,
     pub compress_data: bool,
 }
@@ -44,12 +51,15 @@ impl TcpConnectionInfo {
     pub fn new(
         connection: Arc<MyNoSqlTcpConnection>,
         name: ReaderName,
+        ip: String,
         compress_data: bool,
     ) -> Self {
         Self {
             connection,
             name,
+            ip,
             sent_per_second_accumulator: AtomicUsize::new(0),
+            pending_sends: Mutex::new(VecDeque::new()),
             sent_per_second: SendPerSecond::new(),
             compress_data,
         }
@@ -68,10 +78,7 @@ impl TcpConnectionInfo {
     }
 
     pub fn get_ip(&self) -> String {
-        match &self.connection.addr {
-            Some(addr) => format!("{}", addr),
-            None => "unknown".to_string(),
-        }
+        self.ip.to_string()
     }
 
     pub fn is_compressed_data(&self) -> bool {
@@ -95,10 +102,31 @@ impl TcpConnectionInfo {
     }
      */
 
-    pub async fn send(&self, tcp_contract: &[MyNoSqlTcpContract]) {
+    /// `false` - a send made earlier has waited longer than `send_timeout` to be
+    /// written out: the reader does not read, and is to be disconnected.
+    pub async fn send(
+        &self,
+        tcp_contract: &[MyNoSqlTcpContract],
+        send_timeout: Option<Duration>,
+    ) -> bool {
         let sent_amount = self.connection.send_many(tcp_contract);
+
+        let timed_out = {
+            let mut pending_sends = self.pending_sends.lock().unwrap();
+            pending_sends.push_back((sent_amount, tcp_contract.len(), Instant::now()));
+            remove_delivered(&mut pending_sends, self.get_pending_to_send());
+
+            is_timed_out(&pending_sends, send_timeout)
+        };
+
         self.sent_per_second_accumulator
             .fetch_add(sent_amount, std::sync::atomic::Ordering::SeqCst);
+
+        !timed_out
+    }
+
+    pub async fn disconnect(&self) {
+        self.connection.disconnect().await;
     }
 
     pub async fn timer_1sec_tick(&self) {
@@ -114,4 +142,76 @@ impl TcpConnectionInfo {
             .pending_to_send_buffer_size
             .load(std::sync::atomic::Ordering::Relaxed)
     }
+
+    pub fn get_pending_events(&self) -> usize {
+        let mut pending_sends = self.pending_sends.lock().unwrap();
+        remove_delivered(&mut pending_sends, self.get_pending_to_send());
+
+        pending_sends.iter().map(|(_, amount, _)| amount).sum()
+    }
+}
+
+/// The socket writer reports its buffer in bytes only. It writes the buffer out in
+/// order, so a send is delivered once the bytes after it cover all that is pending.
+fn remove_delivered(pending_sends: &mut VecDeque<(usize, usize, Instant)>, pending_to_send: usize) {
+    let mut queued: usize = pending_sends.iter().map(|(size, _, _)| size).sum();
+
+    while let Some((size, _, _)) = pending_sends.front() {
+        if queued - size < pending_to_send {
+            break;
+        }
+
+        queued -= size;
+        pending_sends.pop_front();
+    }
+}
+
+/// Whether the oldest send still not written out has waited longer than `send_timeout`.
+fn is_timed_out(
+    pending_sends: &VecDeque<(usize, usize, Instant)>,
+    send_timeout: Option<Duration>,
+) -> bool {
+    let (Some(send_timeout), Some((_, _, queued))) = (send_timeout, pending_sends.front()) else {
+        return false;
+    };
+
+    queued.elapsed() > send_timeout
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sends_written_out_are_removed() {
+        let now = Instant::now();
+        let mut pending_sends = VecDeque::from([(100, 1, now), (50, 2, now), (10, 1, now)]);
+
+        // The first send is partly in the buffer yet.
+        remove_delivered(&mut pending_sends, 70);
+        assert_eq!(3, pending_sends.len());
+
+        remove_delivered(&mut pending_sends, 60);
+        assert_eq!(
+            vec![(50, 2, now), (10, 1, now)],
+            Vec::from(pending_sends.clone())
+        );
+
+        remove_delivered(&mut pending_sends, 0);
+        assert!(pending_sends.is_empty());
+    }
+
+    #[test]
+    fn test_send_times_out_by_the_oldest_pending_one() {
+        let now = Instant::now();
+        let pending_sends = VecDeque::from([(100, 1, now - Duration::from_secs(40)), (50, 1, now)]);
+
+        assert!(is_timed_out(&pending_sends, Some(Duration::from_secs(30))));
+        assert!(!is_timed_out(&pending_sends, Some(Duration::from_secs(60))));
+        assert!(!is_timed_out(&pending_sends, None));
+        assert!(!is_timed_out(
+            &VecDeque::new(),
+            Some(Duration::from_secs(30))
+        ));
+    }
 }
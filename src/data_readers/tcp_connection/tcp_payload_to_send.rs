@@ -1,47 +1,53 @@
 use my_no_sql_sdk::tcp_contracts::{DeleteRowTcpContract, MyNoSqlTcpContract};
 
+use crate::data_readers::PartitionScope;
 use crate::db_sync::SyncEvent;
 use my_no_sql_sdk::core::my_json::consts::EMPTY_ARRAY;
 
-pub async fn serialize(sync_event: &SyncEvent, compress: bool) -> Vec<MyNoSqlTcpContract> {
+/// With a `scope` only the partitions in it are sent; an event which touches none of
+/// them serializes to nothing. With a `sequence` the table name of every contract
+/// carries it - for a reader which subscribed with `fromSequence`.
+pub async fn serialize(
+    sync_event: &SyncEvent,
+    compress: bool,
+    scope: Option<&PartitionScope>,
+    sequence: Option<u64>,
+) -> Vec<MyNoSqlTcpContract> {
     match sync_event {
         SyncEvent::TableFirstInit(sync_data) => {
             let table_snapshot = sync_data.db_table.get_table_snapshot();
 
-            let data = table_snapshot.as_json_array().build();
+            let data = crate::data_readers::table_snapshot_as_json(&table_snapshot, scope);
 
-            let tcp_contract = MyNoSqlTcpContract::InitTable {
-                table_name: sync_data.db_table.name.to_string(),
-                data: data.into_bytes(),
-            };
-
-            if compress {
-                return vec![tcp_contract.compress_if_make_since()];
-            }
-
-            return vec![tcp_contract];
+            return vec![serialize_init_table(
+                get_table_name(sync_data.db_table.name.as_str(), sequence),
+                data.into_bytes(),
+                compress,
+            )];
         }
         SyncEvent::UpdateTableAttributes(_) => vec![],
         SyncEvent::InitTable(sync_data) => {
-            let data = sync_data.table_snapshot.as_json_array().build();
-
-            let tcp_contract = MyNoSqlTcpContract::InitTable {
-                table_name: sync_data.table_data.table_name.to_string(),
-                data: data.into_bytes(),
-            };
-
-            if compress {
-                return vec![tcp_contract.compress_if_make_since()];
-            }
-
-            return vec![tcp_contract];
+            let data =
+                crate::data_readers::table_snapshot_as_json(&sync_data.table_snapshot, scope);
+
+            return vec![serialize_init_table(
+                get_table_name(sync_data.table_data.table_name.as_str(), sequence),
+                data.into_bytes(),
+                compress,
+            )];
         }
         SyncEvent::InitPartitions(data) => {
             let mut result = Vec::with_capacity(data.partitions_to_update.len());
             for partition in data.partitions_to_update.iter() {
+                if let Some(scope) = scope {
+                    if !scope.contains(partition.partition_key.as_str()) {
+                        continue;
+                    }
+                }
+
                 let tcp_contract = MyNoSqlTcpContract::InitPartition {
                     partition_key: partition.partition_key.to_string(),
-                    table_name: data.table_data.table_name.to_string(),
+                    table_name: get_table_name(data.table_data.table_name.as_str(), sequence),
                     data: if let Some(db_partition_snapshot) = &partition.snapshot {
                         db_partition_snapshot
                             .db_rows_snapshot
@@ -63,9 +69,15 @@ pub async fn serialize(sync_event: &SyncEvent, compress: bool) -> Vec<MyNoSqlTcp
             return result;
         }
         SyncEvent::UpdateRows(data) => {
+            let db_rows = data.rows_by_partition.get_rows_snapshot(scope);
+
+            if scope.is_some() && db_rows.len() == 0 {
+                return vec![];
+            }
+
             let tcp_contract = MyNoSqlTcpContract::UpdateRows {
-                table_name: data.table_data.table_name.to_string(),
-                data: data.rows_by_partition.as_json_array().build().into_bytes(),
+                table_name: get_table_name(data.table_data.table_name.as_str(), sequence),
+                data: db_rows.as_json_array().build().into_bytes(),
             };
 
             if compress {
@@ -79,8 +91,14 @@ pub async fn serialize(sync_event: &SyncEvent, compress: bool) -> Vec<MyNoSqlTcp
 
             if let Some(deleted_partitions) = &data.deleted_partitions {
                 for partition_key in deleted_partitions.iter() {
+                    if let Some(scope) = scope {
+                        if !scope.contains(partition_key.as_str()) {
+                            continue;
+                        }
+                    }
+
                     let contract = MyNoSqlTcpContract::InitPartition {
-                        table_name: data.table_data.table_name.to_string(),
+                        table_name: get_table_name(data.table_data.table_name.as_str(), sequence),
                         partition_key: partition_key.to_string(),
                         data: EMPTY_ARRAY.to_vec(),
                     };
@@ -91,6 +109,12 @@ pub async fn serialize(sync_event: &SyncEvent, compress: bool) -> Vec<MyNoSqlTcp
 
             if let Some(deleted_rows) = &data.deleted_rows {
                 for deleted_row in deleted_rows.iter() {
+                    if let Some(scope) = scope {
+                        if !scope.contains(deleted_row.partition_key.as_str()) {
+                            continue;
+                        }
+                    }
+
                     let mut deleted_rows = Vec::new();
 
                     for db_row in deleted_row.db_rows.iter() {
@@ -103,7 +127,7 @@ pub async fn serialize(sync_event: &SyncEvent, compress: bool) -> Vec<MyNoSqlTcp
                     }
 
                     let contract = MyNoSqlTcpContract::DeleteRows {
-                        table_name: data.table_data.table_name.to_string(),
+                        table_name: get_table_name(data.table_data.table_name.as_str(), sequence),
                         rows: deleted_rows,
                     };
 
@@ -115,7 +139,7 @@ pub async fn serialize(sync_event: &SyncEvent, compress: bool) -> Vec<MyNoSqlTcp
         }
         SyncEvent::DeleteTable(data) => {
             let contract = MyNoSqlTcpContract::InitTable {
-                table_name: data.table_data.table_name.to_string(),
+                table_name: get_table_name(data.table_data.table_name.as_str(), sequence),
                 data: EMPTY_ARRAY.to_vec(),
             };
 
@@ -123,3 +147,60 @@ pub async fn serialize(sync_event: &SyncEvent, compress: bool) -> Vec<MyNoSqlTcp
         }
     }
 }
+
+fn get_table_name(table_name: &str, sequence: Option<u64>) -> String {
+    match sequence {
+        Some(sequence) => crate::data_readers::get_sequenced_table_name(table_name, sequence),
+        None => table_name.to_string(),
+    }
+}
+
+pub fn serialize_init_table(
+    table_name: String,
+    data: Vec<u8>,
+    compress: bool,
+) -> MyNoSqlTcpContract {
+    let tcp_contract = MyNoSqlTcpContract::InitTable { table_name, data };
+
+    if compress {
+        return tcp_contract.compress_if_make_since();
+    }
+
+    tcp_contract
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What a reader does with the table name of an event: finds the table by the name
+    /// before `?`, and takes the sequence after `sequence=`.
+    fn read_table_name(table_name: &str) -> (&str, Option<u64>) {
+        let table = crate::data_readers::get_subscribe_table_name(table_name);
+
+        let sequence = table_name
+            .strip_prefix(table)
+            .and_then(|rest| rest.strip_prefix("?sequence="))
+            .map(|sequence| sequence.parse().unwrap());
+
+        (table, sequence)
+    }
+
+    #[test]
+    fn test_sequenced_table_name_round_trip() {
+        assert_eq!(
+            ("orders", None),
+            read_table_name(&get_table_name("orders", None))
+        );
+
+        assert_eq!(
+            ("orders", Some(1718000000000123)),
+            read_table_name(&get_table_name("orders", Some(1718000000000123)))
+        );
+
+        assert_eq!(
+            ("client-orders-2", Some(0)),
+            read_table_name(&get_table_name("client-orders-2", Some(0)))
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod web_socket_connection_info;
+pub use web_socket_connection_info::*;
+pub mod web_socket_frames;
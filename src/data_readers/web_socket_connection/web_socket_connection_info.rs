@@ -0,0 +1,148 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use hyper_tungstenite::tungstenite::Message;
+use my_http_server::web_sockets::MyWebSocket;
+use my_no_sql_sdk::core::rust_extensions::date_time::{
+    AtomicDateTimeAsMicroseconds, DateTimeAsMicroseconds,
+};
+use my_no_sql_sdk::tcp_contracts::MyNoSqlTcpContract;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::data_readers::tcp_connection::{ReaderName, SendPerSecond};
+use crate::data_readers::PendingToSend;
+
+/// Reader which speaks the TCP contracts over a WebSocket of the HTTP server.
+///
+/// Frames are queued and written by a task of their own, so a slow socket holds
+/// up its own reader only - never the sync loop which feeds every reader.
+pub struct WebSocketConnectionInfo {
+    web_socket: Arc<MyWebSocket>,
+    pub name: ReaderName,
+    pub compress_data: bool,
+    pub connected: DateTimeAsMicroseconds,
+    pub last_incoming_moment: AtomicDateTimeAsMicroseconds,
+    sender: UnboundedSender<Vec<u8>>,
+    pending_to_send: Arc<PendingToSend>,
+    sent_per_second_accumulator: AtomicUsize,
+    received_per_second_accumulator: AtomicUsize,
+    /// (incoming, outgoing) bytes of the last full second.
+    traffic_per_second: (AtomicUsize, AtomicUsize),
+    pub sent_per_second: SendPerSecond,
+}
+
+impl WebSocketConnectionInfo {
+    pub fn new(web_socket: Arc<MyWebSocket>, name: ReaderName, compress_data: bool) -> Self {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<Vec<u8>>();
+        let pending_to_send = Arc::new(PendingToSend::new());
+
+        let web_socket_to_write = web_socket.clone();
+        let pending_to_write = pending_to_send.clone();
+
+        tokio::spawn(async move {
+            while let Some(payload) = receiver.recv().await {
+                let size = payload.len();
+
+                web_socket_to_write
+                    .send_message(Message::Binary(payload.into()))
+                    .await;
+
+                pending_to_write.remove(size);
+            }
+        });
+
+        Self {
+            web_socket,
+            name,
+            compress_data,
+            connected: DateTimeAsMicroseconds::now(),
+            last_incoming_moment: AtomicDateTimeAsMicroseconds::now(),
+            sender,
+            pending_to_send,
+            sent_per_second_accumulator: AtomicUsize::new(0),
+            received_per_second_accumulator: AtomicUsize::new(0),
+            traffic_per_second: (AtomicUsize::new(0), AtomicUsize::new(0)),
+            sent_per_second: SendPerSecond::new(),
+        }
+    }
+
+    pub fn get_id(&self) -> i64 {
+        self.web_socket.id
+    }
+
+    pub fn get_ip(&self) -> String {
+        format!("{}", self.web_socket.addr)
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name.get_name()
+    }
+
+    pub fn is_node(&self) -> bool {
+        self.name.is_node()
+    }
+
+    pub fn is_compressed_data(&self) -> bool {
+        self.compress_data
+    }
+
+    pub async fn send(&self, tcp_contracts: &[MyNoSqlTcpContract]) {
+        let mut sent_amount = 0;
+
+        for tcp_contract in tcp_contracts {
+            let payload = super::web_socket_frames::serialize(tcp_contract);
+            let size = payload.len();
+
+            self.pending_to_send.add(size);
+
+            if self.sender.send(payload).is_err() {
+                self.pending_to_send.remove(size);
+                return;
+            }
+
+            sent_amount += size;
+        }
+
+        self.sent_per_second_accumulator
+            .fetch_add(sent_amount, Ordering::SeqCst);
+    }
+
+    pub async fn disconnect(&self) {
+        self.web_socket.disconnect().await;
+    }
+
+    pub fn mark_incoming(&self, now: DateTimeAsMicroseconds, size: usize) {
+        self.last_incoming_moment.update(now);
+        self.received_per_second_accumulator
+            .fetch_add(size, Ordering::SeqCst);
+    }
+
+    pub async fn timer_1sec_tick(&self) {
+        let received = self
+            .received_per_second_accumulator
+            .swap(0, Ordering::SeqCst);
+        let sent = self.sent_per_second_accumulator.swap(0, Ordering::SeqCst);
+
+        self.traffic_per_second.0.store(received, Ordering::Relaxed);
+        self.traffic_per_second.1.store(sent, Ordering::Relaxed);
+
+        self.sent_per_second.add(sent).await;
+    }
+
+    pub fn get_traffic_per_second(&self) -> (usize, usize) {
+        (
+            self.traffic_per_second.0.load(Ordering::Relaxed),
+            self.traffic_per_second.1.load(Ordering::Relaxed),
+        )
+    }
+
+    pub fn get_pending_to_send(&self) -> usize {
+        self.pending_to_send.get_size()
+    }
+
+    pub fn get_pending_events(&self) -> usize {
+        self.pending_to_send.get_amount()
+    }
+}
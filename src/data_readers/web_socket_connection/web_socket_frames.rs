@@ -0,0 +1,19 @@
+use my_no_sql_sdk::tcp_contracts::{MyNoSqlReaderTcpSerializer, MyNoSqlTcpContract};
+use my_tcp_sockets::{socket_reader::SocketReaderInMem, TcpSocketSerializer};
+
+/// One contract per binary frame, in the very bytes the TCP port sends it as - a
+/// client reads a frame the way it reads a packet off the socket.
+pub fn serialize(contract: &MyNoSqlTcpContract) -> Vec<u8> {
+    let mut result = Vec::new();
+    MyNoSqlReaderTcpSerializer::new().serialize(&mut result, contract, &());
+    result
+}
+
+pub async fn deserialize(payload: Vec<u8>) -> Result<MyNoSqlTcpContract, String> {
+    let mut socket_reader = SocketReaderInMem::new(payload);
+
+    MyNoSqlReaderTcpSerializer::new()
+        .deserialize(&mut socket_reader, &())
+        .await
+        .map_err(|err| format!("{:?}", err))
+}
@@ -15,6 +15,18 @@ pub enum DbOperationError {
     /// creating one just to fail inside it would leave a folder behind.
     NamespaceNotFound(String),
     ApplicationIsNotInitializedYet,
+    /// A merge patch which can not be applied to the row it addresses.
+    InvalidMergePatch(String),
+    /// The field an increment addresses holds something which is not a number, or
+    /// the result does not fit one.
+    FieldIsNotNumeric(String),
+    /// A conditional write found the row, but not meeting the condition on this field.
+    ConditionNotMet(String),
+    /// No API key, or one which is not known.
+    Unauthorized(String),
+    /// The reader has no credential which lets it read this, or the API key does not
+    /// allow the operation.
+    AccessDenied(String),
     DbEntityParseFail(DbEntityParseFail),
 }
 
@@ -0,0 +1,178 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConditionOperator {
+    #[default]
+    #[serde(rename = "eq")]
+    Eq,
+    #[serde(rename = "ne")]
+    Ne,
+    #[serde(rename = "gt")]
+    Gt,
+    #[serde(rename = "gte")]
+    Gte,
+    #[serde(rename = "lt")]
+    Lt,
+    #[serde(rename = "lte")]
+    Lte,
+    #[serde(rename = "exists")]
+    Exists,
+    #[serde(rename = "notExists")]
+    NotExists,
+}
+
+/// A predicate over one field of a row: `{"field":"Status","op":"eq","value":"Pending"}`.
+/// The field is a top-level name or a dotted path into nested objects.
+///
+/// A field the row does not have is never equal, greater or less than anything —
+/// so `ne` is the only comparison it satisfies. Numbers compare as numbers
+/// (`1` equals `1.0`), strings compare by their text, and values of different
+/// types are never ordered against each other.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldCondition {
+    pub field: String,
+    #[serde(default)]
+    pub op: ConditionOperator,
+    #[serde(default)]
+    pub value: Option<Value>,
+}
+
+impl FieldCondition {
+    pub fn is_met(&self, row: &Value) -> bool {
+        let field = get_json_field(row, self.field.as_str());
+
+        match self.op {
+            ConditionOperator::Exists => return field.is_some(),
+            ConditionOperator::NotExists => return field.is_none(),
+            _ => {}
+        }
+
+        let expected = self.value.as_ref().unwrap_or(&Value::Null);
+
+        let Some(field) = field else {
+            return self.op == ConditionOperator::Ne;
+        };
+
+        match self.op {
+            ConditionOperator::Eq => json_values_are_equal(field, expected),
+            ConditionOperator::Ne => !json_values_are_equal(field, expected),
+            ConditionOperator::Gt => {
+                compare_json_values(field, expected) == Some(Ordering::Greater)
+            }
+            ConditionOperator::Gte => matches!(
+                compare_json_values(field, expected),
+                Some(Ordering::Greater) | Some(Ordering::Equal)
+            ),
+            ConditionOperator::Lt => compare_json_values(field, expected) == Some(Ordering::Less),
+            ConditionOperator::Lte => matches!(
+                compare_json_values(field, expected),
+                Some(Ordering::Less) | Some(Ordering::Equal)
+            ),
+            ConditionOperator::Exists | ConditionOperator::NotExists => unreachable!(),
+        }
+    }
+}
+
+/// The first condition the row does not meet, `None` when it meets all of them.
+pub fn get_first_unmet<'s>(
+    conditions: &'s [FieldCondition],
+    row: &Value,
+) -> Option<&'s FieldCondition> {
+    conditions.iter().find(|condition| !condition.is_met(row))
+}
+
+pub fn all_are_met(conditions: &[FieldCondition], row: &Value) -> bool {
+    get_first_unmet(conditions, row).is_none()
+}
+
+/// `Customer.Name` walks into nested objects. A path crossing something which is
+/// not an object leads nowhere.
+pub fn get_json_field<'s>(row: &'s Value, path: &str) -> Option<&'s Value> {
+    let mut result = row;
+
+    for segment in path.split('.') {
+        result = result.as_object()?.get(segment)?;
+    }
+
+    Some(result)
+}
+
+pub fn json_values_are_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        _ => a == b,
+    }
+}
+
+pub fn compare_json_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn condition(field: &str, op: ConditionOperator, value: Value) -> FieldCondition {
+        FieldCondition {
+            field: field.to_string(),
+            op,
+            value: Some(value),
+        }
+    }
+
+    #[test]
+    fn test_eq_and_ne() {
+        let row = json!({"Status": "Pending", "Amount": 5});
+
+        assert!(condition("Status", ConditionOperator::Eq, json!("Pending")).is_met(&row));
+        assert!(!condition("Status", ConditionOperator::Eq, json!("Paid")).is_met(&row));
+        assert!(condition("Status", ConditionOperator::Ne, json!("Paid")).is_met(&row));
+        assert!(condition("Amount", ConditionOperator::Eq, json!(5.0)).is_met(&row));
+    }
+
+    #[test]
+    fn test_missing_field_only_meets_ne_and_not_exists() {
+        let row = json!({"Status": "Pending"});
+
+        assert!(!condition("Owner", ConditionOperator::Eq, json!("me")).is_met(&row));
+        assert!(!condition("Owner", ConditionOperator::Gt, json!("a")).is_met(&row));
+        assert!(condition("Owner", ConditionOperator::Ne, json!("me")).is_met(&row));
+        assert!(condition("Owner", ConditionOperator::NotExists, Value::Null).is_met(&row));
+        assert!(!condition("Owner", ConditionOperator::Exists, Value::Null).is_met(&row));
+    }
+
+    #[test]
+    fn test_ordering_and_dotted_path() {
+        let row = json!({"Order": {"Amount": 10, "Created": "2024-05-01"}});
+
+        assert!(condition("Order.Amount", ConditionOperator::Gt, json!(9.5)).is_met(&row));
+        assert!(condition("Order.Amount", ConditionOperator::Lte, json!(10)).is_met(&row));
+        assert!(condition("Order.Created", ConditionOperator::Lt, json!("2024-06")).is_met(&row));
+        assert!(!condition("Order.Amount", ConditionOperator::Gt, json!("9")).is_met(&row));
+    }
+
+    #[test]
+    fn test_first_unmet_is_reported() {
+        let row = json!({"Status": "Pending", "Owner": "bob"});
+
+        let conditions = vec![
+            condition("Status", ConditionOperator::Eq, json!("Pending")),
+            condition("Owner", ConditionOperator::Eq, json!("alice")),
+        ];
+
+        let unmet = get_first_unmet(&conditions, &row).unwrap();
+
+        assert_eq!(unmet.field, "Owner");
+        assert!(!all_are_met(&conditions, &row));
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
     db_sync::EventSource,
 };
 
+/// Returns the keys of the partitions it deleted.
 pub async fn keep_max_partitions_amount(
     app: &AppContext,
     db_namespace: &Arc<DbNamespace>,
@@ -16,7 +17,7 @@ pub async fn keep_max_partitions_amount(
     max_partitions_amount: usize,
     event_src: EventSource,
     persist_moment: DateTimeAsMicroseconds,
-) -> Result<(), DbOperationError> {
+) -> Result<Vec<String>, DbOperationError> {
     super::super::check_app_states(app)?;
 
     let partitions_to_gc = {
@@ -27,18 +28,25 @@ pub async fn keep_max_partitions_amount(
             .get_partitions_to_gc_by_max_amount(max_partitions_amount)
     };
 
-    if let Some(partitions_to_gc) = partitions_to_gc {
-        super::super::write::delete_partitions(
-            app,
-            db_namespace,
-            db_table,
-            partitions_to_gc.into_iter().map(|itm| itm.partition_key),
-            event_src,
-            persist_moment,
-            DateTimeAsMicroseconds::now(),
-        )
-        .await?;
-    }
-
-    Ok(())
+    let Some(partitions_to_gc) = partitions_to_gc else {
+        return Ok(vec![]);
+    };
+
+    let result = partitions_to_gc
+        .iter()
+        .map(|itm| itm.partition_key.to_string())
+        .collect();
+
+    super::super::write::delete_partitions(
+        app,
+        db_namespace,
+        db_table,
+        partitions_to_gc.into_iter().map(|itm| itm.partition_key),
+        event_src,
+        persist_moment,
+        DateTimeAsMicroseconds::now(),
+    )
+    .await?;
+
+    Ok(result)
 }
@@ -9,6 +9,7 @@ use crate::{
     db_sync::EventSource,
 };
 
+/// Returns how many rows it deleted.
 pub async fn execute(
     app: &AppContext,
     db_namespace: &Arc<DbNamespace>,
@@ -17,7 +18,7 @@ pub async fn execute(
     max_rows_amount: usize,
     event_source: EventSource,
     persist_moment: DateTimeAsMicroseconds,
-) -> Result<(), DbOperationError> {
+) -> Result<usize, DbOperationError> {
     super::super::check_app_states(app)?;
 
     let rows_to_gc = {
@@ -25,7 +26,7 @@ pub async fn execute(
         let partition = table_data.get_partition(partition_key.as_str());
 
         if partition.is_none() {
-            return Ok(());
+            return Ok(0);
         }
 
         let db_rows = partition
@@ -34,12 +35,14 @@ pub async fn execute(
             .get_rows_to_gc_by_max_amount(max_rows_amount);
 
         if db_rows.is_none() {
-            return Ok(());
+            return Ok(0);
         }
 
         db_rows.unwrap()
     };
 
+    let rows = rows_to_gc.len();
+
     super::super::write::bulk_delete(
         app,
         db_namespace,
@@ -51,5 +54,5 @@ pub async fn execute(
     )
     .await?;
 
-    Ok(())
+    Ok(rows)
 }
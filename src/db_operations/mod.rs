@@ -1,6 +1,7 @@
 pub mod bulk_processes;
 mod check_app_states;
 mod error;
+pub mod field_conditions;
 pub mod gc;
 pub mod multipart;
 pub mod parse_json_entity;
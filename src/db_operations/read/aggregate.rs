@@ -0,0 +1,485 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::db::DbRow;
+use my_no_sql_sdk::server::DbTable;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    app::AppContext,
+    db_operations::{field_conditions::FieldCondition, DbOperationError},
+};
+
+/// A group-by over a field with a value per row (an id, a timestamp) would turn
+/// the "small JSON result" into a copy of the table. Rows which would open a
+/// group above this are left out and the result is marked as truncated.
+pub const MAX_GROUPS: usize = 10_000;
+
+pub enum AggregateScope<'s> {
+    Table,
+    Partition(&'s str),
+    PartitionKeyPrefix(&'s str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AggregateFunction {
+    #[serde(rename = "count")]
+    Count,
+    #[serde(rename = "sum")]
+    Sum,
+    #[serde(rename = "min")]
+    Min,
+    #[serde(rename = "max")]
+    Max,
+    #[serde(rename = "avg")]
+    Avg,
+}
+
+impl AggregateFunction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Count => "count",
+            Self::Sum => "sum",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Avg => "avg",
+        }
+    }
+}
+
+/// `count` without a field counts rows; with one it counts the rows which have
+/// that field. `sum` and `avg` take only numbers into account; `min` and `max`
+/// compare numbers with numbers and strings with strings, so they work on ISO
+/// dates as well.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Aggregation {
+    #[serde(rename = "op")]
+    pub function: AggregateFunction,
+    #[serde(default)]
+    pub field: Option<String>,
+}
+
+impl Aggregation {
+    /// `count`, `sum(Amount)` — what the value is reported under.
+    pub fn get_name(&self) -> String {
+        match self.field.as_deref() {
+            Some(field) => format!("{}({})", self.function.as_str(), field),
+            None => self.function.as_str().to_string(),
+        }
+    }
+}
+
+pub struct AggregateGroup {
+    /// Value of the group-by field. `None` when there is no group-by; `Null`
+    /// for the rows which do not have the field.
+    pub key: Option<Value>,
+    pub rows: usize,
+    /// One per requested aggregation, in the order they were requested.
+    pub values: Vec<Value>,
+}
+
+pub struct AggregateResult {
+    pub rows_scanned: usize,
+    pub rows_matched: usize,
+    pub truncated: bool,
+    pub groups: Vec<AggregateGroup>,
+}
+
+/// The result as both the HTTP endpoint and the MCP tool answer it.
+#[derive(Serialize, Debug)]
+pub struct AggregateResponseContract {
+    #[serde(rename = "rowsScanned")]
+    pub rows_scanned: usize,
+
+    #[serde(rename = "rowsMatched")]
+    pub rows_matched: usize,
+
+    /// More distinct `groupBy` values than `MAX_GROUPS`: the rows of the groups
+    /// above the limit are not in the result.
+    pub truncated: bool,
+
+    pub groups: Vec<AggregateGroupContract>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct AggregateGroupContract {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<Value>,
+
+    pub rows: usize,
+
+    /// `{"count":4,"sum(Amount)":19.5}` — one entry per requested aggregation.
+    pub values: Map<String, Value>,
+}
+
+impl AggregateResponseContract {
+    pub fn new(result: AggregateResult, aggregations: &[Aggregation]) -> Self {
+        Self {
+            rows_scanned: result.rows_scanned,
+            rows_matched: result.rows_matched,
+            truncated: result.truncated,
+            groups: result
+                .groups
+                .into_iter()
+                .map(|group| AggregateGroupContract {
+                    key: group.key,
+                    rows: group.rows,
+                    values: aggregations
+                        .iter()
+                        .map(|itm| itm.get_name())
+                        .zip(group.values)
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Everything is computed in place under the read lock of the table: no row is
+/// cloned out of it, each one is parsed into a reused buffer and dropped.
+pub async fn aggregate(
+    app: &Arc<AppContext>,
+    db_table: &Arc<DbTable>,
+    scope: AggregateScope<'_>,
+    filter: &[FieldCondition],
+    group_by: Option<&str>,
+    aggregations: &[Aggregation],
+) -> Result<AggregateResult, DbOperationError> {
+    super::super::check_app_states(app)?;
+
+    let mut state = AggregateState::new(filter, group_by, aggregations);
+
+    let table_data = db_table.data.read();
+
+    match scope {
+        AggregateScope::Table => {
+            for db_partition in table_data.get_partitions() {
+                for db_row in db_partition.get_all_rows() {
+                    state.add(db_row);
+                }
+            }
+        }
+        AggregateScope::Partition(partition_key) => {
+            if let Some(db_partition) = table_data.get_partition(partition_key) {
+                for db_row in db_partition.get_all_rows() {
+                    state.add(db_row);
+                }
+            }
+        }
+        AggregateScope::PartitionKeyPrefix(prefix) => {
+            for db_partition in table_data.get_partitions() {
+                if !db_partition.partition_key.as_str().starts_with(prefix) {
+                    continue;
+                }
+
+                for db_row in db_partition.get_all_rows() {
+                    state.add(db_row);
+                }
+            }
+        }
+    }
+
+    Ok(state.into_result())
+}
+
+struct AggregateState<'s> {
+    filter: &'s [FieldCondition],
+    group_by: Option<&'s str>,
+    aggregations: &'s [Aggregation],
+    groups: BTreeMap<String, (Option<Value>, usize, Vec<Accumulator>)>,
+    rows_scanned: usize,
+    rows_matched: usize,
+    truncated: bool,
+    json_buffer: String,
+}
+
+impl<'s> AggregateState<'s> {
+    fn new(
+        filter: &'s [FieldCondition],
+        group_by: Option<&'s str>,
+        aggregations: &'s [Aggregation],
+    ) -> Self {
+        Self {
+            filter,
+            group_by,
+            aggregations,
+            groups: BTreeMap::new(),
+            rows_scanned: 0,
+            rows_matched: 0,
+            truncated: false,
+            json_buffer: String::new(),
+        }
+    }
+
+    fn add(&mut self, db_row: &DbRow) {
+        self.rows_scanned += 1;
+
+        self.json_buffer.clear();
+        db_row.write_json(&mut self.json_buffer);
+
+        let Ok(row) = serde_json::from_str::<Value>(self.json_buffer.as_str()) else {
+            return;
+        };
+
+        self.add_value(&row);
+    }
+
+    fn add_value(&mut self, row: &Value) {
+        if !crate::db_operations::field_conditions::all_are_met(self.filter, row) {
+            return;
+        }
+
+        let key = self.group_by.map(|group_by| {
+            crate::db_operations::field_conditions::get_json_field(row, group_by)
+                .cloned()
+                .unwrap_or(Value::Null)
+        });
+
+        let group_id = match key.as_ref() {
+            Some(key) => key.to_string(),
+            None => String::new(),
+        };
+
+        if !self.groups.contains_key(&group_id) {
+            if self.groups.len() >= MAX_GROUPS {
+                self.truncated = true;
+                return;
+            }
+
+            let accumulators = self
+                .aggregations
+                .iter()
+                .map(|itm| Accumulator::new(itm.function))
+                .collect();
+
+            self.groups.insert(group_id.clone(), (key, 0, accumulators));
+        }
+
+        self.rows_matched += 1;
+
+        let (_, rows, accumulators) = self.groups.get_mut(&group_id).unwrap();
+
+        *rows += 1;
+
+        for (aggregation, accumulator) in self.aggregations.iter().zip(accumulators.iter_mut()) {
+            match aggregation.field.as_deref() {
+                Some(field) => {
+                    if let Some(value) =
+                        crate::db_operations::field_conditions::get_json_field(row, field)
+                    {
+                        accumulator.add(value);
+                    }
+                }
+                None => accumulator.add_row(),
+            }
+        }
+    }
+
+    fn into_result(self) -> AggregateResult {
+        AggregateResult {
+            rows_scanned: self.rows_scanned,
+            rows_matched: self.rows_matched,
+            truncated: self.truncated,
+            groups: self
+                .groups
+                .into_values()
+                .map(|(key, rows, accumulators)| AggregateGroup {
+                    key,
+                    rows,
+                    values: accumulators
+                        .into_iter()
+                        .map(|itm| itm.into_value())
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+enum Accumulator {
+    Count(usize),
+    Sum(f64),
+    Min(Option<Value>),
+    Max(Option<Value>),
+    Avg { sum: f64, count: usize },
+}
+
+impl Accumulator {
+    fn new(function: AggregateFunction) -> Self {
+        match function {
+            AggregateFunction::Count => Self::Count(0),
+            AggregateFunction::Sum => Self::Sum(0.0),
+            AggregateFunction::Min => Self::Min(None),
+            AggregateFunction::Max => Self::Max(None),
+            AggregateFunction::Avg => Self::Avg { sum: 0.0, count: 0 },
+        }
+    }
+
+    /// An aggregation with no field sees the row itself: only `count` can do
+    /// anything with that.
+    fn add_row(&mut self) {
+        if let Self::Count(count) = self {
+            *count += 1;
+        }
+    }
+
+    fn add(&mut self, value: &Value) {
+        match self {
+            Self::Count(count) => {
+                if !value.is_null() {
+                    *count += 1;
+                }
+            }
+            Self::Sum(sum) => {
+                if let Some(value) = value.as_f64() {
+                    *sum += value;
+                }
+            }
+            Self::Avg { sum, count } => {
+                if let Some(value) = value.as_f64() {
+                    *sum += value;
+                    *count += 1;
+                }
+            }
+            Self::Min(current) => {
+                if is_better(current.as_ref(), value, std::cmp::Ordering::Less) {
+                    *current = Some(value.clone());
+                }
+            }
+            Self::Max(current) => {
+                if is_better(current.as_ref(), value, std::cmp::Ordering::Greater) {
+                    *current = Some(value.clone());
+                }
+            }
+        }
+    }
+
+    fn into_value(self) -> Value {
+        match self {
+            Self::Count(count) => Value::from(count),
+            Self::Sum(sum) => f64_to_value(sum),
+            Self::Min(value) | Self::Max(value) => value.unwrap_or(Value::Null),
+            Self::Avg { sum, count } => {
+                if count == 0 {
+                    Value::Null
+                } else {
+                    f64_to_value(sum / count as f64)
+                }
+            }
+        }
+    }
+}
+
+/// Only numbers and strings take part in `min`/`max`. The first one seen fixes
+/// the type of the group; values of another type cannot be ordered against it
+/// and are passed over.
+fn is_better(current: Option<&Value>, candidate: &Value, wanted: std::cmp::Ordering) -> bool {
+    if !candidate.is_number() && !candidate.is_string() {
+        return false;
+    }
+
+    match current {
+        Some(current) => {
+            crate::db_operations::field_conditions::compare_json_values(candidate, current)
+                == Some(wanted)
+        }
+        None => true,
+    }
+}
+
+fn f64_to_value(value: f64) -> Value {
+    serde_json::Number::from_f64(value)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::db_operations::field_conditions::ConditionOperator;
+
+    fn aggregation(function: AggregateFunction, field: Option<&str>) -> Aggregation {
+        Aggregation {
+            function,
+            field: field.map(|itm| itm.to_string()),
+        }
+    }
+
+    fn rows() -> Vec<Value> {
+        vec![
+            json!({"Status": "Paid", "Amount": 10, "Created": "2024-01-02"}),
+            json!({"Status": "Paid", "Amount": 2.5, "Created": "2024-01-01"}),
+            json!({"Status": "Pending", "Amount": 7}),
+            json!({"Amount": "n/a"}),
+        ]
+    }
+
+    #[test]
+    fn test_aggregations_without_group_by() {
+        let aggregations = vec![
+            aggregation(AggregateFunction::Count, None),
+            aggregation(AggregateFunction::Sum, Some("Amount")),
+            aggregation(AggregateFunction::Avg, Some("Amount")),
+            aggregation(AggregateFunction::Min, Some("Created")),
+            aggregation(AggregateFunction::Max, Some("Amount")),
+        ];
+
+        let mut state = AggregateState::new(&[], None, &aggregations);
+
+        for row in rows() {
+            state.add_value(&row);
+        }
+
+        let result = state.into_result();
+
+        assert_eq!(result.rows_matched, 4);
+        assert_eq!(result.groups.len(), 1);
+
+        let group = &result.groups[0];
+        assert!(group.key.is_none());
+        assert_eq!(
+            group.values,
+            vec![
+                json!(4),
+                json!(19.5),
+                json!(6.5),
+                json!("2024-01-01"),
+                json!(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_by_with_filter() {
+        let aggregations = vec![
+            aggregation(AggregateFunction::Count, None),
+            aggregation(AggregateFunction::Sum, Some("Amount")),
+        ];
+
+        let filter = vec![FieldCondition {
+            field: "Status".to_string(),
+            op: ConditionOperator::Exists,
+            value: None,
+        }];
+
+        let mut state = AggregateState::new(&filter, Some("Status"), &aggregations);
+
+        for row in rows() {
+            state.add_value(&row);
+        }
+
+        let result = state.into_result();
+
+        assert_eq!(result.rows_matched, 3);
+        assert_eq!(result.groups.len(), 2);
+
+        assert_eq!(result.groups[0].key, Some(json!("Paid")));
+        assert_eq!(result.groups[0].values, vec![json!(2), json!(12.5)]);
+
+        assert_eq!(result.groups[1].key, Some(json!("Pending")));
+        assert_eq!(result.groups[1].values, vec![json!(1), json!(7.0)]);
+    }
+}
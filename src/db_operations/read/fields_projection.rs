@@ -0,0 +1,279 @@
+use my_no_sql_sdk::core::db::DbRow;
+use my_no_sql_sdk::core::my_json::json_writer::JsonArrayWriter;
+use serde_json::{Map, Value};
+
+/// Keys every projected row keeps no matter what was asked for: without them
+/// a client can neither address the row again nor do an optimistic-concurrency
+/// write against it.
+const ALWAYS_INCLUDED: [&str; 3] = ["PartitionKey", "RowKey", "TimeStamp"];
+
+/// `fields=` of the read endpoints: the top-level or dotted-path fields a
+/// client wants back instead of the whole row. `Status,Customer.Name` gives
+/// `{"Customer":{"Name":..},"Status":..}` plus the keys of the row.
+///
+/// A field the row does not have is simply left out — rows of one table do
+/// not have to share a schema.
+#[derive(Debug, Clone)]
+pub struct FieldsProjection {
+    paths: Vec<Vec<String>>,
+}
+
+impl FieldsProjection {
+    /// `None` when nothing is listed, which means "the whole row" — the same
+    /// as not passing the parameter at all.
+    pub fn new<'s>(fields: impl Iterator<Item = &'s str>) -> Option<Self> {
+        let mut paths: Vec<Vec<String>> = Vec::new();
+
+        for field in fields {
+            let field = field.trim();
+
+            if field.is_empty() {
+                continue;
+            }
+
+            let path: Vec<String> = field
+                .split('.')
+                .map(|segment| segment.trim().to_string())
+                .collect();
+
+            if path.iter().any(|segment| segment.is_empty()) {
+                continue;
+            }
+
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+
+        if paths.is_empty() {
+            return None;
+        }
+
+        Some(Self { paths })
+    }
+
+    /// The HTTP form: `fields=Status,Customer.Name`.
+    pub fn parse(fields: Option<&str>) -> Option<Self> {
+        Self::new(fields?.split(','))
+    }
+
+    pub fn project_value(&self, row: &Value) -> Value {
+        let Value::Object(src) = row else {
+            return row.clone();
+        };
+
+        let mut result = Map::new();
+
+        for key in ALWAYS_INCLUDED {
+            if let Some(value) = src.get(key) {
+                result.insert(key.to_string(), value.clone());
+            }
+        }
+
+        for path in &self.paths {
+            if let Some(value) = get_by_path(src, path) {
+                insert_by_path(&mut result, path, value.clone());
+            }
+        }
+
+        Value::Object(result)
+    }
+
+    /// Falls back to the whole row if its content somehow is not a JSON object:
+    /// handing a client something it did not ask for beats dropping the row.
+    pub fn project_row(&self, db_row: &DbRow) -> Vec<u8> {
+        let content = db_row.to_vec();
+
+        match serde_json::from_slice::<Value>(content.as_slice()) {
+            Ok(value) => serde_json::to_vec(&self.project_value(&value)).unwrap_or(content),
+            Err(_) => content,
+        }
+    }
+
+    /// Same for a JSON array of rows — the way a partition is stored in a
+    /// snapshot file.
+    pub fn project_array(&self, content: Vec<u8>) -> Vec<u8> {
+        match serde_json::from_slice::<Vec<Value>>(content.as_slice()) {
+            Ok(rows) => {
+                let rows: Vec<Value> = rows.iter().map(|row| self.project_value(row)).collect();
+                serde_json::to_vec(&rows).unwrap_or(content)
+            }
+            Err(_) => content,
+        }
+    }
+}
+
+fn get_by_path<'s>(src: &'s Map<String, Value>, path: &[String]) -> Option<&'s Value> {
+    let (first, rest) = path.split_first()?;
+
+    let value = src.get(first)?;
+
+    if rest.is_empty() {
+        return Some(value);
+    }
+
+    match value {
+        Value::Object(inner) => get_by_path(inner, rest),
+        _ => None,
+    }
+}
+
+fn insert_by_path(dest: &mut Map<String, Value>, path: &[String], value: Value) {
+    let Some((first, rest)) = path.split_first() else {
+        return;
+    };
+
+    if rest.is_empty() {
+        dest.insert(first.clone(), value);
+        return;
+    }
+
+    let entry = dest
+        .entry(first.clone())
+        .or_insert_with(|| Value::Object(Map::new()));
+
+    // `Customer` and `Customer.Name` both asked for: the whole object is
+    // already there and includes the nested field.
+    if let Value::Object(inner) = entry {
+        insert_by_path(inner, rest, value);
+    }
+}
+
+/// What the read operations compile their JSON array with: the plain
+/// `JsonArrayWriter` unless the request asked for a projection.
+pub enum RowsJsonArray<'f> {
+    Full(JsonArrayWriter),
+    Projected {
+        fields: &'f FieldsProjection,
+        content: Vec<u8>,
+    },
+}
+
+impl<'f> RowsJsonArray<'f> {
+    pub fn new(fields: Option<&'f FieldsProjection>) -> Self {
+        match fields {
+            Some(fields) => Self::Projected {
+                fields,
+                content: vec![b'['],
+            },
+            None => Self::Full(JsonArrayWriter::new()),
+        }
+    }
+
+    pub fn write(self, db_row: &DbRow) -> Self {
+        match self {
+            Self::Full(json_array_writer) => Self::Full(json_array_writer.write(db_row)),
+            Self::Projected {
+                fields,
+                mut content,
+            } => {
+                if content.len() > 1 {
+                    content.push(b',');
+                }
+
+                content.extend_from_slice(fields.project_row(db_row).as_slice());
+                Self::Projected { fields, content }
+            }
+        }
+    }
+
+    pub fn build(self) -> Vec<u8> {
+        match self {
+            Self::Full(json_array_writer) => json_array_writer.build().into_bytes(),
+            Self::Projected { mut content, .. } => {
+                content.push(b']');
+                content
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::FieldsProjection;
+
+    #[test]
+    fn test_keys_are_always_included() {
+        let fields = FieldsProjection::parse(Some("Status")).unwrap();
+
+        let row = json!({
+            "PartitionKey": "pk",
+            "RowKey": "rk",
+            "TimeStamp": "2024-01-01T00:00:00",
+            "Status": "Pending",
+            "Payload": {"Big": [1, 2, 3]}
+        });
+
+        let result = fields.project_value(&row);
+
+        assert_eq!(
+            json!({
+                "PartitionKey": "pk",
+                "RowKey": "rk",
+                "TimeStamp": "2024-01-01T00:00:00",
+                "Status": "Pending"
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_dotted_path_keeps_nesting() {
+        let fields = FieldsProjection::parse(Some("Customer.Name, Customer.Address.City")).unwrap();
+
+        let row = json!({
+            "PartitionKey": "pk",
+            "RowKey": "rk",
+            "Customer": {"Name": "John", "Age": 30, "Address": {"City": "Kyiv", "Zip": "01001"}}
+        });
+
+        let result = fields.project_value(&row);
+
+        assert_eq!(
+            json!({
+                "PartitionKey": "pk",
+                "RowKey": "rk",
+                "Customer": {"Name": "John", "Address": {"City": "Kyiv"}}
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_missing_fields_are_skipped() {
+        let fields = FieldsProjection::parse(Some("Status,Customer.Name")).unwrap();
+
+        let row = json!({"PartitionKey": "pk", "RowKey": "rk", "Customer": "flat"});
+
+        let result = fields.project_value(&row);
+
+        assert_eq!(json!({"PartitionKey": "pk", "RowKey": "rk"}), result);
+    }
+
+    #[test]
+    fn test_empty_list_means_no_projection() {
+        assert!(FieldsProjection::parse(Some(" , ,")).is_none());
+        assert!(FieldsProjection::parse(None).is_none());
+    }
+
+    #[test]
+    fn test_project_array() {
+        let fields = FieldsProjection::parse(Some("A")).unwrap();
+
+        let content = br#"[{"PartitionKey":"pk","RowKey":"1","A":1,"B":2},{"PartitionKey":"pk","RowKey":"2","B":3}]"#;
+
+        let result = fields.project_array(content.to_vec());
+
+        let result: serde_json::Value = serde_json::from_slice(result.as_slice()).unwrap();
+
+        assert_eq!(
+            json!([
+                {"PartitionKey": "pk", "RowKey": "1", "A": 1},
+                {"PartitionKey": "pk", "RowKey": "2"}
+            ]),
+            result
+        );
+    }
+}
@@ -1,6 +1,5 @@
 use std::sync::Arc;
 
-use my_no_sql_sdk::core::my_json::json_writer::JsonArrayWriter;
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 use my_no_sql_sdk::server::DbTable;
 
@@ -9,7 +8,7 @@ use crate::{
     db_operations::{DbOperationError, UpdateStatistics},
 };
 
-use super::ReadOperationResult;
+use super::{FieldsProjection, ReadOperationResult, RowsJsonArray};
 
 pub async fn get_highest_row_and_below(
     app: &Arc<AppContext>,
@@ -17,6 +16,7 @@ pub async fn get_highest_row_and_below(
     partition_key: &String,
     row_key: &String,
     limit: Option<usize>,
+    fields: Option<&FieldsProjection>,
     update_statistics: UpdateStatistics,
     now: DateTimeAsMicroseconds,
 ) -> Result<ReadOperationResult, DbOperationError> {
@@ -32,7 +32,7 @@ pub async fn get_highest_row_and_below(
 
     let db_partition = db_partition.unwrap();
 
-    let mut json_array_writer = JsonArrayWriter::new();
+    let mut json_array_writer = RowsJsonArray::new(fields);
     let mut count = 0;
     for db_row in db_partition.get_highest_row_and_below(row_key) {
         if let Some(limit) = limit {
@@ -46,9 +46,7 @@ pub async fn get_highest_row_and_below(
         count += 1;
     }
 
-    return Ok(ReadOperationResult::RowsArray(
-        json_array_writer.build().into_bytes(),
-    ));
+    return Ok(ReadOperationResult::RowsArray(json_array_writer.build()));
 }
 
 /*
@@ -1,3 +1,5 @@
+pub mod aggregate;
+mod fields_projection;
 mod get_highest_row_and_below;
 pub mod get_rows_as_vec;
 pub mod multipart;
@@ -6,6 +8,7 @@ mod read_operation_result;
 pub mod rows;
 pub mod table;
 
+pub use fields_projection::{FieldsProjection, RowsJsonArray};
 pub use get_highest_row_and_below::get_highest_row_and_below;
 pub use read_operation_result::ReadOperationResult;
 pub mod partitions;
@@ -1,16 +1,18 @@
 use std::sync::Arc;
 
 use my_no_sql_sdk::core::db::DbRow;
-use my_no_sql_sdk::core::my_json::json_writer::JsonArrayWriter;
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 
-pub fn filter_and_compile_json<'s>(
+use super::{FieldsProjection, RowsJsonArray};
+
+pub fn filter_and_compile_json<'s, 'f>(
     iterator: impl Iterator<Item = &'s Arc<DbRow>>,
     limit: Option<usize>,
     skip: Option<usize>,
-    handle: impl Fn(&'s Arc<DbRow>),
-) -> JsonArrayWriter {
-    let mut result = JsonArrayWriter::new();
+    fields: Option<&'f FieldsProjection>,
+    mut handle: impl FnMut(&'s Arc<DbRow>),
+) -> RowsJsonArray<'f> {
+    let mut result = RowsJsonArray::new(fields);
 
     let mut no = 0;
     let mut added = 0;
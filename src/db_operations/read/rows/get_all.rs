@@ -1,6 +1,5 @@
 use std::sync::Arc;
 
-use my_no_sql_sdk::core::my_json::json_writer::JsonArrayWriter;
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 use my_no_sql_sdk::server::DbTable;
 
@@ -9,13 +8,14 @@ use crate::{
     db_operations::{DbOperationError, UpdateStatistics},
 };
 
-use super::super::ReadOperationResult;
+use super::super::{FieldsProjection, ReadOperationResult, RowsJsonArray};
 
 pub async fn get_all(
     app: &Arc<AppContext>,
     db_table_wrapper: &Arc<DbTable>,
     limit: Option<usize>,
     skip: Option<usize>,
+    fields: Option<&FieldsProjection>,
     update_statistics: UpdateStatistics,
     now: DateTimeAsMicroseconds,
 ) -> Result<ReadOperationResult, DbOperationError> {
@@ -23,15 +23,17 @@ pub async fn get_all(
 
     let table_data = db_table_wrapper.data.read();
 
-    let mut json_array_writer = JsonArrayWriter::new();
+    let mut json_array_writer = RowsJsonArray::new(fields);
+    let mut rows = 0;
     for (db_partition, db_row) in table_data.get_all_rows(skip, limit) {
         update_statistics.update(db_table_wrapper, db_partition, Some(db_row), now);
         json_array_writer = json_array_writer.write(db_row.as_ref());
+        rows += 1;
     }
 
-    return Ok(ReadOperationResult::RowsArray(
-        json_array_writer.build().into_bytes(),
-    ));
+    crate::app::report_rows(rows);
+
+    return Ok(ReadOperationResult::RowsArray(json_array_writer.build()));
 }
 
 /*
@@ -8,7 +8,7 @@ use crate::{
     db_operations::{DbOperationError, UpdateStatistics},
 };
 
-use super::super::ReadOperationResult;
+use super::super::{FieldsProjection, ReadOperationResult};
 
 pub async fn get_all_by_partition_key(
     app: &Arc<AppContext>,
@@ -16,6 +16,7 @@ pub async fn get_all_by_partition_key(
     partition_key: &String,
     limit: Option<usize>,
     skip: Option<usize>,
+    fields: Option<&FieldsProjection>,
     update_statistics: UpdateStatistics,
     now: DateTimeAsMicroseconds,
 ) -> Result<ReadOperationResult, DbOperationError> {
@@ -31,16 +32,20 @@ pub async fn get_all_by_partition_key(
 
     let db_partition = db_partition.unwrap();
 
+    let mut rows = 0;
+
     let json_array_writer = super::super::read_filter::filter_and_compile_json(
         db_partition.get_all_rows().into_iter(),
         limit,
         skip,
+        fields,
         |db_row| {
             update_statistics.update(db_table, db_partition, Some(db_row), now);
+            rows += 1;
         },
     );
 
-    return Ok(ReadOperationResult::RowsArray(
-        json_array_writer.build().into_bytes(),
-    ));
+    crate::app::report_rows(rows);
+
+    return Ok(ReadOperationResult::RowsArray(json_array_writer.build()));
 }
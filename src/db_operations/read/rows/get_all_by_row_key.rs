@@ -1,6 +1,5 @@
 use std::sync::Arc;
 
-use my_no_sql_sdk::core::my_json::json_writer::JsonArrayWriter;
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 use my_no_sql_sdk::server::DbTable;
 
@@ -9,7 +8,7 @@ use crate::{
     db_operations::{DbOperationError, UpdateStatistics},
 };
 
-use super::super::ReadOperationResult;
+use super::super::{FieldsProjection, ReadOperationResult, RowsJsonArray};
 
 pub async fn get_all_by_row_key(
     app: &Arc<AppContext>,
@@ -17,6 +16,7 @@ pub async fn get_all_by_row_key(
     row_key: &str,
     limit: Option<usize>,
     skip: Option<usize>,
+    fields: Option<&FieldsProjection>,
     update_statistics: UpdateStatistics,
     now: DateTimeAsMicroseconds,
 ) -> Result<ReadOperationResult, DbOperationError> {
@@ -24,13 +24,15 @@ pub async fn get_all_by_row_key(
 
     let table_data = db_table.data.read();
 
-    let mut json_array_writer = JsonArrayWriter::new();
+    let mut json_array_writer = RowsJsonArray::new(fields);
+    let mut rows = 0;
     for (db_partition, db_row) in table_data.get_by_row_key(row_key, skip, limit) {
         update_statistics.update(db_table, db_partition, Some(db_row), now);
         json_array_writer = json_array_writer.write(db_row.as_ref());
+        rows += 1;
     }
 
-    return Ok(ReadOperationResult::RowsArray(
-        json_array_writer.build().into_bytes(),
-    ));
+    crate::app::report_rows(rows);
+
+    return Ok(ReadOperationResult::RowsArray(json_array_writer.build()));
 }
@@ -8,13 +8,14 @@ use crate::{
     db_operations::{DbOperationError, UpdateStatistics},
 };
 
-use super::super::ReadOperationResult;
+use super::super::{FieldsProjection, ReadOperationResult};
 
 pub async fn get_single(
     app: &Arc<AppContext>,
     db_table_wrapper: &Arc<DbTable>,
     partition_key: &String,
     row_key: &String,
+    fields: Option<&FieldsProjection>,
     update_statistics: UpdateStatistics,
     now: DateTimeAsMicroseconds,
 ) -> Result<ReadOperationResult, DbOperationError> {
@@ -39,6 +40,12 @@ pub async fn get_single(
     let db_row = db_row.unwrap();
 
     update_statistics.update(db_table_wrapper, db_partition, Some(db_row), now);
+    crate::app::report_rows(1);
 
-    return Ok(ReadOperationResult::SingleRow(db_row.to_vec()));
+    let content = match fields {
+        Some(fields) => fields.project_row(db_row),
+        None => db_row.to_vec(),
+    };
+
+    return Ok(ReadOperationResult::SingleRow(content));
 }
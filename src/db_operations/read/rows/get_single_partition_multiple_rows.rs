@@ -1,6 +1,5 @@
 use std::sync::Arc;
 
-use my_no_sql_sdk::core::my_json::json_writer::JsonArrayWriter;
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 use my_no_sql_sdk::server::DbTable;
 
@@ -9,13 +8,14 @@ use crate::{
     db_operations::{DbOperationError, UpdateStatistics},
 };
 
-use super::super::ReadOperationResult;
+use super::super::{FieldsProjection, ReadOperationResult, RowsJsonArray};
 
 pub async fn get_single_partition_multiple_rows(
     app: &Arc<AppContext>,
     db_table_wrapper: &Arc<DbTable>,
     partition_key: &String,
     row_keys: Vec<String>,
+    fields: Option<&FieldsProjection>,
     update_statistics: UpdateStatistics,
     now: DateTimeAsMicroseconds,
 ) -> Result<ReadOperationResult, DbOperationError> {
@@ -30,18 +30,21 @@ pub async fn get_single_partition_multiple_rows(
 
     let db_partition = db_partition.unwrap();
 
-    let mut json_array_writer = JsonArrayWriter::new();
+    let mut json_array_writer = RowsJsonArray::new(fields);
+    let mut rows = 0;
     for row_key in &row_keys {
         let db_row = db_partition.get_row(row_key);
 
         if let Some(db_row) = db_row {
             update_statistics.update(db_table_wrapper, db_partition, Some(db_row), now);
             json_array_writer = json_array_writer.write(db_row.as_ref());
+            rows += 1;
         }
     }
-    return Ok(ReadOperationResult::RowsArray(
-        json_array_writer.build().into_bytes(),
-    ));
+
+    crate::app::report_rows(rows);
+
+    return Ok(ReadOperationResult::RowsArray(json_array_writer.build()));
 }
 
 /*
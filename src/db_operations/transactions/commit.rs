@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
+use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 
 use crate::{
-    app::AppContext, db_sync::EventSource, db_transactions::steps::TransactionalOperationStep,
+    app::AppContext, audit_log::AuditRecord, db_sync::EventSource,
+    db_transactions::steps::TransactionalOperationStep,
 };
 
 use super::TransactionOperationError;
@@ -13,8 +15,8 @@ pub async fn commit(
     transaction_id: &str,
     event_src: EventSource,
     persist_moment: DateTimeAsMicroseconds,
-    now: DateTimeAsMicroseconds,
-) -> Result<(), TransactionOperationError> {
+    now: &JsonTimeStamp,
+) -> Result<Vec<AuditRecord>, TransactionOperationError> {
     let transaction = app.active_transactions.remove(transaction_id).await;
 
     if transaction.is_none() {
@@ -44,11 +46,23 @@ pub async fn commit(
         tables.insert(table_name.to_string(), db_table);
     }
 
+    // Nothing is applied unless every step is going to go through.
+    for (table_name, steps) in transaction.operations.iter() {
+        let db_table = tables.get(table_name.as_str()).unwrap();
+        let table_data = db_table.data.read();
+        super::validate::validate_steps(&table_data, steps.iter(), now.date_time)?;
+    }
+
+    // The steps which remove data, for the caller to put into the audit log.
+    let mut audit_records = Vec::new();
+
     for (_, mut events) in transaction.operations {
         for event in events.drain(..) {
             match event {
                 TransactionalOperationStep::CleanTable { table_name } => {
                     let db_table = tables.get(table_name.as_str()).unwrap();
+                    let rows = db_table.data.read().get_rows_amount();
+
                     crate::db_operations::write::clean_table(
                         app,
                         &db_namespace,
@@ -57,12 +71,24 @@ pub async fn commit(
                         persist_moment,
                     )
                     .await?;
+
+                    audit_records.push(
+                        AuditRecord::new("CleanTable")
+                            .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+                            .with_rows(rows)
+                            .with_details(format!("TransactionId: {}", transaction_id)),
+                    );
                 }
                 TransactionalOperationStep::DeletePartitions {
                     table_name,
                     partition_keys,
                 } => {
                     let db_table = tables.get(table_name.as_str()).unwrap();
+                    let audit_record = AuditRecord::new("DeletePartitions")
+                        .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+                        .with_partition_keys(partition_keys.iter().cloned())
+                        .with_details(format!("TransactionId: {}", transaction_id));
+
                     crate::db_operations::write::delete_partitions(
                         app,
                         &db_namespace,
@@ -70,9 +96,11 @@ pub async fn commit(
                         partition_keys.into_iter(),
                         event_src.clone(),
                         persist_moment,
-                        now,
+                        now.date_time,
                     )
                     .await?;
+
+                    audit_records.push(audit_record);
                 }
                 TransactionalOperationStep::DeleteRows {
                     table_name,
@@ -80,6 +108,12 @@ pub async fn commit(
                     row_keys,
                 } => {
                     let db_table = tables.get(table_name.as_str()).unwrap();
+                    let audit_record = AuditRecord::new("DeleteRows")
+                        .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+                        .with_partition_keys([partition_key.to_string()].into_iter())
+                        .with_rows(row_keys.len())
+                        .with_details(format!("TransactionId: {}", transaction_id));
+
                     crate::db_operations::write::bulk_delete(
                         app,
                         &db_namespace,
@@ -87,9 +121,11 @@ pub async fn commit(
                         [(partition_key, row_keys)].into_iter(),
                         event_src.clone(),
                         persist_moment,
-                        now,
+                        now.date_time,
                     )
                     .await?;
+
+                    audit_records.push(audit_record);
                 }
                 TransactionalOperationStep::UpdateRows(state) => {
                     let db_table = tables.get(state.table_name.as_str()).unwrap();
@@ -100,6 +136,52 @@ pub async fn commit(
                         state.rows_by_partition,
                         event_src.clone(),
                         persist_moment,
+                        now.date_time,
+                    )
+                    .await?;
+                }
+                TransactionalOperationStep::MergePatch {
+                    table_name,
+                    partition_key,
+                    row_key,
+                    patch,
+                    expected_time_stamp,
+                } => {
+                    let db_table = tables.get(table_name.as_str()).unwrap();
+                    crate::db_operations::write::merge_patch::execute(
+                        app,
+                        &db_namespace,
+                        &db_table,
+                        partition_key,
+                        row_key,
+                        &patch,
+                        expected_time_stamp,
+                        event_src.clone(),
+                        persist_moment,
+                        now,
+                    )
+                    .await?;
+                }
+                TransactionalOperationStep::Increment {
+                    table_name,
+                    partition_key,
+                    row_key,
+                    field,
+                    delta,
+                    initial_value,
+                } => {
+                    let db_table = tables.get(table_name.as_str()).unwrap();
+                    crate::db_operations::write::increment::execute(
+                        app,
+                        &db_namespace,
+                        &db_table,
+                        partition_key,
+                        row_key,
+                        field.as_str(),
+                        &delta,
+                        &initial_value,
+                        event_src.clone(),
+                        persist_moment,
                         now,
                     )
                     .await?;
@@ -108,5 +190,5 @@ pub async fn commit(
         }
     }
 
-    Ok(())
+    Ok(audit_records)
 }
@@ -2,6 +2,7 @@ mod append_events;
 mod cancel;
 mod commit;
 mod error;
+mod validate;
 
 pub use append_events::append_events;
 pub use cancel::cancel;
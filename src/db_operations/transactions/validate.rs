@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use my_no_sql_sdk::core::db::DbTableInner;
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use serde_json::{Map, Value};
+
+use crate::{db_operations::DbOperationError, db_transactions::steps::TransactionalOperationStep};
+
+/// A row as the steps before the current one leave it. `None` - deleted.
+type RowState = Option<(Map<String, Value>, DateTimeAsMicroseconds)>;
+
+#[derive(Default)]
+struct PartitionOverlay {
+    cleaned: bool,
+    rows: HashMap<String, RowState>,
+}
+
+/// The rows the steps of a transaction have touched so far, over the table as it is.
+struct TableOverlay<'s> {
+    table_data: &'s DbTableInner,
+    cleaned: bool,
+    partitions: HashMap<String, PartitionOverlay>,
+    now: DateTimeAsMicroseconds,
+}
+
+impl<'s> TableOverlay<'s> {
+    fn get_row(&self, partition_key: &str, row_key: &str) -> RowState {
+        if let Some(partition) = self.partitions.get(partition_key) {
+            if let Some(row) = partition.rows.get(row_key) {
+                return row.clone();
+            }
+
+            if partition.cleaned {
+                return None;
+            }
+        }
+
+        if self.cleaned {
+            return None;
+        }
+
+        let db_row = self
+            .table_data
+            .get_partition(partition_key)?
+            .get_row(row_key)?;
+
+        let content = match serde_json::from_slice(db_row.to_vec().as_slice()) {
+            Ok(Value::Object(content)) => content,
+            _ => Map::new(),
+        };
+
+        Some((content, db_row.get_time_stamp_as_date_time()))
+    }
+
+    /// A row a step writes gets the commit moment as its `TimeStamp`.
+    fn set_row(&mut self, partition_key: &str, row_key: &str, content: Option<Map<String, Value>>) {
+        let now = self.now;
+
+        self.partitions
+            .entry(partition_key.to_string())
+            .or_default()
+            .rows
+            .insert(row_key.to_string(), content.map(|content| (content, now)));
+    }
+}
+
+/// Plays the steps of one table against its current rows without changing anything,
+/// so a `MergePatch` or an `Increment` which is going to fail - the row is missing,
+/// it is not the expected version, the field is not a number - fails the commit
+/// before its first step is applied. Rows written or deleted by the earlier steps of
+/// the same transaction are taken as they will be by then.
+pub fn validate_steps<'s>(
+    table_data: &DbTableInner,
+    steps: impl Iterator<Item = &'s TransactionalOperationStep>,
+    now: DateTimeAsMicroseconds,
+) -> Result<(), DbOperationError> {
+    let mut overlay = TableOverlay {
+        table_data,
+        cleaned: false,
+        partitions: HashMap::new(),
+        now,
+    };
+
+    for step in steps {
+        match step {
+            TransactionalOperationStep::CleanTable { .. } => {
+                overlay.cleaned = true;
+                overlay.partitions.clear();
+            }
+            TransactionalOperationStep::DeletePartitions { partition_keys, .. } => {
+                for partition_key in partition_keys {
+                    overlay.partitions.insert(
+                        partition_key.to_string(),
+                        PartitionOverlay {
+                            cleaned: true,
+                            rows: HashMap::new(),
+                        },
+                    );
+                }
+            }
+            TransactionalOperationStep::DeleteRows {
+                partition_key,
+                row_keys,
+                ..
+            } => {
+                for row_key in row_keys {
+                    overlay.set_row(partition_key, row_key, None);
+                }
+            }
+            TransactionalOperationStep::UpdateRows(state) => {
+                for (partition_key, db_rows) in state.rows_by_partition.iter() {
+                    for db_row in db_rows {
+                        let content = match serde_json::from_slice(db_row.to_vec().as_slice()) {
+                            Ok(Value::Object(content)) => content,
+                            _ => Map::new(),
+                        };
+
+                        overlay.set_row(partition_key, db_row.get_row_key(), Some(content));
+                    }
+                }
+            }
+            TransactionalOperationStep::MergePatch {
+                partition_key,
+                row_key,
+                patch,
+                expected_time_stamp,
+                ..
+            } => {
+                crate::db_operations::write::merge_patch::validate_patch(
+                    patch,
+                    partition_key,
+                    row_key,
+                )?;
+
+                let Some((content, time_stamp)) = overlay.get_row(partition_key, row_key) else {
+                    return Err(DbOperationError::RecordNotFound);
+                };
+
+                if let Some(expected_time_stamp) = expected_time_stamp {
+                    if time_stamp.unix_microseconds != expected_time_stamp.unix_microseconds {
+                        return Err(DbOperationError::OptimisticConcurrencyUpdateFails);
+                    }
+                }
+
+                let mut content = Value::Object(content);
+                crate::db_operations::write::merge_patch::apply_merge_patch(&mut content, patch);
+
+                let content = match content {
+                    Value::Object(content) => content,
+                    _ => Map::new(),
+                };
+
+                overlay.set_row(partition_key, row_key, Some(content));
+            }
+            TransactionalOperationStep::Increment {
+                partition_key,
+                row_key,
+                field,
+                delta,
+                initial_value,
+                ..
+            } => {
+                let mut content = match overlay.get_row(partition_key, row_key) {
+                    Some((content, _)) => content,
+                    None => Map::new(),
+                };
+
+                crate::db_operations::write::increment::increment_field(
+                    &mut content,
+                    field,
+                    delta,
+                    initial_value,
+                )?;
+
+                overlay.set_row(partition_key, row_key, Some(content));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -20,6 +20,8 @@ pub async fn bulk_delete(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<(), DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::bulk_delete");
+
     super::super::check_app_states(app)?;
 
     enum PersistOp {
@@ -67,6 +67,8 @@ pub async fn execute(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<BulkDeleteIfResult, DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::bulk_delete_if");
+
     super::super::check_app_states(app)?;
 
     enum PersistOp {
@@ -156,7 +158,11 @@ pub async fn execute(
             }
         }
 
-        (sync_data, persist_ops, BulkDeleteIfResult { deleted, skipped })
+        (
+            sync_data,
+            persist_ops,
+            BulkDeleteIfResult { deleted, skipped },
+        )
     };
 
     // Nothing matched - no persistence to schedule and nothing to tell the readers about.
@@ -28,6 +28,8 @@ pub async fn execute(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<(), DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::bulk_insert_or_replace_if_new");
+
     super::super::check_app_states(app)?;
 
     let (update_rows_state, to_persist, has_writes) = {
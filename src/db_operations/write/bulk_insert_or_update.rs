@@ -20,6 +20,8 @@ pub async fn execute(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<(), DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::bulk_insert_or_update");
+
     super::super::check_app_states(app)?;
 
     let (update_rows_state, to_persist, has_insert_or_replace) = {
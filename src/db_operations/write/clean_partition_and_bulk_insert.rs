@@ -21,6 +21,9 @@ pub async fn clean_partition_and_bulk_insert(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<(), DbOperationError> {
+    let _span =
+        crate::telemetry::start_span("db_operations::write::clean_partition_and_bulk_insert");
+
     super::super::check_app_states(app)?;
 
     let (partition_keys, sync_events) = {
@@ -17,6 +17,8 @@ pub async fn clean_table(
     event_src: EventSource,
     persist_moment: DateTimeAsMicroseconds,
 ) -> Result<(), DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::clean_table");
+
     super::super::check_app_states(app)?;
 
     let persist = {
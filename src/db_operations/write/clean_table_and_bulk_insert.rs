@@ -20,6 +20,8 @@ pub async fn execute(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<(), DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::clean_table_and_bulk_insert");
+
     super::super::check_app_states(app)?;
 
     let sync_data = {
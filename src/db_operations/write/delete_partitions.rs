@@ -20,6 +20,8 @@ pub async fn delete_partitions(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<(), DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::delete_partitions");
+
     super::super::check_app_states(app)?;
 
     let (sync_data, removed_partition_keys) = {
@@ -23,6 +23,8 @@ pub async fn execute(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<WriteOperationResult, DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::delete_row");
+
     super::super::check_app_states(app)?;
 
     let (partition_key, removed_row, sync_data) = {
@@ -0,0 +1,250 @@
+use crate::app::DbNamespace;
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::db::{DbRow, PartitionKeyParameter, RowKeyParameter};
+use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use my_no_sql_sdk::server::DbTable;
+use serde_json::{Map, Number, Value};
+
+use crate::{
+    app::AppContext,
+    db_operations::DbOperationError,
+    db_sync::{states::UpdateRowsSyncData, EventSource, SyncEvent},
+};
+
+pub struct IncrementResult {
+    pub db_row: Arc<DbRow>,
+    pub value: Number,
+}
+
+/// `a + b` keeping integers integers: two whole numbers add up exactly (and an
+/// overflow is an error, not a silent switch to a float); anything else is added as
+/// `f64`.
+pub fn add_numbers(a: &Number, b: &Number) -> Option<Number> {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        return a.checked_add(b).map(Number::from);
+    }
+
+    Number::from_f64(a.as_f64()? + b.as_f64()?)
+}
+
+/// Adds `delta` to the number at `field` (a top-level name or a dotted path) of `row`
+/// and returns the new value. A missing field, and missing objects on the way to it,
+/// count from `initial_value`. A field which is there but is not a number is an error.
+pub fn increment_field(
+    row: &mut Map<String, Value>,
+    field: &str,
+    delta: &Number,
+    initial_value: &Number,
+) -> Result<Number, DbOperationError> {
+    let mut segments: Vec<&str> = field.split('.').collect();
+
+    if segments.iter().any(|segment| segment.is_empty()) {
+        return Err(DbOperationError::FieldIsNotNumeric(field.to_string()));
+    }
+
+    let last = segments.pop().unwrap();
+
+    if segments.is_empty() && is_reserved_field(last) {
+        return Err(DbOperationError::FieldIsNotNumeric(field.to_string()));
+    }
+
+    let mut current = row;
+
+    for segment in segments {
+        let value = current
+            .entry(segment.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        let Value::Object(inner) = value else {
+            return Err(DbOperationError::FieldIsNotNumeric(field.to_string()));
+        };
+
+        current = inner;
+    }
+
+    let value = match current.get(last) {
+        Some(Value::Number(value)) => value.clone(),
+        Some(_) => return Err(DbOperationError::FieldIsNotNumeric(field.to_string())),
+        None => initial_value.clone(),
+    };
+
+    let Some(value) = add_numbers(&value, delta) else {
+        return Err(DbOperationError::FieldIsNotNumeric(field.to_string()));
+    };
+
+    current.insert(last.to_string(), Value::Number(value.clone()));
+
+    Ok(value)
+}
+
+fn is_reserved_field(field: &str) -> bool {
+    matches!(field, "PartitionKey" | "RowKey" | "TimeStamp" | "Expires")
+}
+
+/// Read-add-write of a counter under the write lock of the table, so concurrent
+/// increments never lose each other. A row which does not exist yet is created with
+/// just its keys and the counter.
+///
+/// The row is stamped with the server clock and distributed as a regular `UpdateRows`
+/// event, so readers see the new value the same way they see any other write.
+pub async fn execute(
+    app: &AppContext,
+    db_namespace: &Arc<DbNamespace>,
+    db_table: &Arc<DbTable>,
+    partition_key: impl PartitionKeyParameter,
+    row_key: impl RowKeyParameter,
+    field: &str,
+    delta: &Number,
+    initial_value: &Number,
+    event_src: EventSource,
+    persist_moment: DateTimeAsMicroseconds,
+    now: &JsonTimeStamp,
+) -> Result<IncrementResult, DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::increment");
+
+    super::super::check_app_states(app)?;
+
+    let (partition_key, result, update_rows_state) = {
+        let mut table_data = db_table.data.write();
+
+        let current_content = table_data
+            .get_partition(partition_key.as_str())
+            .and_then(|db_partition| db_partition.get_row(row_key.as_str()))
+            .map(|db_row| db_row.to_vec());
+
+        let mut content = match current_content {
+            Some(current_content) => {
+                match serde_json::from_slice::<Value>(current_content.as_slice()) {
+                    Ok(Value::Object(content)) => content,
+                    _ => return Err(DbOperationError::FieldIsNotNumeric(field.to_string())),
+                }
+            }
+            None => {
+                let mut content = Map::new();
+                content.insert(
+                    "PartitionKey".to_string(),
+                    Value::String(partition_key.as_str().to_string()),
+                );
+                content.insert(
+                    "RowKey".to_string(),
+                    Value::String(row_key.as_str().to_string()),
+                );
+                content
+            }
+        };
+
+        let value = increment_field(&mut content, field, delta, initial_value)?;
+
+        content.remove("TimeStamp");
+
+        let content = serde_json::to_vec(&content).unwrap();
+
+        let db_row = crate::operations::parse_db_json_entity(content.as_slice(), now)?;
+        let db_row = Arc::new(db_row);
+
+        let (partition_key, _) =
+            table_data.insert_or_replace_row(db_row.clone(), Some(now.date_time));
+
+        let mut update_rows_state = UpdateRowsSyncData::new(&table_data, event_src);
+        update_rows_state
+            .rows_by_partition
+            .add_row(partition_key.clone(), db_row.clone());
+
+        (
+            partition_key,
+            IncrementResult { db_row, value },
+            update_rows_state,
+        )
+    };
+
+    db_namespace
+        .persist_markers
+        .persist_rows(
+            &db_table.name,
+            &partition_key,
+            persist_moment,
+            [&result.db_row].into_iter(),
+        )
+        .await;
+
+    crate::operations::sync::dispatch(app, db_namespace, SyncEvent::UpdateRows(update_rows_state));
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn as_map(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(map) => map,
+            _ => panic!("Not an object"),
+        }
+    }
+
+    #[test]
+    fn test_integers_stay_integers() {
+        let mut row = as_map(json!({"Used": 41}));
+
+        let value = increment_field(&mut row, "Used", &Number::from(1), &Number::from(0)).unwrap();
+
+        assert_eq!(Number::from(42), value);
+        assert_eq!(json!({"Used": 42}), Value::Object(row));
+    }
+
+    #[test]
+    fn test_missing_field_counts_from_initial_value() {
+        let mut row = as_map(json!({"PartitionKey": "pk", "RowKey": "rk"}));
+
+        let value = increment_field(
+            &mut row,
+            "Quota.Used",
+            &Number::from(-1),
+            &Number::from(100),
+        )
+        .unwrap();
+
+        assert_eq!(Number::from(99), value);
+        assert_eq!(
+            json!({"PartitionKey": "pk", "RowKey": "rk", "Quota": {"Used": 99}}),
+            Value::Object(row)
+        );
+    }
+
+    #[test]
+    fn test_float_delta() {
+        let mut row = as_map(json!({"Balance": 10}));
+
+        let value = increment_field(
+            &mut row,
+            "Balance",
+            &Number::from_f64(0.5).unwrap(),
+            &Number::from(0),
+        )
+        .unwrap();
+
+        assert_eq!(Some(10.5), value.as_f64());
+    }
+
+    #[test]
+    fn test_non_numeric_field_and_overflow_are_refused() {
+        let mut row = as_map(json!({"Status": "Paid", "Big": i64::MAX}));
+
+        assert!(increment_field(&mut row, "Status", &Number::from(1), &Number::from(0)).is_err());
+        assert!(
+            increment_field(&mut row, "Status.Count", &Number::from(1), &Number::from(0)).is_err()
+        );
+        assert!(increment_field(&mut row, "Big", &Number::from(1), &Number::from(0)).is_err());
+        assert!(increment_field(&mut row, "RowKey", &Number::from(1), &Number::from(0)).is_err());
+
+        assert_eq!(
+            json!({"Status": "Paid", "Big": i64::MAX}),
+            Value::Object(row)
+        );
+    }
+}
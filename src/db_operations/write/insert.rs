@@ -42,6 +42,8 @@ pub async fn execute(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<(), DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::insert");
+
     let (partition_key, update_rows_state) = {
         let mut table_data = db_table.data.write();
 
@@ -22,6 +22,8 @@ pub async fn execute(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<WriteOperationResult, DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::insert_or_replace");
+
     super::super::check_app_states(app)?;
 
     let (partition_key, update_rows_state) = {
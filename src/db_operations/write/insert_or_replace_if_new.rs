@@ -29,6 +29,8 @@ pub async fn execute(
     persist_moment: DateTimeAsMicroseconds,
     now: DateTimeAsMicroseconds,
 ) -> Result<WriteOperationResult, DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::insert_or_replace_if_new");
+
     super::super::check_app_states(app)?;
 
     let result = {
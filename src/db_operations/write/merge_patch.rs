@@ -0,0 +1,230 @@
+use crate::app::DbNamespace;
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::db::{PartitionKeyParameter, RowKeyParameter};
+use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use my_no_sql_sdk::server::DbTable;
+use serde_json::{Map, Value};
+
+use crate::{
+    app::AppContext,
+    db_operations::DbOperationError,
+    db_sync::{states::UpdateRowsSyncData, EventSource, SyncEvent},
+};
+
+use super::WriteOperationResult;
+
+/// Applies an RFC 7386 merge patch to `target`: objects merge key by key, `null`
+/// removes a key and anything else replaces the value at that key.
+pub fn apply_merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+
+    let target = target.as_object_mut().unwrap();
+
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+            continue;
+        }
+
+        apply_merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+    }
+}
+
+/// What a patch of a stored row may not do. A patch which is not an object would
+/// replace the whole row, and the keys of a row are its address — changing them is a
+/// move, not an update. Repeating the keys with their current values is fine.
+/// `TimeStamp` is not checked here: the server stamps the patched row itself.
+pub fn validate_patch(
+    patch: &Value,
+    partition_key: &str,
+    row_key: &str,
+) -> Result<(), DbOperationError> {
+    let Value::Object(patch) = patch else {
+        return Err(DbOperationError::InvalidMergePatch(
+            "Patch must be a JSON object".to_string(),
+        ));
+    };
+
+    for (key, expected) in [("PartitionKey", partition_key), ("RowKey", row_key)] {
+        if let Some(value) = patch.get(key) {
+            if value.as_str() != Some(expected) {
+                return Err(DbOperationError::InvalidMergePatch(format!(
+                    "Patch can not change {}",
+                    key
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Read-modify-write of one row done entirely under the write lock of the table, so
+/// no other write can slip in between reading the stored row and putting the patched
+/// one back. With `expected_time_stamp` the patch is applied only when the stored row
+/// is still that version — the same check `replace` does, answering
+/// `OptimisticConcurrencyUpdateFails` (409) otherwise.
+///
+/// The patched row gets the server clock as its `TimeStamp` and goes out as a regular
+/// `UpdateRows` event.
+pub async fn execute(
+    app: &AppContext,
+    db_namespace: &Arc<DbNamespace>,
+    db_table: &Arc<DbTable>,
+    partition_key: impl PartitionKeyParameter,
+    row_key: impl RowKeyParameter,
+    patch: &Value,
+    expected_time_stamp: Option<DateTimeAsMicroseconds>,
+    event_src: EventSource,
+    persist_moment: DateTimeAsMicroseconds,
+    now: &JsonTimeStamp,
+) -> Result<WriteOperationResult, DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::merge_patch");
+
+    super::super::check_app_states(app)?;
+
+    validate_patch(patch, partition_key.as_str(), row_key.as_str())?;
+
+    let (partition_key, db_row, update_rows_state) = {
+        let mut table_data = db_table.data.write();
+
+        let content = {
+            let Some(db_partition) = table_data.get_partition(partition_key.as_str()) else {
+                return Err(DbOperationError::RecordNotFound);
+            };
+
+            let Some(current_db_row) = db_partition.get_row(row_key.as_str()) else {
+                return Err(DbOperationError::RecordNotFound);
+            };
+
+            if let Some(expected_time_stamp) = expected_time_stamp {
+                if current_db_row
+                    .get_time_stamp_as_date_time()
+                    .unix_microseconds
+                    != expected_time_stamp.unix_microseconds
+                {
+                    return Err(DbOperationError::OptimisticConcurrencyUpdateFails);
+                }
+            }
+
+            let mut content: Value = serde_json::from_slice(current_db_row.to_vec().as_slice())
+                .map_err(|err| {
+                    DbOperationError::InvalidMergePatch(format!(
+                        "Stored row is not a JSON object: {}",
+                        err
+                    ))
+                })?;
+
+            apply_merge_patch(&mut content, patch);
+
+            // The stored TimeStamp is replaced by the server clock when the row is
+            // parsed back; one coming in the patch would be replaced just the same.
+            if let Value::Object(content) = &mut content {
+                content.remove("TimeStamp");
+            }
+
+            serde_json::to_vec(&content).unwrap()
+        };
+
+        let db_row = crate::operations::parse_db_json_entity(content.as_slice(), now)?;
+        let db_row = Arc::new(db_row);
+
+        let partition_key = table_data
+            .get_partition(partition_key.as_str())
+            .unwrap()
+            .partition_key
+            .clone();
+
+        table_data.remove_row(&partition_key, &db_row, false, None);
+        table_data.insert_row(&db_row, Some(now.date_time));
+
+        let mut update_rows_state = UpdateRowsSyncData::new(&table_data, event_src);
+        update_rows_state
+            .rows_by_partition
+            .add_row(partition_key.clone(), db_row.clone());
+
+        (partition_key, db_row, update_rows_state)
+    };
+
+    db_namespace
+        .persist_markers
+        .persist_rows(
+            &db_table.name,
+            &partition_key,
+            persist_moment,
+            [&db_row].into_iter(),
+        )
+        .await;
+
+    crate::operations::sync::dispatch(app, db_namespace, SyncEvent::UpdateRows(update_rows_state));
+
+    Ok(WriteOperationResult::SingleRow(db_row))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_fields_are_merged_replaced_and_removed() {
+        let mut row = json!({
+            "PartitionKey": "pk",
+            "RowKey": "rk",
+            "Status": "Pending",
+            "Comment": "to remove",
+            "Customer": {"Name": "John", "Age": 30}
+        });
+
+        apply_merge_patch(
+            &mut row,
+            &json!({"Status": "Paid", "Comment": null, "Customer": {"Age": 31}, "Tags": ["a"]}),
+        );
+
+        assert_eq!(
+            json!({
+                "PartitionKey": "pk",
+                "RowKey": "rk",
+                "Status": "Paid",
+                "Customer": {"Name": "John", "Age": 31},
+                "Tags": ["a"]
+            }),
+            row
+        );
+    }
+
+    #[test]
+    fn test_rfc_7386_examples() {
+        let mut target = json!({"a": "b"});
+        apply_merge_patch(&mut target, &json!({"a": {"bb": {"ccc": null}}}));
+        assert_eq!(json!({"a": {"bb": {}}}), target);
+
+        let mut target = json!({"a": [{"b": "c"}]});
+        apply_merge_patch(&mut target, &json!({"a": [1]}));
+        assert_eq!(json!({"a": [1]}), target);
+
+        let mut target = json!({"e": null});
+        apply_merge_patch(&mut target, &json!({"a": 1}));
+        assert_eq!(json!({"e": null, "a": 1}), target);
+    }
+
+    #[test]
+    fn test_patch_can_not_move_the_row() {
+        assert!(validate_patch(&json!({"Status": "Paid"}), "pk", "rk").is_ok());
+        assert!(validate_patch(&json!({"PartitionKey": "pk", "RowKey": "rk"}), "pk", "rk").is_ok());
+
+        assert!(validate_patch(&json!({"RowKey": "other"}), "pk", "rk").is_err());
+        assert!(validate_patch(&json!({"PartitionKey": null}), "pk", "rk").is_err());
+        assert!(validate_patch(&json!([1]), "pk", "rk").is_err());
+    }
+}
@@ -11,13 +11,16 @@ pub mod clean_table_and_bulk_insert;
 mod delete_partitions;
 pub mod delete_row;
 pub mod delete_row_if;
+pub mod increment;
 mod move_table_to_namespace;
 pub use move_table_to_namespace::move_table_to_namespace;
 pub mod insert;
 pub mod insert_or_replace;
 pub mod insert_or_replace_if_new;
+pub mod merge_patch;
 pub mod replace;
 pub mod table;
+pub mod write_where;
 
 mod write_operation_result;
 
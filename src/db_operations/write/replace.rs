@@ -97,6 +97,8 @@ pub async fn execute(
     persist_moment: DateTimeAsMicroseconds,
     now: &JsonTimeStamp,
 ) -> Result<WriteOperationResult, DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::replace");
+
     let (partition_key, update_rows_state) = {
         let mut table_data = db_table.data.write();
 
@@ -331,6 +331,9 @@ pub async fn delete(
         SyncEvent::DeleteTable(sync_data),
     );
 
+    app.logs
+        .remove_table(db_namespace.name.as_str(), table_name.as_str());
+
     Ok(())
 }
 
@@ -6,3 +6,12 @@ pub enum WriteOperationResult {
     SingleRow(Arc<DbRow>),
     Empty,
 }
+
+impl WriteOperationResult {
+    pub fn get_rows_amount(&self) -> usize {
+        match self {
+            WriteOperationResult::SingleRow(_) => 1,
+            WriteOperationResult::Empty => 0,
+        }
+    }
+}
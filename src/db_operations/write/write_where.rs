@@ -0,0 +1,374 @@
+use crate::app::DbNamespace;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::db::{DbRow, PartitionKey};
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use my_no_sql_sdk::server::DbTable;
+use serde_json::Value;
+
+use crate::{
+    app::AppContext,
+    db_operations::{field_conditions::FieldCondition, DbOperationError},
+    db_sync::{
+        states::{DeleteRowsEventSyncData, UpdateRowsSyncData},
+        EventSource, SyncEvent,
+    },
+};
+
+use super::WriteOperationResult;
+
+/// A row to write, and what the stored row has to look like for the write to happen.
+pub struct RowToWriteWhere {
+    pub db_row: Arc<DbRow>,
+    pub conditions: Vec<FieldCondition>,
+}
+
+pub struct RowToDeleteWhere {
+    pub partition_key: String,
+    pub row_key: String,
+    pub conditions: Vec<FieldCondition>,
+}
+
+/// What happens to a row which is not in the table yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingRow {
+    /// `insert_or_replace`: the conditions are checked against a row with no fields,
+    /// so `notExists` passes, `eq` does not — and the row is inserted when they pass.
+    Insert,
+    /// `replace`: there is nothing to replace, the row is skipped as `NotFound`.
+    Skip,
+}
+
+/// Why a requested row was not written or deleted.
+pub enum WriteWhereSkipReason {
+    /// There is no such PartitionKey/RowKey.
+    NotFound,
+    /// The stored row does not meet the condition on this field.
+    ConditionNotMet(String),
+}
+
+impl WriteWhereSkipReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WriteWhereSkipReason::NotFound => "NotFound",
+            WriteWhereSkipReason::ConditionNotMet(_) => "ConditionNotMet",
+        }
+    }
+
+    pub fn get_field(&self) -> Option<&str> {
+        match self {
+            WriteWhereSkipReason::NotFound => None,
+            WriteWhereSkipReason::ConditionNotMet(field) => Some(field.as_str()),
+        }
+    }
+}
+
+pub struct SkippedRow {
+    pub partition_key: String,
+    pub row_key: String,
+    pub reason: WriteWhereSkipReason,
+}
+
+pub struct WriteWhereResult {
+    /// Rows which were written - or, for a delete, removed.
+    pub rows: Vec<Arc<DbRow>>,
+    /// The requested rows which were left alone, each with the reason why.
+    pub skipped: Vec<SkippedRow>,
+}
+
+impl WriteWhereResult {
+    /// The single-row endpoints answer the way `replace` does: 404 when the row is
+    /// not there, 409 when it is not the way the conditions want it.
+    pub fn into_single_row(mut self) -> Result<WriteOperationResult, DbOperationError> {
+        if let Some(skipped) = self.skipped.pop() {
+            return match skipped.reason {
+                WriteWhereSkipReason::NotFound => Err(DbOperationError::RecordNotFound),
+                WriteWhereSkipReason::ConditionNotMet(field) => {
+                    Err(DbOperationError::ConditionNotMet(field))
+                }
+            };
+        }
+
+        match self.rows.pop() {
+            Some(db_row) => Ok(WriteOperationResult::SingleRow(db_row)),
+            None => Ok(WriteOperationResult::Empty),
+        }
+    }
+}
+
+/// The stored row as the conditions see it. Parsing is skipped when there are no
+/// conditions to check - the write then only needs to know the row is there.
+fn read_stored_row(db_row: &DbRow, conditions: &[FieldCondition]) -> Value {
+    if conditions.is_empty() {
+        return Value::Null;
+    }
+
+    serde_json::from_slice(db_row.to_vec().as_slice()).unwrap_or(Value::Null)
+}
+
+/// `None` when the write may go ahead.
+fn check_conditions(
+    stored: Option<Value>,
+    conditions: &[FieldCondition],
+    missing_row: MissingRow,
+) -> Option<WriteWhereSkipReason> {
+    let stored = match stored {
+        Some(stored) => stored,
+        None => {
+            if missing_row == MissingRow::Skip {
+                return Some(WriteWhereSkipReason::NotFound);
+            }
+
+            Value::Object(Default::default())
+        }
+    };
+
+    crate::db_operations::field_conditions::get_first_unmet(conditions, &stored)
+        .map(|condition| WriteWhereSkipReason::ConditionNotMet(condition.field.clone()))
+}
+
+/// Insert-or-replace (`MissingRow::Insert`) or replace (`MissingRow::Skip`) guarded
+/// by field conditions on the stored row instead of its `TimeStamp`. Every row is
+/// checked and written under the same write lock, so nobody can change a row between
+/// the check and the write. Rows which fail are reported in
+/// [`WriteWhereResult::skipped`]; the rest of the batch is still written.
+pub async fn write(
+    app: &AppContext,
+    db_namespace: &Arc<DbNamespace>,
+    db_table: &Arc<DbTable>,
+    rows: Vec<RowToWriteWhere>,
+    missing_row: MissingRow,
+    event_src: EventSource,
+    persist_moment: DateTimeAsMicroseconds,
+    now: DateTimeAsMicroseconds,
+) -> Result<WriteWhereResult, DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::write_where::write");
+
+    super::super::check_app_states(app)?;
+
+    let (update_rows_state, to_persist, result) = {
+        let mut table_data = db_table.data.write();
+        let mut update_rows_state = UpdateRowsSyncData::new(&table_data, event_src);
+        let mut to_persist: BTreeMap<String, (PartitionKey, Vec<Arc<DbRow>>)> = BTreeMap::new();
+
+        let mut result = WriteWhereResult {
+            rows: Vec::new(),
+            skipped: Vec::new(),
+        };
+
+        for row in rows {
+            let skip_reason = {
+                let stored = table_data
+                    .get_partition(row.db_row.get_partition_key())
+                    .and_then(|db_partition| db_partition.get_row(row.db_row.get_row_key()))
+                    .map(|db_row| read_stored_row(db_row, &row.conditions));
+
+                check_conditions(stored, &row.conditions, missing_row)
+            };
+
+            if let Some(reason) = skip_reason {
+                result.skipped.push(SkippedRow {
+                    partition_key: row.db_row.get_partition_key().to_string(),
+                    row_key: row.db_row.get_row_key().to_string(),
+                    reason,
+                });
+                continue;
+            }
+
+            let (partition_key, _) =
+                table_data.insert_or_replace_row(row.db_row.clone(), Some(now));
+
+            update_rows_state
+                .rows_by_partition
+                .add_row(partition_key.clone(), row.db_row.clone());
+
+            to_persist
+                .entry(row.db_row.get_partition_key().to_string())
+                .or_insert_with(|| (partition_key, Vec::new()))
+                .1
+                .push(row.db_row.clone());
+
+            result.rows.push(row.db_row);
+        }
+
+        (update_rows_state, to_persist, result)
+    };
+
+    if to_persist.is_empty() {
+        return Ok(result);
+    }
+
+    for (_, (partition_key, db_rows)) in to_persist {
+        db_namespace
+            .persist_markers
+            .persist_rows(
+                &db_table.name,
+                &partition_key,
+                persist_moment,
+                db_rows.iter(),
+            )
+            .await;
+    }
+
+    crate::operations::sync::dispatch(app, db_namespace, SyncEvent::UpdateRows(update_rows_state));
+
+    Ok(result)
+}
+
+/// Delete guarded by field conditions on the stored row. Same rules as [`write`]: the
+/// checks and the removals share one write lock, and rows which fail are reported
+/// instead of failing the batch.
+pub async fn delete(
+    app: &AppContext,
+    db_namespace: &Arc<DbNamespace>,
+    db_table: &Arc<DbTable>,
+    rows: Vec<RowToDeleteWhere>,
+    event_src: EventSource,
+    persist_moment: DateTimeAsMicroseconds,
+    now: DateTimeAsMicroseconds,
+) -> Result<WriteWhereResult, DbOperationError> {
+    let _span = crate::telemetry::start_span("db_operations::write::write_where::delete");
+
+    super::super::check_app_states(app)?;
+
+    enum PersistOp {
+        Partition(PartitionKey),
+        Rows(PartitionKey, Vec<Arc<DbRow>>),
+    }
+
+    let (sync_data, persist_ops, result) = {
+        let mut table_data = db_table.data.write();
+        let mut sync_data = DeleteRowsEventSyncData::new(&table_data, event_src);
+        let mut persist_ops: Vec<PersistOp> = Vec::new();
+
+        let mut result = WriteWhereResult {
+            rows: Vec::new(),
+            skipped: Vec::new(),
+        };
+
+        for row in rows {
+            let skip_reason = {
+                let stored = table_data
+                    .get_partition(row.partition_key.as_str())
+                    .and_then(|db_partition| db_partition.get_row(row.row_key.as_str()))
+                    .map(|db_row| read_stored_row(db_row, &row.conditions));
+
+                check_conditions(stored, &row.conditions, MissingRow::Skip)
+            };
+
+            if let Some(reason) = skip_reason {
+                result.skipped.push(SkippedRow {
+                    partition_key: row.partition_key,
+                    row_key: row.row_key,
+                    reason,
+                });
+                continue;
+            }
+
+            // The row was found a moment ago under this very lock, so the removal can
+            // not come back empty.
+            let (partition_key, removed_row, partition_is_empty) = table_data
+                .remove_row(&row.partition_key, &row.row_key, true, Some(now))
+                .unwrap();
+
+            if partition_is_empty {
+                sync_data.new_deleted_partition(&partition_key);
+                persist_ops.push(PersistOp::Partition(partition_key));
+            } else {
+                sync_data.add_deleted_row(&partition_key, removed_row.clone());
+                persist_ops.push(PersistOp::Rows(partition_key, vec![removed_row.clone()]));
+            }
+
+            result.rows.push(removed_row);
+        }
+
+        (sync_data, persist_ops, result)
+    };
+
+    if persist_ops.is_empty() {
+        return Ok(result);
+    }
+
+    for op in persist_ops {
+        match op {
+            PersistOp::Partition(partition_key) => {
+                db_namespace
+                    .persist_markers
+                    .persist_partition(&db_table.name, &partition_key, persist_moment)
+                    .await;
+            }
+            PersistOp::Rows(partition_key, rows) => {
+                db_namespace
+                    .persist_markers
+                    .delete_db_rows(&db_table.name, &partition_key, persist_moment, rows.iter())
+                    .await;
+            }
+        }
+    }
+
+    crate::operations::sync::dispatch(app, db_namespace, SyncEvent::DeleteRows(sync_data));
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::db_operations::field_conditions::ConditionOperator;
+
+    fn condition(field: &str, op: ConditionOperator, value: Value) -> FieldCondition {
+        FieldCondition {
+            field: field.to_string(),
+            op,
+            value: Some(value),
+        }
+    }
+
+    #[test]
+    fn test_stored_row_has_to_meet_the_conditions() {
+        let conditions = vec![condition("Status", ConditionOperator::Eq, json!("Pending"))];
+
+        let result = check_conditions(
+            Some(json!({"Status": "Pending"})),
+            &conditions,
+            MissingRow::Skip,
+        );
+        assert!(result.is_none());
+
+        let result = check_conditions(
+            Some(json!({"Status": "Paid"})),
+            &conditions,
+            MissingRow::Skip,
+        );
+        assert_eq!(
+            Some("Status"),
+            result.as_ref().and_then(|itm| itm.get_field())
+        );
+    }
+
+    #[test]
+    fn test_missing_row() {
+        let conditions = vec![condition(
+            "Owner",
+            ConditionOperator::NotExists,
+            Value::Null,
+        )];
+
+        let result = check_conditions(None, &conditions, MissingRow::Skip);
+        assert_eq!(Some("NotFound"), result.as_ref().map(|itm| itm.as_str()));
+
+        // An insert sees a row with no fields: `notExists` passes...
+        assert!(check_conditions(None, &conditions, MissingRow::Insert).is_none());
+
+        // ...and `eq` does not.
+        let conditions = vec![condition("Owner", ConditionOperator::Eq, json!("me"))];
+        let result = check_conditions(None, &conditions, MissingRow::Insert);
+        assert_eq!(
+            Some("ConditionNotMet"),
+            result.as_ref().map(|itm| itm.as_str())
+        );
+    }
+}
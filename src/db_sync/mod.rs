@@ -1,8 +1,12 @@
 mod namespace_sync_event;
 pub mod states;
 mod sync_attributes;
+mod sync_batches;
 mod sync_event;
+mod sync_history;
 
 pub use namespace_sync_event::NamespaceSyncEvent;
 pub use sync_attributes::{DataSynchronizationPeriod, EventSource};
+pub use sync_batches::*;
 pub use sync_event::*;
+pub use sync_history::*;
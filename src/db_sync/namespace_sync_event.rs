@@ -1,4 +1,5 @@
 use my_no_sql_sdk::core::db::DbNamespaceName;
+use opentelemetry::trace::SpanContext;
 
 use super::SyncEvent;
 
@@ -11,10 +12,29 @@ use super::SyncEvent;
 pub struct NamespaceSyncEvent {
     pub namespace: DbNamespaceName,
     pub event: SyncEvent,
+    /// Position of the event in the change sequence of its table - given by the
+    /// sync loop as the event is taken off the queue. `None` for a
+    /// `TableFirstInit`, which is not a change.
+    pub sequence: Option<u64>,
+    /// The span the event was dispatched under, which the sync of it is traced
+    /// under as well - for the events of a batch, the span of its first event.
+    /// `None` with tracing off.
+    pub trace_parent: Option<SpanContext>,
 }
 
 impl NamespaceSyncEvent {
     pub fn new(namespace: DbNamespaceName, event: SyncEvent) -> Self {
-        Self { namespace, event }
+        Self {
+            namespace,
+            event,
+            sequence: None,
+            trace_parent: crate::telemetry::get_current_span_context(),
+        }
+    }
+
+    /// The event is synced under `trace_parent` rather than the span it is sent under.
+    pub fn with_trace_parent(mut self, trace_parent: Option<SpanContext>) -> Self {
+        self.trace_parent = trace_parent;
+        self
     }
 }
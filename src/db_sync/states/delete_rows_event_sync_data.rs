@@ -4,6 +4,7 @@ use my_no_sql_sdk::core::db::{DbRow, DbTableInner, PartitionKey, PartitionKeyPar
 use my_no_sql_sdk::core::my_json::json_writer::{JsonArrayWriter, JsonNullValue, JsonObjectWriter};
 use my_no_sql_sdk::core::rust_extensions::sorted_vec::{EntityWithStrKey, SortedVecWithStrKey};
 
+use crate::data_readers::PartitionScope;
 use crate::db_sync::EventSource;
 
 use super::SyncTableData;
@@ -116,12 +117,71 @@ impl DeleteRowsEventSyncData {
             .insert_or_replace(partition_key.to_partition_key());
     }
 
-    pub fn as_vec(&self) -> Vec<u8> {
+    /// Size of the partition and row keys, in bytes.
+    pub fn get_size(&self) -> usize {
+        let mut result = 0;
+
+        if let Some(deleted_partitions) = &self.deleted_partitions {
+            for partition_key in deleted_partitions.iter() {
+                result += partition_key.as_str().len();
+            }
+        }
+
+        if let Some(deleted_rows) = &self.deleted_rows {
+            for deleted_rows_data in deleted_rows.iter() {
+                result += deleted_rows_data.partition_key.as_str().len();
+
+                for deleted_row in deleted_rows_data.db_rows.iter() {
+                    result += deleted_row.get_row_key().len();
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Whether anything in `scope` was deleted; always `true` without a scope.
+    pub fn has_partitions_in_scope(&self, scope: Option<&PartitionScope>) -> bool {
+        let Some(scope) = scope else {
+            return true;
+        };
+
+        if let Some(deleted_partitions) = &self.deleted_partitions {
+            if deleted_partitions
+                .iter()
+                .any(|partition_key| scope.contains(partition_key.as_str()))
+            {
+                return true;
+            }
+        }
+
+        if let Some(deleted_rows) = &self.deleted_rows {
+            if deleted_rows
+                .iter()
+                .any(|itm| scope.contains(itm.partition_key.as_str()))
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn as_vec(&self, scope: Option<&PartitionScope>) -> Vec<u8> {
+        let in_scope = |partition_key: &str| match scope {
+            Some(scope) => scope.contains(partition_key),
+            None => true,
+        };
+
         let mut json_object_writer = JsonObjectWriter::new();
 
         {
             if let Some(deleted_partitions) = &self.deleted_partitions {
                 for partition_key in deleted_partitions.iter() {
+                    if !in_scope(partition_key.as_str()) {
+                        continue;
+                    }
+
                     json_object_writer =
                         json_object_writer.write(partition_key.as_str(), JsonNullValue);
                 }
@@ -129,6 +189,10 @@ impl DeleteRowsEventSyncData {
 
             if let Some(deleted_rows) = &self.deleted_rows {
                 for deleted_rows_data in deleted_rows.iter() {
+                    if !in_scope(deleted_rows_data.partition_key.as_str()) {
+                        continue;
+                    }
+
                     let mut deleted_rows_json_array = JsonArrayWriter::new();
                     for deleted_row in deleted_rows_data.db_rows.iter() {
                         deleted_rows_json_array =
@@ -3,6 +3,7 @@ use my_no_sql_sdk::core::my_json::json_writer::{EmptyJsonArray, JsonObjectWriter
 use my_no_sql_sdk::core::rust_extensions::sorted_vec::{EntityWithStrKey, SortedVecWithStrKey};
 use my_no_sql_sdk::server::db_snapshots::DbPartitionSnapshot;
 
+use crate::data_readers::PartitionScope;
 use crate::db_sync::EventSource;
 
 use super::SyncTableData;
@@ -68,10 +69,37 @@ impl InitPartitionsSyncEventData {
             });
     }
 
-    pub fn as_json(&self) -> JsonObjectWriter {
+    /// Size of the partitions as they are sent, in bytes.
+    pub fn get_size(&self) -> usize {
+        self.partitions_to_update
+            .iter()
+            .map(|itm| match &itm.snapshot {
+                Some(snapshot) => snapshot.db_rows_snapshot.as_json_array().build().len(),
+                None => itm.partition_key.as_str().len(),
+            })
+            .sum()
+    }
+
+    pub fn has_partitions_in_scope(&self, scope: Option<&PartitionScope>) -> bool {
+        match scope {
+            Some(scope) => self
+                .partitions_to_update
+                .iter()
+                .any(|itm| scope.contains(itm.partition_key.as_str())),
+            None => true,
+        }
+    }
+
+    pub fn as_json(&self, scope: Option<&PartitionScope>) -> JsonObjectWriter {
         let mut json_object_writer = JsonObjectWriter::new();
 
         for db_partition in self.partitions_to_update.iter() {
+            if let Some(scope) = scope {
+                if !scope.contains(db_partition.partition_key.as_str()) {
+                    continue;
+                }
+            }
+
             if let Some(db_partition_snapshot) = &db_partition.snapshot {
                 json_object_writer = json_object_writer.write(
                     db_partition.partition_key.as_str(),
@@ -13,5 +13,5 @@ pub use init_partitions_sync_data::InitPartitionsSyncEventData;
 pub use init_table_sync_data::InitTableEventSyncData;
 pub use sync_table_data::SyncTableData;
 pub use table_first_init_sync_data::TableFirstInitSyncData;
-pub use update_rows_sync_data::UpdateRowsSyncData;
+pub use update_rows_sync_data::{UpdateRowsSyncData, UpdatedRowsByPartition};
 pub use update_table_attributes_sync_data::UpdateTableAttributesSyncData;
@@ -7,4 +7,7 @@ use crate::data_readers::DataReader;
 pub struct TableFirstInitSyncData {
     pub db_table: Arc<DbTable>,
     pub data_reader: Arc<DataReader>,
+    /// Last sequence the reader has seen, when it resumes a session. It is sent just
+    /// the events after it, if the sync history still has them.
+    pub resume_from: Option<u64>,
 }
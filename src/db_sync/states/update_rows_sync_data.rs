@@ -1,15 +1,81 @@
-use my_no_sql_sdk::core::db::DbTableInner;
-use my_no_sql_sdk::server::db_snapshots::DbRowsByPartitionsSnapshot;
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
+use my_no_sql_sdk::core::db::{DbRow, DbTableInner, PartitionKey};
+use my_no_sql_sdk::server::db_snapshots::DbRowsSnapshot;
+
+use crate::data_readers::PartitionScope;
 use crate::db_sync::EventSource;
 
 use super::SyncTableData;
 
+/// Updated rows grouped by partition, so a reader which is subscribed to some
+/// partitions only can be sent just its part of them.
+pub struct UpdatedRowsByPartition {
+    partitions: BTreeMap<String, Vec<Arc<DbRow>>>,
+}
+
+impl UpdatedRowsByPartition {
+    pub fn new() -> Self {
+        Self {
+            partitions: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_row(&mut self, partition_key: PartitionKey, db_row: Arc<DbRow>) {
+        self.partitions
+            .entry(partition_key.to_string())
+            .or_default()
+            .push(db_row);
+    }
+
+    pub fn add_rows(&mut self, partition_key: PartitionKey, db_rows: Vec<Arc<DbRow>>) {
+        self.partitions
+            .entry(partition_key.to_string())
+            .or_default()
+            .extend(db_rows);
+    }
+
+    /// Size of the rows as they are sent, in bytes.
+    pub fn get_size(&self) -> usize {
+        self.partitions
+            .values()
+            .flat_map(|db_rows| db_rows.iter())
+            .map(|db_row| db_row.to_vec().len())
+            .sum()
+    }
+
+    pub fn into_partitions(self) -> BTreeMap<String, Vec<Arc<DbRow>>> {
+        self.partitions
+    }
+
+    /// Rows of the partitions in `scope`; all of them when there is no scope.
+    pub fn get_rows_snapshot(&self, scope: Option<&PartitionScope>) -> DbRowsSnapshot {
+        let mut result = DbRowsSnapshot::with_capacity(
+            self.partitions.values().map(|db_rows| db_rows.len()).sum(),
+        );
+
+        for (partition_key, db_rows) in self.partitions.iter() {
+            if let Some(scope) = scope {
+                if !scope.contains(partition_key) {
+                    continue;
+                }
+            }
+
+            for db_row in db_rows {
+                result.push(db_row.clone());
+            }
+        }
+
+        result
+    }
+}
+
 pub struct UpdateRowsSyncData {
     pub table_data: SyncTableData,
     #[allow(dead_code)]
     pub event_src: EventSource,
-    pub rows_by_partition: DbRowsByPartitionsSnapshot,
+    pub rows_by_partition: UpdatedRowsByPartition,
 }
 
 impl UpdateRowsSyncData {
@@ -17,7 +83,7 @@ impl UpdateRowsSyncData {
         Self {
             table_data: SyncTableData::new(db_table),
             event_src,
-            rows_by_partition: DbRowsByPartitionsSnapshot::new(),
+            rows_by_partition: UpdatedRowsByPartition::new(),
         }
     }
 }
@@ -66,7 +66,7 @@ pub enum EventSource {
 
 impl EventSource {
     pub fn as_client_request(app: &AppContext) -> Self {
-        let locations = vec![app.settings.location.to_string()];
+        let locations = vec![app.settings.load().location.to_string()];
 
         let data = ClientRequestsSourceData {
             locations,
@@ -0,0 +1,424 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use my_no_sql_sdk::core::db::{DbNamespaceName, DbRow, PartitionKey};
+use opentelemetry::trace::SpanContext;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    states::{DeleteRowsEventSyncData, SyncTableData, UpdateRowsSyncData, UpdatedRowsByPartition},
+    EventSource, SyncEvent,
+};
+
+/// How long the changes of a table are held back to be merged before they go to the
+/// readers. `WindowMs` is for every table, `Tables` overrides it per table name; 0
+/// sends the changes of the table one by one, the way it is done without the setting.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SyncBatchingSettings {
+    #[serde(rename = "WindowMs", default)]
+    pub window_ms: Option<u64>,
+    #[serde(rename = "Tables", default)]
+    pub tables: HashMap<String, u64>,
+}
+
+impl SyncBatchingSettings {
+    pub fn get_window(&self, table_name: &str) -> Option<Duration> {
+        let window_ms = match self.tables.get(table_name) {
+            Some(window_ms) => *window_ms,
+            None => self.window_ms?,
+        };
+
+        if window_ms == 0 {
+            return None;
+        }
+
+        Some(Duration::from_millis(window_ms))
+    }
+
+    /// How often the batches are checked: a quarter of the shortest window, so no
+    /// batch is held for more than a quarter longer than it asks for.
+    pub fn get_timer_interval(&self) -> Option<Duration> {
+        let shortest = self
+            .window_ms
+            .iter()
+            .chain(self.tables.values())
+            .filter(|window_ms| **window_ms > 0)
+            .min()?;
+
+        Some(Duration::from_millis((*shortest / 4).max(1)))
+    }
+}
+
+/// Row changes of one table merged down to the last state of every row. A row which
+/// is updated a thousand times is sent once, with the latest version; a row which is
+/// updated and then deleted is only sent as deleted.
+///
+/// A partition deleted as a whole drops everything the batch held for it. Rows put
+/// into it afterwards are kept, which is why the deletes of a batch are always sent
+/// before its updates.
+pub struct RowsBatch<TRow> {
+    updated: BTreeMap<String, BTreeMap<String, TRow>>,
+    deleted: BTreeMap<String, BTreeMap<String, TRow>>,
+    deleted_partitions: BTreeSet<String>,
+}
+
+impl<TRow> RowsBatch<TRow> {
+    pub fn new() -> Self {
+        Self {
+            updated: BTreeMap::new(),
+            deleted: BTreeMap::new(),
+            deleted_partitions: BTreeSet::new(),
+        }
+    }
+
+    pub fn update(&mut self, partition_key: &str, row_key: &str, row: TRow) {
+        remove_row(&mut self.deleted, partition_key, row_key);
+
+        self.updated
+            .entry(partition_key.to_string())
+            .or_default()
+            .insert(row_key.to_string(), row);
+    }
+
+    pub fn delete(&mut self, partition_key: &str, row_key: &str, row: TRow) {
+        remove_row(&mut self.updated, partition_key, row_key);
+
+        // The partition is deleted in the batch already - with the row in it.
+        if self.deleted_partitions.contains(partition_key) {
+            return;
+        }
+
+        self.deleted
+            .entry(partition_key.to_string())
+            .or_default()
+            .insert(row_key.to_string(), row);
+    }
+
+    pub fn delete_partition(&mut self, partition_key: &str) {
+        self.updated.remove(partition_key);
+        self.deleted.remove(partition_key);
+        self.deleted_partitions.insert(partition_key.to_string());
+    }
+
+    pub fn has_deletes(&self) -> bool {
+        !self.deleted.is_empty() || !self.deleted_partitions.is_empty()
+    }
+
+    pub fn has_updates(&self) -> bool {
+        !self.updated.is_empty()
+    }
+}
+
+fn remove_row<TRow>(
+    rows: &mut BTreeMap<String, BTreeMap<String, TRow>>,
+    partition_key: &str,
+    row_key: &str,
+) {
+    if let Some(partition) = rows.get_mut(partition_key) {
+        partition.remove(row_key);
+
+        if partition.is_empty() {
+            rows.remove(partition_key);
+        }
+    }
+}
+
+/// `UpdateRows` and `DeleteRows` of one table which wait for the window to end.
+struct SyncBatch {
+    namespace: DbNamespaceName,
+    table_data: SyncTableData,
+    event_src: EventSource,
+    rows: RowsBatch<Arc<DbRow>>,
+    events: usize,
+    flush_at: Instant,
+    /// Span of the first event of the batch - the sync of the batch is traced under it.
+    trace_parent: Option<SpanContext>,
+}
+
+impl SyncBatch {
+    fn new(namespace: DbNamespaceName, sync_event: &SyncEvent, window: Duration) -> Option<Self> {
+        let (table_data, event_src) = match sync_event {
+            SyncEvent::UpdateRows(data) => (&data.table_data, &data.event_src),
+            SyncEvent::DeleteRows(data) => (&data.table_data, &data.event_src),
+            _ => return None,
+        };
+
+        let result = Self {
+            namespace,
+            table_data: SyncTableData {
+                table_name: table_data.table_name.clone(),
+            },
+            event_src: event_src.clone(),
+            rows: RowsBatch::new(),
+            events: 0,
+            flush_at: Instant::now() + window,
+            trace_parent: crate::telemetry::get_current_span_context(),
+        };
+
+        Some(result)
+    }
+
+    fn add(&mut self, sync_event: SyncEvent) {
+        match sync_event {
+            SyncEvent::UpdateRows(data) => {
+                for (partition_key, db_rows) in data.rows_by_partition.into_partitions() {
+                    for db_row in db_rows {
+                        self.rows.update(
+                            partition_key.as_str(),
+                            db_row.get_row_key(),
+                            db_row.clone(),
+                        );
+                    }
+                }
+            }
+            SyncEvent::DeleteRows(data) => {
+                if let Some(deleted_partitions) = &data.deleted_partitions {
+                    for partition_key in deleted_partitions.iter() {
+                        self.rows.delete_partition(partition_key.as_str());
+                    }
+                }
+
+                if let Some(deleted_rows) = &data.deleted_rows {
+                    for deleted_row_data in deleted_rows.iter() {
+                        for db_row in deleted_row_data.db_rows.iter() {
+                            self.rows.delete(
+                                deleted_row_data.partition_key.as_str(),
+                                db_row.get_row_key(),
+                                db_row.clone(),
+                            );
+                        }
+                    }
+                }
+            }
+            _ => panic!("Only UpdateRows and DeleteRows are merged"),
+        }
+
+        self.events += 1;
+    }
+
+    fn into_flush(self) -> SyncBatchFlush {
+        let mut events = Vec::with_capacity(2);
+
+        if self.rows.has_deletes() {
+            let mut data = DeleteRowsEventSyncData {
+                table_data: SyncTableData {
+                    table_name: self.table_data.table_name.clone(),
+                },
+                event_src: self.event_src.clone(),
+                deleted_partitions: None,
+                deleted_rows: None,
+            };
+
+            for partition_key in self.rows.deleted_partitions {
+                data.new_deleted_partition(&PartitionKey::new(partition_key));
+            }
+
+            for (partition_key, db_rows) in self.rows.deleted {
+                let partition_key = PartitionKey::new(partition_key);
+                for (_, db_row) in db_rows {
+                    data.add_deleted_row(&partition_key, db_row);
+                }
+            }
+
+            events.push(SyncEvent::DeleteRows(data));
+        }
+
+        if self.rows.has_updates() {
+            let mut rows_by_partition = UpdatedRowsByPartition::new();
+
+            for (partition_key, db_rows) in self.rows.updated {
+                rows_by_partition.add_rows(
+                    PartitionKey::new(partition_key),
+                    db_rows.into_values().collect(),
+                );
+            }
+
+            events.push(SyncEvent::UpdateRows(UpdateRowsSyncData {
+                table_data: self.table_data,
+                event_src: self.event_src,
+                rows_by_partition,
+            }));
+        }
+
+        SyncBatchFlush {
+            merged: self.events - events.len(),
+            namespace: self.namespace,
+            events,
+            trace_parent: self.trace_parent,
+        }
+    }
+}
+
+/// Events which are to go to the sync queue now, in this order. `merged` is how many
+/// events fewer it is than were dispatched.
+pub struct SyncBatchFlush {
+    pub namespace: DbNamespaceName,
+    pub events: Vec<SyncEvent>,
+    pub merged: usize,
+    /// The span the events are synced under - the flush is sent by the timer thread
+    /// as often as not, with no span of its own.
+    pub trace_parent: Option<SpanContext>,
+}
+
+/// Batches of the tables which have a batching window, keyed by (namespace, table).
+///
+/// `send` is called under the lock: an event which can not be merged is only sent
+/// after the batch of its table, and two flushes of the same table never overtake
+/// one another on their way to the queue.
+pub struct SyncBatches {
+    batches: Mutex<HashMap<(String, String), SyncBatch>>,
+}
+
+impl SyncBatches {
+    pub fn new() -> Self {
+        Self {
+            batches: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn add(
+        &self,
+        namespace: DbNamespaceName,
+        sync_event: SyncEvent,
+        window: Option<Duration>,
+        mut send: impl FnMut(SyncBatchFlush),
+    ) {
+        let mut batches = self.batches.lock().unwrap();
+
+        let key = (
+            namespace.as_str().to_string(),
+            sync_event.get_table_name().to_string(),
+        );
+
+        if let Some(window) = window {
+            if !batches.contains_key(&key) {
+                if let Some(batch) = SyncBatch::new(namespace.clone(), &sync_event, window) {
+                    batches.insert(key.clone(), batch);
+                }
+            }
+
+            if let Some(batch) = batches.get_mut(&key) {
+                if let SyncEvent::UpdateRows(_) | SyncEvent::DeleteRows(_) = &sync_event {
+                    batch.add(sync_event);
+                    return;
+                }
+            }
+        }
+
+        if let Some(batch) = batches.remove(&key) {
+            send(batch.into_flush());
+        }
+
+        send(SyncBatchFlush {
+            namespace,
+            events: vec![sync_event],
+            merged: 0,
+            trace_parent: crate::telemetry::get_current_span_context(),
+        });
+    }
+
+    pub fn flush_expired(&self, now: Instant, mut send: impl FnMut(SyncBatchFlush)) {
+        let mut batches = self.batches.lock().unwrap();
+
+        let expired: Vec<_> = batches
+            .iter()
+            .filter(|(_, batch)| batch.flush_at <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in expired {
+            if let Some(batch) = batches.remove(&key) {
+                send(batch.into_flush());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_rows(rows: &BTreeMap<String, BTreeMap<String, &'static str>>) -> Vec<&'static str> {
+        rows.values()
+            .flat_map(|partition| partition.values().copied())
+            .collect()
+    }
+
+    #[test]
+    fn test_latest_version_of_row_is_kept() {
+        let mut batch = RowsBatch::new();
+
+        batch.update("pk", "rk", "v1");
+        batch.update("pk", "rk", "v2");
+        batch.update("pk", "rk2", "v1");
+        batch.update("pk", "rk", "v3");
+
+        assert_eq!(vec!["v3", "v1"], get_rows(&batch.updated));
+        assert!(!batch.has_deletes());
+    }
+
+    #[test]
+    fn test_delete_replaces_update_and_back() {
+        let mut batch = RowsBatch::new();
+
+        batch.update("pk", "rk", "v1");
+        batch.delete("pk", "rk", "v1");
+        assert!(!batch.has_updates());
+        assert_eq!(vec!["v1"], get_rows(&batch.deleted));
+
+        batch.update("pk", "rk", "v2");
+        assert!(!batch.has_deletes());
+        assert_eq!(vec!["v2"], get_rows(&batch.updated));
+    }
+
+    #[test]
+    fn test_deleted_partition_drops_what_was_before() {
+        let mut batch = RowsBatch::new();
+
+        batch.update("pk", "rk", "v1");
+        batch.delete("pk", "rk2", "v1");
+        batch.update("pk2", "rk", "v1");
+
+        batch.delete_partition("pk");
+        assert_eq!(vec!["v1"], get_rows(&batch.updated));
+        assert!(batch.deleted.is_empty());
+
+        // Put into the partition after it is deleted - kept, and sent after the delete.
+        batch.update("pk", "rk3", "v2");
+        batch.delete("pk", "rk3", "v2");
+        batch.update("pk", "rk4", "v2");
+
+        assert!(batch.deleted.is_empty());
+        assert_eq!(vec!["v2", "v1"], get_rows(&batch.updated));
+        assert!(batch.deleted_partitions.contains("pk"));
+    }
+
+    #[test]
+    fn test_windows() {
+        let settings = SyncBatchingSettings {
+            window_ms: Some(50),
+            tables: [("hot".to_string(), 20), ("off".to_string(), 0)]
+                .into_iter()
+                .collect(),
+        };
+
+        assert_eq!(Some(Duration::from_millis(50)), settings.get_window("any"));
+        assert_eq!(Some(Duration::from_millis(20)), settings.get_window("hot"));
+        assert_eq!(None, settings.get_window("off"));
+        assert_eq!(
+            Some(Duration::from_millis(5)),
+            settings.get_timer_interval()
+        );
+
+        let settings = SyncBatchingSettings {
+            window_ms: None,
+            tables: HashMap::new(),
+        };
+
+        assert_eq!(None, settings.get_window("any"));
+        assert_eq!(None, settings.get_timer_interval());
+    }
+}
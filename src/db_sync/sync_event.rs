@@ -32,6 +32,32 @@ impl SyncEvent {
             SyncEvent::TableFirstInit(data) => data.db_table.name.as_str(),
         }
     }
+
+    /// Rough size of the data the event carries, in bytes - what it costs to keep
+    /// it in the sync history. Events with no rows in them count as 0.
+    pub fn get_size(&self) -> usize {
+        match self {
+            SyncEvent::InitPartitions(data) => data.get_size(),
+            SyncEvent::UpdateRows(data) => data.rows_by_partition.get_size(),
+            SyncEvent::DeleteRows(data) => data.get_size(),
+            SyncEvent::UpdateTableAttributes(_)
+            | SyncEvent::InitTable(_)
+            | SyncEvent::DeleteTable(_)
+            | SyncEvent::TableFirstInit(_) => 0,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            SyncEvent::UpdateTableAttributes(_) => "UpdateTableAttributes",
+            SyncEvent::InitTable(_) => "InitTable",
+            SyncEvent::InitPartitions(_) => "InitPartitions",
+            SyncEvent::UpdateRows(_) => "UpdateRows",
+            SyncEvent::DeleteRows(_) => "DeleteRows",
+            SyncEvent::DeleteTable(_) => "DeleteTable",
+            SyncEvent::TableFirstInit(_) => "TableFirstInit",
+        }
+    }
 }
 
 impl Into<SyncEvent> for InitTableEventSyncData {
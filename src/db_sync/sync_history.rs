@@ -0,0 +1,252 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use super::{NamespaceSyncEvent, SyncEvent};
+
+pub const DEFAULT_SYNC_HISTORY_SIZE: usize = 1024;
+
+pub const DEFAULT_SYNC_HISTORY_MAX_SIZE_MB: usize = 16;
+
+/// Change sequence of one table plus the most recent events, so a reader which
+/// comes back a moment later is sent what it missed instead of the whole table.
+///
+/// The sequence starts at the moment the history is created (in microseconds)
+/// rather than at zero: a reader which kept a position from before a restart then
+/// presents a number the new history does not cover, and gets a full init - never
+/// somebody else's events under a number it happens to know.
+///
+/// The events are kept while there are no more than `capacity` of them and they take
+/// no more than `max_size` bytes - whichever limit is hit first drops the oldest.
+pub struct TableSyncHistory<TEvent> {
+    first_sequence: u64,
+    last_sequence: u64,
+    events: VecDeque<(u64, usize, TEvent)>,
+    size: usize,
+}
+
+impl<TEvent: Clone> TableSyncHistory<TEvent> {
+    pub fn new(start_sequence: u64) -> Self {
+        Self {
+            first_sequence: start_sequence,
+            last_sequence: start_sequence,
+            events: VecDeque::new(),
+            size: 0,
+        }
+    }
+
+    pub fn get_last_sequence(&self) -> u64 {
+        self.last_sequence
+    }
+
+    pub fn push(&mut self, event: TEvent, size: usize, capacity: usize, max_size: usize) -> u64 {
+        self.last_sequence += 1;
+
+        self.events.push_back((self.last_sequence, size, event));
+        self.size += size;
+
+        while self.events.len() > capacity || self.size > max_size {
+            let Some((_, size, _)) = self.events.pop_front() else {
+                break;
+            };
+
+            self.size -= size;
+        }
+
+        self.last_sequence
+    }
+
+    /// Takes the next sequence and drops everything kept before it: the event
+    /// replaces the whole table, so a reader from an earlier position gets a full
+    /// init, and one from this position on resumes as usual.
+    pub fn reset(&mut self) -> u64 {
+        self.last_sequence += 1;
+        self.first_sequence = self.last_sequence;
+        self.events.clear();
+        self.size = 0;
+
+        self.last_sequence
+    }
+
+    /// Events after `sequence`, oldest first. `None` when the history no longer - or
+    /// never did - cover that position, and the reader needs a full init instead.
+    pub fn get_since(&self, sequence: u64) -> Option<Vec<(u64, TEvent)>> {
+        if sequence < self.first_sequence || sequence > self.last_sequence {
+            return None;
+        }
+
+        if sequence == self.last_sequence {
+            return Some(vec![]);
+        }
+
+        let (oldest, _, _) = self.events.front()?;
+
+        if *oldest > sequence + 1 {
+            return None;
+        }
+
+        let result = self
+            .events
+            .iter()
+            .filter(|(event_sequence, _, _)| *event_sequence > sequence)
+            .map(|(event_sequence, _, event)| (*event_sequence, event.clone()))
+            .collect();
+
+        Some(result)
+    }
+}
+
+/// Sync histories of every table, keyed by (namespace, table). Fed by the sync loop
+/// only - which is what makes the order of the sequence the order readers see.
+pub struct SyncHistory {
+    capacity: usize,
+    max_size: usize,
+    tables: Mutex<HashMap<(String, String), TableSyncHistory<Arc<NamespaceSyncEvent>>>>,
+}
+
+impl SyncHistory {
+    pub fn new(capacity: usize, max_size: usize) -> Self {
+        Self {
+            capacity,
+            max_size,
+            tables: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Gives the event the next sequence number of its table and keeps it. A
+    /// `TableFirstInit` is addressed to one reader and changes nothing, so it gets no
+    /// number and is not kept. An `InitTable` or a `DeleteTable` replaces everything
+    /// before it, so it clears the history of the table instead of being kept.
+    pub fn add(&self, mut model: NamespaceSyncEvent) -> Arc<NamespaceSyncEvent> {
+        if let SyncEvent::TableFirstInit(_) = &model.event {
+            return Arc::new(model);
+        }
+
+        let mut tables = self.tables.lock().unwrap();
+
+        let history = tables
+            .entry(get_key(
+                model.namespace.as_str(),
+                model.event.get_table_name(),
+            ))
+            .or_insert_with(new_table_history);
+
+        model.sequence = Some(history.get_last_sequence() + 1);
+
+        match &model.event {
+            SyncEvent::InitTable(_) | SyncEvent::DeleteTable(_) => {
+                history.reset();
+                Arc::new(model)
+            }
+            _ => {
+                let size = model.event.get_size();
+                let model = Arc::new(model);
+                history.push(model.clone(), size, self.capacity, self.max_size);
+                model
+            }
+        }
+    }
+
+    pub fn get_last_sequence(&self, namespace: &str, table_name: &str) -> u64 {
+        let mut tables = self.tables.lock().unwrap();
+
+        tables
+            .entry(get_key(namespace, table_name))
+            .or_insert_with(new_table_history)
+            .get_last_sequence()
+    }
+
+    pub fn get_since(
+        &self,
+        namespace: &str,
+        table_name: &str,
+        sequence: u64,
+    ) -> Option<Vec<Arc<NamespaceSyncEvent>>> {
+        let tables = self.tables.lock().unwrap();
+
+        let events = tables
+            .get(&get_key(namespace, table_name))?
+            .get_since(sequence)?;
+
+        Some(events.into_iter().map(|(_, event)| event).collect())
+    }
+}
+
+fn get_key(namespace: &str, table_name: &str) -> (String, String) {
+    (namespace.to_string(), table_name.to_string())
+}
+
+fn new_table_history<TEvent: Clone>() -> TableSyncHistory<TEvent> {
+    TableSyncHistory::new(DateTimeAsMicroseconds::now().unix_microseconds as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missed_events_are_returned() {
+        let mut history = TableSyncHistory::new(100);
+
+        assert_eq!(101, history.push("a", 1, 10, 100));
+        assert_eq!(102, history.push("b", 1, 10, 100));
+        assert_eq!(103, history.push("c", 1, 10, 100));
+
+        assert_eq!(Some(vec![(102, "b"), (103, "c")]), history.get_since(101));
+        assert_eq!(
+            Some(vec![(101, "a"), (102, "b"), (103, "c")]),
+            history.get_since(100)
+        );
+        assert_eq!(Some(vec![]), history.get_since(103));
+    }
+
+    #[test]
+    fn test_position_the_ring_does_not_cover() {
+        let mut history = TableSyncHistory::new(100);
+
+        for event in ["a", "b", "c", "d"] {
+            history.push(event, 1, 2, 100);
+        }
+
+        // 103 and 104 are kept; a reader at 102 misses nothing else.
+        assert_eq!(Some(vec![(103, "c"), (104, "d")]), history.get_since(102));
+        assert_eq!(None, history.get_since(101));
+
+        // From before the history started, or from a future it never reached.
+        assert_eq!(None, history.get_since(99));
+        assert_eq!(None, history.get_since(105));
+    }
+
+    #[test]
+    fn test_events_above_max_size_are_dropped() {
+        let mut history = TableSyncHistory::new(100);
+
+        history.push("a", 40, 10, 100);
+        history.push("b", 40, 10, 100);
+        history.push("c", 40, 10, 100);
+
+        // "a" does not fit any more.
+        assert_eq!(Some(vec![(102, "b"), (103, "c")]), history.get_since(101));
+        assert_eq!(None, history.get_since(100));
+
+        // An event bigger than the budget is not kept at all.
+        history.push("d", 200, 10, 100);
+        assert_eq!(Some(vec![]), history.get_since(104));
+        assert_eq!(None, history.get_since(103));
+    }
+
+    #[test]
+    fn test_reset_drops_earlier_positions() {
+        let mut history = TableSyncHistory::new(100);
+
+        history.push("a", 1, 10, 100);
+        assert_eq!(102, history.reset());
+        history.push("b", 1, 10, 100);
+
+        assert_eq!(None, history.get_since(101));
+        assert_eq!(Some(vec![(103, "b")]), history.get_since(102));
+    }
+}
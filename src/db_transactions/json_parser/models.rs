@@ -6,7 +6,10 @@ use my_no_sql_sdk::core::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::db_transactions::steps::{TransactionalOperationStep, UpdateRowsStepState};
+use crate::{
+    db_operations::DbOperationError,
+    db_transactions::steps::{TransactionalOperationStep, UpdateRowsStepState},
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CleanTableTransactionJsonModel {
@@ -109,3 +112,92 @@ impl InsertOrUpdateTransactionJsonModel {
         Ok(result)
     }
 }
+
+/////////
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MergePatchTransactionJsonModel {
+    #[serde(rename = "tableName")]
+    pub table_name: String,
+    #[serde(rename = "partitionKey")]
+    pub partition_key: String,
+    #[serde(rename = "rowKey")]
+    pub row_key: String,
+
+    /// TimeStamp the row was read at. When present the patch is applied only if the
+    /// row is still at this version.
+    #[serde(rename = "timeStamp")]
+    pub time_stamp: Option<String>,
+
+    #[serde(rename = "patch")]
+    pub patch: serde_json::Value,
+}
+
+impl MergePatchTransactionJsonModel {
+    pub fn into(self) -> Result<TransactionalOperationStep, DbOperationError> {
+        crate::db_operations::write::merge_patch::validate_patch(
+            &self.patch,
+            self.partition_key.as_str(),
+            self.row_key.as_str(),
+        )?;
+
+        let expected_time_stamp = match self.time_stamp.as_deref() {
+            Some(time_stamp) => {
+                let Some(expected_time_stamp) =
+                    my_no_sql_sdk::abstractions::parse_time_stamp(time_stamp)
+                else {
+                    return Err(DbOperationError::InvalidMergePatch(format!(
+                        "'{}' is not a valid TimeStamp",
+                        time_stamp
+                    )));
+                };
+
+                Some(expected_time_stamp)
+            }
+            None => None,
+        };
+
+        Ok(TransactionalOperationStep::MergePatch {
+            table_name: self.table_name,
+            partition_key: self.partition_key,
+            row_key: self.row_key,
+            patch: self.patch,
+            expected_time_stamp,
+        })
+    }
+}
+
+/////////
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct IncrementTransactionJsonModel {
+    #[serde(rename = "tableName")]
+    pub table_name: String,
+    #[serde(rename = "partitionKey")]
+    pub partition_key: String,
+    #[serde(rename = "rowKey")]
+    pub row_key: String,
+    #[serde(rename = "field")]
+    pub field: String,
+    #[serde(rename = "delta")]
+    pub delta: serde_json::Number,
+
+    /// What a missing row or field counts from. Default is 0.
+    #[serde(rename = "initialValue")]
+    pub initial_value: Option<serde_json::Number>,
+}
+
+impl Into<TransactionalOperationStep> for IncrementTransactionJsonModel {
+    fn into(self) -> TransactionalOperationStep {
+        TransactionalOperationStep::Increment {
+            table_name: self.table_name,
+            partition_key: self.partition_key,
+            row_key: self.row_key,
+            field: self.field,
+            delta: self.delta,
+            initial_value: self
+                .initial_value
+                .unwrap_or_else(|| serde_json::Number::from(0)),
+        }
+    }
+}
@@ -8,13 +8,16 @@ use crate::{
 
 use super::models::{
     CleanTableTransactionJsonModel, DeletePartitionsTransactionJsonModel,
-    DeleteRowsTransactionJsonModel, InsertOrUpdateTransactionJsonModel,
+    DeleteRowsTransactionJsonModel, IncrementTransactionJsonModel,
+    InsertOrUpdateTransactionJsonModel, MergePatchTransactionJsonModel,
 };
 
 const JSON_TRANSACTION_CLEAN_TABLE: &str = "CleanTable";
 const JSON_TRANSACTION_DELETE_PARTITIONS: &str = "CleanPartitions";
 const JSON_TRANSACTION_DELETE_ROWS: &str = "DeleteRows";
 const JSON_TRANSACTION_INSERT_OR_UPDATE: &str = "InsertOrUpdate";
+const JSON_TRANSACTION_MERGE_PATCH: &str = "MergePatch";
+const JSON_TRANSACTION_INCREMENT: &str = "Increment";
 
 pub fn parse_transactions(
     payload: &[u8],
@@ -54,6 +57,20 @@ pub fn parse_transactions(
 
             result.push(model.into()?)
         }
+
+        if type_model.transaction_type == JSON_TRANSACTION_MERGE_PATCH {
+            let model: MergePatchTransactionJsonModel =
+                serde_json::from_slice(json_object.as_slice())?;
+
+            result.push(model.into()?)
+        }
+
+        if type_model.transaction_type == JSON_TRANSACTION_INCREMENT {
+            let model: IncrementTransactionJsonModel =
+                serde_json::from_slice(json_object.as_slice())?;
+
+            result.push(model.into())
+        }
     }
 
     Ok(result)
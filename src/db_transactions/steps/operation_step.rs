@@ -1,3 +1,5 @@
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+
 use super::UpdateRowsStepState;
 
 pub enum TransactionalOperationStep {
@@ -16,6 +18,25 @@ pub enum TransactionalOperationStep {
     },
 
     UpdateRows(UpdateRowsStepState),
+
+    /// RFC 7386 merge patch of one row, applied at commit under the table write lock.
+    MergePatch {
+        table_name: String,
+        partition_key: String,
+        row_key: String,
+        patch: serde_json::Value,
+        expected_time_stamp: Option<DateTimeAsMicroseconds>,
+    },
+
+    /// Adds `delta` to a numeric field of one row, creating the row when it is missing.
+    Increment {
+        table_name: String,
+        partition_key: String,
+        row_key: String,
+        field: String,
+        delta: serde_json::Number,
+        initial_value: serde_json::Number,
+    },
 }
 
 impl TransactionalOperationStep {
@@ -32,6 +53,8 @@ impl TransactionalOperationStep {
                 row_keys: _,
             } => table_name,
             TransactionalOperationStep::UpdateRows(state) => state.table_name.as_str(),
+            TransactionalOperationStep::MergePatch { table_name, .. } => table_name,
+            TransactionalOperationStep::Increment { table_name, .. } => table_name,
         }
     }
 }
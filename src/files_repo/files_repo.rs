@@ -1,6 +1,9 @@
+use std::sync::Arc;
+
 use my_no_sql_sdk::core::db::DbTableAttributes;
 use tokio::sync::Mutex;
 
+use crate::app::logs::Logs;
 use crate::persist_repo::{LoadedPartition, LoadedTableAttrs};
 use crate::scripts::serializers::table_attrs::TableMetadataFileContract;
 
@@ -22,10 +25,10 @@ impl FilesRepo {
     /// `skip_errors` mirrors `SkipBrokenPartitions`: it decides whether a
     /// corrupt `tables.meta` is skipped (attributes restored with defaults)
     /// or fatal.
-    pub async fn open(root: String, skip_errors: bool) -> Self {
+    pub async fn open(root: String, skip_errors: bool, logs: Arc<Logs>) -> Self {
         println!("Opening files persistence at: {}", root);
         Self {
-            inner: Mutex::new(FilesRepoInner::open(root, skip_errors).await),
+            inner: Mutex::new(FilesRepoInner::open(root, skip_errors, logs).await),
         }
     }
 
@@ -1,8 +1,9 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
 
 use ahash::AHashMap;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
+use crate::app::logs::{LogItem, Logs, SystemProcess};
 use crate::persist_repo::{LoadedPartition, LoadedTableAttrs};
 use crate::scripts::serializers::table_attrs::TableMetadataFileContract;
 
@@ -50,6 +51,7 @@ pub struct FilesRepoInner {
     /// the recovery scan, so it stays monotonic across restarts and never
     /// depends on the (non-monotonic) wall clock.
     next_version: u64,
+    logs: Arc<Logs>,
 }
 
 /// A slot picked up by the recovery scan.
@@ -61,12 +63,12 @@ struct ScannedSlot {
 }
 
 impl FilesRepoInner {
-    pub async fn open(root: String, skip_errors: bool) -> Self {
+    pub async fn open(root: String, skip_errors: bool, logs: Arc<Logs>) -> Self {
         tokio::fs::create_dir_all(&root)
             .await
             .expect("files_repo: can not create root directory");
 
-        let (tables, legacy_json) = load_tables_meta(&root, skip_errors).await;
+        let (tables, legacy_json) = load_tables_meta(&root, skip_errors, &logs).await;
         let classes = discover_class_files(&root).await;
 
         let result = Self {
@@ -75,13 +77,20 @@ impl FilesRepoInner {
             index: AHashMap::new(),
             tables,
             next_version: 0,
+            logs,
         };
 
         // A tables.meta still in the legacy JSON format is converted to YAML
         // right away, so the migration does not wait for the next metadata
         // change (which may never come).
         if legacy_json && !result.tables.is_empty() {
-            println!("files_repo: converting tables.meta from legacy json to yaml");
+            result.logs.write(
+                LogItem::info(
+                    SystemProcess::Persist,
+                    "Converting tables.meta from legacy json to yaml",
+                )
+                .with_context("path", result.root.as_str()),
+            );
             result.persist_tables_meta().await;
         }
 
@@ -154,9 +163,12 @@ impl FilesRepoInner {
                                 slot_index, path
                             );
                         }
-                        println!(
-                            "files_repo: skipping corrupt slot {} in {}",
-                            slot_index, path
+                        self.logs.write(
+                            LogItem::error(
+                                SystemProcess::Persist,
+                                format!("Skipping corrupt slot {}", slot_index),
+                            )
+                            .with_context("path", path.as_str()),
                         );
                         // Reuse the broken slot; it can never be decoded to a key.
                         self.classes
@@ -377,9 +389,15 @@ impl FilesRepoInner {
                 Ok(_) => true,
                 Err(err) if err.kind() == std::io::ErrorKind::NotFound => true,
                 Err(err) => {
-                    println!(
-                        "files_repo: could not vacuum page-file {}: {} (will retry)",
-                        size_class, err
+                    self.logs.write(
+                        LogItem::error(
+                            SystemProcess::Vacuum,
+                            format!(
+                                "Can not vacuum page-file {}, will retry. Err: {}",
+                                size_class, err
+                            ),
+                        )
+                        .with_context("path", self.root.as_str()),
                     );
                     false
                 }
@@ -387,9 +405,15 @@ impl FilesRepoInner {
 
             if removed {
                 self.classes.remove(&size_class);
-                println!(
-                    "files_repo: vacuumed fully-freed page-file for size class {}",
-                    size_class
+                self.logs.write(
+                    LogItem::info(
+                        SystemProcess::Vacuum,
+                        format!(
+                            "Vacuumed fully-freed page-file for size class {}",
+                            size_class
+                        ),
+                    )
+                    .with_context("path", self.root.as_str()),
                 );
             }
         }
@@ -513,9 +537,15 @@ impl FilesRepoInner {
         state.slot_count = live_count;
         state.free.clear();
 
-        println!(
-            "files_repo: compacted page-file {}: {} -> {} slots",
-            size_class, slot_count, live_count
+        self.logs.write(
+            LogItem::info(
+                SystemProcess::Vacuum,
+                format!(
+                    "Compacted page-file {}: {} -> {} slots",
+                    size_class, slot_count, live_count
+                ),
+            )
+            .with_context("path", self.root.as_str()),
         );
     }
 
@@ -612,6 +642,7 @@ impl FilesRepoInner {
 async fn load_tables_meta(
     root: &str,
     skip_errors: bool,
+    logs: &Logs,
 ) -> (BTreeMap<String, TableMetadataFileContract>, bool) {
     let path = format!("{}/{}", root, TABLES_META_FILE);
     let bytes = match tokio::fs::read(&path).await {
@@ -635,7 +666,10 @@ async fn load_tables_meta(
                 path, yaml_err, json_err
             );
             if skip_errors {
-                println!("{}. Table attributes will be restored with defaults.", msg);
+                logs.write(LogItem::error(
+                    SystemProcess::Persist,
+                    format!("{}. Table attributes will be restored with defaults.", msg),
+                ));
                 (BTreeMap::new(), false)
             } else {
                 panic!("{}", msg);
@@ -2,8 +2,11 @@
 //! wrapper against a real temp directory, including reopen (recovery scan),
 //! slot reuse, relocation between size classes, vacuum and corruption skip.
 
+use std::sync::Arc;
+
 use my_no_sql_sdk::core::db::DbTableAttributes;
 
+use crate::app::logs::Logs;
 use crate::persist_repo::LoadedPartition;
 
 use super::FilesRepo;
@@ -28,7 +31,7 @@ async fn file_len(path: &str) -> Option<u64> {
 /// Opens the repo on `dir` and performs the init-time recovery scan, exactly
 /// like the production init flow does before any writes.
 async fn reopen(dir: &str, skip_errors: bool) -> (FilesRepo, Vec<LoadedPartition>) {
-    let repo = FilesRepo::open(dir.to_string(), skip_errors).await;
+    let repo = FilesRepo::open(dir.to_string(), skip_errors, Arc::new(Logs::new())).await;
     let loaded = repo.load_all_partitions(skip_errors).await;
     (repo, loaded)
 }
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use tonic::{Request, Status};
+
+use crate::{
+    app::{ApiKey, ApiRole, ApiScope, AppContext},
+    db_operations::DbOperationError,
+};
+
+/// Metadata carrying the API key. `authorization: Bearer <key>` is taken as well.
+pub const API_KEY_METADATA: &str = "x-api-key";
+
+/// Interceptor of the writer service: a call with no valid key is refused before it
+/// gets anywhere, one with a valid key gets it in its extensions - for the method to
+/// check it has the role the method requires, over the table it touches.
+pub fn authenticate(app: &AppContext, mut request: Request<()>) -> Result<Request<()>, Status> {
+    let Some(api_keys) = app.api_keys.as_ref() else {
+        return Ok(request);
+    };
+
+    let api_key = api_keys
+        .authenticate(get_api_key(&request))
+        .map_err(into_status)?;
+
+    request.extensions_mut().insert(api_key);
+
+    Ok(request)
+}
+
+/// Whether the key the call was authenticated with allows the method. Everything is
+/// allowed when no `ApiKeysFile` is set.
+pub fn check_access<T>(
+    app: &AppContext,
+    request: &Request<T>,
+    role: ApiRole,
+    scope: ApiScope,
+) -> Result<(), Status> {
+    if app.api_keys.is_none() {
+        return Ok(());
+    }
+
+    let Some(api_key) = request.extensions().get::<Arc<ApiKey>>() else {
+        return Err(Status::unauthenticated("API key is required"));
+    };
+
+    crate::app::check_api_access(api_key, role, &scope).map_err(into_status)
+}
+
+/// Name of the namespace a request names - the default one if it names none.
+pub fn get_namespace_name(name_space: Option<&str>) -> &str {
+    match name_space {
+        Some(name_space) if !name_space.is_empty() => name_space,
+        _ => my_no_sql_sdk::DEFAULT_NAMESPACE,
+    }
+}
+
+fn get_api_key<T>(request: &Request<T>) -> Option<&str> {
+    let metadata = request.metadata();
+
+    if let Some(api_key) = metadata
+        .get(API_KEY_METADATA)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+    {
+        return Some(api_key);
+    }
+
+    metadata
+        .get("authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+fn into_status(err: DbOperationError) -> Status {
+    match err {
+        DbOperationError::Unauthorized(message) => Status::unauthenticated(message),
+        DbOperationError::AccessDenied(message) => Status::permission_denied(message),
+        err => Status::internal(format!("{:?}", err)),
+    }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use tonic::Request;
+
+use crate::{
+    app::{ApiKey, AppContext},
+    audit_log::{AuditActorKind, AuditRecord},
+};
+
+/// Who a gRPC call comes from, taken before the request is consumed: the key it was
+/// authenticated with and the address of the peer.
+pub struct AuditCaller {
+    actor: AuditActorKind,
+    actor_name: Option<String>,
+    ip: Option<String>,
+}
+
+impl AuditCaller {
+    pub fn new<T>(request: &Request<T>) -> Self {
+        let (actor, actor_name) = match request.extensions().get::<Arc<ApiKey>>() {
+            Some(api_key) => (AuditActorKind::ApiKey, Some(api_key.name.clone())),
+            None => (AuditActorKind::Anonymous, None),
+        };
+
+        Self {
+            actor,
+            actor_name,
+            ip: request.remote_addr().map(|addr| addr.ip().to_string()),
+        }
+    }
+}
+
+/// Records an operation of a gRPC call, with whoever `caller` says it comes from.
+pub async fn write_audit(app: &AppContext, caller: &AuditCaller, record: AuditRecord) {
+    if app.audit_log.is_none() {
+        return;
+    }
+
+    let mut record = record.with_actor(caller.actor.clone(), caller.actor_name.clone());
+
+    if let Some(ip) = caller.ip.as_ref() {
+        record = record.with_ip(ip.to_string());
+    }
+
+    app.write_audit(record).await;
+}
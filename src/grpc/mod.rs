@@ -1,3 +1,7 @@
+mod api_keys;
+mod audit;
 mod models;
+pub mod reader_grpc;
 pub mod server;
+mod tls_incoming;
 pub mod writer_grpc;
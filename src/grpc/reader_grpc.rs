@@ -0,0 +1,239 @@
+use super::server::MyNoSqlServerReaderGrpcService;
+use crate::app::AppContext;
+use crate::data_readers::{
+    grpc_connection::{grpc_payload_to_send, GrpcEventsSender},
+    tcp_connection::ReaderName,
+    DataReader, DataReaderConnection, PartitionScope, SubscribeOptions, READER_TOKEN_SEPARATOR,
+};
+use crate::mynosqlserver_grpc::reader_grpc_request::Request as ReaderRequest;
+use crate::mynosqlserver_grpc::reader_server::Reader;
+use crate::mynosqlserver_grpc::*;
+use crate::tcp::reader_contracts::ReaderContractsSession;
+use futures::StreamExt;
+use futures_core::Stream;
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use my_no_sql_sdk::tcp_contracts::MyNoSqlTcpContract;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use tonic::Status;
+
+static NEXT_CONNECTION_ID: AtomicI64 = AtomicI64::new(0);
+
+#[tonic::async_trait]
+impl Reader for MyNoSqlServerReaderGrpcService {
+    type SubscribeStream =
+        Pin<Box<dyn Stream<Item = Result<ReaderGrpcEvent, Status>> + Send + Sync + 'static>>;
+
+    async fn subscribe(
+        &self,
+        request: tonic::Request<tonic::Streaming<ReaderGrpcRequest>>,
+    ) -> Result<tonic::Response<Self::SubscribeStream>, tonic::Status> {
+        let ip = match request.remote_addr() {
+            Some(addr) => addr.to_string(),
+            None => "unknown".to_string(),
+        };
+
+        let mut requests = request.into_inner();
+
+        // Unbounded, as the sync loop must never wait on one reader. What is queued is
+        // not unbounded though: every event goes through `GrpcEventsSender`, which
+        // counts it until the stream takes it, and a reader over the `SlowReader`
+        // limits is reinitialized or disconnected like a TCP one.
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        let sender = GrpcEventsSender::new(sender);
+
+        let session = GrpcReaderSession {
+            connection_id: NEXT_CONNECTION_ID.fetch_add(1, Ordering::SeqCst),
+            ip,
+            sender: sender.clone(),
+        };
+
+        let app = self.app.clone();
+
+        tokio::spawn(async move {
+            while let Ok(Some(request)) = requests.message().await {
+                handle_request(&app, &session, request).await;
+            }
+
+            if let Some(data_reader) = app.data_readers.remove_grpc(session.connection_id).await {
+                app.metrics.remove_pending_to_sync(&data_reader.connection);
+            }
+        });
+
+        let events = tokio_stream::wrappers::UnboundedReceiverStream::new(receiver)
+            .take_while(|event| futures::future::ready(event.is_some()))
+            .map(move |event| {
+                let event = event.unwrap();
+                sender.mark_sent(&event);
+                Ok(event)
+            });
+
+        Ok(tonic::Response::new(Box::pin(events)))
+    }
+}
+
+/// The requests of the stream are the TCP contracts under other names, and go
+/// through the very same handling.
+async fn handle_request(app: &AppContext, session: &GrpcReaderSession, request: ReaderGrpcRequest) {
+    if let Some(data_reader) = session.get_data_reader(app).await {
+        if let DataReaderConnection::Grpc(info) = &data_reader.connection {
+            info.last_incoming_moment
+                .update(DateTimeAsMicroseconds::now());
+        }
+    }
+
+    let Some(request) = request.request else {
+        return;
+    };
+
+    match request {
+        ReaderRequest::Greeting(greeting) => {
+            // Passed on the way a TCP reader presents it, so it is checked in one place.
+            let name = match greeting.token {
+                Some(token) => format!("{}{}{}", greeting.name, READER_TOKEN_SEPARATOR, token),
+                None => greeting.name,
+            };
+
+            let contract = MyNoSqlTcpContract::Greeting { name };
+            crate::tcp::reader_contracts::handle(app, session, contract).await;
+
+            if let Some(namespace) = greeting.name_space {
+                let contract = MyNoSqlTcpContract::SetNamespace { namespace };
+                crate::tcp::reader_contracts::handle(app, session, contract).await;
+            }
+        }
+        ReaderRequest::Subscribe(subscribe) => {
+            // No partitions named - the whole table, as without options.
+            let scope = if subscribe.partition_keys.is_empty()
+                && subscribe.partition_key_prefixes.is_empty()
+            {
+                None
+            } else {
+                let mut scope = PartitionScope::new();
+                scope.add_partition_keys(subscribe.partition_keys.into_iter());
+                scope.add_prefixes(subscribe.partition_key_prefixes.into_iter());
+                Some(scope)
+            };
+
+            let options = SubscribeOptions {
+                scope,
+                from_sequence: subscribe.from_sequence,
+            };
+
+            crate::tcp::reader_contracts::handle_subscribe(
+                app,
+                session,
+                subscribe.table_name,
+                options,
+            )
+            .await;
+        }
+        ReaderRequest::Unsubscribe(unsubscribe) => {
+            let contract = MyNoSqlTcpContract::Unsubscribe(unsubscribe.table_name);
+            crate::tcp::reader_contracts::handle(app, session, contract).await;
+        }
+        ReaderRequest::UpdatePartitionsExpirationTime(request) => {
+            let mut partitions = Vec::with_capacity(request.partitions.len());
+
+            for partition in request.partitions {
+                let Some(expiration_time) =
+                    session.parse_expiration_time(partition.expiration_time.as_deref())
+                else {
+                    return;
+                };
+
+                partitions.push((partition.partition_key, expiration_time));
+            }
+
+            let contract = MyNoSqlTcpContract::UpdatePartitionsExpirationTime {
+                confirmation_id: 0,
+                table_name: request.table_name,
+                partitions,
+            };
+            crate::tcp::reader_contracts::handle(app, session, contract).await;
+        }
+        ReaderRequest::UpdateRowsExpirationTime(request) => {
+            let Some(expiration_time) =
+                session.parse_expiration_time(request.expiration_time.as_deref())
+            else {
+                return;
+            };
+
+            let contract = MyNoSqlTcpContract::UpdateRowsExpirationTime {
+                confirmation_id: 0,
+                table_name: request.table_name,
+                partition_key: request.partition_key,
+                row_keys: request.row_keys,
+                expiration_time,
+            };
+            crate::tcp::reader_contracts::handle(app, session, contract).await;
+        }
+    }
+}
+
+struct GrpcReaderSession {
+    connection_id: i64,
+    ip: String,
+    sender: GrpcEventsSender,
+}
+
+impl GrpcReaderSession {
+    /// `Some(None)` - no expiration time, which removes it. A time which does not
+    /// parse is answered with an Error and gives `None`.
+    fn parse_expiration_time(
+        &self,
+        expiration_time: Option<&str>,
+    ) -> Option<Option<DateTimeAsMicroseconds>> {
+        let Some(expiration_time) = expiration_time else {
+            return Some(None);
+        };
+
+        match DateTimeAsMicroseconds::from_str(expiration_time) {
+            Some(expiration_time) => Some(Some(expiration_time)),
+            None => {
+                self.sender.send(grpc_payload_to_send::new_error(format!(
+                    "Invalid expiration time '{}'",
+                    expiration_time
+                )));
+                None
+            }
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ReaderContractsSession for GrpcReaderSession {
+    fn get_session_id(&self) -> String {
+        format!("Grpc-{}", self.connection_id)
+    }
+
+    /// Only what the client has to hear about goes out; `Pong` and confirmations
+    /// have nothing to answer on a gRPC stream.
+    async fn send(&self, contract: &MyNoSqlTcpContract) {
+        let message = match contract {
+            MyNoSqlTcpContract::Error { message } => message.to_string(),
+            MyNoSqlTcpContract::TableNotFound(table_name) => {
+                format!("Table {} is not found", table_name)
+            }
+            _ => return,
+        };
+
+        self.sender.send(grpc_payload_to_send::new_error(message));
+    }
+
+    async fn get_data_reader(&self, app: &AppContext) -> Option<Arc<DataReader>> {
+        app.data_readers.get_grpc(self.connection_id).await
+    }
+
+    async fn greeting(&self, app: &AppContext, name: ReaderName, _compress: bool) {
+        app.data_readers
+            .add_grpc(
+                self.connection_id,
+                name,
+                self.ip.to_string(),
+                self.sender.clone(),
+            )
+            .await;
+    }
+}
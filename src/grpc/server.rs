@@ -1,10 +1,15 @@
 use crate::app::AppContext;
+use crate::mynosqlserver_grpc::reader_server::ReaderServer;
 use crate::mynosqlserver_grpc::writer_server::WriterServer;
+use crate::telemetry::TracedGrpcService;
 use anyhow::*;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tonic::transport::Server;
 
+/// Of `GrpcListenAddr` when it is not set.
+pub const DEFAULT_GRPC_PORT: u16 = 5124;
+
 #[derive(Clone)]
 pub struct MyNoSqlServerWriterGrpcSerice {
     pub app: Arc<AppContext>,
@@ -16,14 +21,47 @@ impl MyNoSqlServerWriterGrpcSerice {
     }
 }
 
-pub async fn start(app: Arc<AppContext>, port: u16) -> Result<()> {
-    let addr = SocketAddr::from(([0, 0, 0, 0], port));
-    let service = MyNoSqlServerWriterGrpcSerice::new(app);
+#[derive(Clone)]
+pub struct MyNoSqlServerReaderGrpcService {
+    pub app: Arc<AppContext>,
+}
+
+impl MyNoSqlServerReaderGrpcService {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+/// With `Tls` set the listener takes TLS off itself - unlike the HTTP and the reader TCP
+/// ones, it has no plain port behind it.
+pub async fn start(app: Arc<AppContext>, listen_addr: SocketAddr) -> Result<()> {
+    let service = MyNoSqlServerWriterGrpcSerice::new(app.clone());
+    let reader_service = MyNoSqlServerReaderGrpcService::new(app.clone());
+
+    // Readers present the credentials of `ReaderAclFile` with their greeting instead.
+    let writer_app = app.clone();
+    let writer_server = WriterServer::with_interceptor(service, move |request| {
+        super::api_keys::authenticate(writer_app.as_ref(), request)
+    });
+
+    let router = Server::builder()
+        // Reader streams live as long as the reader does - a span of one would be
+        // of no use. Their deliveries are traced by the sync instead.
+        .add_service(TracedGrpcService::new(writer_server))
+        .add_service(ReaderServer::new(reader_service));
+
+    if app.tls.is_none() {
+        println!("Listening to {:?} as grpc endpoint", listen_addr);
+        return router.serve(listen_addr).await.context("Server error");
+    }
+
+    let incoming = super::tls_incoming::accept_tls(app, listen_addr)
+        .await
+        .map_err(|err| anyhow!(err))?;
 
-    println!("Listening to {:?} as grpc endpoint", addr);
-    Server::builder()
-        .add_service(WriterServer::new(service))
-        .serve(addr)
+    println!("Listening to {:?} as grpc endpoint with TLS", listen_addr);
+    router
+        .serve_with_incoming(incoming)
         .await
         .context("Server error")
 }
@@ -0,0 +1,145 @@
+use std::{
+    io,
+    net::SocketAddr,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf},
+    net::{TcpListener, TcpStream},
+};
+use tokio_rustls::{server::TlsStream, TlsAcceptor};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::transport::server::{Connected, TcpConnectInfo};
+
+use crate::{
+    app::{
+        logs::{LogItem, SystemProcess},
+        AppContext,
+    },
+    tls::TlsProtocol,
+};
+
+/// Connections which have not been taken by the server yet.
+const ACCEPTED_QUEUE_SIZE: usize = 128;
+
+/// Connection of the gRPC listener, with TLS taken off by the listener itself - the
+/// calls see the address of the client as it is.
+pub struct TlsConnection {
+    stream: TlsStream<TcpStream>,
+    local_addr: Option<SocketAddr>,
+    remote_addr: SocketAddr,
+}
+
+impl Connected for TlsConnection {
+    type ConnectInfo = TcpConnectInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        TcpConnectInfo {
+            local_addr: self.local_addr,
+            remote_addr: Some(self.remote_addr),
+        }
+    }
+}
+
+impl AsyncRead for TlsConnection {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for TlsConnection {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().stream).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().stream).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.stream.is_write_vectored()
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_shutdown(cx)
+    }
+}
+
+/// Listens at `addr` and hands the server the connections which completed the TLS
+/// handshake. Every handshake runs on its own, so a slow client holds up nobody, and
+/// gets the certificate current when it is accepted.
+pub async fn accept_tls(
+    app: Arc<AppContext>,
+    addr: SocketAddr,
+) -> Result<ReceiverStream<io::Result<TlsConnection>>, String> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .map_err(|err| format!("Can not listen TLS at {}. Err: {}", addr, err))?;
+
+    let (sender, receiver) = tokio::sync::mpsc::channel(ACCEPTED_QUEUE_SIZE);
+
+    tokio::spawn(async move {
+        // The server is gone once it drops its end.
+        while !sender.is_closed() {
+            let (stream, remote_addr) = match listener.accept().await {
+                Ok(accepted) => accepted,
+                Err(err) => {
+                    app.logs.write(
+                        LogItem::error(
+                            SystemProcess::Tls,
+                            format!("Can not accept a connection. Err: {}", err),
+                        )
+                        .with_context("addr", addr.to_string()),
+                    );
+                    continue;
+                }
+            };
+
+            let Some(tls) = app.tls.as_ref() else {
+                return;
+            };
+
+            let acceptor = TlsAcceptor::from(tls.get_server_config(TlsProtocol::Http));
+            let sender = sender.clone();
+
+            tokio::spawn(async move {
+                let local_addr = stream.local_addr().ok();
+
+                // A failed handshake - a plain client, a scanner, a client certificate
+                // the CA did not sign - is not worth a log record of its own.
+                let Ok(stream) = acceptor.accept(stream).await else {
+                    return;
+                };
+
+                let connection = TlsConnection {
+                    stream,
+                    local_addr,
+                    remote_addr,
+                };
+
+                let _ = sender.send(Ok(connection)).await;
+            });
+        }
+    });
+
+    Ok(ReceiverStream::new(receiver))
+}
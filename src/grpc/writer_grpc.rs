@@ -1,5 +1,6 @@
 use super::server::MyNoSqlServerWriterGrpcSerice;
-use crate::db_operations::UpdateStatistics;
+use crate::app::{ApiRole, ApiScope, OperationKind, OperationTimer};
+use crate::db_operations::{read::FieldsProjection, UpdateStatistics};
 use crate::db_sync::EventSource;
 use crate::http_server::controllers::ToSetExpirationTime;
 use crate::mynosqlserver_grpc::writer_server::Writer;
@@ -12,6 +13,7 @@ use tonic::Status;
 const OK_GRPC_RESPONSE: i32 = 0;
 const TABLE_NOT_FOUND_GRPC_RESPONSE: i32 = 1;
 const DB_ROW_NOT_FOUND_GRPC_RESPONSE: i32 = 2;
+const FIELD_IS_NOT_NUMERIC_GRPC_RESPONSE: i32 = 3;
 
 #[tonic::async_trait]
 impl Writer for MyNoSqlServerWriterGrpcSerice {
@@ -28,6 +30,26 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
         &self,
         request: tonic::Request<CreateTableIfNotExistsGrpcRequest>,
     ) -> Result<tonic::Response<()>, tonic::Status> {
+        let timer = OperationTimer::new(
+            &self.app,
+            OperationKind::Grpc,
+            "CreateTableIfNotExists",
+            super::api_keys::get_namespace_name(request.get_ref().name_space.as_deref()),
+            request.get_ref().table_name.as_str(),
+        );
+
+        super::api_keys::check_access(
+            &self.app,
+            &request,
+            ApiRole::Writer,
+            ApiScope::Table {
+                namespace: super::api_keys::get_namespace_name(
+                    request.get_ref().name_space.as_deref(),
+                ),
+                table_name: request.get_ref().table_name.as_str(),
+            },
+        )?;
+
         let request = request.into_inner();
 
         let db_namespace = self
@@ -65,6 +87,8 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
         .await
         .unwrap();
 
+        timer.succeeded();
+
         return Ok(tonic::Response::new(()));
     }
 
@@ -72,6 +96,26 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
         &self,
         request: tonic::Request<SetTableAttributesGrpcRequest>,
     ) -> Result<tonic::Response<()>, tonic::Status> {
+        let timer = OperationTimer::new(
+            &self.app,
+            OperationKind::Grpc,
+            "SetTableAttributes",
+            super::api_keys::get_namespace_name(request.get_ref().name_space.as_deref()),
+            request.get_ref().table_name.as_str(),
+        );
+
+        super::api_keys::check_access(
+            &self.app,
+            &request,
+            ApiRole::Writer,
+            ApiScope::Table {
+                namespace: super::api_keys::get_namespace_name(
+                    request.get_ref().name_space.as_deref(),
+                ),
+                table_name: request.get_ref().table_name.as_str(),
+            },
+        )?;
+
         let request = request.into_inner();
 
         let db_namespace = self
@@ -117,6 +161,8 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
         .await
         .unwrap();
 
+        timer.succeeded();
+
         return Ok(tonic::Response::new(()));
     }
 
@@ -124,6 +170,27 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
         &self,
         request: tonic::Request<GetEntitiesGrpcRequest>,
     ) -> Result<tonic::Response<Self::GetRowsStream>, tonic::Status> {
+        let mut timer = OperationTimer::new(
+            &self.app,
+            OperationKind::Grpc,
+            "GetRows",
+            super::api_keys::get_namespace_name(request.get_ref().name_space.as_deref()),
+            request.get_ref().table_name.as_str(),
+        )
+        .with_partition_key(request.get_ref().partition_key.as_deref());
+
+        super::api_keys::check_access(
+            &self.app,
+            &request,
+            ApiRole::Reader,
+            ApiScope::Table {
+                namespace: super::api_keys::get_namespace_name(
+                    request.get_ref().name_space.as_deref(),
+                ),
+                table_name: request.get_ref().table_name.as_str(),
+            },
+        )?;
+
         let request = request.into_inner();
 
         let db_namespace = self
@@ -193,17 +260,28 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
         .await
         .unwrap();
 
+        timer.set_rows(db_rows.len());
+
+        let fields = FieldsProjection::new(request.fields.iter().map(|itm| itm.as_str()));
+
         tokio::spawn(async move {
             for db_row in db_rows {
+                let content = match fields.as_ref() {
+                    Some(fields) => fields.project_row(&db_row),
+                    None => db_row.to_vec(),
+                };
+
                 let grpc = TableEntityTransportGrpcContract {
                     content_type: 0,
-                    content: db_row.to_vec(),
+                    content,
                 };
 
                 tx.send(Ok(grpc)).await.unwrap();
             }
         });
 
+        timer.succeeded();
+
         Ok(tonic::Response::new(Box::pin(
             tokio_stream::wrappers::ReceiverStream::new(rx),
         )))
@@ -213,6 +291,27 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
         &self,
         request: tonic::Request<GetEntityGrpcRequest>,
     ) -> Result<tonic::Response<GetDbRowGrpcResponse>, tonic::Status> {
+        let timer = OperationTimer::new(
+            &self.app,
+            OperationKind::Grpc,
+            "GetRow",
+            super::api_keys::get_namespace_name(request.get_ref().name_space.as_deref()),
+            request.get_ref().table_name.as_str(),
+        )
+        .with_partition_key(Some(request.get_ref().partition_key.as_str()));
+
+        super::api_keys::check_access(
+            &self.app,
+            &request,
+            ApiRole::Reader,
+            ApiScope::Table {
+                namespace: super::api_keys::get_namespace_name(
+                    request.get_ref().name_space.as_deref(),
+                ),
+                table_name: request.get_ref().table_name.as_str(),
+            },
+        )?;
+
         let request = request.into_inner();
 
         let db_namespace = self
@@ -289,6 +388,8 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
             entity: Some(entity),
         };
 
+        timer.succeeded();
+
         Ok(tonic::Response::new(result))
     }
 
@@ -296,7 +397,36 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
         &self,
         request: tonic::Request<TransactionPayloadGrpcRequest>,
     ) -> Result<tonic::Response<TransactionGrpcResponse>, tonic::Status> {
+        let timer = OperationTimer::new(
+            &self.app,
+            OperationKind::Grpc,
+            "PostTransactionActions",
+            super::api_keys::get_namespace_name(request.get_ref().name_space.as_deref()),
+            "",
+        )
+        .with_payload_size(
+            request
+                .get_ref()
+                .actions
+                .iter()
+                .map(|itm| itm.payload.len())
+                .sum(),
+        );
+
         println!("PostTransaction");
+        // The tables are inside the payloads of the actions - the key has to cover
+        // every one of the namespace.
+        super::api_keys::check_access(
+            &self.app,
+            &request,
+            ApiRole::Writer,
+            ApiScope::AllTables(super::api_keys::get_namespace_name(
+                request.get_ref().name_space.as_deref(),
+            )),
+        )?;
+
+        let audit_caller = super::audit::AuditCaller::new(&request);
+
         let request = request.into_inner();
 
         let db_namespace = self
@@ -334,15 +464,19 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
 
         if request.commit {
             let event_src = EventSource::as_client_request(self.app.as_ref());
-            crate::db_operations::transactions::commit(
+            let audit_records = crate::db_operations::transactions::commit(
                 self.app.as_ref(),
                 &transaction_id,
                 event_src,
                 crate::app::DEFAULT_PERSIST_PERIOD.get_sync_moment(),
-                now.date_time,
+                &now,
             )
             .await
             .unwrap();
+
+            for audit_record in audit_records {
+                super::audit::write_audit(&self.app, &audit_caller, audit_record).await;
+            }
         }
 
         let result = TransactionGrpcResponse {
@@ -351,6 +485,8 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
         };
 
         println!("PostTransaction Done");
+        timer.succeeded();
+
         return Ok(tonic::Response::new(result));
     }
 
@@ -358,13 +494,135 @@ impl Writer for MyNoSqlServerWriterGrpcSerice {
         &self,
         request: tonic::Request<CancelTransactionGrpcRequest>,
     ) -> Result<tonic::Response<()>, tonic::Status> {
+        let timer =
+            OperationTimer::new(&self.app, OperationKind::Grpc, "CancelTransaction", "", "");
+
         println!("CancelTransaction");
+        super::api_keys::check_access(&self.app, &request, ApiRole::Writer, ApiScope::Any)?;
+
         let request = request.into_inner();
 
         // No namespace here on purpose: the transaction id resolves to the
         // transaction, which already knows the namespace it was started in.
         self.app.active_transactions.remove(&request.id).await;
 
+        timer.succeeded();
+
         return Ok(tonic::Response::new(()));
     }
+
+    async fn increment(
+        &self,
+        request: tonic::Request<IncrementGrpcRequest>,
+    ) -> Result<tonic::Response<IncrementGrpcResponse>, tonic::Status> {
+        let timer = OperationTimer::new(
+            &self.app,
+            OperationKind::Grpc,
+            "Increment",
+            super::api_keys::get_namespace_name(request.get_ref().name_space.as_deref()),
+            request.get_ref().table_name.as_str(),
+        )
+        .with_partition_key(Some(request.get_ref().partition_key.as_str()));
+
+        super::api_keys::check_access(
+            &self.app,
+            &request,
+            ApiRole::Writer,
+            ApiScope::Table {
+                namespace: super::api_keys::get_namespace_name(
+                    request.get_ref().name_space.as_deref(),
+                ),
+                table_name: request.get_ref().table_name.as_str(),
+            },
+        )?;
+
+        let request = request.into_inner();
+
+        let db_namespace = self
+            .app
+            .get_or_create_namespace(request.name_space.as_deref())
+            .await
+            .map_err(|err| tonic::Status::internal(format!("{:?}", err)))?;
+
+        let db_table = crate::db_operations::read::table::get(
+            self.app.as_ref(),
+            &db_namespace,
+            &request.table_name,
+        )
+        .await;
+
+        let Ok(db_table) = db_table else {
+            let result = IncrementGrpcResponse {
+                response_code: TABLE_NOT_FOUND_GRPC_RESPONSE,
+                value: 0.0,
+                entity: None,
+            };
+
+            return Ok(tonic::Response::new(result));
+        };
+
+        let delta = f64_to_json_number(request.delta)
+            .ok_or_else(|| tonic::Status::invalid_argument("Delta is not a finite number"))?;
+
+        let initial_value =
+            f64_to_json_number(request.initial_value.unwrap_or(0.0)).ok_or_else(|| {
+                tonic::Status::invalid_argument("InitialValue is not a finite number")
+            })?;
+
+        let event_src = EventSource::as_client_request(self.app.as_ref());
+
+        let now = JsonTimeStamp::now();
+
+        let result = crate::db_operations::write::increment::execute(
+            self.app.as_ref(),
+            &db_namespace,
+            &db_table,
+            request.partition_key,
+            request.row_key,
+            request.field.as_str(),
+            &delta,
+            &initial_value,
+            event_src,
+            crate::app::DEFAULT_PERSIST_PERIOD.get_sync_moment(),
+            &now,
+        )
+        .await;
+
+        let result = match result {
+            Ok(result) => result,
+            Err(crate::db_operations::DbOperationError::FieldIsNotNumeric(_)) => {
+                let result = IncrementGrpcResponse {
+                    response_code: FIELD_IS_NOT_NUMERIC_GRPC_RESPONSE,
+                    value: 0.0,
+                    entity: None,
+                };
+
+                return Ok(tonic::Response::new(result));
+            }
+            Err(err) => return Err(tonic::Status::internal(format!("{:?}", err))),
+        };
+
+        let result = IncrementGrpcResponse {
+            response_code: OK_GRPC_RESPONSE,
+            value: result.value.as_f64().unwrap_or_default(),
+            entity: Some(TableEntityTransportGrpcContract {
+                content_type: 0,
+                content: result.db_row.to_vec(),
+            }),
+        };
+
+        timer.succeeded();
+
+        Ok(tonic::Response::new(result))
+    }
+}
+
+/// A whole `double` is taken as an integer, so counters driven over gRPC stay
+/// integers in the row just like the ones driven over HTTP.
+fn f64_to_json_number(value: f64) -> Option<serde_json::Number> {
+    if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        return Some(serde_json::Number::from(value as i64));
+    }
+
+    serde_json::Number::from_f64(value)
 }
@@ -0,0 +1,556 @@
+use std::sync::Arc;
+
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpServerMiddleware};
+
+use crate::app::{ApiRole, ApiScope, AppContext};
+use crate::db_operations::DbOperationError;
+
+/// Header carrying the API key. `Authorization: Bearer <key>` is taken as well, for
+/// the clients which can only set that one - a Prometheus scraper, for instance.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+const TABLE_NAME_QUERY_PARAM: &str = "tableName";
+
+/// What of the data an action touches, for the key to cover.
+#[derive(Clone, Copy)]
+enum RouteScope {
+    Any,
+    Namespace,
+    /// The table named by `tableName`. An action which has it elsewhere - in the
+    /// body - requires a key covering every table of the namespace.
+    Table,
+    Server,
+}
+
+/// The role every action requires, by method and route. A route is given without
+/// its `/api` prefix, so the deprecated routes - the same ones without it - are
+/// covered too. An `/api` route which is not here requires an admin key which
+/// covers everything: a new action is closed until it is given its role.
+///
+/// Not here on purpose: `IsAlive`, `Ready`, and the `DataReader` ones - the readers present
+/// the credentials of `ReaderAclFile` instead.
+const ACTION_ROLES: &[(&str, &str, ApiRole, RouteScope)] = &[
+    ("GET", "/metrics", ApiRole::Reader, RouteScope::Any),
+    ("GET", "/Status", ApiRole::Reader, RouteScope::Any),
+    ("GET", "/Connections", ApiRole::Reader, RouteScope::Any),
+    ("GET", "/Audit", ApiRole::Admin, RouteScope::Server),
+    ("GET", "/Logs", ApiRole::Reader, RouteScope::Server),
+    (
+        "GET",
+        "/Logs/FatalErrors",
+        ApiRole::Reader,
+        RouteScope::Server,
+    ),
+    ("GET", "/Logs/Process", ApiRole::Reader, RouteScope::Server),
+    ("GET", "/Logs/Table", ApiRole::Reader, RouteScope::Table),
+    ("GET", "/Settings", ApiRole::Reader, RouteScope::Any),
+    (
+        "GET",
+        "/Settings/Effective",
+        ApiRole::Reader,
+        RouteScope::Server,
+    ),
+    ("GET", "/Namespaces/List", ApiRole::Reader, RouteScope::Any),
+    (
+        "GET",
+        "/Tables/List",
+        ApiRole::Reader,
+        RouteScope::Namespace,
+    ),
+    (
+        "GET",
+        "/Tables/PartitionsCount",
+        ApiRole::Reader,
+        RouteScope::Table,
+    ),
+    (
+        "GET",
+        "/Tables/TableSize",
+        ApiRole::Reader,
+        RouteScope::Table,
+    ),
+    ("GET", "/Count", ApiRole::Reader, RouteScope::Table),
+    ("GET", "/Partitions", ApiRole::Reader, RouteScope::Table),
+    (
+        "GET",
+        "/Partitions/Count",
+        ApiRole::Reader,
+        RouteScope::Table,
+    ),
+    (
+        "GET",
+        "/Partitions/Details",
+        ApiRole::Reader,
+        RouteScope::Table,
+    ),
+    ("GET", "/Row", ApiRole::Reader, RouteScope::Table),
+    ("GET", "/Row/Download", ApiRole::Reader, RouteScope::Table),
+    (
+        "GET",
+        "/Rows/HighestRowAndBelow",
+        ApiRole::Reader,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Rows/SinglePartitionMultipleRows",
+        ApiRole::Reader,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Query/Aggregate",
+        ApiRole::Reader,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Multipart/First",
+        ApiRole::Reader,
+        RouteScope::Table,
+    ),
+    ("POST", "/Multipart/Next", ApiRole::Reader, RouteScope::Any),
+    ("POST", "/Ping", ApiRole::Writer, RouteScope::Any),
+    ("POST", "/Tables/Create", ApiRole::Writer, RouteScope::Table),
+    (
+        "POST",
+        "/Tables/CreateIfNotExists",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    ("PUT", "/Tables/Clean", ApiRole::Writer, RouteScope::Table),
+    (
+        "POST",
+        "/Transactions/Start",
+        ApiRole::Writer,
+        RouteScope::Namespace,
+    ),
+    (
+        "POST",
+        "/Transactions/Append",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Transactions/Commit",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Transactions/Cancel",
+        ApiRole::Writer,
+        RouteScope::Any,
+    ),
+    ("DELETE", "/Row", ApiRole::Writer, RouteScope::Table),
+    ("PATCH", "/Row", ApiRole::Writer, RouteScope::Table),
+    (
+        "DELETE",
+        "/Row/DeleteIf",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Row/DeleteWhere",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    ("POST", "/Row/Increment", ApiRole::Writer, RouteScope::Table),
+    ("POST", "/Row/Insert", ApiRole::Writer, RouteScope::Table),
+    (
+        "POST",
+        "/Row/InsertOrReplace",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Row/InsertOrReplaceIfNew",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Row/InsertOrReplaceWhere",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    ("PUT", "/Row/Replace", ApiRole::Writer, RouteScope::Table),
+    (
+        "PUT",
+        "/Row/ReplaceWhere",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "DELETE",
+        "/Rows/DeletePartitions",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/CleanAndBulkInsert",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/CleanAndBulkInsertByChunks",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/CleanAndBulkInsertByChunksCancel",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/CleanAndBulkInsertByChunksCommit",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    ("POST", "/Bulk/Delete", ApiRole::Writer, RouteScope::Table),
+    ("POST", "/Bulk/DeleteIf", ApiRole::Writer, RouteScope::Table),
+    (
+        "POST",
+        "/Bulk/DeleteWhere",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/InsertOrReplace",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/InsertOrReplaceIfNew",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/InsertOrReplaceIfNewByChunks",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/InsertOrReplaceIfNewByChunksCancel",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/InsertOrReplaceIfNewByChunksCommit",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/InsertOrReplaceWhere",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Bulk/ReplaceWhere",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/GarbageCollector/CleanAndKeepMaxPartitions",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/GarbageCollector/CleanAndKeepMaxRecords",
+        ApiRole::Writer,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Tables/UpdatePersist",
+        ApiRole::Admin,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Tables/UpdateCompressed",
+        ApiRole::Admin,
+        RouteScope::Table,
+    ),
+    (
+        "DELETE",
+        "/Tables/Delete",
+        ApiRole::Admin,
+        RouteScope::Table,
+    ),
+    (
+        "POST",
+        "/Tables/MigrateFrom",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+    ("DELETE", "/Namespaces", ApiRole::Admin, RouteScope::Server),
+    ("POST", "/Persist/Force", ApiRole::Admin, RouteScope::Server),
+    (
+        "GET",
+        "/Debug/GetRowStatistics",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+    (
+        "GET",
+        "/Debug/SlowOperations",
+        ApiRole::Reader,
+        RouteScope::Server,
+    ),
+    ("GET", "/Backup/List", ApiRole::Admin, RouteScope::Server),
+    (
+        "GET",
+        "/Backup/Download",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+    ("GET", "/Backup/Tables", ApiRole::Admin, RouteScope::Server),
+    (
+        "GET",
+        "/Backup/Partitions",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+    ("GET", "/Backup/Rows", ApiRole::Admin, RouteScope::Server),
+    (
+        "POST",
+        "/Backup/MakeBackup",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+    (
+        "POST",
+        "/Backup/RestoreFromBackup",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+    (
+        "POST",
+        "/Backup/RestoreFromZip",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+    (
+        "POST",
+        "/Backup/RestorePartition",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+    ("POST", "/Settings", ApiRole::Admin, RouteScope::Server),
+    (
+        "POST",
+        "/Settings/McpWrites",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+    (
+        "POST",
+        "/Settings/UiWrites",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+    (
+        "POST",
+        "/Settings/Reload",
+        ApiRole::Admin,
+        RouteScope::Server,
+    ),
+];
+
+/// Routes anybody may call with no key at all - the probes of an orchestrator.
+const OPEN_ROUTES: &[&str] = &["/IsAlive", "/Ready"];
+
+/// Routes of the readers, web socket included.
+const DATA_READER_ROUTES: &str = "/DataReader/";
+
+/// The MCP tools reach every namespace and include restoring backups, so the MCP
+/// endpoint takes an admin key which covers everything.
+const MCP_PATH: &str = "/mcp";
+
+/// Checks the API key of every request to an action, when `ApiKeysFile` is set.
+/// Goes in front of the controllers - of the TCP listener only: the unix socket
+/// is reachable by whoever may open the file, which is the check it has.
+pub struct ApiKeysMiddleware {
+    app: Arc<AppContext>,
+}
+
+impl ApiKeysMiddleware {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpServerMiddleware for ApiKeysMiddleware {
+    async fn handle_request(
+        &self,
+        ctx: &mut HttpContext,
+    ) -> Option<Result<HttpOkResult, HttpFailResult>> {
+        let api_keys = self.app.api_keys.as_ref()?;
+
+        let (role, route_scope) =
+            get_action_role(ctx.request.method.as_str(), ctx.request.get_uri().path())?;
+
+        let result = api_keys.authenticate(get_api_key(ctx)).and_then(|api_key| {
+            // The scope is checked on what the action is going to read: a parameter it
+            // could read another value of is refused.
+            super::get_query_param(ctx, super::NAMESPACE_HEADER)
+                .map_err(DbOperationError::NamespaceNameValidationError)?;
+
+            let table_name = super::get_query_param(ctx, TABLE_NAME_QUERY_PARAM)
+                .map_err(DbOperationError::TableNameValidationError)?;
+
+            let namespace =
+                super::get_request_namespace_name(ctx).unwrap_or(my_no_sql_sdk::DEFAULT_NAMESPACE);
+
+            let scope = match route_scope {
+                RouteScope::Any => ApiScope::Any,
+                RouteScope::Namespace => ApiScope::Namespace(namespace),
+                RouteScope::Table => match table_name.as_deref() {
+                    Some(table_name) => ApiScope::Table {
+                        namespace,
+                        table_name,
+                    },
+                    None => ApiScope::AllTables(namespace),
+                },
+                RouteScope::Server => ApiScope::Server,
+            };
+
+            crate::app::check_api_access(&api_key, role, &scope)
+        });
+
+        match result {
+            Ok(()) => None,
+            Err(err) => Some(Err(err.into())),
+        }
+    }
+}
+
+/// Role and scope the request requires. `None` - it is not an action which requires
+/// a key.
+fn get_action_role(method: &str, path: &str) -> Option<(ApiRole, RouteScope)> {
+    if path.eq_ignore_ascii_case(MCP_PATH) || starts_with_ignore_case(path, "/mcp/") {
+        return Some((ApiRole::Admin, RouteScope::Server));
+    }
+
+    let (is_api, route) = match strip_prefix_ignore_case(path, "/api") {
+        Some(route) if route.starts_with('/') => (true, route),
+        _ => (false, path),
+    };
+
+    let route = route.trim_end_matches('/');
+
+    if OPEN_ROUTES
+        .iter()
+        .any(|open_route| route.eq_ignore_ascii_case(open_route))
+        || starts_with_ignore_case(route, DATA_READER_ROUTES)
+    {
+        return None;
+    }
+
+    let action_role = ACTION_ROLES
+        .iter()
+        .find(|(action_method, action_route, _, _)| {
+            action_method.eq_ignore_ascii_case(method) && action_route.eq_ignore_ascii_case(route)
+        });
+
+    match action_role {
+        Some((_, _, role, scope)) => Some((*role, *scope)),
+        // Swagger, the UI files and the web socket of the readers.
+        None if !is_api => None,
+        None => Some((ApiRole::Admin, RouteScope::Server)),
+    }
+}
+
+pub fn get_api_key(ctx: &HttpContext) -> Option<&str> {
+    use my_http_server::HttpRequestHeaders;
+
+    let headers = ctx.request.get_headers();
+
+    if let Some(api_key) = headers
+        .try_get_case_insensitive_as_str(API_KEY_HEADER)
+        .ok()
+        .flatten()
+        .filter(|value| !value.is_empty())
+    {
+        return Some(api_key);
+    }
+
+    headers
+        .try_get_case_insensitive_as_str("authorization")
+        .ok()
+        .flatten()
+        .and_then(|value| strip_prefix_ignore_case(value, "Bearer "))
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+fn starts_with_ignore_case(value: &str, prefix: &str) -> bool {
+    strip_prefix_ignore_case(value, prefix).is_some()
+}
+
+fn strip_prefix_ignore_case<'s>(value: &'s str, prefix: &str) -> Option<&'s str> {
+    if value.len() < prefix.len() || !value.is_char_boundary(prefix.len()) {
+        return None;
+    }
+
+    let (head, tail) = value.split_at(prefix.len());
+
+    if head.eq_ignore_ascii_case(prefix) {
+        Some(tail)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_role(method: &str, path: &str) -> Option<ApiRole> {
+        get_action_role(method, path).map(|(role, _)| role)
+    }
+
+    #[test]
+    fn test_routes_are_matched_with_and_without_api_prefix() {
+        assert_eq!(
+            Some(ApiRole::Admin),
+            get_role("DELETE", "/api/Tables/Delete")
+        );
+        assert_eq!(Some(ApiRole::Admin), get_role("DELETE", "/Tables/Delete"));
+        assert_eq!(Some(ApiRole::Writer), get_role("put", "/api/tables/clean"));
+
+        assert_eq!(Some(ApiRole::Reader), get_role("GET", "/api/Row"));
+        assert_eq!(Some(ApiRole::Writer), get_role("DELETE", "/api/Row"));
+    }
+
+    #[test]
+    fn test_unknown_api_routes_are_closed_and_the_rest_is_open() {
+        assert_eq!(Some(ApiRole::Admin), get_role("POST", "/api/Something/New"));
+        assert_eq!(Some(ApiRole::Admin), get_role("POST", "/mcp"));
+
+        assert_eq!(None, get_role("GET", "/api/IsAlive"));
+        assert_eq!(None, get_role("GET", "/api/Ready"));
+        assert_eq!(None, get_role("POST", "/api/DataReader/Greeting"));
+        assert_eq!(None, get_role("GET", "/api/DataReader/WebSocket"));
+        assert_eq!(None, get_role("GET", "/index.html"));
+        assert_eq!(None, get_role("GET", "/swagger/index.html"));
+    }
+}
@@ -0,0 +1,62 @@
+use my_http_server::HttpContext;
+
+use crate::{
+    app::AppContext,
+    audit_log::{AuditActorKind, AuditRecord},
+};
+
+/// Header the UI marks its requests with, so the audit log can tell them from the
+/// ones of the writers.
+pub const CLIENT_HEADER: &str = "x-client";
+
+const UI_CLIENT: &str = "ui";
+
+/// Records an operation of an HTTP request, with whoever the request says it comes
+/// from: the key it presents, the writer session it replays, or the UI.
+pub async fn write_audit(app: &AppContext, ctx: &HttpContext, record: AuditRecord) {
+    if app.audit_log.is_none() {
+        return;
+    }
+
+    let (actor, actor_name) = get_actor(app, ctx).await;
+
+    let record = record
+        .with_actor(actor, actor_name)
+        .with_ip(ctx.request.get_ip().get_real_ip().to_string());
+
+    app.write_audit(record).await;
+}
+
+async fn get_actor(app: &AppContext, ctx: &HttpContext) -> (AuditActorKind, Option<String>) {
+    use my_http_server::HttpRequestHeaders;
+
+    if let Some(api_keys) = app.api_keys.as_ref() {
+        if let Ok(api_key) = api_keys.authenticate(super::api_keys_middleware::get_api_key(ctx)) {
+            return (AuditActorKind::ApiKey, Some(api_key.name.clone()));
+        }
+    }
+
+    if let Some(session) = super::statistics_middleware::get_session(ctx) {
+        let name = match app.http_writers.get_name(session).await {
+            Some(name) => format!("{} ({})", name, session),
+            None => session.to_string(),
+        };
+
+        return (AuditActorKind::Writer, Some(name));
+    }
+
+    let is_ui = ctx
+        .request
+        .get_headers()
+        .try_get_case_insensitive_as_str(CLIENT_HEADER)
+        .ok()
+        .flatten()
+        .map(|value| value.eq_ignore_ascii_case(UI_CLIENT))
+        .unwrap_or(false);
+
+    if is_ui {
+        return (AuditActorKind::Ui, None);
+    }
+
+    (AuditActorKind::Anonymous, None)
+}
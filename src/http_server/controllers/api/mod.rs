@@ -3,3 +3,5 @@ mod models;
 pub use is_alive_action::*;
 mod ping_action;
 pub use ping_action::*;
+mod ready_action;
+pub use ready_action::*;
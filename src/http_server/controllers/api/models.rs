@@ -8,3 +8,59 @@ pub struct IsAliveResponse {
     pub version: String,
     pub env_info: Option<String>,
 }
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct SkippedPartitionModel {
+    #[serde(rename = "partitionKey")]
+    pub partition_key: String,
+    pub error: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct ReadyTableModel {
+    #[serde(rename = "tableName")]
+    pub table_name: String,
+    pub loaded: bool,
+    #[serde(rename = "partitionsTotal")]
+    pub partitions_total: usize,
+    #[serde(rename = "partitionsLoaded")]
+    pub partitions_loaded: usize,
+    #[serde(rename = "bytesTotal")]
+    pub bytes_total: usize,
+    #[serde(rename = "bytesLoaded")]
+    pub bytes_loaded: usize,
+    #[serde(rename = "skippedPartitions")]
+    pub skipped_partitions: Vec<SkippedPartitionModel>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct ReadyNamespaceModel {
+    pub namespace: String,
+    /// pending, reading, loading or loaded.
+    pub status: String,
+    #[serde(rename = "bytesTotal")]
+    pub bytes_total: usize,
+    #[serde(rename = "bytesLoaded")]
+    pub bytes_loaded: usize,
+    pub tables: Vec<ReadyTableModel>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct ReadyResponse {
+    pub ready: bool,
+    /// Share of the persisted data loaded, 0 to 1.
+    pub progress: f64,
+    #[serde(rename = "etaSeconds")]
+    pub eta_seconds: Option<u64>,
+    #[serde(rename = "initializingSeconds")]
+    pub initializing_seconds: i64,
+    #[serde(rename = "tablesTotal")]
+    pub tables_total: usize,
+    #[serde(rename = "tablesLoaded")]
+    pub tables_loaded: usize,
+    #[serde(rename = "currentTable")]
+    pub current_table: Option<String>,
+    #[serde(rename = "skippedPartitions")]
+    pub skipped_partitions: usize,
+    pub namespaces: Vec<ReadyNamespaceModel>,
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use my_http_server::{
+    macros::http_route, HttpContext, HttpFailResult, HttpOkResult, HttpOutput, WebContentType,
+};
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{app::AppContext, operations::init::InitStateInner};
+
+use super::models::{ReadyNamespaceModel, ReadyResponse, ReadyTableModel, SkippedPartitionModel};
+
+#[http_route(
+    method: "GET",
+    route: "/api/Ready",
+    controller: "Monitoring",
+    description: "Readiness check: 503 until the tables are loaded, with how far the load got",
+    summary: "Returns the progress of the load of the tables on start up",
+    result:[
+        {status_code: 200, description: "Tables are loaded", model: "ReadyResponse"},
+        {status_code: 503, description: "Tables are being loaded", model: "ReadyResponse"},
+    ]
+)]
+pub struct ReadyAction {
+    app: Arc<AppContext>,
+}
+
+impl ReadyAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &ReadyAction,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let ready = action.app.states.is_initialized();
+
+    let response = create_response(
+        &action.app.init_state.get(),
+        ready,
+        action.app.created,
+        DateTimeAsMicroseconds::now(),
+    );
+
+    // Not an error of the action: it did answer, it is the server which is not ready.
+    HttpOutput::Content {
+        status_code: if ready { 200 } else { 503 },
+        headers: WebContentType::Json.into(),
+        content: serde_json::to_vec(&response).unwrap(),
+    }
+    .into_ok_result(false)
+    .into()
+}
+
+fn create_response(
+    init_state: &InitStateInner,
+    ready: bool,
+    created: DateTimeAsMicroseconds,
+    now: DateTimeAsMicroseconds,
+) -> ReadyResponse {
+    let namespaces = init_state
+        .namespaces
+        .iter()
+        .map(|namespace| ReadyNamespaceModel {
+            namespace: namespace.namespace.to_string(),
+            status: namespace.status.as_str().to_string(),
+            bytes_total: namespace.get_bytes_total(),
+            bytes_loaded: namespace.get_bytes_loaded(),
+            tables: namespace
+                .tables
+                .iter()
+                .map(|table| ReadyTableModel {
+                    table_name: table.table_name.to_string(),
+                    loaded: table.loaded,
+                    partitions_total: table.partitions_total,
+                    partitions_loaded: table.partitions_loaded,
+                    bytes_total: table.bytes_total,
+                    bytes_loaded: table.bytes_loaded,
+                    skipped_partitions: table
+                        .skipped_partitions
+                        .iter()
+                        .map(|itm| SkippedPartitionModel {
+                            partition_key: itm.partition_key.to_string(),
+                            error: itm.error.to_string(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect();
+
+    let initializing_seconds = match init_state.finished {
+        Some(finished) => finished.seconds_before(created),
+        None => now.seconds_before(created),
+    };
+
+    ReadyResponse {
+        ready,
+        progress: init_state.get_progress(),
+        eta_seconds: init_state.get_eta(now).map(|itm| itm.as_secs()),
+        initializing_seconds,
+        tables_total: init_state.total_tables,
+        tables_loaded: init_state.loaded,
+        current_table: init_state.current_table.clone(),
+        skipped_partitions: init_state.get_skipped_partitions_amount(),
+        namespaces,
+    }
+}
@@ -0,0 +1,84 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use std::sync::Arc;
+
+use crate::{app::AppContext, audit_log::AuditFilter};
+
+use super::models::{AuditHttpResponse, GetAuditInputModel, DEFAULT_AUDIT_LIMIT};
+
+#[http_route(
+    method: "GET",
+    route: "/api/Audit",
+    input_data: "GetAuditInputModel",
+    controller: "Audit",
+    description: "Audit log",
+    summary: "Returns destructive and administrative operations, the newest first",
+    result:[
+        {status_code: 200, description: "Audit records", model: "AuditHttpResponse"},
+        {status_code: 404, description: "Audit log is not enabled"},
+    ]
+)]
+pub struct GetAuditAction {
+    app: Arc<AppContext>,
+}
+
+impl GetAuditAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetAuditAction,
+    input_data: GetAuditInputModel,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let Some(audit_log) = action.app.audit_log.as_ref() else {
+        return Err(HttpFailResult::as_not_found(
+            "Audit log is not enabled. Set AuditLog in the settings".to_string(),
+            false,
+        ));
+    };
+
+    let filter = AuditFilter {
+        actor: input_data.actor,
+        operation: input_data.operation,
+        namespace: input_data.namespace,
+        table_name: input_data.table_name,
+        from: parse_moment("from", input_data.from.as_deref())?,
+        to: parse_moment("to", input_data.to.as_deref())?,
+    };
+
+    let records = audit_log
+        .read(
+            &filter,
+            input_data.skip.unwrap_or(0),
+            input_data.limit.unwrap_or(DEFAULT_AUDIT_LIMIT),
+        )
+        .await
+        .map_err(|err| HttpFailResult::as_fatal_error(format!("{}", err)))?;
+
+    let response = AuditHttpResponse {
+        records: records.into_iter().map(|itm| itm.into()).collect(),
+    };
+
+    HttpOutput::as_json(response).into_ok_result(false).into()
+}
+
+fn parse_moment(
+    name: &str,
+    value: Option<&str>,
+) -> Result<Option<DateTimeAsMicroseconds>, HttpFailResult> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    match DateTimeAsMicroseconds::from_str(value) {
+        Some(moment) => Ok(Some(moment)),
+        None => Err(HttpFailResult::as_validation_error(format!(
+            "'{}' is not a valid value of {}",
+            value, name
+        ))),
+    }
+}
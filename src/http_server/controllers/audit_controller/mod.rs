@@ -0,0 +1,3 @@
+mod get_action;
+mod models;
+pub use get_action::GetAuditAction;
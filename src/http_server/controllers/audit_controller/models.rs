@@ -0,0 +1,77 @@
+use my_http_server::macros::*;
+use serde::{Deserialize, Serialize};
+
+use crate::audit_log::AuditRecord;
+
+pub const DEFAULT_AUDIT_LIMIT: usize = 100;
+
+#[derive(MyHttpInput)]
+pub struct GetAuditInputModel {
+    #[http_query(name = "actor"; description = "Kind of the actor (writer, apiKey, mcp, ui, anonymous) or its name")]
+    pub actor: Option<String>,
+
+    #[http_query(name = "operation"; description = "Operation, e.g. DeleteTable")]
+    pub operation: Option<String>,
+
+    #[http_query(name = "namespace"; description = "Namespace the operation worked in")]
+    pub namespace: Option<String>,
+
+    #[http_query(name = "tableName"; description = "Table the operation worked on")]
+    pub table_name: Option<String>,
+
+    #[http_query(name = "from"; description = "Records from this moment on")]
+    pub from: Option<String>,
+
+    #[http_query(name = "to"; description = "Records up to this moment")]
+    pub to: Option<String>,
+
+    #[http_query(name = "skip"; description = "Skip amount of records before start collecting them")]
+    pub skip: Option<usize>,
+
+    #[http_query(name = "limit"; description = "Limit amount of records. Default is 100")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct AuditRecordHttpModel {
+    pub timestamp: String,
+    pub actor: String,
+    #[serde(rename = "actorName")]
+    pub actor_name: Option<String>,
+    pub ip: Option<String>,
+    pub operation: String,
+    pub namespace: Option<String>,
+    #[serde(rename = "tableName")]
+    pub table_name: Option<String>,
+    #[serde(rename = "partitionKeys")]
+    pub partition_keys: Vec<String>,
+    pub rows: Option<usize>,
+    pub details: Option<String>,
+}
+
+impl From<AuditRecord> for AuditRecordHttpModel {
+    fn from(src: AuditRecord) -> Self {
+        let actor = serde_json::to_value(&src.actor)
+            .ok()
+            .and_then(|value| value.as_str().map(|itm| itm.to_string()))
+            .unwrap_or_default();
+
+        Self {
+            timestamp: src.timestamp,
+            actor,
+            actor_name: src.actor_name,
+            ip: src.ip,
+            operation: src.operation,
+            namespace: src.namespace,
+            table_name: src.table_name,
+            partition_keys: src.partition_keys,
+            rows: src.rows,
+            details: src.details,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct AuditHttpResponse {
+    pub records: Vec<AuditRecordHttpModel>,
+}
@@ -3,6 +3,7 @@ use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput, WebC
 use std::sync::Arc;
 
 use crate::app::AppContext;
+use crate::db_operations::read::FieldsProjection;
 
 use super::inspect_models::SnapshotPartitionContract;
 
@@ -35,6 +36,8 @@ async fn handle_request(
 ) -> Result<HttpOkResult, HttpFailResult> {
     let db_namespace = crate::http_server::get_request_namespace_existing(&action.app, ctx).await?;
 
+    let fields = FieldsProjection::parse(input_data.fields.as_deref());
+
     match crate::operations::backup::read_snapshot_partition_rows(
         &action.app,
         &db_namespace,
@@ -47,7 +50,10 @@ async fn handle_request(
         Ok(content) => HttpOutput::Content {
             headers: WebContentType::Json.into(),
             status_code: 200,
-            content,
+            content: match fields {
+                Some(fields) => fields.project_array(content),
+                None => content,
+            },
         }
         .into_ok_result(true)
         .into(),
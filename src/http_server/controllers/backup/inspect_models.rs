@@ -31,4 +31,7 @@ pub struct SnapshotPartitionContract {
 
     #[http_query(name: "partitionKey"; description: "Partition key inside the snapshot table")]
     pub partition_key: String,
+
+    #[http_query(name: "fields"; description: "Comma-separated top-level or dotted-path fields to return instead of the whole row. PartitionKey, RowKey and TimeStamp are always returned")]
+    pub fields: Option<String>,
 }
@@ -3,6 +3,7 @@ use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use std::sync::Arc;
 
 use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
 
 #[http_route(
     method: "POST",
@@ -47,7 +48,19 @@ async fn handle_request(
     .await;
 
     match restore_result {
-        Ok(_) => HttpOutput::Empty.into_ok_result(true).into(),
+        Ok(_) => {
+            let audit_record = AuditRecord::new("RestoreFromBackup")
+                .with_namespace(db_namespace.name.as_str())
+                .with_details(format!(
+                    "File: {}, Table: {}, CleanTable: {}",
+                    input_data.file_name,
+                    input_data.get_table_name().unwrap_or("*"),
+                    input_data.clean_table
+                ));
+
+            crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+            HttpOutput::Empty.into_ok_result(true).into()
+        }
         Err(err) => Err(HttpFailResult::as_fatal_error(format!("{:?}", err))),
     }
 }
@@ -4,6 +4,7 @@ use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use std::sync::Arc;
 
 use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
 
 #[http_route(
     method: "POST",
@@ -45,7 +46,18 @@ async fn handle_request(
     .await;
 
     match restore_result {
-        Ok(_) => HttpOutput::Empty.into_ok_result(true).into(),
+        Ok(_) => {
+            let audit_record = AuditRecord::new("RestoreFromZip")
+                .with_namespace(db_namespace.name.as_str())
+                .with_details(format!(
+                    "Table: {}, CleanTable: {}",
+                    table_name.as_deref().unwrap_or("*"),
+                    input_data.clean_table
+                ));
+
+            crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+            HttpOutput::Empty.into_ok_result(true).into()
+        }
         Err(err) => Err(HttpFailResult::as_fatal_error(format!("{:?}", err))),
     }
 }
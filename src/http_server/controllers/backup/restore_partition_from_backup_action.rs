@@ -3,6 +3,7 @@ use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use std::sync::Arc;
 
 use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
 
 #[http_route(
     method: "POST",
@@ -42,7 +43,15 @@ async fn handle_request(
     .await;
 
     match restore_result {
-        Ok(_) => HttpOutput::Empty.into_ok_result(true).into(),
+        Ok(_) => {
+            let audit_record = AuditRecord::new("RestorePartitionFromBackup")
+                .with_table(db_namespace.name.as_str(), input_data.table_name.as_str())
+                .with_partition_keys(std::iter::once(input_data.partition_key.clone()))
+                .with_details(format!("File: {}", input_data.file_name));
+
+            crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+            HttpOutput::Empty.into_ok_result(true).into()
+        }
         Err(err) => Err(HttpFailResult::as_fatal_error(err)),
     }
 }
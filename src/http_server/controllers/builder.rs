@@ -8,6 +8,7 @@ pub fn build(app: &Arc<AppContext>) -> ControllersMiddleware {
     let mut result = ControllersMiddleware::new(None, None);
 
     result.register_get_action(Arc::new(super::api::IsAliveAction));
+    result.register_get_action(Arc::new(super::api::ReadyAction::new(app.clone())));
     result.register_post_action(Arc::new(super::api::PingAction::new(app.clone())));
 
     result.register_get_action(Arc::new(super::tables_controller::GetListAction::new(
@@ -109,6 +110,12 @@ pub fn build(app: &Arc<AppContext>) -> ControllersMiddleware {
     result.register_post_action(Arc::new(
         super::ui_settings_controller::SetUiWritesAction::new(app.clone()),
     ));
+    result.register_get_action(Arc::new(
+        super::ui_settings_controller::GetEffectiveSettingsAction::new(app.clone()),
+    ));
+    result.register_post_action(Arc::new(
+        super::ui_settings_controller::ReloadSettingsAction::new(app.clone()),
+    ));
 
     result.register_get_action(Arc::new(super::status_controller::StatusAction::new(
         app.clone(),
@@ -118,10 +125,26 @@ pub fn build(app: &Arc<AppContext>) -> ControllersMiddleware {
         super::connections_controller::GetConnectionsAction::new(app.clone()),
     ));
 
+    result.register_get_action(Arc::new(super::audit_controller::GetAuditAction::new(
+        app.clone(),
+    )));
+
     result.register_post_action(Arc::new(super::bulk::BulkDeleteAction::new(app.clone())));
 
     result.register_post_action(Arc::new(super::bulk::BulkDeleteIfAction::new(app.clone())));
 
+    result.register_post_action(Arc::new(super::bulk::BulkDeleteWhereAction::new(
+        app.clone(),
+    )));
+
+    result.register_post_action(Arc::new(super::bulk::BulkInsertOrReplaceWhereAction::new(
+        app.clone(),
+    )));
+
+    result.register_post_action(Arc::new(super::bulk::BulkReplaceWhereAction::new(
+        app.clone(),
+    )));
+
     result.register_post_action(Arc::new(super::bulk::CleanAndBulkInsertAction::new(
         app.clone(),
     )));
@@ -194,6 +217,14 @@ pub fn build(app: &Arc<AppContext>) -> ControllersMiddleware {
         app.clone(),
     )));
 
+    result.register_patch_action(Arc::new(super::row_controller::PatchRowAction::new(
+        app.clone(),
+    )));
+
+    result.register_post_action(Arc::new(super::row_controller::IncrementAction::new(
+        app.clone(),
+    )));
+
     result.register_delete_action(Arc::new(super::row_controller::DeleteRowAction::new(
         app.clone(),
     )));
@@ -202,6 +233,18 @@ pub fn build(app: &Arc<AppContext>) -> ControllersMiddleware {
         app.clone(),
     )));
 
+    result.register_post_action(Arc::new(
+        super::row_controller::InsertOrReplaceWhereAction::new(app.clone()),
+    ));
+
+    result.register_put_action(Arc::new(super::row_controller::ReplaceWhereAction::new(
+        app.clone(),
+    )));
+
+    result.register_post_action(Arc::new(super::row_controller::DeleteWhereAction::new(
+        app.clone(),
+    )));
+
     result.register_get_action(Arc::new(
         super::rows_controller::GetHighestRowAndBelowAction::new(app.clone()),
     ));
@@ -214,31 +257,27 @@ pub fn build(app: &Arc<AppContext>) -> ControllersMiddleware {
         super::rows_controller::DeletePartitionsAction::new(app.clone()),
     ));
 
-    /*
-        result.register_get_action(Arc::new(super::logs_controller::GetFatalErrorsAction::new(
-            app.clone(),
-        )));
-
-        result.register_get_action(Arc::new(super::logs_controller::GetLogsByTableAction::new(
-            app.clone(),
-        )));
-
-        result.register_get_action(Arc::new(super::logs_controller::SelectTableAction::new(
-            app.clone(),
-        )));
-
+    result.register_post_action(Arc::new(super::query_controller::AggregateAction::new(
+        app.clone(),
+    )));
 
-     result.register_get_action(Arc::new(
-         super::logs_controller::GetLogsByProcessAction::new(app.clone()),
-     ));
+    result.register_get_action(Arc::new(super::logs_controller::GetLogsAction::new(
+        app.clone(),
+    )));
 
-     result.register_get_action(Arc::new(super::logs_controller::SelectProcessAction::new()));
+    result.register_get_action(Arc::new(super::logs_controller::GetFatalErrorsAction::new(
+        app.clone(),
+    )));
 
-     result.register_get_action(Arc::new(super::logs_controller::HomeAction::new(
-         app.clone(),
-     )));
+    result.register_get_action(Arc::new(super::logs_controller::GetLogsByTableAction::new(
+        app.clone(),
+    )));
 
+    result.register_get_action(Arc::new(
+        super::logs_controller::GetLogsByProcessAction::new(app.clone()),
+    ));
 
+    /*
      result.register_get_action(Arc::new(super::home_controller::IndexAction::new(
          app.clone(),
      )));
@@ -259,6 +298,14 @@ pub fn build(app: &Arc<AppContext>) -> ControllersMiddleware {
         super::data_reader_controller::GetChangesAction::new(app.clone()),
     ));
 
+    result.register_post_action(Arc::new(
+        super::data_reader_controller::UpdatePartitionsAction::new(app.clone()),
+    ));
+
+    result.register_get_action(Arc::new(super::data_reader_controller::StreamAction::new(
+        app.clone(),
+    )));
+
     result.register_post_action(Arc::new(super::data_reader_controller::PingAction::new(
         app.clone(),
     )));
@@ -269,6 +316,10 @@ pub fn build(app: &Arc<AppContext>) -> ControllersMiddleware {
         super::debug_controller::GetRowStatisticsAction::new(app.clone()),
     ));
 
+    result.register_get_action(Arc::new(
+        super::debug_controller::GetSlowOperationsAction::new(app.clone()),
+    ));
+
     result.register_post_action(Arc::new(force_persist_action));
 
     // Partitions Controller
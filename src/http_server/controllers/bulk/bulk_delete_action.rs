@@ -5,6 +5,7 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
 use crate::db_sync::EventSource;
 
 use super::models::BulkDeleteInputContract;
@@ -51,6 +52,11 @@ async fn handle_request(
     let rows_to_delete: BTreeMap<String, Vec<String>> =
         serde_json::from_slice(input_data.body.as_slice()).unwrap();
 
+    let audit_record = AuditRecord::new("BulkDelete")
+        .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+        .with_partition_keys(rows_to_delete.keys().cloned())
+        .with_rows(rows_to_delete.values().map(|row_keys| row_keys.len()).sum());
+
     let now = DateTimeAsMicroseconds::now();
 
     crate::db_operations::write::bulk_delete(
@@ -64,5 +70,7 @@ async fn handle_request(
     )
     .await?;
 
+    crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+
     HttpOutput::Empty.into_ok_result(true).into()
 }
@@ -5,6 +5,7 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
 use crate::db_operations::write::bulk_delete_if::RowToDeleteIf;
 use crate::db_sync::EventSource;
 
@@ -66,6 +67,15 @@ async fn handle_request(
     )
     .await?;
 
+    crate::http_server::write_audit(
+        &action.app,
+        ctx,
+        AuditRecord::new("BulkDeleteIf")
+            .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+            .with_rows(result.deleted),
+    )
+    .await;
+
     let response = BulkDeleteIfResponseContract {
         deleted: result.deleted,
         skipped: result
@@ -0,0 +1,87 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use std::sync::Arc;
+
+use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
+use crate::db_sync::EventSource;
+use crate::http_server::controllers::row_controller::models::DeleteWhereBody;
+
+use super::models::{BulkDeleteWhereInputContract, BulkDeleteWhereResponseContract};
+
+#[http_route(
+    method: "POST",
+    route: "/api/Bulk/DeleteWhere",
+    input_data: "BulkDeleteWhereInputContract",
+    summary: "Bulk delete of the rows which meet their conditions",
+    description: "Deletes a row of the batch only when the stored row meets its conditions. Rows which do not meet them - and rows which are not there - are left alone and reported back in the response, the rest of the batch is still deleted",
+    controller: "Bulk",
+    result:[
+        {status_code: 200, description: "Amount of deleted rows and the ones which were left in place", model: "BulkDeleteWhereResponseContract"},
+        {status_code: 400, description: "Table not found, or the body is not valid"},
+    ]
+)]
+pub struct BulkDeleteWhereAction {
+    app: Arc<AppContext>,
+}
+
+impl BulkDeleteWhereAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &BulkDeleteWhereAction,
+    input_data: BulkDeleteWhereInputContract,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let db_namespace = crate::http_server::get_request_namespace_existing(&action.app, ctx).await?;
+
+    let db_table = crate::db_operations::read::table::get(
+        action.app.as_ref(),
+        &db_namespace,
+        input_data.table_name.as_str(),
+    )
+    .await?;
+
+    let body: Vec<DeleteWhereBody> = match serde_json::from_slice(input_data.body.as_slice()) {
+        Ok(body) => body,
+        Err(err) => {
+            return Err(HttpFailResult::as_validation_error(format!(
+                "Invalid body: {}",
+                err
+            )));
+        }
+    };
+
+    let event_src = EventSource::as_client_request(action.app.as_ref());
+
+    let result = crate::db_operations::write::write_where::delete(
+        action.app.as_ref(),
+        &db_namespace,
+        &db_table,
+        body.into_iter().map(|itm| itm.into()).collect(),
+        event_src,
+        input_data.sync_period.get_sync_moment(),
+        DateTimeAsMicroseconds::now(),
+    )
+    .await?;
+
+    crate::http_server::write_audit(
+        &action.app,
+        ctx,
+        AuditRecord::new("BulkDeleteWhere")
+            .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+            .with_rows(result.rows.len()),
+    )
+    .await;
+
+    let response = BulkDeleteWhereResponseContract {
+        deleted: result.rows.len(),
+        skipped: result.skipped.into_iter().map(|itm| itm.into()).collect(),
+    };
+
+    HttpOutput::as_json(response).into_ok_result(false).into()
+}
@@ -0,0 +1,88 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
+
+use crate::app::AppContext;
+use crate::db_operations::write::write_where::MissingRow;
+use crate::db_sync::EventSource;
+use crate::http_server::controllers::row_controller::models::WriteWhereBody;
+
+use super::models::{BulkWriteWhereInputContract, BulkWriteWhereResponseContract};
+
+#[http_route(
+    method: "POST",
+    route: "/api/Bulk/InsertOrReplaceWhere",
+    input_data: "BulkWriteWhereInputContract",
+    summary: "Bulk insert or replace of the rows whose stored version meets their conditions",
+    description: "Writes an entity of the batch only when its stored row meets its conditions; a row which is not there yet is checked as a row with no fields and inserted when they pass. Rows which do not meet them are left as they are and reported back in the response, the rest of the batch is still written",
+    controller: "Bulk",
+    result:[
+        {status_code: 200, description: "Amount of written rows and the ones which were left as they are", model: "BulkWriteWhereResponseContract"},
+        {status_code: 400, description: "Table not found, or an entity of the batch is not valid"},
+    ]
+)]
+pub struct BulkInsertOrReplaceWhereAction {
+    app: Arc<AppContext>,
+}
+
+impl BulkInsertOrReplaceWhereAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &BulkInsertOrReplaceWhereAction,
+    input_data: BulkWriteWhereInputContract,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let db_namespace = crate::http_server::get_request_namespace(&action.app, ctx).await?;
+
+    let db_table = crate::db_operations::read::table::get(
+        action.app.as_ref(),
+        &db_namespace,
+        input_data.table_name.as_str(),
+    )
+    .await?;
+
+    let body: Vec<WriteWhereBody> = match serde_json::from_slice(input_data.body.as_slice()) {
+        Ok(body) => body,
+        Err(err) => {
+            return Err(HttpFailResult::as_validation_error(format!(
+                "Invalid body: {}",
+                err
+            )));
+        }
+    };
+
+    let now = JsonTimeStamp::now();
+
+    let mut rows = Vec::with_capacity(body.len());
+
+    for itm in body {
+        rows.push(itm.into_row_to_write(&now)?);
+    }
+
+    let event_src = EventSource::as_client_request(action.app.as_ref());
+
+    let result = crate::db_operations::write::write_where::write(
+        action.app.as_ref(),
+        &db_namespace,
+        &db_table,
+        rows,
+        MissingRow::Insert,
+        event_src,
+        input_data.sync_period.get_sync_moment(),
+        now.date_time,
+    )
+    .await?;
+
+    let response = BulkWriteWhereResponseContract {
+        written: result.rows.len(),
+        skipped: result.skipped.into_iter().map(|itm| itm.into()).collect(),
+    };
+
+    HttpOutput::as_json(response).into_ok_result(false).into()
+}
@@ -4,6 +4,7 @@ use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
 use std::sync::Arc;
 
 use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
 use crate::db_sync::EventSource;
 
 use super::models::CleanAndBulkInsertInputContract;
@@ -56,6 +57,13 @@ async fn handle_request(
             &now,
         )?;
 
+    let rows = rows_by_partition.iter().map(|(_, rows)| rows.len()).sum();
+
+    let audit_record = AuditRecord::new("CleanAndBulkInsert")
+        .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+        .with_partition_keys(input_data.partition_key.iter().cloned())
+        .with_rows(rows);
+
     match input_data.partition_key {
         Some(partition_key) => {
             crate::db_operations::write::clean_partition_and_bulk_insert(
@@ -84,5 +92,8 @@ async fn handle_request(
         }
     }
 
+    crate::app::report_rows(rows);
+    crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+
     return HttpOutput::Empty.into_ok_result(true).into();
 }
@@ -4,6 +4,7 @@ use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 use std::sync::Arc;
 
 use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
 use crate::db_sync::EventSource;
 
 use super::models::BulkProcessInputContract;
@@ -56,5 +57,14 @@ async fn handle_request(
     )
     .await?;
 
+    crate::http_server::write_audit(
+        &action.app,
+        ctx,
+        AuditRecord::new("CleanAndBulkInsertByChunks")
+            .with_namespace(db_namespace.name.as_str())
+            .with_details(format!("ProcessId: {}", input_data.process_id)),
+    )
+    .await;
+
     HttpOutput::Empty.into_ok_result(true).into()
 }
@@ -57,6 +57,8 @@ async fn handle_request(
             &now,
         )?;
 
+    let rows = rows_by_partition.iter().map(|(_, rows)| rows.len()).sum();
+
     crate::db_operations::write::bulk_insert_or_update::execute(
         action.app.as_ref(),
         &db_namespace,
@@ -68,5 +70,7 @@ async fn handle_request(
     )
     .await?;
 
+    crate::app::report_rows(rows);
+
     HttpOutput::Empty.into_ok_result(true).into()
 }
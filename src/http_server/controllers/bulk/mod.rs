@@ -1,6 +1,9 @@
 mod bulk_delete_action;
 mod bulk_delete_if_action;
+mod bulk_delete_where_action;
 mod bulk_insert_or_replace_if_new_action;
+mod bulk_insert_or_replace_where_action;
+mod bulk_replace_where_action;
 mod clean_and_bulk_insert_action;
 mod clean_and_bulk_insert_by_chunks_action;
 mod clean_and_bulk_insert_by_chunks_cancel_action;
@@ -13,7 +16,10 @@ mod models;
 
 pub use bulk_delete_action::BulkDeleteAction;
 pub use bulk_delete_if_action::BulkDeleteIfAction;
+pub use bulk_delete_where_action::BulkDeleteWhereAction;
 pub use bulk_insert_or_replace_if_new_action::BulkInsertOrReplaceIfNewAction;
+pub use bulk_insert_or_replace_where_action::BulkInsertOrReplaceWhereAction;
+pub use bulk_replace_where_action::BulkReplaceWhereAction;
 pub use clean_and_bulk_insert_action::CleanAndBulkInsertAction;
 pub use clean_and_bulk_insert_by_chunks_action::CleanAndBulkInsertByChunksAction;
 pub use clean_and_bulk_insert_by_chunks_cancel_action::CleanAndBulkInsertByChunksCancelAction;
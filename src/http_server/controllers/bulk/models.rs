@@ -5,8 +5,11 @@ use my_http_server::RawDataTyped;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    db_operations::write::write_where::SkippedRow,
     db_sync::DataSynchronizationPeriod,
-    http_server::controllers::row_controller::models::BaseDbRowContract,
+    http_server::controllers::row_controller::models::{
+        BaseDbRowContract, DeleteWhereHttpContract, WriteWhereHttpContract,
+    },
 };
 
 #[derive(MyHttpInput)]
@@ -198,3 +201,83 @@ pub struct BulkInsertOrReplaceIfNewInputContract {
     )]
     pub body: RawDataTyped<Vec<BaseDbRowContract>>,
 }
+
+#[derive(MyHttpInput)]
+pub struct BulkWriteWhereInputContract {
+    #[http_header(name = "ns"; description = "Namespace to work in. Empty or absent means the default namespace")]
+    pub namespace: Option<String>,
+
+    #[http_query(name = "tableName"; description = "Name of a table")]
+    pub table_name: String,
+
+    #[http_query(name = "syncPeriod"; description = "Synchronization period"; default)]
+    pub sync_period: DataSynchronizationPeriod,
+
+    #[http_body_raw(
+        description = "[{\"entity\":{...DbEntity...},\"conditions\":[{\"field\":\"Status\",\"op\":\"eq\",\"value\":\"Pending\"}]}]. Each entity is written only when its stored row meets its conditions"
+    )]
+    pub body: RawDataTyped<Vec<WriteWhereHttpContract>>,
+}
+
+#[derive(MyHttpInput)]
+pub struct BulkDeleteWhereInputContract {
+    #[http_header(name = "ns"; description = "Namespace to work in. Empty or absent means the default namespace")]
+    pub namespace: Option<String>,
+
+    #[http_query(name = "tableName"; description = "Name of a table")]
+    pub table_name: String,
+
+    #[http_query(name = "syncPeriod"; description = "Synchronization period"; default)]
+    pub sync_period: DataSynchronizationPeriod,
+
+    #[http_body_raw(
+        description = "[{\"PartitionKey\":\"pk\",\"RowKey\":\"rk\",\"conditions\":[{\"field\":\"Owner\",\"op\":\"eq\",\"value\":\"me\"}]}]. Each row is deleted only when it meets its conditions"
+    )]
+    pub body: RawDataTyped<Vec<DeleteWhereHttpContract>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct BulkWriteWhereResponseContract {
+    /// How many rows were written.
+    pub written: usize,
+
+    /// The requested rows which were left as they are, each with the reason why.
+    pub skipped: Vec<SkippedWriteWhereRowContract>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct BulkDeleteWhereResponseContract {
+    /// How many rows really left the table.
+    pub deleted: usize,
+
+    /// The requested rows which were left in place, each with the reason why.
+    pub skipped: Vec<SkippedWriteWhereRowContract>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct SkippedWriteWhereRowContract {
+    #[serde(rename = "PartitionKey")]
+    pub partition_key: String,
+
+    #[serde(rename = "RowKey")]
+    pub row_key: String,
+
+    /// `ConditionNotMet` - the stored row does not meet the condition on `Field`;
+    /// `NotFound` - there is no such row at all.
+    #[serde(rename = "Reason")]
+    pub reason: String,
+
+    #[serde(rename = "Field", skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+}
+
+impl Into<SkippedWriteWhereRowContract> for SkippedRow {
+    fn into(self) -> SkippedWriteWhereRowContract {
+        SkippedWriteWhereRowContract {
+            field: self.reason.get_field().map(|itm| itm.to_string()),
+            reason: self.reason.as_str().to_string(),
+            partition_key: self.partition_key,
+            row_key: self.row_key,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::app::AppContext;
+use crate::data_readers::SlowReaderAction;
 use my_http_server::macros::*;
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 use serde::{Deserialize, Serialize};
@@ -17,6 +18,14 @@ pub struct ConnectionReaderModel {
     pub pending_to_send: usize,
     #[serde(rename = "isNode")]
     pub is_node: bool,
+    #[serde(rename = "pendingEvents")]
+    pub pending_events: usize,
+    #[serde(rename = "tablesBehind")]
+    pub tables_behind: Vec<String>,
+    #[serde(rename = "slowReaderReinits")]
+    pub slow_reader_reinits: usize,
+    /// Name of the credential the reader authenticated with.
+    pub credential: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
@@ -45,6 +54,10 @@ pub struct ConnectionsModel {
     pub write_payloads_per_second: usize,
     #[serde(rename = "writeBytesPerSecond")]
     pub write_bytes_per_second: usize,
+    #[serde(rename = "slowReaderReinits")]
+    pub slow_reader_reinits: u64,
+    #[serde(rename = "slowReaderDisconnects")]
+    pub slow_reader_disconnects: u64,
     pub readers: Vec<ConnectionReaderModel>,
     pub writers: Vec<ConnectionWriterModel>,
 }
@@ -71,6 +84,12 @@ impl ConnectionsModel {
                 outgoing_per_second: outgoing,
                 pending_to_send: metrics.pending_to_send,
                 is_node: data_reader.is_node(),
+                pending_events: data_reader.get_pending_events(),
+                tables_behind: data_reader.get_tables_behind(),
+                slow_reader_reinits: data_reader.get_reinits(),
+                credential: data_reader
+                    .get_credential()
+                    .map(|credential| credential.name.to_string()),
             });
         }
 
@@ -103,6 +122,12 @@ impl ConnectionsModel {
             outgoing_per_second: total_outgoing,
             write_payloads_per_second: app.write_payloads_per_second.get_value(),
             write_bytes_per_second: app.write_bytes_per_second.get_value(),
+            slow_reader_reinits: app
+                .metrics
+                .get_slow_reader_actions(SlowReaderAction::Reinit),
+            slow_reader_disconnects: app
+                .metrics
+                .get_slow_reader_actions(SlowReaderAction::Disconnect),
             readers,
             writers,
         }
@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use crate::{
     app::AppContext,
-    data_readers::{http_connection::HttpPayload, DataReaderConnection},
+    data_readers::{http_connection::HttpPayload, DataReader, DataReaderConnection},
     db_operations::DbOperationError,
     http_server::{controllers::mappers::ToSetExpirationTime, http_sessions::HttpSessionsSupport},
 };
@@ -49,6 +49,7 @@ async fn handle_request(
     for update_model in &body_model.update_expiration_time {
         update_expiration_time(
             &action.app,
+            &data_reader,
             &db_namespace,
             update_model.table_name.as_str(),
             &update_model.items,
@@ -80,12 +81,22 @@ async fn handle_request(
     .into_err(true, true)
 }
 
+/// Held to the same `ReaderAclFile` a subscribe is: an expiration time is as good
+/// as deleting the rows.
 async fn update_expiration_time(
-    _app: &Arc<AppContext>,
+    app: &Arc<AppContext>,
+    data_reader: &DataReader,
     db_namespace: &Arc<crate::app::DbNamespace>,
     table_name: &str,
     items: &[UpdateExpirationDateTime],
 ) -> Result<(), DbOperationError> {
+    crate::operations::data_readers::check_read_access(
+        app,
+        data_reader,
+        db_namespace.name.as_str(),
+        Some(table_name),
+    )?;
+
     let db_table = db_namespace.db.get_table(table_name);
     if db_table.is_none() {
         return Ok(());
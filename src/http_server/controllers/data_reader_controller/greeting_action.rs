@@ -43,6 +43,12 @@ async fn handle_request(
         )
         .await;
 
+    crate::operations::data_readers::authenticate_reader(
+        &action.app,
+        &result,
+        http_input.token.as_deref(),
+    );
+
     let response = DataReaderGreetingResult {
         session_id: result.id.to_string(),
     };
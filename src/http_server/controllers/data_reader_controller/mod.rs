@@ -2,8 +2,12 @@ mod get_changes_action;
 mod greeting_action;
 mod models;
 mod ping_action;
+mod stream_action;
 mod subscribe_action;
+mod update_partitions_action;
 pub use get_changes_action::GetChangesAction;
 pub use greeting_action::GreetingAction;
 pub use ping_action::PingAction;
+pub use stream_action::StreamAction;
 pub use subscribe_action::SubscribeAction;
+pub use update_partitions_action::UpdatePartitionsAction;
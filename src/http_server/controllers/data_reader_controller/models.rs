@@ -2,12 +2,17 @@ use my_http_server::macros::*;
 use my_http_server::RawDataTyped;
 use serde::{Deserialize, Serialize};
 
+use crate::data_readers::PartitionScope;
+
 #[derive(MyHttpInput)]
 pub struct DataReaderGreetingInputModel {
     #[http_query(name = "name"; description = "Name of Application")]
     pub name: String,
     #[http_query(name = "version"; description = "Version of client library")]
     pub version: String,
+
+    #[http_header(name = "token"; description = "Credential of the reader. Required when the server has a ReaderAclFile")]
+    pub token: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
@@ -23,6 +28,114 @@ pub struct SubscribeToTableInputModel {
 
     #[http_query(name = "tableName"; description = "Table to subscriber")]
     pub table_name: String,
+
+    #[http_query(name = "partitionKeys"; description = "Partitions to get. The whole table when neither keys nor prefixes are given")]
+    pub partition_keys: Option<Vec<String>>,
+
+    #[http_query(name = "partitionKeyPrefixes"; description = "Get the partitions whose keys start with these prefixes")]
+    pub partition_key_prefixes: Option<Vec<String>>,
+
+    #[http_query(name = "fromSequence"; description = "Last sequence of the table the reader has seen. Only the missed changes are sent when the server still has them")]
+    pub from_sequence: Option<u64>,
+}
+
+impl SubscribeToTableInputModel {
+    pub fn get_partition_scope(&self) -> Option<PartitionScope> {
+        if self.partition_keys.is_none() && self.partition_key_prefixes.is_none() {
+            return None;
+        }
+
+        let mut scope = PartitionScope::new();
+
+        if let Some(partition_keys) = &self.partition_keys {
+            scope.add_partition_keys(partition_keys.iter().cloned());
+        }
+
+        if let Some(prefixes) = &self.partition_key_prefixes {
+            scope.add_prefixes(prefixes.iter().cloned());
+        }
+
+        Some(scope)
+    }
+}
+
+#[derive(MyHttpInput)]
+pub struct UpdatePartitionsInputModel {
+    #[http_header(name = "session"; description = "Id of session")]
+    pub session_id: String,
+
+    #[http_body_raw(description = "Partitions to add to or remove from the subscription")]
+    pub body: RawDataTyped<UpdatePartitionsBodyModel>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct UpdatePartitionsBodyModel {
+    #[serde(rename = "tableName")]
+    pub table_name: String,
+    #[serde(rename = "addPartitionKeys", default)]
+    pub add_partition_keys: Vec<String>,
+    #[serde(rename = "removePartitionKeys", default)]
+    pub remove_partition_keys: Vec<String>,
+    #[serde(rename = "addPrefixes", default)]
+    pub add_prefixes: Vec<String>,
+    #[serde(rename = "removePrefixes", default)]
+    pub remove_prefixes: Vec<String>,
+    #[serde(rename = "wholeTable", default)]
+    pub whole_table: bool,
+}
+
+impl UpdatePartitionsBodyModel {
+    pub fn apply(&self, scope: &mut PartitionScope) {
+        scope.remove_partition_keys(self.remove_partition_keys.iter().map(|itm| itm.as_str()));
+        scope.remove_prefixes(self.remove_prefixes.iter().map(|itm| itm.as_str()));
+        scope.add_partition_keys(self.add_partition_keys.iter().cloned());
+        scope.add_prefixes(self.add_prefixes.iter().cloned());
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct PartitionScopeHttpContract {
+    #[serde(rename = "wholeTable")]
+    pub whole_table: bool,
+    #[serde(rename = "partitionKeys")]
+    pub partition_keys: Vec<String>,
+    #[serde(rename = "prefixes")]
+    pub prefixes: Vec<String>,
+}
+
+impl From<Option<PartitionScope>> for PartitionScopeHttpContract {
+    fn from(scope: Option<PartitionScope>) -> Self {
+        match scope {
+            Some(scope) => Self {
+                whole_table: false,
+                partition_keys: scope.get_partition_keys(),
+                prefixes: scope.get_prefixes(),
+            },
+            None => Self {
+                whole_table: true,
+                partition_keys: vec![],
+                prefixes: vec![],
+            },
+        }
+    }
+}
+
+#[derive(MyHttpInput)]
+pub struct DataReaderStreamInputModel {
+    #[http_query(name = "name"; description = "Name of Application")]
+    pub name: String,
+
+    #[http_query(name = "version"; description = "Version of client library")]
+    pub version: String,
+
+    #[http_query(name = "tableName"; description = "Tables to subscribe to")]
+    pub table_names: Vec<String>,
+
+    #[http_query(name = "token"; description = "Credential of the reader. Required when the server has a ReaderAclFile. A query parameter, as EventSource can not send headers")]
+    pub token: Option<String>,
+
+    #[http_header(name = "Last-Event-ID"; description = "Id of the last event the client got. Sent by the browser on reconnect to resume the tables from there")]
+    pub last_event_id: Option<String>,
 }
 
 #[derive(MyHttpInput)]
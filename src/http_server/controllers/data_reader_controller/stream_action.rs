@@ -0,0 +1,154 @@
+use bytes::Bytes;
+use futures::StreamExt;
+use http_body_util::{BodyExt, StreamBody};
+use hyper::body::Frame;
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use my_no_sql_sdk::core::db::DbNamespaceName;
+use std::sync::Arc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use crate::{
+    app::AppContext,
+    data_readers::{http_connection::SseStream, DataReaderConnection},
+};
+
+use super::models::DataReaderStreamInputModel;
+
+#[http_route(
+    method: "GET",
+    route: "/api/DataReader/Stream",
+    controller: "DataReader",
+    summary: "Server-Sent Events change feed",
+    description: "Opens a session, subscribes it to the tables and pushes initTable/initPartitions/updateRows/deleteRows events as they happen. The first event carries the id of the session",
+    input_data: "DataReaderStreamInputModel",
+    result:[
+        {status_code: 200, description: "text/event-stream"},
+    ]
+)]
+pub struct StreamAction {
+    app: Arc<AppContext>,
+}
+
+impl StreamAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &StreamAction,
+    input_data: DataReaderStreamInputModel,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let db_namespace =
+        crate::http_server::get_request_namespace_of_subscribe(&action.app, ctx).await?;
+
+    let data_reader = action
+        .app
+        .data_readers
+        .add_http(
+            input_data.name,
+            input_data.version,
+            ctx.request.get_ip().get_real_ip().to_string(),
+        )
+        .await;
+
+    crate::operations::data_readers::authenticate_reader(
+        &action.app,
+        &data_reader,
+        input_data.token.as_deref(),
+    );
+
+    // All the tables are checked before anything is sent: a stream which is refused
+    // one of them is not opened at all.
+    let namespace = match &db_namespace {
+        Some(db_namespace) => db_namespace.name.to_string(),
+        None => crate::http_server::get_request_namespace_name(ctx)
+            .map(|namespace| namespace.to_string())
+            .unwrap_or_else(|| DbNamespaceName::default().to_string()),
+    };
+
+    for table_name in input_data.table_names.iter() {
+        let result = crate::operations::data_readers::check_read_access(
+            &action.app,
+            &data_reader,
+            namespace.as_str(),
+            Some(table_name.as_str()),
+        );
+
+        if let Err(err) = result {
+            action.app.data_readers.remove_http(&data_reader).await;
+            return Err(err.into());
+        }
+    }
+
+    let DataReaderConnection::Http(http_info) = &data_reader.connection else {
+        unreachable!("add_http gives an HTTP session");
+    };
+
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+    let pending_to_send = http_info.get_stream_pending_to_send();
+
+    let sse_stream = SseStream::new(sender, pending_to_send.clone());
+    sse_stream.send_session(data_reader.id.as_str());
+
+    // Attached before anything is subscribed: the first-init snapshots are
+    // dispatched by the subscriptions and have to find the stream there.
+    http_info.attach_stream(sse_stream).await;
+
+    let positions = match &input_data.last_event_id {
+        Some(last_event_id) => {
+            crate::data_readers::http_connection::parse_event_id(last_event_id.as_str())
+        }
+        None => Default::default(),
+    };
+
+    for table_name in input_data.table_names.iter() {
+        match &db_namespace {
+            Some(db_namespace) => {
+                let result = crate::operations::data_readers::subscribe(
+                    &action.app,
+                    db_namespace,
+                    data_reader.clone(),
+                    table_name.as_str(),
+                    None,
+                    positions.get(table_name.as_str()).copied(),
+                )
+                .await;
+
+                // The stream is never handed to the client, so nobody would close the
+                // session it is attached to.
+                if let Err(err) = result {
+                    http_info.disconnect().await;
+                    action.app.data_readers.remove_http(&data_reader).await;
+                    return Err(err.into());
+                }
+            }
+            None => {
+                crate::operations::data_readers::send_empty_snapshot(
+                    &action.app,
+                    data_reader.get_namespace(),
+                    data_reader.clone(),
+                    table_name.as_str(),
+                );
+            }
+        }
+    }
+
+    // A frame is no longer pending once the response takes it to write out.
+    let body = UnboundedReceiverStream::new(receiver).map(move |chunk| {
+        pending_to_send.remove(chunk.len());
+        Ok(Frame::data(Bytes::from(chunk)))
+    });
+
+    let response = hyper::Response::builder()
+        .status(200)
+        .header("Content-Type", "text/event-stream")
+        .header("Cache-Control", "no-cache")
+        .header("X-Accel-Buffering", "no")
+        .body(StreamBody::new(body).boxed())
+        .unwrap();
+
+    HttpOutput::Raw(response).into_ok_result(false).into()
+}
@@ -1,6 +1,10 @@
-use crate::{app::AppContext, http_server::http_sessions::*};
+use crate::{
+    app::AppContext,
+    http_server::{get_request_namespace_name, http_sessions::*},
+};
 use my_http_server::macros::*;
 use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use my_no_sql_sdk::core::db::DbNamespaceName;
 use std::sync::Arc;
 
 use super::models::SubscribeToTableInputModel;
@@ -43,6 +47,13 @@ async fn handle_request(
     let db_namespace = match db_namespace {
         Some(db_namespace) => db_namespace,
         None => {
+            crate::operations::data_readers::check_read_access(
+                &action.app,
+                &data_reader,
+                get_request_namespace_name(ctx).unwrap_or(DbNamespaceName::default().as_str()),
+                Some(input_data.table_name.as_str()),
+            )?;
+
             // A namespace nobody has written to yet is not a reason to refuse a
             // reader — it gets the same empty snapshot a missing table gets. The
             // name it is sent under is the envelope's only, so the reader's own
@@ -63,6 +74,8 @@ async fn handle_request(
         &db_namespace,
         data_reader,
         input_data.table_name.as_str(),
+        input_data.get_partition_scope(),
+        input_data.from_sequence,
     )
     .await?;
 
@@ -0,0 +1,56 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use std::sync::Arc;
+
+use crate::{app::AppContext, http_server::http_sessions::HttpSessionsSupport};
+
+use super::models::{PartitionScopeHttpContract, UpdatePartitionsInputModel};
+
+#[http_route(
+    method: "POST",
+    route: "/api/DataReader/UpdatePartitions",
+    controller: "DataReader",
+    summary: "Changes the partitions of a subscribed table the HTTP reader gets",
+    description: "Adds partitions to or removes them from the subscription without reconnecting. The reader is initialized with the table again, holding just the partitions of the new scope",
+    input_data: "UpdatePartitionsInputModel",
+    result:[
+        {status_code: 200, description: "The scope after the update", model: "PartitionScopeHttpContract"},
+    ]
+)]
+pub struct UpdatePartitionsAction {
+    app: Arc<AppContext>,
+}
+
+impl UpdatePartitionsAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &UpdatePartitionsAction,
+    input_data: UpdatePartitionsInputModel,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let body = input_data.body.deserialize_json()?;
+
+    // A reader changes the scope of its own session only - the ids of the readers
+    // are no secret, and `/api/DataReader/` takes no API key.
+    let data_reader = action
+        .app
+        .get_http_session(input_data.session_id.as_str())
+        .await?;
+
+    let scope = crate::operations::data_readers::update_partition_scope(
+        &action.app,
+        data_reader,
+        body.table_name.as_str(),
+        |scope| body.apply(scope),
+        body.whole_table,
+    )
+    .await?;
+
+    let response: PartitionScopeHttpContract = scope.into();
+
+    HttpOutput::as_json(response).into_ok_result(true).into()
+}
@@ -1,6 +1,10 @@
+use std::sync::Arc;
+
 use my_http_server::macros::*;
 use serde::{Deserialize, Serialize};
 
+use crate::app::SlowOperation;
+
 #[derive(MyHttpInput)]
 pub struct GetRowStatisticsInputData {
     #[http_header(name = "ns"; description = "Namespace to work in. Empty or absent means the default namespace")]
@@ -19,3 +23,56 @@ pub struct RowStatisticsContract {
     pub row_read_time: String,
     pub row_write_time: String,
 }
+
+pub const DEFAULT_SLOW_OPERATIONS_LIMIT: usize = 100;
+
+#[derive(MyHttpInput)]
+pub struct GetSlowOperationsInputData {
+    #[http_query(name = "kind"; description = "http, grpc, reader, persist, backup, vacuum or sync")]
+    pub kind: Option<String>,
+
+    #[http_query(name = "limit"; description = "Limit amount of records. Default is 100")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Deserialize, Serialize, Debug, MyHttpObjectStructure)]
+pub struct SlowOperationContract {
+    pub date: String,
+    pub kind: String,
+    pub operation: String,
+    pub namespace: String,
+    #[serde(rename = "tableName")]
+    pub table_name: String,
+    #[serde(rename = "durationMicros")]
+    pub duration_micros: u64,
+    pub succeeded: bool,
+    #[serde(rename = "partitionKey")]
+    pub partition_key: Option<String>,
+    pub rows: Option<usize>,
+    #[serde(rename = "payloadSize")]
+    pub payload_size: Option<usize>,
+    pub session: Option<String>,
+}
+
+impl From<Arc<SlowOperation>> for SlowOperationContract {
+    fn from(src: Arc<SlowOperation>) -> Self {
+        Self {
+            date: src.date.to_rfc3339(),
+            kind: src.kind.as_str().to_string(),
+            operation: src.operation.to_string(),
+            namespace: src.namespace.to_string(),
+            table_name: src.table_name.to_string(),
+            duration_micros: src.duration.as_micros() as u64,
+            succeeded: src.succeeded,
+            partition_key: src.details.partition_key.clone(),
+            rows: src.details.rows,
+            payload_size: src.details.payload_size,
+            session: src.details.session.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, MyHttpObjectStructure)]
+pub struct SlowOperationsContract {
+    pub items: Vec<SlowOperationContract>,
+}
@@ -0,0 +1,58 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use std::sync::Arc;
+
+use crate::app::{AppContext, OperationKind};
+
+use super::contracts::*;
+
+#[http_route(
+    method: "GET",
+    route: "/api/Debug/SlowOperations",
+    summary: "Get slow operations",
+    description: "Returns the latest operations which took longer than their threshold, the newest first",
+    controller: "Debug",
+    input_data: "GetSlowOperationsInputData",
+    result:[
+        {status_code: 200, description: "Slow operations", model: "SlowOperationsContract"},
+    ]
+)]
+pub struct GetSlowOperationsAction {
+    app: Arc<AppContext>,
+}
+
+impl GetSlowOperationsAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetSlowOperationsAction,
+    input_data: GetSlowOperationsInputData,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let kind = match input_data.kind.as_deref() {
+        Some(kind) => match OperationKind::parse(kind) {
+            Some(kind) => Some(kind),
+            None => {
+                return Err(HttpFailResult::as_validation_error(format!(
+                    "'{}' is not a kind of operation",
+                    kind
+                )));
+            }
+        },
+        None => None,
+    };
+
+    let items = action.app.slow_operations.get(
+        kind,
+        input_data.limit.unwrap_or(DEFAULT_SLOW_OPERATIONS_LIMIT),
+    );
+
+    let result = SlowOperationsContract {
+        items: items.into_iter().map(|itm| itm.into()).collect(),
+    };
+
+    HttpOutput::as_json(result).into_ok_result(false).into()
+}
@@ -1,3 +1,5 @@
 mod get_row_statistics_action;
 pub use get_row_statistics_action::*;
+mod get_slow_operations_action;
+pub use get_slow_operations_action::*;
 mod contracts;
@@ -2,7 +2,7 @@ use my_http_server::macros::*;
 use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use std::sync::Arc;
 
-use crate::{app::AppContext, db_sync::EventSource};
+use crate::{app::AppContext, audit_log::AuditRecord, db_sync::EventSource};
 
 use super::models::CleanAndKeepMaxPartitionsAmountInputContract;
 
@@ -45,7 +45,7 @@ async fn handle_request(
 
     let event_src = EventSource::as_client_request(action.app.as_ref());
 
-    crate::db_operations::gc::keep_max_partitions_amount(
+    let partition_keys = crate::db_operations::gc::keep_max_partitions_amount(
         action.app.as_ref(),
         &db_namespace,
         &db_table,
@@ -55,5 +55,16 @@ async fn handle_request(
     )
     .await?;
 
+    if !partition_keys.is_empty() {
+        crate::http_server::write_audit(
+            &action.app,
+            ctx,
+            AuditRecord::new("CleanAndKeepMaxPartitions")
+                .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+                .with_partition_keys(partition_keys.into_iter()),
+        )
+        .await;
+    }
+
     HttpOutput::Empty.into_ok_result(true).into()
 }
@@ -2,7 +2,7 @@ use my_http_server::macros::*;
 use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use std::sync::Arc;
 
-use crate::{app::AppContext, db_sync::EventSource};
+use crate::{app::AppContext, audit_log::AuditRecord, db_sync::EventSource};
 
 use super::models::CleanPartitionAndKeepMaxRowsAmountInputContract;
 
@@ -45,7 +45,11 @@ async fn handle_request<'s>(
 
     let event_src = EventSource::as_client_request(action.app.as_ref());
 
-    crate::db_operations::gc::keep_partition_max_records::execute(
+    let audit_record = AuditRecord::new("CleanAndKeepMaxRecords")
+        .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+        .with_partition_keys([input_data.partition_key.to_string()].into_iter());
+
+    let rows = crate::db_operations::gc::keep_partition_max_records::execute(
         action.app.as_ref(),
         &db_namespace,
         db_table.as_ref(),
@@ -56,5 +60,9 @@ async fn handle_request<'s>(
     )
     .await?;
 
+    if rows > 0 {
+        crate::http_server::write_audit(&action.app, ctx, audit_record.with_rows(rows)).await;
+    }
+
     HttpOutput::Empty.into_ok_result(true).into()
 }
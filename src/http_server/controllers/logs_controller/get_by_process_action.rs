@@ -1,18 +1,21 @@
 use my_http_server::macros::*;
-use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput, WebContentType};
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use std::sync::Arc;
 
-use my_no_sql_server_core::logs::*;
-use rust_extensions::StopWatch;
+use crate::app::{logs::SystemProcess, AppContext};
 
-use crate::app::AppContext;
-
-use super::contracts::GetLogsByProcess;
+use super::models::{GetLogsByProcessInputModel, LogsHttpResponse, DEFAULT_LOGS_LIMIT};
 
 #[http_route(
     method: "GET",
-    route: "/Logs/Process/{process_name}",
-    input_data: "GetLogsByProcess"
+    route: "/api/Logs/Process",
+    input_data: "GetLogsByProcessInputModel",
+    controller: "Logs",
+    description: "Latest log entries of a process",
+    summary: "Returns the latest entries of a process of the server, the newest first",
+    result:[
+        {status_code: 200, description: "Log entries", model: "LogsHttpResponse"},
+    ]
 )]
 pub struct GetLogsByProcessAction {
     app: Arc<AppContext>,
@@ -26,43 +29,22 @@ impl GetLogsByProcessAction {
 
 async fn handle_request(
     action: &GetLogsByProcessAction,
-    input_data: GetLogsByProcess,
+    input_data: GetLogsByProcessInputModel,
     _ctx: &mut HttpContext,
 ) -> Result<HttpOkResult, HttpFailResult> {
-    let process = SystemProcess::parse(&input_data.process_name);
-
-    if process.is_none() {
-        return HttpOutput::Content {
-            headers: None,
-            content_type: Some(WebContentType::Text),
-            content: format!("Invalid process name: {}", input_data.process_name.as_str()).into(),
-        }
-        .into_ok_result(true)
-        .into();
-    }
-
-    let process = process.unwrap();
-
-    let mut sw = StopWatch::new();
-    sw.start();
-    let logs_result = action.app.logs.get_by_process(process).await;
-
-    match logs_result {
-        Some(logs) => super::logs::compile_result("logs by process", logs, sw).into(),
-        None => {
-            sw.pause();
-
-            HttpOutput::Content {
-                headers: None,
-                content_type: Some(WebContentType::Text),
-                content: format!(
-                    "Result compiled in: {:?}. No log records for the process '{}'",
-                    sw.duration(),
-                    input_data.process_name
-                )
-                .into_bytes(),
-            }
-            .into_ok_result(true)
-        }
-    }
+    let Some(process) = SystemProcess::parse(input_data.process.as_str()) else {
+        return Err(HttpFailResult::as_validation_error(format!(
+            "'{}' is not a process",
+            input_data.process
+        )));
+    };
+
+    let items = action
+        .app
+        .logs
+        .get_by_process(process, input_data.limit.unwrap_or(DEFAULT_LOGS_LIMIT));
+
+    HttpOutput::as_json(LogsHttpResponse::new(items))
+        .into_ok_result(false)
+        .into()
 }
@@ -0,0 +1,47 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use std::sync::Arc;
+
+use crate::app::AppContext;
+
+use super::models::{GetLogsByTableInputModel, LogsHttpResponse, DEFAULT_LOGS_LIMIT};
+
+#[http_route(
+    method: "GET",
+    route: "/api/Logs/Table",
+    input_data: "GetLogsByTableInputModel",
+    controller: "Logs",
+    description: "Latest log entries of a table",
+    summary: "Returns the latest entries of a table of the namespace of the request, the newest first",
+    result:[
+        {status_code: 200, description: "Log entries", model: "LogsHttpResponse"},
+    ]
+)]
+pub struct GetLogsByTableAction {
+    app: Arc<AppContext>,
+}
+
+impl GetLogsByTableAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetLogsByTableAction,
+    input_data: GetLogsByTableInputModel,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let namespace = crate::http_server::get_request_namespace_name(ctx)
+        .unwrap_or(my_no_sql_sdk::DEFAULT_NAMESPACE);
+
+    let items = action.app.logs.get_by_table(
+        namespace,
+        input_data.table_name.as_str(),
+        input_data.limit.unwrap_or(DEFAULT_LOGS_LIMIT),
+    );
+
+    HttpOutput::as_json(LogsHttpResponse::new(items))
+        .into_ok_result(false)
+        .into()
+}
@@ -1,13 +1,21 @@
 use my_http_server::macros::*;
-use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput, WebContentType};
-use rust_extensions::StopWatch;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use std::sync::Arc;
 
 use crate::app::AppContext;
 
+use super::models::{GetFatalErrorsInputModel, LogsHttpResponse, DEFAULT_LOGS_LIMIT};
+
 #[http_route(
     method: "GET",
-    route: "/Logs/FatalErrors",
+    route: "/api/Logs/FatalErrors",
+    input_data: "GetFatalErrorsInputModel",
+    controller: "Logs",
+    description: "Latest fatal errors",
+    summary: "Returns the latest fatal errors, the newest first",
+    result:[
+        {status_code: 200, description: "Log entries", model: "LogsHttpResponse"},
+    ]
 )]
 pub struct GetFatalErrorsAction {
     app: Arc<AppContext>,
@@ -21,29 +29,15 @@ impl GetFatalErrorsAction {
 
 async fn handle_request(
     action: &GetFatalErrorsAction,
+    input_data: GetFatalErrorsInputModel,
     _ctx: &mut HttpContext,
 ) -> Result<HttpOkResult, HttpFailResult> {
-    let mut sw = StopWatch::new();
-    sw.start();
-    let logs_result = action.app.logs.get_fatal_errors().await;
-
-    match logs_result {
-        Some(logs) => super::logs::compile_result("FatalError logs", logs, sw).into(),
-        None => {
-            sw.pause();
+    let items = action
+        .app
+        .logs
+        .get_fatal_errors(input_data.limit.unwrap_or(DEFAULT_LOGS_LIMIT));
 
-            let content = format!(
-                "Result compiled in: {:?}. No fatal error records",
-                sw.duration(),
-            );
-
-            HttpOutput::Content {
-                headers: None,
-                content_type: Some(WebContentType::Text),
-                content: content.into_bytes(),
-            }
-            .into_ok_result(true)
-            .into()
-        }
-    }
+    HttpOutput::as_json(LogsHttpResponse::new(items))
+        .into_ok_result(false)
+        .into()
 }
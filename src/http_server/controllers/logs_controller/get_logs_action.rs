@@ -0,0 +1,56 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use std::sync::Arc;
+
+use crate::app::{logs::LogLevel, AppContext};
+
+use super::models::{GetLogsInputModel, LogsHttpResponse, DEFAULT_LOGS_LIMIT};
+
+#[http_route(
+    method: "GET",
+    route: "/api/Logs",
+    input_data: "GetLogsInputModel",
+    controller: "Logs",
+    description: "Latest log entries",
+    summary: "Returns the latest entries of the persistence, backup, GC, vacuum and sync code, the newest first",
+    result:[
+        {status_code: 200, description: "Log entries", model: "LogsHttpResponse"},
+    ]
+)]
+pub struct GetLogsAction {
+    app: Arc<AppContext>,
+}
+
+impl GetLogsAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetLogsAction,
+    input_data: GetLogsInputModel,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let level = match input_data.level.as_deref() {
+        Some(level) => match LogLevel::parse(level) {
+            Some(level) => Some(level),
+            None => {
+                return Err(HttpFailResult::as_validation_error(format!(
+                    "'{}' is not a log level",
+                    level
+                )));
+            }
+        },
+        None => None,
+    };
+
+    let items = action
+        .app
+        .logs
+        .get(level, input_data.limit.unwrap_or(DEFAULT_LOGS_LIMIT));
+
+    HttpOutput::as_json(LogsHttpResponse::new(items))
+        .into_ok_result(false)
+        .into()
+}
@@ -1,14 +1,9 @@
-mod contracts;
 mod get_by_process_action;
+mod get_by_table_action;
 mod get_fatal_errors_action;
-mod get_logs_by_table_action;
-mod home_action;
-mod logs;
-mod select_process_action;
-mod select_table_action;
-pub use get_by_process_action::*;
-pub use get_fatal_errors_action::*;
-pub use get_logs_by_table_action::*;
-pub use home_action::HomeAction;
-pub use select_process_action::*;
-pub use select_table_action::*;
+mod get_logs_action;
+mod models;
+pub use get_by_process_action::GetLogsByProcessAction;
+pub use get_by_table_action::GetLogsByTableAction;
+pub use get_fatal_errors_action::GetFatalErrorsAction;
+pub use get_logs_action::GetLogsAction;
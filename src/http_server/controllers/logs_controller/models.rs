@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use my_http_server::macros::*;
+use serde::{Deserialize, Serialize};
+
+use crate::app::logs::LogItem;
+
+pub const DEFAULT_LOGS_LIMIT: usize = 100;
+
+#[derive(MyHttpInput)]
+pub struct GetLogsInputModel {
+    #[http_query(name = "level"; description = "Info, Error or FatalError")]
+    pub level: Option<String>,
+
+    #[http_query(name = "limit"; description = "Limit amount of entries. Default is 100")]
+    pub limit: Option<usize>,
+}
+
+#[derive(MyHttpInput)]
+pub struct GetFatalErrorsInputModel {
+    #[http_query(name = "limit"; description = "Limit amount of entries. Default is 100")]
+    pub limit: Option<usize>,
+}
+
+#[derive(MyHttpInput)]
+pub struct GetLogsByTableInputModel {
+    #[http_query(name = "tableName"; description = "Name of a table")]
+    pub table_name: String,
+
+    #[http_query(name = "limit"; description = "Limit amount of entries. Default is 100")]
+    pub limit: Option<usize>,
+}
+
+#[derive(MyHttpInput)]
+pub struct GetLogsByProcessInputModel {
+    #[http_query(name = "process"; description = "Init, Persist, Backup, Gc, Vacuum, Sync, DataReaders or Tls")]
+    pub process: String,
+
+    #[http_query(name = "limit"; description = "Limit amount of entries. Default is 100")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct LogContextHttpModel {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct LogItemHttpModel {
+    pub date: String,
+    pub level: String,
+    pub process: String,
+    pub message: String,
+    pub namespace: Option<String>,
+    #[serde(rename = "tableName")]
+    pub table_name: Option<String>,
+    pub context: Vec<LogContextHttpModel>,
+}
+
+impl From<Arc<LogItem>> for LogItemHttpModel {
+    fn from(src: Arc<LogItem>) -> Self {
+        Self {
+            date: src.date.to_rfc3339(),
+            level: src.level.as_str().to_string(),
+            process: src.process.as_str().to_string(),
+            message: src.message.to_string(),
+            namespace: src.namespace.clone(),
+            table_name: src.table_name.clone(),
+            context: src
+                .context
+                .iter()
+                .map(|(key, value)| LogContextHttpModel {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct LogsHttpResponse {
+    pub items: Vec<LogItemHttpModel>,
+}
+
+impl LogsHttpResponse {
+    pub fn new(items: Vec<Arc<LogItem>>) -> Self {
+        Self {
+            items: items.into_iter().map(|itm| itm.into()).collect(),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod api;
+pub mod audit_controller;
 //mod as_html;
 pub mod builder;
 pub mod bulk;
@@ -7,13 +8,14 @@ pub mod data_reader_controller;
 pub mod debug_controller;
 pub mod gc_controller;
 //pub mod home_controller;
+pub mod logs_controller;
 mod mappers;
 pub mod persist_controller;
 
-//pub mod logs_controller;
 pub mod multipart;
 pub mod namespaces_controller;
 pub mod prometheus_controller;
+pub mod query_controller;
 pub mod row_controller;
 pub mod rows_controller;
 pub mod status_controller;
@@ -2,7 +2,10 @@ use my_http_server::macros::*;
 use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use std::sync::Arc;
 
-use crate::app::{AppContext, DeleteNamespaceError};
+use crate::{
+    app::{AppContext, DeleteNamespaceError},
+    audit_log::AuditRecord,
+};
 
 use super::models::DeleteNamespaceContract;
 
@@ -31,7 +34,7 @@ impl DeleteNamespaceAction {
 async fn handle_request(
     action: &DeleteNamespaceAction,
     input_data: DeleteNamespaceContract,
-    _ctx: &mut HttpContext,
+    ctx: &mut HttpContext,
 ) -> Result<HttpOkResult, HttpFailResult> {
     crate::db_operations::check_app_states(action.app.as_ref())?;
 
@@ -67,13 +70,20 @@ async fn handle_request(
             ),
         })?;
 
+    crate::http_server::write_audit(
+        &action.app,
+        ctx,
+        AuditRecord::new("DeleteNamespace").with_namespace(db_namespace.name.as_str()),
+    )
+    .await;
+
     // The namespace is out of the routing tables by now, so nothing can write
     // into it any more and removing its folder is safe. A folder which can not
     // be removed is reported, not swallowed: the namespace is gone from memory
     // either way and the leftover would come back as a namespace on the next
     // start.
     let folder = crate::persist_repo::get_namespace_folder(
-        action.app.settings.get_persistence_dest().as_str(),
+        action.app.settings.load().get_persistence_dest().as_str(),
         db_namespace.name.as_str(),
     );
 
@@ -0,0 +1,107 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use std::sync::Arc;
+
+use crate::app::AppContext;
+use crate::db_operations::read::aggregate::{
+    AggregateFunction, AggregateResponseContract, AggregateScope, Aggregation,
+};
+
+use super::models::*;
+
+#[http_route(
+    method: "POST",
+    route: "/api/Query/Aggregate",
+    controller: "Query",
+    description: "Computes count, sum, min, max and avg over JSON fields of the rows of a table, a partition or the partitions with a key prefix - optionally grouped by a field and limited by a row filter. Nothing but the small result leaves the server",
+    summary: "Aggregates rows of a table",
+    input_data: "AggregateInputContract",
+    result:[
+        {status_code: 200, description: "Aggregated values per group"},
+        {status_code: 400, description: "Table not found or invalid query"},
+    ]
+)]
+pub struct AggregateAction {
+    app: Arc<AppContext>,
+}
+
+impl AggregateAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &AggregateAction,
+    input_data: AggregateInputContract,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let query: AggregateQueryBody = match serde_json::from_slice(input_data.body.as_slice()) {
+        Ok(query) => query,
+        Err(err) => {
+            return Err(HttpFailResult::as_validation_error(format!(
+                "Invalid body: {}",
+                err
+            )));
+        }
+    };
+
+    let scope = match (
+        query.partition_key.as_deref(),
+        query.partition_key_prefix.as_deref(),
+    ) {
+        (Some(_), Some(_)) => {
+            return Err(HttpFailResult::as_validation_error(
+                "partitionKey and partitionKeyPrefix can not be used together".to_string(),
+            ));
+        }
+        (Some(partition_key), None) => AggregateScope::Partition(partition_key),
+        (None, Some(prefix)) => AggregateScope::PartitionKeyPrefix(prefix),
+        (None, None) => AggregateScope::Table,
+    };
+
+    if let Some(aggregation) = query
+        .aggregations
+        .iter()
+        .find(|itm| itm.function != AggregateFunction::Count && itm.field.is_none())
+    {
+        return Err(HttpFailResult::as_validation_error(format!(
+            "Aggregation '{}' needs a field",
+            aggregation.get_name()
+        )));
+    }
+
+    // A query which names nothing to compute is asked for the one thing every
+    // dashboard starts with.
+    let aggregations = if query.aggregations.is_empty() {
+        vec![Aggregation {
+            function: AggregateFunction::Count,
+            field: None,
+        }]
+    } else {
+        query.aggregations
+    };
+
+    let db_namespace = crate::http_server::get_request_namespace_existing(&action.app, ctx).await?;
+
+    let db_table = crate::db_operations::read::table::get(
+        action.app.as_ref(),
+        &db_namespace,
+        input_data.table_name.as_str(),
+    )
+    .await?;
+
+    let result = crate::db_operations::read::aggregate::aggregate(
+        &action.app,
+        &db_table,
+        scope,
+        query.filter.as_slice(),
+        query.group_by.as_deref(),
+        aggregations.as_slice(),
+    )
+    .await?;
+
+    let response = AggregateResponseContract::new(result, aggregations.as_slice());
+
+    HttpOutput::as_json(response).into_ok_result(true).into()
+}
@@ -0,0 +1,4 @@
+mod aggregate_action;
+pub mod models;
+
+pub use aggregate_action::AggregateAction;
@@ -0,0 +1,68 @@
+use my_http_server::macros::*;
+use my_http_server::RawDataTyped;
+use serde::{Deserialize, Serialize};
+
+use crate::db_operations::{field_conditions::FieldCondition, read::aggregate::Aggregation};
+
+#[derive(MyHttpInput)]
+pub struct AggregateInputContract {
+    #[http_header(name = "ns"; description = "Namespace to work in. Empty or absent means the default namespace")]
+    pub namespace: Option<String>,
+
+    #[http_query(name = "tableName"; description = "Name of a table")]
+    pub table_name: String,
+
+    #[http_body_raw(
+        description = "What to compute: {\"partitionKeyPrefix\":\"tenant-1\",\"groupBy\":\"Status\",\"aggregations\":[{\"op\":\"count\"},{\"op\":\"sum\",\"field\":\"Amount\"}],\"filter\":[{\"field\":\"Currency\",\"op\":\"eq\",\"value\":\"USD\"}]}. Ops are count, sum, min, max and avg; filter ops are eq, ne, gt, gte, lt, lte, exists and notExists. The condition value is any JSON value"
+    )]
+    pub body: RawDataTyped<AggregateQueryHttpContract>,
+}
+
+/// Swagger shape of the body. The body itself is read as `AggregateQueryBody`:
+/// a condition value there is any JSON value, not only a string.
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct AggregateQueryHttpContract {
+    #[serde(rename = "partitionKey")]
+    pub partition_key: Option<String>,
+
+    #[serde(rename = "partitionKeyPrefix")]
+    pub partition_key_prefix: Option<String>,
+
+    #[serde(rename = "groupBy")]
+    pub group_by: Option<String>,
+
+    pub aggregations: Vec<AggregationHttpContract>,
+
+    pub filter: Option<Vec<FieldConditionHttpContract>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct AggregationHttpContract {
+    pub op: String,
+    pub field: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct FieldConditionHttpContract {
+    pub field: String,
+    pub op: String,
+    pub value: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AggregateQueryBody {
+    #[serde(rename = "partitionKey", default)]
+    pub partition_key: Option<String>,
+
+    #[serde(rename = "partitionKeyPrefix", default)]
+    pub partition_key_prefix: Option<String>,
+
+    #[serde(rename = "groupBy", default)]
+    pub group_by: Option<String>,
+
+    #[serde(default)]
+    pub aggregations: Vec<Aggregation>,
+
+    #[serde(default)]
+    pub filter: Vec<FieldCondition>,
+}
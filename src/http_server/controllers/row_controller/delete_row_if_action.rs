@@ -6,6 +6,7 @@ use std::sync::Arc;
 use crate::db_operations;
 
 use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
 use crate::db_sync::EventSource;
 
 use super::models::{BaseDbRowContract, DeleteRowIfInputModel};
@@ -59,11 +60,16 @@ async fn handle_request(
         )));
     };
 
+    let audit_record = AuditRecord::new("DeleteRowIf")
+        .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+        .with_partition_keys(std::iter::once(http_input.partition_key.clone()))
+        .with_details(format!("RowKey: {}", http_input.row_key));
+
     let event_src = EventSource::as_client_request(action.app.as_ref());
 
     let now = DateTimeAsMicroseconds::now();
 
-    db_operations::write::delete_row_if::execute(
+    let result = db_operations::write::delete_row_if::execute(
         action.app.as_ref(),
         &db_namespace,
         &db_table,
@@ -74,6 +80,10 @@ async fn handle_request(
         http_input.sync_period.get_sync_moment(),
         now,
     )
-    .await?
-    .into()
+    .await?;
+
+    let audit_record = audit_record.with_rows(result.get_rows_amount());
+    crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+
+    result.into()
 }
@@ -0,0 +1,83 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult};
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use std::sync::Arc;
+
+use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
+use crate::db_sync::EventSource;
+
+use super::models::{BaseDbRowContract, DeleteWhereBody, DeleteWhereInputContract};
+
+#[http_route(
+    method: "POST",
+    route: "/api/Row/DeleteWhere",
+    controller: "Row",
+    description: "Deletes the row only when it meets the conditions",
+    summary: "The conditions are checked against the stored row under the table write lock. A row which does not meet them answers 409 and stays in place",
+    input_data: "DeleteWhereInputContract",
+    result:[
+        {status_code: 200, description: "Deleted row",  model:"BaseDbRowContract"},
+        {status_code: 400, description: "Table not found, or the body is not valid"},
+        {status_code: 404, description: "Row not found"},
+        {status_code: 409, description: "The stored row does not meet a condition"},
+    ]
+)]
+pub struct DeleteWhereAction {
+    app: Arc<AppContext>,
+}
+
+impl DeleteWhereAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &DeleteWhereAction,
+    input_data: DeleteWhereInputContract,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let db_namespace = crate::http_server::get_request_namespace_existing(&action.app, ctx).await?;
+
+    let db_table = crate::db_operations::read::table::get(
+        action.app.as_ref(),
+        &db_namespace,
+        input_data.table_name.as_str(),
+    )
+    .await?;
+
+    let body: DeleteWhereBody = match serde_json::from_slice(input_data.body.as_slice()) {
+        Ok(body) => body,
+        Err(err) => {
+            return Err(HttpFailResult::as_validation_error(format!(
+                "Invalid body: {}",
+                err
+            )));
+        }
+    };
+
+    let audit_record = AuditRecord::new("DeleteWhere")
+        .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+        .with_partition_keys(std::iter::once(body.partition_key.clone()))
+        .with_details(format!("RowKey: {}", body.row_key));
+
+    let event_src = EventSource::as_client_request(action.app.as_ref());
+
+    let result = crate::db_operations::write::write_where::delete(
+        action.app.as_ref(),
+        &db_namespace,
+        &db_table,
+        vec![body.into()],
+        event_src,
+        input_data.sync_period.get_sync_moment(),
+        DateTimeAsMicroseconds::now(),
+    )
+    .await?
+    .into_single_row()?;
+
+    let audit_record = audit_record.with_rows(result.get_rows_amount());
+    crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+
+    result.into()
+}
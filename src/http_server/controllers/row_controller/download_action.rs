@@ -47,6 +47,7 @@ async fn handle_request(
     .await?;
 
     let now = DateTimeAsMicroseconds::now();
+    let fields = input_data.get_fields_projection();
 
     let result = if let Some(partition_key) = input_data.partition_key.as_ref() {
         crate::db_operations::read::rows::get_all_by_partition_key(
@@ -55,6 +56,7 @@ async fn handle_request(
             partition_key,
             input_data.limit,
             input_data.skip,
+            fields.as_ref(),
             no_update_statistics(),
             now,
         )
@@ -65,6 +67,7 @@ async fn handle_request(
             &db_table,
             input_data.limit,
             input_data.skip,
+            fields.as_ref(),
             no_update_statistics(),
             now,
         )
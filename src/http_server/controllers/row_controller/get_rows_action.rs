@@ -44,6 +44,7 @@ async fn handle_request(
     .await?;
 
     let compress = wants_zstd(input_data.x_compress.as_deref());
+    let fields = input_data.get_fields_projection();
     let now = DateTimeAsMicroseconds::now();
     if let Some(partition_key) = input_data.partition_key.as_ref() {
         if let Some(row_key) = input_data.row_key.as_ref() {
@@ -52,6 +53,7 @@ async fn handle_request(
                 &db_table,
                 partition_key,
                 row_key,
+                fields.as_ref(),
                 input_data.get_update_statistics(),
                 now,
             )
@@ -65,6 +67,7 @@ async fn handle_request(
                 partition_key,
                 input_data.limit,
                 input_data.skip,
+                fields.as_ref(),
                 input_data.get_update_statistics(),
                 now,
             )
@@ -80,6 +83,7 @@ async fn handle_request(
                 row_key,
                 input_data.limit,
                 input_data.skip,
+                fields.as_ref(),
                 input_data.get_update_statistics(),
                 now,
             )
@@ -92,6 +96,7 @@ async fn handle_request(
                 &db_table,
                 input_data.limit,
                 input_data.skip,
+                fields.as_ref(),
                 input_data.get_update_statistics(),
                 now,
             )
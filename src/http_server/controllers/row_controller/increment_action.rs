@@ -0,0 +1,80 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
+
+use crate::app::AppContext;
+use crate::db_sync::EventSource;
+
+use super::models::{IncrementInputModel, IncrementResponseContract};
+
+#[http_route(
+    method: "POST",
+    route: "/api/Row/Increment",
+    controller: "Row",
+    description: "Atomically adds a number to a numeric field of a row",
+    summary: "Adds delta to the field and returns {\"value\": <new value>}. A missing row is created and a missing field counts from initialValue",
+    input_data: "IncrementInputModel",
+    result:[
+        {status_code: 200, description: "New value of the field"},
+        {status_code: 400, description: "Table not found, delta or initialValue is not a number, or the field holds something which is not a number"},
+    ]
+)]
+pub struct IncrementAction {
+    app: Arc<AppContext>,
+}
+
+impl IncrementAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &IncrementAction,
+    http_input: IncrementInputModel,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let db_namespace = crate::http_server::get_request_namespace_existing(&action.app, ctx).await?;
+
+    let db_table = crate::db_operations::read::table::get(
+        action.app.as_ref(),
+        &db_namespace,
+        http_input.table_name.as_ref(),
+    )
+    .await?;
+
+    let delta = http_input
+        .get_delta()
+        .map_err(HttpFailResult::as_validation_error)?;
+
+    let initial_value = http_input
+        .get_initial_value()
+        .map_err(HttpFailResult::as_validation_error)?;
+
+    let event_src = EventSource::as_client_request(action.app.as_ref());
+
+    let now = JsonTimeStamp::now();
+
+    let result = crate::db_operations::write::increment::execute(
+        action.app.as_ref(),
+        &db_namespace,
+        &db_table,
+        http_input.partition_key,
+        http_input.row_key,
+        http_input.field.as_str(),
+        &delta,
+        &initial_value,
+        event_src,
+        http_input.sync_period.get_sync_moment(),
+        &now,
+    )
+    .await?;
+
+    let response = IncrementResponseContract {
+        value: result.value,
+    };
+
+    HttpOutput::as_json(response).into_ok_result(true).into()
+}
@@ -1,20 +1,30 @@
 mod count_action;
 mod delete_row_action;
 mod delete_row_if_action;
+mod delete_where_action;
 mod download_action;
 mod get_rows_action;
+mod increment_action;
 mod insert_action;
 mod insert_or_replace_action;
 mod insert_or_replace_if_new_action;
+mod insert_or_replace_where_action;
 pub mod models;
+mod patch_row_action;
 mod replace_row_action;
+mod replace_where_action;
 
 pub use count_action::RowCountAction;
 pub use delete_row_action::*;
 pub use delete_row_if_action::DeleteRowIfAction;
+pub use delete_where_action::DeleteWhereAction;
 pub use download_action::DownloadRowsAction;
 pub use get_rows_action::*;
+pub use increment_action::IncrementAction;
 pub use insert_action::InsertRowAction;
 pub use insert_or_replace_action::InsertOrReplaceAction;
 pub use insert_or_replace_if_new_action::InsertOrReplaceIfNewAction;
+pub use insert_or_replace_where_action::InsertOrReplaceWhereAction;
+pub use patch_row_action::PatchRowAction;
 pub use replace_row_action::*;
+pub use replace_where_action::ReplaceWhereAction;
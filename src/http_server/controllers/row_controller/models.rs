@@ -1,11 +1,21 @@
 use my_http_server::macros::*;
 use my_http_server::RawDataTyped;
+use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
+use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    db_operations::UpdateStatistics, db_sync::DataSynchronizationPeriod,
-    http_server::controllers::mappers::ToSetExpirationTime,
+    db_operations::{
+        field_conditions::FieldCondition,
+        read::FieldsProjection,
+        write::write_where::{RowToDeleteWhere, RowToWriteWhere},
+        DbOperationError, UpdateStatistics,
+    },
+    db_sync::DataSynchronizationPeriod,
+    http_server::controllers::{
+        mappers::ToSetExpirationTime, query_controller::models::FieldConditionHttpContract,
+    },
 };
 
 #[derive(MyHttpInput)]
@@ -69,6 +79,192 @@ pub struct ReplaceInputContract {
     pub body: RawDataTyped<BaseDbRowContract>,
 }
 
+#[derive(MyHttpInput)]
+pub struct PatchRowInputModel {
+    #[http_header(name = "ns"; description = "Namespace to work in. Empty or absent means the default namespace")]
+    pub namespace: Option<String>,
+    #[http_query(name = "tableName"; description = "Name of a table")]
+    pub table_name: String,
+
+    #[http_query(name = "partitionKey"; description = "Partition Key")]
+    pub partition_key: String,
+
+    #[http_query(name = "rowKey"; description = "Row Key")]
+    pub row_key: String,
+
+    #[http_query(
+        name = "timeStamp";
+        description = "Optional TimeStamp the row was read at. When given, the patch is applied only if this is still the TimeStamp stored in the table"
+    )]
+    pub time_stamp: Option<String>,
+
+    #[http_query(name = "syncPeriod"; description = "Synchronization period"; default)]
+    pub sync_period: DataSynchronizationPeriod,
+
+    #[http_body_raw(
+        description = "RFC 7386 merge patch: a JSON object whose fields replace the ones of the row, nested objects merge, null removes a field"
+    )]
+    pub body: RawDataTyped<MergePatchHttpContract>,
+}
+
+#[derive(MyHttpInput)]
+pub struct IncrementInputModel {
+    #[http_header(name = "ns"; description = "Namespace to work in. Empty or absent means the default namespace")]
+    pub namespace: Option<String>,
+    #[http_query(name = "tableName"; description = "Name of a table")]
+    pub table_name: String,
+
+    #[http_query(name = "partitionKey"; description = "Partition Key")]
+    pub partition_key: String,
+
+    #[http_query(name = "rowKey"; description = "Row Key")]
+    pub row_key: String,
+
+    #[http_query(name = "field"; description = "Numeric field to change. Top-level name or a dotted path")]
+    pub field: String,
+
+    #[http_query(name = "delta"; description = "Number to add. Negative to decrement")]
+    pub delta: String,
+
+    #[http_query(
+        name = "initialValue";
+        description = "What a missing row or field counts from. Default is 0"
+    )]
+    pub initial_value: Option<String>,
+
+    #[http_query(name = "syncPeriod"; description = "Synchronization period"; default)]
+    pub sync_period: DataSynchronizationPeriod,
+}
+
+impl IncrementInputModel {
+    pub fn get_delta(&self) -> Result<serde_json::Number, String> {
+        parse_number("delta", self.delta.as_str())
+    }
+
+    pub fn get_initial_value(&self) -> Result<serde_json::Number, String> {
+        match self.initial_value.as_deref() {
+            Some(initial_value) => parse_number("initialValue", initial_value),
+            None => Ok(serde_json::Number::from(0)),
+        }
+    }
+}
+
+/// Parsed as a JSON number, so `5` stays an integer and `0.5` is a float.
+fn parse_number(name: &str, src: &str) -> Result<serde_json::Number, String> {
+    serde_json::from_str(src.trim()).map_err(|_| format!("{} '{}' is not a number", name, src))
+}
+
+#[derive(Serialize, Debug)]
+pub struct IncrementResponseContract {
+    pub value: serde_json::Number,
+}
+
+#[derive(MyHttpInput)]
+pub struct WriteWhereInputContract {
+    #[http_header(name = "ns"; description = "Namespace to work in. Empty or absent means the default namespace")]
+    pub namespace: Option<String>,
+
+    #[http_query(name = "tableName"; description = "Name of a table")]
+    pub table_name: String,
+
+    #[http_query(name = "syncPeriod"; description = "Synchronization period"; default)]
+    pub sync_period: DataSynchronizationPeriod,
+
+    #[http_body_raw(
+        description = "{\"entity\":{...DbEntity...},\"conditions\":[{\"field\":\"Status\",\"op\":\"eq\",\"value\":\"Pending\"}]}. The entity is written only when the stored row meets all the conditions. Ops: eq, ne, gt, gte, lt, lte, exists, notExists; the value is any JSON value"
+    )]
+    pub body: RawDataTyped<WriteWhereHttpContract>,
+}
+
+#[derive(MyHttpInput)]
+pub struct DeleteWhereInputContract {
+    #[http_header(name = "ns"; description = "Namespace to work in. Empty or absent means the default namespace")]
+    pub namespace: Option<String>,
+
+    #[http_query(name = "tableName"; description = "Name of a table")]
+    pub table_name: String,
+
+    #[http_query(name = "syncPeriod"; description = "Synchronization period"; default)]
+    pub sync_period: DataSynchronizationPeriod,
+
+    #[http_body_raw(
+        description = "{\"PartitionKey\":\"pk\",\"RowKey\":\"rk\",\"conditions\":[{\"field\":\"Owner\",\"op\":\"eq\",\"value\":\"me\"}]}. The row is deleted only when it meets all the conditions"
+    )]
+    pub body: RawDataTyped<DeleteWhereHttpContract>,
+}
+
+/// Swagger shape of a conditional write. The body itself is read as
+/// [`WriteWhereBody`]: the entity is any row and a condition value any JSON value.
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct WriteWhereHttpContract {
+    pub entity: BaseDbRowContract,
+    pub conditions: Vec<FieldConditionHttpContract>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct DeleteWhereHttpContract {
+    #[serde(rename = "PartitionKey")]
+    pub partition_key: String,
+
+    #[serde(rename = "RowKey")]
+    pub row_key: String,
+
+    pub conditions: Vec<FieldConditionHttpContract>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct WriteWhereBody {
+    pub entity: serde_json::Value,
+
+    #[serde(default)]
+    pub conditions: Vec<FieldCondition>,
+}
+
+impl WriteWhereBody {
+    pub fn into_row_to_write(
+        self,
+        now: &JsonTimeStamp,
+    ) -> Result<RowToWriteWhere, DbOperationError> {
+        let entity = serde_json::to_vec(&self.entity).unwrap();
+        let db_row = crate::operations::parse_db_json_entity(entity.as_slice(), now)?;
+
+        Ok(RowToWriteWhere {
+            db_row: Arc::new(db_row),
+            conditions: self.conditions,
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteWhereBody {
+    #[serde(rename = "PartitionKey")]
+    pub partition_key: String,
+
+    #[serde(rename = "RowKey")]
+    pub row_key: String,
+
+    #[serde(default)]
+    pub conditions: Vec<FieldCondition>,
+}
+
+impl Into<RowToDeleteWhere> for DeleteWhereBody {
+    fn into(self) -> RowToDeleteWhere {
+        RowToDeleteWhere {
+            partition_key: self.partition_key,
+            row_key: self.row_key,
+            conditions: self.conditions,
+        }
+    }
+}
+
+/// Swagger shape of the `PATCH /api/Row` body. Any field of the row may be in it;
+/// `Expires` is listed as the one every row understands.
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct MergePatchHttpContract {
+    #[serde(rename = "Expires")]
+    pub expires: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
 pub struct BaseDbRowContract {
     #[serde(rename = "PartitionKey")]
@@ -103,6 +299,9 @@ pub struct GetRowInputModel {
     #[http_query(name = "skip"; description = "Skip amount of records before start collecting them")]
     pub skip: Option<usize>,
 
+    #[http_query(name = "fields"; description = "Comma-separated top-level or dotted-path fields to return instead of the whole row. PartitionKey, RowKey and TimeStamp are always returned")]
+    pub fields: Option<String>,
+
     #[http_header(name ="updatePartitionLastReadTime"; description = "Update partition last read time")]
     pub update_partition_last_read_access_time: Option<bool>,
 
@@ -120,6 +319,10 @@ pub struct GetRowInputModel {
 }
 
 impl GetRowInputModel {
+    pub fn get_fields_projection(&self) -> Option<FieldsProjection> {
+        FieldsProjection::parse(self.fields.as_deref())
+    }
+
     pub fn get_update_statistics(&self) -> UpdateStatistics {
         UpdateStatistics {
             update_partition_last_read_access_time: if let Some(value) =
@@ -0,0 +1,94 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult};
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
+
+use crate::app::AppContext;
+use crate::db_sync::EventSource;
+
+use super::models::{BaseDbRowContract, PatchRowInputModel};
+
+#[http_route(
+    method: "PATCH",
+    route: "/api/Row",
+    controller: "Row",
+    description: "Applies a JSON merge patch to a row",
+    summary: "Changes only the fields sent in the body (RFC 7386). With timeStamp the patch is applied only when the row is still at that version",
+    input_data: "PatchRowInputModel",
+    result:[
+        {status_code: 200, description: "Patched row",  model:"BaseDbRowContract"},
+        {status_code: 400, description: "Table not found, the body is not a JSON object, it changes PartitionKey or RowKey, or timeStamp is not a valid TimeStamp"},
+        {status_code: 404, description: "Row not found"},
+        {status_code: 409, description: "Row has been changed since it was read"},
+    ]
+)]
+pub struct PatchRowAction {
+    app: Arc<AppContext>,
+}
+
+impl PatchRowAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &PatchRowAction,
+    http_input: PatchRowInputModel,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let db_namespace = crate::http_server::get_request_namespace_existing(&action.app, ctx).await?;
+
+    let db_table = crate::db_operations::read::table::get(
+        action.app.as_ref(),
+        &db_namespace,
+        http_input.table_name.as_ref(),
+    )
+    .await?;
+
+    let expected_time_stamp = match http_input.time_stamp.as_deref() {
+        Some(time_stamp) => {
+            let Some(expected_time_stamp) =
+                my_no_sql_sdk::abstractions::parse_time_stamp(time_stamp)
+            else {
+                return Err(HttpFailResult::as_validation_error(format!(
+                    "'{}' is not a valid TimeStamp",
+                    time_stamp
+                )));
+            };
+
+            Some(expected_time_stamp)
+        }
+        None => None,
+    };
+
+    let patch: serde_json::Value = match serde_json::from_slice(http_input.body.as_slice()) {
+        Ok(patch) => patch,
+        Err(err) => {
+            return Err(HttpFailResult::as_validation_error(format!(
+                "Invalid body: {}",
+                err
+            )));
+        }
+    };
+
+    let event_src = EventSource::as_client_request(action.app.as_ref());
+
+    let now = JsonTimeStamp::now();
+
+    crate::db_operations::write::merge_patch::execute(
+        action.app.as_ref(),
+        &db_namespace,
+        &db_table,
+        http_input.partition_key,
+        http_input.row_key,
+        &patch,
+        expected_time_stamp,
+        event_src,
+        http_input.sync_period.get_sync_moment(),
+        &now,
+    )
+    .await?
+    .into()
+}
@@ -0,0 +1,80 @@
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult};
+use std::sync::Arc;
+
+use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
+
+use crate::app::AppContext;
+use crate::db_operations::write::write_where::MissingRow;
+use crate::db_sync::EventSource;
+
+use super::models::{BaseDbRowContract, WriteWhereBody, WriteWhereInputContract};
+
+#[http_route(
+    method: "PUT",
+    route: "/api/Row/ReplaceWhere",
+    controller: "Row",
+    description: "Replaces the entity only when the stored row meets the conditions",
+    summary: "The conditions are checked against the stored row under the table write lock. A row which does not meet them answers 409 and stays as it is",
+    input_data: "WriteWhereInputContract",
+    result:[
+        {status_code: 200, description: "Written row",  model:"BaseDbRowContract"},
+        {status_code: 400, description: "Table not found, or the body is not a valid entity with conditions"},
+        {status_code: 404, description: "Row not found"},
+        {status_code: 409, description: "The stored row does not meet a condition"},
+    ]
+)]
+pub struct ReplaceWhereAction {
+    app: Arc<AppContext>,
+}
+
+impl ReplaceWhereAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &ReplaceWhereAction,
+    input_data: WriteWhereInputContract,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let db_namespace = crate::http_server::get_request_namespace(&action.app, ctx).await?;
+
+    let db_table = crate::db_operations::read::table::get(
+        action.app.as_ref(),
+        &db_namespace,
+        input_data.table_name.as_str(),
+    )
+    .await?;
+
+    let body: WriteWhereBody = match serde_json::from_slice(input_data.body.as_slice()) {
+        Ok(body) => body,
+        Err(err) => {
+            return Err(HttpFailResult::as_validation_error(format!(
+                "Invalid body: {}",
+                err
+            )));
+        }
+    };
+
+    let now = JsonTimeStamp::now();
+
+    let row = body.into_row_to_write(&now)?;
+
+    let event_src = EventSource::as_client_request(action.app.as_ref());
+
+    crate::db_operations::write::write_where::write(
+        action.app.as_ref(),
+        &db_namespace,
+        &db_table,
+        vec![row],
+        MissingRow::Skip,
+        event_src,
+        input_data.sync_period.get_sync_moment(),
+        now.date_time,
+    )
+    .await?
+    .into_single_row()?
+    .into()
+}
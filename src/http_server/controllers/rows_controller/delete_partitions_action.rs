@@ -3,7 +3,7 @@ use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 use std::sync::Arc;
 
-use crate::{app::AppContext, db_sync::EventSource};
+use crate::{app::AppContext, audit_log::AuditRecord, db_sync::EventSource};
 
 use super::models::DeletePartitionsInputContract;
 
@@ -43,6 +43,10 @@ async fn handle_request(
     )
     .await?;
 
+    let audit_record = AuditRecord::new("DeletePartitions")
+        .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+        .with_partition_keys(input_data.partition_keys.iter().cloned());
+
     let event_src = EventSource::as_client_request(action.app.as_ref());
 
     let now = DateTimeAsMicroseconds::now();
@@ -58,5 +62,7 @@ async fn handle_request(
     )
     .await?;
 
+    crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+
     HttpOutput::Empty.into_ok_result(true).into()
 }
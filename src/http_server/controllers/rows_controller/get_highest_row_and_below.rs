@@ -63,6 +63,7 @@ async fn handle_request(
         &input_data.partition_key,
         &input_data.row_key,
         limit,
+        input_data.get_fields_projection().as_ref(),
         input_data.get_update_statistics(),
         DateTimeAsMicroseconds::now(),
     )
@@ -50,6 +50,7 @@ async fn handle_request(
         &db_table,
         &input_data.partition_key,
         row_keys,
+        input_data.get_fields_projection().as_ref(),
         input_data.get_update_statistics(),
         DateTimeAsMicroseconds::now(),
     )
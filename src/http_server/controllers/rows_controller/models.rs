@@ -2,7 +2,8 @@ use my_http_server::macros::*;
 use my_http_server::RawDataTyped;
 
 use crate::{
-    db_operations::UpdateStatistics, db_sync::DataSynchronizationPeriod,
+    db_operations::{read::FieldsProjection, UpdateStatistics},
+    db_sync::DataSynchronizationPeriod,
     http_server::controllers::mappers::ToSetExpirationTime,
 };
 #[derive(MyHttpInput)]
@@ -21,6 +22,9 @@ pub struct GetHighestRowsAndBelowInputContract {
     #[http_query(name = "maxAmount"; description = "Limit amount of records we are going to get")]
     pub max_amount: Option<usize>,
 
+    #[http_query(name = "fields"; description = "Comma-separated top-level or dotted-path fields to return instead of the whole row. PartitionKey, RowKey and TimeStamp are always returned")]
+    pub fields: Option<String>,
+
     #[http_header(name ="updatePartitionLastReadTime"; description = "Update partition last read time")]
     pub update_partition_last_read_access_time: Option<bool>,
 
@@ -38,6 +42,10 @@ pub struct GetHighestRowsAndBelowInputContract {
 }
 
 impl GetHighestRowsAndBelowInputContract {
+    pub fn get_fields_projection(&self) -> Option<FieldsProjection> {
+        FieldsProjection::parse(self.fields.as_deref())
+    }
+
     pub fn get_update_statistics(&self) -> UpdateStatistics {
         UpdateStatistics {
             update_partition_last_read_access_time: if let Some(value) =
@@ -73,6 +81,9 @@ pub struct GetSinglePartitionMultipleRowsActionInputContract {
     #[http_body_raw(description = "Row keys")]
     pub body: RawDataTyped<Vec<String>>,
 
+    #[http_query(name = "fields"; description = "Comma-separated top-level or dotted-path fields to return instead of the whole row. PartitionKey, RowKey and TimeStamp are always returned")]
+    pub fields: Option<String>,
+
     #[http_header(name ="updatePartitionLastReadTime"; description = "Update partition last read time")]
     pub update_partition_last_read_access_time: Option<bool>,
 
@@ -87,6 +98,10 @@ pub struct GetSinglePartitionMultipleRowsActionInputContract {
 }
 
 impl GetSinglePartitionMultipleRowsActionInputContract {
+    pub fn get_fields_projection(&self) -> Option<FieldsProjection> {
+        FieldsProjection::parse(self.fields.as_deref())
+    }
+
     pub fn get_update_statistics(&self) -> UpdateStatistics {
         UpdateStatistics {
             update_partition_last_read_access_time: if let Some(value) =
@@ -178,6 +178,9 @@ async fn get_readers(app: &AppContext) -> (Vec<ReaderModel>, usize, usize, usize
         match &data_reader.connection {
             crate::data_readers::DataReaderConnection::Tcp(_) => tcp_count += 1,
             crate::data_readers::DataReaderConnection::Http(_) => http_count += 1,
+            // Pushed to like a TCP reader.
+            crate::data_readers::DataReaderConnection::WebSocket(_) => tcp_count += 1,
+            crate::data_readers::DataReaderConnection::Grpc(_) => tcp_count += 1,
         }
 
         let (_, outgoing) = data_reader.get_traffic_per_second();
@@ -20,7 +20,7 @@ impl NonInitializedModel {
     pub async fn new(app: &AppContext) -> Self {
         let now = DateTimeAsMicroseconds::now();
 
-        let state = app.init_state.clone().await;
+        let state = app.init_state.get();
 
         Self {
             tables_total: state.total_tables,
@@ -9,6 +9,17 @@ pub struct LocationModel {
     pub compress: bool,
 }
 
+/// What the operations of one kind added up to since the start. The UI turns two
+/// of these in a row into a rate and an average latency.
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct OperationStatsModel {
+    pub kind: String,
+    pub count: u64,
+    pub errors: u64,
+    #[serde(rename = "durationMicros")]
+    pub duration_micros: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
 pub struct StatusBarModel {
     pub location: LocationModel,
@@ -30,6 +41,7 @@ pub struct StatusBarModel {
     pub write_payloads_per_second: usize,
     #[serde(rename = "writeBytesPerSecond")]
     pub write_bytes_per_second: usize,
+    pub operations: Vec<OperationStatsModel>,
 }
 
 impl StatusBarModel {
@@ -44,8 +56,8 @@ impl StatusBarModel {
         Self {
             master_node: None,
             location: LocationModel {
-                id: app.settings.location.to_string(),
-                compress: app.settings.compress_data,
+                id: app.settings.load().location.to_string(),
+                compress: app.settings.load().compress_data,
             },
             persist_amount: app.get_persist_amount(),
             tcp_connections,
@@ -55,6 +67,17 @@ impl StatusBarModel {
             read_per_second,
             write_payloads_per_second: app.write_payloads_per_second.get_value(),
             write_bytes_per_second: app.write_bytes_per_second.get_value(),
+            operations: app
+                .metrics
+                .get_operations_stats()
+                .into_iter()
+                .map(|itm| OperationStatsModel {
+                    kind: itm.kind.as_str().to_string(),
+                    count: itm.count,
+                    errors: itm.errors,
+                    duration_micros: itm.duration_micros,
+                })
+                .collect(),
         }
     }
 }
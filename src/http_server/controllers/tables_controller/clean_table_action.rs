@@ -2,7 +2,7 @@ use my_http_server::macros::*;
 use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use std::sync::Arc;
 
-use crate::{app::AppContext, db_sync::EventSource};
+use crate::{app::AppContext, audit_log::AuditRecord, db_sync::EventSource};
 
 use super::models::CleanTableContract;
 
@@ -41,6 +41,8 @@ async fn handle_request(
     )
     .await?;
 
+    let rows = db_table.data.read().get_rows_amount();
+
     let event_src = EventSource::as_client_request(action.app.as_ref());
 
     crate::db_operations::write::clean_table(
@@ -52,5 +54,14 @@ async fn handle_request(
     )
     .await?;
 
+    crate::http_server::write_audit(
+        &action.app,
+        ctx,
+        AuditRecord::new("CleanTable")
+            .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+            .with_rows(rows),
+    )
+    .await;
+
     return HttpOutput::Empty.into_ok_result(true);
 }
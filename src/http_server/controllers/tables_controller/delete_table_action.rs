@@ -2,7 +2,7 @@ use my_http_server::macros::*;
 use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 use std::sync::Arc;
 
-use crate::{app::AppContext, db_sync::EventSource};
+use crate::{app::AppContext, audit_log::AuditRecord, db_sync::EventSource};
 
 use super::{super::super::contracts::input_params::*, models::DeleteTableContract};
 
@@ -36,12 +36,17 @@ async fn handle_request(
     // The api key is checked BEFORE the namespace is resolved: resolving it
     // creates the namespace, so doing it first would let an unauthorized caller
     // leave a folder on disk with every rejected request.
-    if input_data.api_key != action.app.settings.table_api_key.as_str() {
+    if input_data.api_key != action.app.settings.load().table_api_key.as_str() {
         return Err(HttpFailResult::as_unauthorized(None));
     }
 
     let db_namespace = crate::http_server::get_request_namespace_existing(&action.app, ctx).await?;
 
+    let rows = get_rows_amount(&action.app, &db_namespace, input_data.table_name.as_str()).await;
+
+    let audit_record = AuditRecord::new("DeleteTable")
+        .with_table(db_namespace.name.as_str(), input_data.table_name.as_str());
+
     let event_src = EventSource::as_client_request(action.app.as_ref());
 
     crate::db_operations::write::table::delete(
@@ -53,5 +58,25 @@ async fn handle_request(
     )
     .await?;
 
+    let audit_record = match rows {
+        Some(rows) => audit_record.with_rows(rows),
+        None => audit_record,
+    };
+
+    crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+
     return HttpOutput::Empty.into_ok_result(true);
 }
+
+async fn get_rows_amount(
+    app: &AppContext,
+    db_namespace: &Arc<crate::app::DbNamespace>,
+    table_name: &str,
+) -> Option<usize> {
+    let db_table = crate::db_operations::read::table::get(app, db_namespace, table_name)
+        .await
+        .ok()?;
+
+    let rows = db_table.data.read().get_rows_amount();
+    Some(rows)
+}
@@ -1,7 +1,7 @@
 use crate::{app::AppContext, db_sync::EventSource};
 use my_http_server::macros::*;
 use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
-use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
 use std::sync::Arc;
 
 use super::models::ProcessTransactionInputModel;
@@ -31,19 +31,23 @@ impl CommitTransactionAction {
 async fn handle_request(
     action: &CommitTransactionAction,
     input_model: ProcessTransactionInputModel,
-    _ctx: &mut HttpContext,
+    ctx: &mut HttpContext,
 ) -> Result<HttpOkResult, HttpFailResult> {
     let even_src = EventSource::as_client_request(action.app.as_ref());
 
-    let now = DateTimeAsMicroseconds::now();
-    crate::db_operations::transactions::commit(
+    let now = JsonTimeStamp::now();
+    let audit_records = crate::db_operations::transactions::commit(
         action.app.as_ref(),
         input_model.transaction_id.as_ref(),
         even_src,
         crate::db_sync::DataSynchronizationPeriod::Sec1.get_sync_moment(),
-        now,
+        &now,
     )
     .await?;
 
+    for audit_record in audit_records {
+        crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+    }
+
     return HttpOutput::Empty.into_ok_result(true).into();
 }
@@ -31,7 +31,8 @@ async fn handle_request(
     action: &GetUiSettingsAction,
     _ctx: &mut HttpContext,
 ) -> Result<HttpOkResult, HttpFailResult> {
-    let model = super::storage::load(action.app.settings.persistence_dest.as_str()).await;
+    let model =
+        super::storage::load(action.app.settings.load_full().persistence_dest.as_str()).await;
     let public = SettingsPublicModel::new(&model, action.app.as_ref());
     HttpOutput::as_json(public).into_ok_result(false).into()
 }
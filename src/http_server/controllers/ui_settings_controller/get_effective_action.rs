@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use crate::app::AppContext;
+
+use super::models::EffectiveSettingsModel;
+
+#[http_route(
+    method: "GET",
+    route: "/api/Settings/Effective",
+    controller: "Settings",
+    description: "Returns the settings the server runs with - the settings file with the environment variables and the command line arguments over it, and the reloads since the start. Tells which settings a reload applies, and which changed ones wait for a restart. TableApiKey is not shown.",
+    summary: "Read effective server settings",
+    result:[
+        {status_code: 200, description: "Effective settings", model: "EffectiveSettingsModel"},
+    ]
+)]
+pub struct GetEffectiveSettingsAction {
+    app: Arc<AppContext>,
+}
+
+impl GetEffectiveSettingsAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetEffectiveSettingsAction,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    HttpOutput::as_json(EffectiveSettingsModel::new(action.app.as_ref()))
+        .into_ok_result(false)
+        .into()
+}
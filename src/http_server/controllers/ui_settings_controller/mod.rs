@@ -1,11 +1,15 @@
 mod get_action;
+mod get_effective_action;
 mod models;
 mod post_action;
+mod reload_action;
 mod set_mcp_writes_action;
 mod set_ui_writes_action;
 pub mod storage;
 
 pub use get_action::GetUiSettingsAction;
+pub use get_effective_action::GetEffectiveSettingsAction;
 pub use post_action::PostUiSettingsAction;
+pub use reload_action::ReloadSettingsAction;
 pub use set_mcp_writes_action::SetMcpWritesAction;
 pub use set_ui_writes_action::SetUiWritesAction;
@@ -3,6 +3,7 @@ use my_http_server::RawDataTyped;
 use serde::{Deserialize, Serialize};
 
 use crate::app::AppContext;
+use crate::settings_reload::SettingsReloadResult;
 
 const DEFAULT_WARN_MS: u32 = 3_000;
 const DEFAULT_BAD_MS: u32 = 10_000;
@@ -118,3 +119,85 @@ pub struct UiWritesInput {
     )]
     pub body: RawDataTyped<UiWritesBody>,
 }
+
+/// Response of POST `/api/Settings/Reload`.
+#[derive(Serialize, Deserialize, Debug, Clone, MyHttpObjectStructure)]
+pub struct SettingsReloadModel {
+    /// Runtime settings which changed and are in effect now.
+    #[serde(rename = "applied")]
+    pub applied: Vec<String>,
+    /// Settings which differ from the ones in effect until the server is restarted.
+    #[serde(rename = "restartRequired")]
+    pub restart_required: Vec<String>,
+}
+
+impl SettingsReloadModel {
+    pub fn new(result: SettingsReloadResult) -> Self {
+        Self {
+            applied: result.applied,
+            restart_required: result.restart_required,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, MyHttpObjectStructure)]
+pub struct EffectiveSettingModel {
+    #[serde(rename = "name")]
+    pub name: String,
+    /// Text as it is, a section as JSON. `None` - not set.
+    #[serde(rename = "value")]
+    pub value: Option<String>,
+    /// A reload applies it.
+    #[serde(rename = "runtime")]
+    pub runtime: bool,
+    /// Changed in the file since the start; a restart applies it.
+    #[serde(rename = "restartRequired")]
+    pub restart_required: bool,
+}
+
+/// Wire shape returned by GET `/api/Settings/Effective`: the settings the server
+/// runs with, by their names in the settings file.
+#[derive(Serialize, Deserialize, Debug, Clone, MyHttpObjectStructure)]
+pub struct EffectiveSettingsModel {
+    #[serde(rename = "file")]
+    pub file: String,
+    #[serde(rename = "settings")]
+    pub settings: Vec<EffectiveSettingModel>,
+}
+
+impl EffectiveSettingsModel {
+    pub fn new(app: &AppContext) -> Self {
+        let restart_required = app.settings_restart_required.lock().unwrap().clone();
+
+        let settings = match serde_json::to_value(app.settings.load().as_ref()) {
+            Ok(serde_json::Value::Object(settings)) => settings,
+            _ => serde_json::Map::new(),
+        };
+
+        let settings = settings
+            .into_iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    serde_json::Value::Null => None,
+                    _ if crate::settings_reload::SECRET_SETTINGS.contains(&name.as_str()) => {
+                        Some("***".to_string())
+                    }
+                    serde_json::Value::String(value) => Some(value),
+                    value => Some(value.to_string()),
+                };
+
+                EffectiveSettingModel {
+                    runtime: crate::settings_reload::is_runtime_setting(name.as_str()),
+                    restart_required: restart_required.contains(&name),
+                    name,
+                    value,
+                }
+            })
+            .collect();
+
+        Self {
+            file: app.settings_sources.get_file_name().to_string(),
+            settings,
+        }
+    }
+}
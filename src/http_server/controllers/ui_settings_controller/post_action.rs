@@ -43,7 +43,8 @@ async fn handle_request(
         }
     };
 
-    let mut current = super::storage::load(action.app.settings.persistence_dest.as_str()).await;
+    let mut current =
+        super::storage::load(action.app.settings.load_full().persistence_dest.as_str()).await;
 
     if let Some(v) = patch.warn_ms {
         current.warn_ms = v;
@@ -54,8 +55,11 @@ async fn handle_request(
 
     let sanitized = current.sanitized();
 
-    if let Err(err) =
-        super::storage::save(action.app.settings.persistence_dest.as_str(), &sanitized).await
+    if let Err(err) = super::storage::save(
+        action.app.settings.load_full().persistence_dest.as_str(),
+        &sanitized,
+    )
+    .await
     {
         return Err(HttpFailResult::as_validation_error(format!(
             "Failed to save settings: {}",
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use my_http_server::macros::*;
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
+
+use super::models::SettingsReloadModel;
+
+#[http_route(
+    method: "POST",
+    route: "/api/Settings/Reload",
+    controller: "Settings",
+    description: "Reads the settings file again and applies BackupIntervalHours, MaxBackupsToKeep, TcpSendTimeoutSec, CompressData and AutoCreateTableOnReaderSubscribe with no restart. The other settings which changed are reported as waiting for a restart. Settings which do not pass the checks of the start are rejected, and nothing is changed.",
+    summary: "Reload server settings",
+    result:[
+        {status_code: 200, description: "Applied settings and the ones waiting for a restart", model: "SettingsReloadModel"},
+        {status_code: 400, description: "The settings do not read"},
+    ]
+)]
+pub struct ReloadSettingsAction {
+    app: Arc<AppContext>,
+}
+
+impl ReloadSettingsAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &ReloadSettingsAction,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let result = crate::operations::reload_settings(action.app.as_ref())
+        .await
+        .map_err(HttpFailResult::as_validation_error)?;
+
+    let mut audit_record = AuditRecord::new("ReloadSettings");
+
+    if !result.applied.is_empty() {
+        audit_record = audit_record.with_details(format!("applied: {}", result.applied.join(", ")));
+    }
+
+    crate::http_server::write_audit(&action.app, ctx, audit_record).await;
+
+    HttpOutput::as_json(SettingsReloadModel::new(result))
+        .into_ok_result(false)
+        .into()
+}
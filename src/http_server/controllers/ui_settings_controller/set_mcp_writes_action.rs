@@ -4,6 +4,7 @@ use my_http_server::macros::*;
 use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 
 use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
 
 use super::models::{McpWritesBody, McpWritesInput, SettingsPublicModel};
 
@@ -31,7 +32,7 @@ impl SetMcpWritesAction {
 async fn handle_request(
     action: &SetMcpWritesAction,
     input_data: McpWritesInput,
-    _ctx: &mut HttpContext,
+    ctx: &mut HttpContext,
 ) -> Result<HttpOkResult, HttpFailResult> {
     let body: McpWritesBody = match serde_json::from_slice(input_data.body.as_slice()) {
         Ok(b) => b,
@@ -49,7 +50,16 @@ async fn handle_request(
         action.app.disable_mcp_writes();
     }
 
-    let settings = super::storage::load(action.app.settings.persistence_dest.as_str()).await;
+    let operation = if body.enabled {
+        "EnableMcpWrites"
+    } else {
+        "DisableMcpWrites"
+    };
+
+    crate::http_server::write_audit(&action.app, ctx, AuditRecord::new(operation)).await;
+
+    let settings =
+        super::storage::load(action.app.settings.load_full().persistence_dest.as_str()).await;
     HttpOutput::as_json(SettingsPublicModel::new(&settings, action.app.as_ref()))
         .into_ok_result(false)
         .into()
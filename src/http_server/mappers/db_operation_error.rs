@@ -46,6 +46,12 @@ impl From<DbOperationError> for HttpFailResult {
                 content: format!("Record is changed").into_bytes(),
             }
             .into_http_fail_result(false, false),
+            DbOperationError::ConditionNotMet(field) => HttpOutput::Content {
+                headers: WebContentType::Json.into(),
+                status_code: 409,
+                content: format!("Condition on field '{}' is not met", field).into_bytes(),
+            }
+            .into_http_fail_result(false, false),
             DbOperationError::RecordAlreadyExists => {
                 let err_model = OperationFailHttpContract {
                     reason: OperationFailReason::RecordAlreadyExists,
@@ -116,6 +122,62 @@ impl From<DbOperationError> for HttpFailResult {
                 }
                 .into_http_fail_result(true, true)
             }
+            DbOperationError::InvalidMergePatch(reason) => {
+                let err_model = OperationFailHttpContract {
+                    reason: OperationFailReason::JsonParseFail,
+                    message: format!("Invalid merge patch: {}", reason),
+                };
+
+                let content = serde_json::to_vec(&err_model).unwrap();
+                HttpOutput::Content {
+                    headers: WebContentType::Json.into(),
+                    status_code: OPERATION_FAIL_HTTP_STATUS_CODE,
+                    content,
+                }
+                .into_http_fail_result(true, true)
+            }
+            DbOperationError::FieldIsNotNumeric(field) => {
+                let err_model = OperationFailHttpContract {
+                    reason: OperationFailReason::JsonParseFail,
+                    message: format!("Field '{}' can not be incremented", field),
+                };
+
+                let content = serde_json::to_vec(&err_model).unwrap();
+                HttpOutput::Content {
+                    headers: WebContentType::Json.into(),
+                    status_code: OPERATION_FAIL_HTTP_STATUS_CODE,
+                    content,
+                }
+                .into_http_fail_result(true, true)
+            }
+            DbOperationError::Unauthorized(message) => {
+                let err_model = OperationFailHttpContract {
+                    reason: OperationFailReason::Unauthorized,
+                    message,
+                };
+
+                let content = serde_json::to_vec(&err_model).unwrap();
+                HttpOutput::Content {
+                    headers: WebContentType::Json.into(),
+                    status_code: 401,
+                    content,
+                }
+                .into_http_fail_result(true, true)
+            }
+            DbOperationError::AccessDenied(message) => {
+                let err_model = OperationFailHttpContract {
+                    reason: OperationFailReason::AccessDenied,
+                    message,
+                };
+
+                let content = serde_json::to_vec(&err_model).unwrap();
+                HttpOutput::Content {
+                    headers: WebContentType::Json.into(),
+                    status_code: 403,
+                    content,
+                }
+                .into_http_fail_result(true, true)
+            }
             DbOperationError::DbEntityParseFail(src) => {
                 from_db_entity_parse_fail_to_http_result(src)
             }
@@ -15,6 +15,10 @@ pub enum OperationFailReason {
     JsonParseFail,
     #[http_enum_case(id = -6; description = "Namespace not found")]
     NamespaceNotFound,
+    #[http_enum_case(id = -7; description = "Access denied")]
+    AccessDenied,
+    #[http_enum_case(id = -8; description = "Unauthorized")]
+    Unauthorized,
 }
 
 #[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
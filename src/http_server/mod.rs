@@ -1,3 +1,7 @@
+mod api_keys_middleware;
+pub use api_keys_middleware::ApiKeysMiddleware;
+mod audit;
+pub use audit::*;
 mod contracts;
 pub mod controllers;
 mod get_table;
@@ -5,6 +9,12 @@ mod http_sessions;
 mod mappers;
 mod namespace;
 pub use namespace::*;
+mod query_string;
+pub use query_string::*;
 pub mod start_up;
 mod statistics_middleware;
 pub use statistics_middleware::StatisticsMiddleware;
+mod tls_peers_middleware;
+pub use tls_peers_middleware::TlsPeersMiddleware;
+mod web_socket_reader_events;
+pub use web_socket_reader_events::*;
@@ -0,0 +1,90 @@
+use my_http_server::HttpContext;
+
+/// Value of a query parameter, decoded the way the input models of the actions
+/// decode it. `Err` - the parameter is given more than once, which would let a check
+/// made on one of them pass for the action which reads the other.
+pub fn get_query_param(ctx: &HttpContext, name: &str) -> Result<Option<String>, String> {
+    let Some(query) = ctx.request.get_uri().query() else {
+        return Ok(None);
+    };
+
+    let mut result = None;
+    let mut found = false;
+
+    for pair in query.split('&') {
+        let Some((key, value)) = pair.split_once('=') else {
+            continue;
+        };
+
+        if !key.eq_ignore_ascii_case(name) {
+            continue;
+        }
+
+        if found {
+            return Err(format!("Query parameter {} is given more than once", name));
+        }
+
+        found = true;
+
+        if !value.is_empty() {
+            result = Some(decode_query_value(value)?);
+        }
+    }
+
+    Ok(result)
+}
+
+/// `%XX` to the byte it stands for, and `+` to a space.
+pub fn decode_query_value(value: &str) -> Result<String, String> {
+    let src = value.as_bytes();
+    let mut result = Vec::with_capacity(src.len());
+
+    let mut i = 0;
+    while i < src.len() {
+        match src[i] {
+            b'%' => {
+                let byte = src
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+                let Some(byte) = byte else {
+                    return Err(format!("Invalid escape in query value '{}'", value));
+                };
+
+                result.push(byte);
+                i += 3;
+            }
+            b'+' => {
+                result.push(b' ');
+                i += 1;
+            }
+            byte => {
+                result.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8(result).map_err(|_| format!("Invalid escape in query value '{}'", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_query_value() {
+        assert_eq!("orders", decode_query_value("orders").unwrap());
+        assert_eq!("a&b=c", decode_query_value("a%26b%3Dc").unwrap());
+        assert_eq!("a b", decode_query_value("a+b").unwrap());
+        assert_eq!(
+            "ключ",
+            decode_query_value("%D0%BA%D0%BB%D1%8E%D1%87").unwrap()
+        );
+
+        assert!(decode_query_value("a%2").is_err());
+        assert!(decode_query_value("a%zz").is_err());
+        assert!(decode_query_value("%ff").is_err());
+    }
+}
@@ -1,13 +1,20 @@
-use std::{net::SocketAddr, sync::Arc};
+use std::sync::Arc;
 
 use mcp_server_middleware::McpMiddleware;
 use my_http_server::controllers::swagger::SwaggerMiddleware;
+use my_http_server::web_sockets::MyWebSocketsMiddleware;
 use my_http_server::{HttpConnectionsCounter, MyHttpServer};
 
 use crate::app::AppContext;
 
+/// Of `HttpListenAddr` when it is not set.
+pub const DEFAULT_HTTP_PORT: u16 = 5123;
+
 pub async fn setup_server(app: &Arc<AppContext>) -> HttpConnectionsCounter {
-    let http_port = SocketAddr::from(([0, 0, 0, 0], 5123));
+    let http_port = crate::tls::get_listen_addr(
+        app.settings.load().tls.as_ref(),
+        app.settings.load().get_http_listen_addr(),
+    );
     println!("Starting HTTP server at Tcp({:?})", http_port);
     let mut http_server = MyHttpServer::new(http_port);
 
@@ -38,20 +45,34 @@ pub async fn setup_server(app: &Arc<AppContext>) -> HttpConnectionsCounter {
 
     let mcp_middleware = Arc::new(build_mcp_middleware(app).await);
 
-    let statistics_middleware =
-        Arc::new(crate::http_server::StatisticsMiddleware::new(app.clone()));
+    let statistics_middleware = Arc::new(crate::http_server::StatisticsMiddleware::new(
+        app.clone(),
+        controllers.clone(),
+    ));
+
+    let api_keys_middleware = Arc::new(crate::http_server::ApiKeysMiddleware::new(app.clone()));
+
+    let tls_peers_middleware = Arc::new(crate::http_server::TlsPeersMiddleware::new(app.clone()));
+
+    let web_sockets_middleware = Arc::new(MyWebSocketsMiddleware::new(
+        crate::http_server::DATA_READER_WEB_SOCKET_PATH,
+        Arc::new(crate::http_server::WebSocketReaderEvents::new(app.clone())),
+    ));
 
     if let Some(unix_socket_http_server) = unix_socket_http_server.as_mut() {
-        unix_socket_http_server.add_middleware(statistics_middleware.clone());
+        unix_socket_http_server.add_middleware(web_sockets_middleware.clone());
         unix_socket_http_server.add_middleware(swagger_middleware.clone());
-        unix_socket_http_server.add_middleware(controllers.clone());
+        unix_socket_http_server.add_middleware(statistics_middleware.clone());
         unix_socket_http_server.add_middleware(mcp_middleware.clone());
         unix_socket_http_server.add_middleware(static_files_middleware.clone());
     }
 
-    http_server.add_middleware(statistics_middleware);
+    // The statistics middleware hands the request to the controllers itself.
+    http_server.add_middleware(tls_peers_middleware);
+    http_server.add_middleware(api_keys_middleware);
+    http_server.add_middleware(web_sockets_middleware);
     http_server.add_middleware(swagger_middleware);
-    http_server.add_middleware(controllers);
+    http_server.add_middleware(statistics_middleware);
     http_server.add_middleware(mcp_middleware);
     http_server.add_middleware(static_files_middleware);
 
@@ -82,6 +103,10 @@ async fn build_mcp_middleware(app: &Arc<AppContext>) -> McpMiddleware {
         app.clone(),
     )));
 
+    mcp.register_tool_call(Arc::new(crate::mcp::AggregateRowsToolCallHandler::new(
+        app.clone(),
+    )));
+
     mcp.register_tool_call(Arc::new(crate::mcp::DeleteRowToolCallHandler::new(
         app.clone(),
     )));
@@ -1,8 +1,13 @@
 use std::sync::Arc;
 
-use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpServerMiddleware};
+use my_http_server::{
+    controllers::ControllersMiddleware, HttpContext, HttpFailResult, HttpOkResult,
+    HttpServerMiddleware,
+};
 
-use crate::app::AppContext;
+use opentelemetry::{context::FutureExt, trace::SpanKind};
+
+use crate::app::{AppContext, OperationKind, OperationTimer};
 
 // Counts traffic into the write statistics, but ONLY for requests we can
 // attribute to a known writer — i.e. those that replay the `session` id issued
@@ -13,13 +18,22 @@ use crate::app::AppContext;
 // Requests without the `session` header are not counted anywhere — we don't
 // know who they are. Body length is taken from the `Content-Length` header so
 // the body itself is never read here and stays available downstream.
+//
+// It also times every action: it stands where the controllers would, and hands
+// the request to them itself, so it is there when the action is done. An action
+// over its threshold goes to the slow operations with the table, the partition,
+// the payload size and the session of the request - and the rows, if the action
+// reports them with `report_rows`. With tracing on, the action runs under a span
+// of its own - a child of the `traceparent` of the request - which is what the
+// writes, the sync and the readers of it are traced under.
 pub struct StatisticsMiddleware {
     app: Arc<AppContext>,
+    controllers: Arc<ControllersMiddleware>,
 }
 
 impl StatisticsMiddleware {
-    pub fn new(app: Arc<AppContext>) -> Self {
-        Self { app }
+    pub fn new(app: Arc<AppContext>, controllers: Arc<ControllersMiddleware>) -> Self {
+        Self { app, controllers }
     }
 }
 
@@ -38,11 +52,98 @@ impl HttpServerMiddleware for StatisticsMiddleware {
             self.app.writers_traffic.increase(session, body_len);
         }
 
-        None
+        let operation = format!(
+            "{} {}",
+            ctx.request.method.as_str(),
+            get_route_template(ctx)
+        );
+
+        // Owned, as the action is going to borrow the request mutably.
+        let namespace = super::get_request_namespace_name(ctx)
+            .unwrap_or(my_no_sql_sdk::DEFAULT_NAMESPACE)
+            .to_string();
+
+        let table_name = super::get_query_param(ctx, "tableName")
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+
+        // The labels take the table once the action is done - and only if it exists
+        // by then: the query string is whatever the client sent.
+        let mut timer = OperationTimer::new(
+            &self.app,
+            OperationKind::Http,
+            operation.as_str(),
+            UNRESOLVED_LABEL,
+            if table_name.is_empty() {
+                ""
+            } else {
+                UNRESOLVED_LABEL
+            },
+        )
+        .with_partition_key(
+            super::get_query_param(ctx, "partitionKey")
+                .ok()
+                .flatten()
+                .as_deref(),
+        )
+        .with_payload_size(get_content_length(ctx))
+        .with_session(get_session(ctx));
+
+        let span = crate::telemetry::start_span_with_parent(
+            operation.clone(),
+            &crate::telemetry::extract_parent(
+                get_header(ctx, "traceparent"),
+                get_header(ctx, "tracestate"),
+            ),
+            SpanKind::Server,
+        );
+        span.set_attribute("db.namespace", namespace.clone());
+        span.set_attribute("db.table", table_name.clone());
+
+        let Some(result) = timer
+            .run(
+                self.controllers
+                    .handle_request(ctx)
+                    .with_context(span.context()),
+            )
+            .await
+        else {
+            // Not an action - Swagger, the UI files, MCP - so nothing to time.
+            timer.skip();
+            return None;
+        };
+
+        if let Some(db_namespace) = self.app.namespaces.get(namespace.as_str()) {
+            if table_name.is_empty() {
+                timer.set_table(namespace.as_str(), "");
+            } else if db_namespace.db.get_table(table_name.as_str()).is_some() {
+                timer.set_table(namespace.as_str(), table_name.as_str());
+            }
+        }
+
+        match &result {
+            Ok(_) => timer.succeeded(),
+            Err(_) => span.set_error(format!("{} failed", operation)),
+        }
+
+        Some(result)
     }
 }
 
-fn get_session(ctx: &HttpContext) -> Option<&str> {
+/// What `ns` and `table_name` are labeled with when the request names a namespace or
+/// a table which does not exist.
+const UNRESOLVED_LABEL: &str = "-";
+
+/// The route of the action, for the `operation` label. No route of the actions has
+/// a parameter in its path, and the controllers match it regardless of the case, so
+/// the path folded to the lower case is the route - whichever way the client spelled
+/// it.
+fn get_route_template(ctx: &HttpContext) -> String {
+    ctx.request.get_uri().path().to_lowercase()
+}
+
+pub fn get_session(ctx: &HttpContext) -> Option<&str> {
     use my_http_server::HttpRequestHeaders;
 
     ctx.request
@@ -53,6 +154,16 @@ fn get_session(ctx: &HttpContext) -> Option<&str> {
         .filter(|value| !value.is_empty())
 }
 
+fn get_header<'s>(ctx: &'s HttpContext, name: &str) -> Option<&'s str> {
+    use my_http_server::HttpRequestHeaders;
+
+    ctx.request
+        .get_headers()
+        .try_get_case_insensitive_as_str(name)
+        .ok()
+        .flatten()
+}
+
 fn get_content_length(ctx: &HttpContext) -> usize {
     use my_http_server::HttpRequestHeaders;
 
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpServerMiddleware};
+
+use crate::{app::AppContext, db_operations::DbOperationError};
+
+/// With TLS on, the peer of every request of the TCP listener is the TLS terminator.
+/// Puts the address of the client behind it in its place - for the readers, the
+/// writers and the audit log - and refuses the requests which did not come through
+/// TLS. Goes in front of everything else, of the TCP listener only.
+pub struct TlsPeersMiddleware {
+    app: Arc<AppContext>,
+}
+
+impl TlsPeersMiddleware {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpServerMiddleware for TlsPeersMiddleware {
+    async fn handle_request(
+        &self,
+        ctx: &mut HttpContext,
+    ) -> Option<Result<HttpOkResult, HttpFailResult>> {
+        match crate::tls::get_peer_addr(self.app.as_ref(), ctx.request.addr) {
+            Ok(peer_addr) => {
+                ctx.request.addr = peer_addr;
+                None
+            }
+            Err(err) => Some(Err(DbOperationError::AccessDenied(err).into())),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::{sync::Arc, time::Duration};
+
+use hyper_tungstenite::tungstenite::Message;
+use my_http_server::{
+    web_sockets::{MyWebSocket, MyWebSocketCallback, WebSocketMessage},
+    HttpFailResult,
+};
+use my_logger::LogEventCtx;
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use my_no_sql_sdk::tcp_contracts::MyNoSqlTcpContract;
+
+use crate::{
+    app::AppContext,
+    data_readers::{
+        tcp_connection::ReaderName, web_socket_connection::web_socket_frames, DataReader,
+        DataReaderConnection,
+    },
+    tcp::reader_contracts::ReaderContractsSession,
+};
+
+pub const DATA_READER_WEB_SOCKET_PATH: &str = "/api/DataReader/WebSocket";
+
+/// Readers which can not reach the TCP port - browsers, clients behind an HTTP
+/// proxy - connect here and speak the same contracts, one per binary frame.
+pub struct WebSocketReaderEvents {
+    app: Arc<AppContext>,
+}
+
+impl WebSocketReaderEvents {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+/// A WebSocket the way the contracts see it. `compress` is asked for with
+/// `?compress=true` when the socket is opened - a `Greeting` has no field for it.
+struct WebSocketReaderSession {
+    web_socket: Arc<MyWebSocket>,
+    compress: bool,
+}
+
+#[async_trait::async_trait]
+impl ReaderContractsSession for WebSocketReaderSession {
+    fn get_session_id(&self) -> String {
+        format!("Ws-{}", self.web_socket.id)
+    }
+
+    async fn send(&self, contract: &MyNoSqlTcpContract) {
+        let payload = web_socket_frames::serialize(contract);
+
+        self.web_socket
+            .send_message(Message::Binary(payload.into()))
+            .await;
+    }
+
+    async fn get_data_reader(&self, app: &AppContext) -> Option<Arc<DataReader>> {
+        app.data_readers.get_web_socket(self.web_socket.id).await
+    }
+
+    async fn greeting(&self, app: &AppContext, name: ReaderName, compress: bool) {
+        app.data_readers
+            .add_web_socket(
+                self.web_socket.clone(),
+                name,
+                (compress || self.compress) && app.settings.load().compress_data,
+            )
+            .await;
+    }
+}
+
+#[async_trait::async_trait]
+impl MyWebSocketCallback for WebSocketReaderEvents {
+    async fn connected(
+        &self,
+        my_web_socket: Arc<MyWebSocket>,
+        _disconnect_timeout: Duration,
+    ) -> Result<(), HttpFailResult> {
+        my_logger::LOGGER.write_info(
+            "WebSocketConnection",
+            "Connected",
+            LogEventCtx::new()
+                .add("id", my_web_socket.id.to_string())
+                .add("addr", my_web_socket.addr.to_string()),
+        );
+
+        self.app.metrics.mark_new_web_socket_connection();
+        Ok(())
+    }
+
+    async fn disconnected(&self, my_web_socket: Arc<MyWebSocket>) {
+        let data_reader = self
+            .app
+            .data_readers
+            .remove_web_socket(my_web_socket.id)
+            .await;
+
+        let name = match &data_reader {
+            Some(data_reader) => data_reader.get_name().to_string(),
+            None => "".to_string(),
+        };
+
+        my_logger::LOGGER.write_info(
+            "WebSocketConnection",
+            "Disconnected",
+            LogEventCtx::new()
+                .add("id", my_web_socket.id.to_string())
+                .add("Name", name),
+        );
+
+        if let Some(data_reader) = data_reader {
+            self.app
+                .metrics
+                .remove_pending_to_sync(&data_reader.connection);
+        }
+
+        self.app.metrics.mark_new_web_socket_disconnection();
+    }
+
+    async fn on_message(&self, my_web_socket: Arc<MyWebSocket>, message: WebSocketMessage) {
+        let payload = match message {
+            WebSocketMessage::Binary(payload) => payload,
+            WebSocketMessage::String(_) => {
+                my_web_socket
+                    .send_message(Message::Text("Contracts are sent as binary frames".into()))
+                    .await;
+                return;
+            }
+        };
+
+        if let Some(data_reader) = self.app.data_readers.get_web_socket(my_web_socket.id).await {
+            if let DataReaderConnection::WebSocket(info) = &data_reader.connection {
+                info.mark_incoming(DateTimeAsMicroseconds::now(), payload.len());
+            }
+        }
+
+        let contract = match web_socket_frames::deserialize(payload).await {
+            Ok(contract) => contract,
+            Err(err) => {
+                my_logger::LOGGER.write_info(
+                    "WebSocketConnection",
+                    "Invalid contract",
+                    LogEventCtx::new()
+                        .add("id", my_web_socket.id.to_string())
+                        .add("err", err),
+                );
+                return;
+            }
+        };
+
+        let session = WebSocketReaderSession {
+            compress: is_compress_requested(my_web_socket.as_ref()),
+            web_socket: my_web_socket,
+        };
+
+        crate::tcp::reader_contracts::handle(&self.app, &session, contract).await;
+    }
+}
+
+fn is_compress_requested(web_socket: &MyWebSocket) -> bool {
+    let Some(query_string) = web_socket.query_string.as_ref() else {
+        return false;
+    };
+
+    query_string.split('&').any(|itm| {
+        itm.eq_ignore_ascii_case("compress=true") || itm.eq_ignore_ascii_case("compress=1")
+    })
+}
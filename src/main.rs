@@ -1,19 +1,23 @@
 use app::AppContext;
 use background::{
-    gc_bulk_processes::GcBulkProcesses, gc_db_rows::GcDbRows,
+    gc_audit_log_timer::GcAuditLogTimer, gc_bulk_processes::GcBulkProcesses, gc_db_rows::GcDbRows,
     gc_http_sessions::GcHttpSessionsTimer, gc_multipart::GcMultipart,
-    metrics_updater::MetricsUpdater, persist::PersistTimer, sync::SyncEventLoop, BackupTimer,
-    GcBackupsTimer, VacuumTimer,
+    metrics_updater::MetricsUpdater, persist::PersistTimer, settings_file_timer::SettingsFileTimer,
+    sync::SyncEventLoop, sync_batches_timer::SyncBatchesTimer,
+    tls_certificates_timer::TlsCertificatesTimer, BackupTimer, GcBackupsTimer, VacuumTimer,
 };
 
 use my_no_sql_sdk::core::rust_extensions::MyTimer;
 use my_no_sql_sdk::tcp_contracts::MyNoSqlTcpSerializerFactory;
 use my_tcp_sockets::TcpServer;
-use std::{net::SocketAddr, sync::Arc, time::Duration};
-use tcp::TcpServerEvents;
+use settings_sources::SettingsSources;
+use std::{sync::Arc, time::Duration};
+use tcp::{dead_connections_detector::DeadConnectionsDetector, TcpServerEvents};
+use tls::TlsProtocol;
 mod zip;
 
 mod app;
+mod audit_log;
 mod consts;
 mod files_repo;
 mod grpc;
@@ -27,12 +31,16 @@ mod db_transactions;
 mod http_server;
 mod scripts;
 mod tcp;
+mod tls;
 
 mod background;
 mod data_readers;
 mod mcp;
 mod operations;
 mod settings_reader;
+mod settings_reload;
+mod settings_sources;
+mod telemetry;
 
 pub mod mynosqlserver_grpc {
     tonic::include_proto!("mynosqlserver");
@@ -43,14 +51,37 @@ static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
 #[tokio::main]
 async fn main() {
-    let settings = settings_reader::read_settings().await;
+    let settings_sources = match SettingsSources::from_process() {
+        Ok(sources) => sources,
+        Err(err) => exit_with_error(err),
+    };
+
+    if settings_sources.help {
+        println!("{}", settings_sources::get_usage());
+        return;
+    }
+
+    let settings = match settings_reader::read_settings(&settings_sources).await {
+        Ok(settings) => settings,
+        Err(err) => exit_with_error(err),
+    };
 
     let settings = Arc::new(settings);
 
-    let app = AppContext::new(settings).await;
+    let app = match AppContext::new(settings, settings_sources).await {
+        Ok(app) => app,
+        Err(err) => exit_with_error(err),
+    };
 
     let app = Arc::new(app);
 
+    let tracer_provider = app
+        .settings
+        .load()
+        .tracing
+        .as_ref()
+        .and_then(|settings| telemetry::start_tracing(&app, settings));
+
     app.migrate_backup_folder().await;
 
     tokio::spawn(crate::operations::init::load_tables(app.clone()));
@@ -60,7 +91,10 @@ async fn main() {
     app.sync
         .register_event_loop(Arc::new(SyncEventLoop::new(app.clone())));
 
-    let reader_tcp_addr = SocketAddr::from(([0, 0, 0, 0], 5125));
+    let reader_tcp_addr = tls::get_listen_addr(
+        app.settings.load().tls.as_ref(),
+        app.settings.load().get_reader_tcp_listen_addr(),
+    );
 
     println!("Listening reader at TCP addr: '{}'", reader_tcp_addr);
 
@@ -109,6 +143,25 @@ async fn main() {
         Arc::new(GcHttpSessionsTimer::new(app.clone())),
     );
 
+    if let Some(timeout) = app.settings.load().get_dead_reader_timeout() {
+        timer_10s.register_timer(
+            "DeadConnectionsDetector",
+            Arc::new(DeadConnectionsDetector::new(app.clone(), timeout)),
+        );
+    }
+
+    timer_10s.register_timer(
+        "SettingsFile",
+        Arc::new(SettingsFileTimer::new(app.clone())),
+    );
+
+    if app.tls.is_some() {
+        timer_10s.register_timer(
+            "TlsCertificates",
+            Arc::new(TlsCertificatesTimer::new(app.clone())),
+        );
+    }
+
     let mut timer_30s = MyTimer::new(Duration::from_secs(30));
     timer_30s.register_timer("GcDbRows", Arc::new(GcDbRows::new(app.clone())));
     timer_30s.register_timer("GcMultipart", Arc::new(GcMultipart::new(app.clone())));
@@ -128,8 +181,28 @@ async fn main() {
     backup_timer.register_timer("GcBackups", Arc::new(GcBackupsTimer::new(app.clone())));
     backup_timer.register_timer("Vacuum", Arc::new(VacuumTimer::new(app.clone())));
 
+    if app.audit_log.is_some() {
+        backup_timer.register_timer("GcAuditLog", Arc::new(GcAuditLogTimer::new(app.clone())));
+    }
+
     backup_timer.start(app.states.clone(), my_logger::LOGGER.clone());
 
+    let sync_batches_timer_interval = app
+        .settings
+        .load()
+        .sync_batching
+        .as_ref()
+        .and_then(|sync_batching| sync_batching.get_timer_interval());
+
+    // Kept until shutdown along with the other timers of `main`.
+    let _sync_batches_timer = sync_batches_timer_interval.map(|interval| {
+        let mut sync_batches_timer = MyTimer::new(interval);
+        sync_batches_timer
+            .register_timer("SyncBatches", Arc::new(SyncBatchesTimer::new(app.clone())));
+        sync_batches_timer.start(app.states.clone(), my_logger::LOGGER.clone());
+        sync_batches_timer
+    });
+
     app.sync
         .start(app.states.clone(), my_logger::LOGGER.clone());
 
@@ -153,9 +226,39 @@ async fn main() {
         )
         .await;
 
-    tokio::task::spawn(crate::grpc::server::start(app.clone(), 5124));
+    tokio::task::spawn(crate::grpc::server::start(
+        app.clone(),
+        app.settings.load().get_grpc_listen_addr(),
+    ));
+
+    if app.tls.is_some() {
+        for (addr, protocol) in [
+            (
+                app.settings.load().get_http_listen_addr(),
+                TlsProtocol::Http,
+            ),
+            (
+                app.settings.load().get_reader_tcp_listen_addr(),
+                TlsProtocol::Tcp,
+            ),
+        ] {
+            tokio::spawn(tls::start_tls_terminator(app.clone(), addr, protocol));
+        }
+    }
 
     app.states.wait_until_shutdown().await;
 
     crate::operations::shutdown(&app).await;
+
+    // The spans of the shutdown itself included.
+    if let Some(tracer_provider) = tracer_provider {
+        if let Err(err) = tracer_provider.shutdown() {
+            println!("Can not flush the traces. Err: {}", err);
+        }
+    }
+}
+
+fn exit_with_error(err: String) -> ! {
+    eprintln!("{}", err);
+    std::process::exit(1);
 }
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use mcp_server_middleware::*;
+use my_ai_agent::macros::ApplyJsonSchema;
+use serde::*;
+
+use crate::{
+    app::AppContext,
+    db_operations::{
+        field_conditions::FieldCondition,
+        read::aggregate::{
+            AggregateFunction, AggregateResponseContract, AggregateScope, Aggregation,
+        },
+    },
+};
+
+#[derive(ApplyJsonSchema, Debug, Serialize, Deserialize)]
+pub struct AggregateRowsInputData {
+    #[property(description = "Optional namespace. Empty means the default namespace")]
+    pub namespace: Option<String>,
+    #[property(description = "Name of the table to aggregate")]
+    pub table_name: String,
+    #[property(description = "Optional: aggregate only this partition")]
+    pub partition_key: Option<String>,
+    #[property(
+        description = "Optional: aggregate only the partitions whose key starts with this prefix. Can not be combined with partition_key"
+    )]
+    pub partition_key_prefix: Option<String>,
+    #[property(
+        description = "What to compute: 'count' (rows), or '<op>:<field>' where op is count, sum, min, max or avg - e.g. 'sum:Amount'. Fields may be dotted paths. Empty means ['count']"
+    )]
+    pub aggregations: Vec<String>,
+    #[property(description = "Optional field to group the rows by")]
+    pub group_by: Option<String>,
+    #[property(
+        description = "Optional JSON array of row conditions which all have to be met: [{\"field\":\"Status\",\"op\":\"eq\",\"value\":\"Paid\"}]. Ops: eq, ne, gt, gte, lt, lte, exists, notExists"
+    )]
+    pub filter_json: Option<String>,
+}
+
+#[derive(ApplyJsonSchema, Debug, Serialize, Deserialize)]
+pub struct AggregateRowsResponse {
+    #[property(
+        description = "JSON object: rowsScanned, rowsMatched, truncated and groups - each with its key, rows and the values keyed like 'sum(Amount)'"
+    )]
+    pub result_json: String,
+}
+
+pub struct AggregateRowsToolCallHandler {
+    app: Arc<AppContext>,
+}
+
+impl AggregateRowsToolCallHandler {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+impl ToolDefinition for AggregateRowsToolCallHandler {
+    const FUNC_NAME: &'static str = "aggregate_rows";
+
+    const DESCRIPTION: &'static str = "\
+Counts rows and computes sum, min, max or avg of their fields over a table, a \
+partition or a partition key prefix, optionally grouped by a field and \
+limited by a row filter. Prefer this to get_rows when only numbers are needed: \
+the rows themselves never leave the server.";
+}
+
+#[async_trait::async_trait]
+impl McpToolCall<AggregateRowsInputData, AggregateRowsResponse> for AggregateRowsToolCallHandler {
+    async fn execute_tool_call(
+        &self,
+        model: AggregateRowsInputData,
+    ) -> Result<AggregateRowsResponse, String> {
+        let scope = match (
+            model.partition_key.as_deref(),
+            model.partition_key_prefix.as_deref(),
+        ) {
+            (Some(_), Some(_)) => {
+                return Err(
+                    "partition_key and partition_key_prefix can not be used together".to_string(),
+                );
+            }
+            (Some(partition_key), None) => AggregateScope::Partition(partition_key),
+            (None, Some(prefix)) => AggregateScope::PartitionKeyPrefix(prefix),
+            (None, None) => AggregateScope::Table,
+        };
+
+        let mut aggregations = Vec::with_capacity(model.aggregations.len());
+
+        for aggregation in &model.aggregations {
+            aggregations.push(parse_aggregation(aggregation.as_str())?);
+        }
+
+        if aggregations.is_empty() {
+            aggregations.push(Aggregation {
+                function: AggregateFunction::Count,
+                field: None,
+            });
+        }
+
+        let filter: Vec<FieldCondition> = match model.filter_json.as_deref() {
+            Some(filter_json) if !filter_json.trim().is_empty() => {
+                serde_json::from_str(filter_json)
+                    .map_err(|err| format!("Invalid filter_json: {}", err))?
+            }
+            _ => vec![],
+        };
+
+        let db_namespace = self
+            .app
+            .get_existing_namespace(model.namespace.as_deref())
+            .map_err(|err| format!("{:?}", err))?;
+
+        let table = crate::db_operations::read::table::get(
+            self.app.as_ref(),
+            &db_namespace,
+            &model.table_name,
+        )
+        .await
+        .map_err(|err| format!("{:?}", err))?;
+
+        let result = crate::db_operations::read::aggregate::aggregate(
+            &self.app,
+            &table,
+            scope,
+            filter.as_slice(),
+            model.group_by.as_deref(),
+            aggregations.as_slice(),
+        )
+        .await
+        .map_err(|err| format!("{:?}", err))?;
+
+        let response = AggregateResponseContract::new(result, aggregations.as_slice());
+
+        Ok(AggregateRowsResponse {
+            result_json: serde_json::to_string(&response).map_err(|err| err.to_string())?,
+        })
+    }
+}
+
+/// `count`, `sum:Amount`.
+fn parse_aggregation(src: &str) -> Result<Aggregation, String> {
+    let (op, field) = match src.split_once(':') {
+        Some((op, field)) => (op.trim(), Some(field.trim().to_string())),
+        None => (src.trim(), None),
+    };
+
+    let function = match op {
+        "count" => AggregateFunction::Count,
+        "sum" => AggregateFunction::Sum,
+        "min" => AggregateFunction::Min,
+        "max" => AggregateFunction::Max,
+        "avg" => AggregateFunction::Avg,
+        _ => return Err(format!("Unknown aggregation '{}'", src)),
+    };
+
+    if function != AggregateFunction::Count && field.is_none() {
+        return Err(format!(
+            "Aggregation '{}' needs a field: '{}:<field>'",
+            src, op
+        ));
+    }
+
+    Ok(Aggregation { function, field })
+}
@@ -8,6 +8,7 @@ use serde::*;
 
 use crate::{
     app::AppContext,
+    audit_log::AuditRecord,
     db_operations,
     db_sync::{DataSynchronizationPeriod, EventSource},
 };
@@ -82,6 +83,12 @@ impl McpToolCall<BulkDeleteRowsInputData, BulkDeleteRowsResponse>
                 .map_err(|err| format!("{:?}", err))?;
 
         let rows_submitted = model.row_keys.len();
+
+        let audit_record = AuditRecord::new("BulkDelete")
+            .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+            .with_partition_keys(std::iter::once(model.partition_key.clone()))
+            .with_rows(rows_submitted);
+
         let mut rows_to_delete: BTreeMap<String, Vec<String>> = BTreeMap::new();
         rows_to_delete.insert(model.partition_key, model.row_keys);
 
@@ -100,6 +107,8 @@ impl McpToolCall<BulkDeleteRowsInputData, BulkDeleteRowsResponse>
         .await
         .map_err(|err| format!("{:?}", err))?;
 
+        super::write_gate::write_audit(self.app.as_ref(), audit_record).await;
+
         Ok(BulkDeleteRowsResponse {
             status: "ok".into(),
             rows_submitted,
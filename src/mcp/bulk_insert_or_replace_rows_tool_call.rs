@@ -7,6 +7,7 @@ use serde::*;
 
 use crate::{
     app::AppContext,
+    audit_log::AuditRecord,
     db_operations,
     db_sync::{DataSynchronizationPeriod, EventSource},
 };
@@ -94,6 +95,11 @@ impl McpToolCall<BulkInsertOrReplaceRowsInputData, BulkInsertOrReplaceRowsRespon
         let partitions_affected = rows_by_partition.len();
         let rows_submitted = rows_by_partition.iter().map(|(_, rows)| rows.len()).sum();
 
+        let audit_record = AuditRecord::new("BulkInsertOrReplaceRows")
+            .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+            .with_partition_keys(rows_by_partition.iter().map(|(pk, _)| pk.clone()))
+            .with_rows(rows_submitted);
+
         db_operations::write::bulk_insert_or_update::execute(
             self.app.as_ref(),
             &db_namespace,
@@ -106,6 +112,8 @@ impl McpToolCall<BulkInsertOrReplaceRowsInputData, BulkInsertOrReplaceRowsRespon
         .await
         .map_err(|err| format!("{:?}", err))?;
 
+        super::write_gate::write_audit(self.app.as_ref(), audit_record).await;
+
         Ok(BulkInsertOrReplaceRowsResponse {
             status: "ok".into(),
             rows_submitted,
@@ -6,6 +6,7 @@ use serde::*;
 
 use crate::{
     app::AppContext,
+    audit_log::AuditRecord,
     db_operations,
     db_sync::{DataSynchronizationPeriod, EventSource},
 };
@@ -65,6 +66,8 @@ impl McpToolCall<CleanTableInputData, CleanTableResponse> for CleanTableToolCall
                 .await
                 .map_err(|err| format!("{:?}", err))?;
 
+        let rows = db_table.data.read().get_rows_amount();
+
         let event_src = EventSource::as_client_request(self.app.as_ref());
 
         db_operations::write::clean_table(
@@ -77,6 +80,14 @@ impl McpToolCall<CleanTableInputData, CleanTableResponse> for CleanTableToolCall
         .await
         .map_err(|err| format!("{:?}", err))?;
 
+        super::write_gate::write_audit(
+            self.app.as_ref(),
+            AuditRecord::new("CleanTable")
+                .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+                .with_rows(rows),
+        )
+        .await;
+
         Ok(CleanTableResponse {
             status: "cleaned".into(),
         })
@@ -7,6 +7,7 @@ use serde::*;
 
 use crate::{
     app::AppContext,
+    audit_log::AuditRecord,
     db_operations,
     db_sync::{DataSynchronizationPeriod, EventSource},
 };
@@ -78,6 +79,10 @@ impl McpToolCall<DeletePartitionsInputData, DeletePartitionsResponse>
 
         let partitions_submitted = model.partition_keys.len();
 
+        let audit_record = AuditRecord::new("DeletePartitions")
+            .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+            .with_partition_keys(model.partition_keys.iter().cloned());
+
         let event_src = EventSource::as_client_request(self.app.as_ref());
         let now = DateTimeAsMicroseconds::now();
 
@@ -93,6 +98,8 @@ impl McpToolCall<DeletePartitionsInputData, DeletePartitionsResponse>
         .await
         .map_err(|err| format!("{:?}", err))?;
 
+        super::write_gate::write_audit(self.app.as_ref(), audit_record).await;
+
         Ok(DeletePartitionsResponse {
             status: "ok".into(),
             partitions_submitted,
@@ -7,6 +7,7 @@ use serde::*;
 
 use crate::{
     app::AppContext,
+    audit_log::AuditRecord,
     db_operations,
     db_sync::{DataSynchronizationPeriod, EventSource},
 };
@@ -67,10 +68,15 @@ impl McpToolCall<DeleteRowInputData, DeleteRowResponse> for DeleteRowToolCallHan
                 .await
                 .map_err(|err| format!("{:?}", err))?;
 
+        let audit_record = AuditRecord::new("DeleteRow")
+            .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+            .with_partition_keys(std::iter::once(model.partition_key.clone()))
+            .with_details(format!("RowKey: {}", model.row_key));
+
         let event_src = EventSource::as_client_request(self.app.as_ref());
         let now = DateTimeAsMicroseconds::now();
 
-        db_operations::write::delete_row::execute(
+        let result = db_operations::write::delete_row::execute(
             self.app.as_ref(),
             &db_namespace,
             &db_table,
@@ -83,6 +89,9 @@ impl McpToolCall<DeleteRowInputData, DeleteRowResponse> for DeleteRowToolCallHan
         .await
         .map_err(|err| format!("{:?}", err))?;
 
+        let audit_record = audit_record.with_rows(result.get_rows_amount());
+        super::write_gate::write_audit(self.app.as_ref(), audit_record).await;
+
         Ok(DeleteRowResponse {
             status: "deleted".into(),
         })
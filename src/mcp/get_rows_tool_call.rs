@@ -5,7 +5,10 @@ use my_ai_agent::macros::ApplyJsonSchema;
 use my_no_sql_sdk::core::db_json_entity::JsonTimeStamp;
 use serde::*;
 
-use crate::{app::AppContext, db_operations::UpdateStatistics};
+use crate::{
+    app::AppContext,
+    db_operations::{read::FieldsProjection, UpdateStatistics},
+};
 
 #[derive(ApplyJsonSchema, Debug, Serialize, Deserialize)]
 pub struct GetRowsInputData {
@@ -17,6 +20,10 @@ pub struct GetRowsInputData {
     pub partition_key: Option<String>,
     #[property(description = "Optional row key filter")]
     pub row_key: Option<String>,
+    #[property(
+        description = "Optional list of top-level or dotted-path fields (e.g. Customer.Name) to return instead of whole rows. PartitionKey, RowKey and TimeStamp are always returned"
+    )]
+    pub fields: Option<Vec<String>>,
 }
 
 #[derive(ApplyJsonSchema, Debug, Serialize, Deserialize)]
@@ -41,7 +48,7 @@ impl ToolDefinition for GetRowsToolCallHandler {
     const FUNC_NAME: &'static str = "get_rows";
 
     const DESCRIPTION: &'static str =
-        "Returns rows from a MyNoSql table. Filter by partition_key and/or row_key (both optional).";
+        "Returns rows from a MyNoSql table. Filter by partition_key and/or row_key (both optional). Pass fields to get only the listed fields of each row.";
 }
 
 #[async_trait::async_trait]
@@ -82,9 +89,21 @@ impl McpToolCall<GetRowsInputData, GetRowsResponse> for GetRowsToolCallHandler {
         .await
         .map_err(|err| format!("{:?}", err))?;
 
+        let fields = model
+            .fields
+            .as_ref()
+            .and_then(|fields| FieldsProjection::new(fields.iter().map(|itm| itm.as_str())));
+
         let rows: Vec<String> = db_rows
             .into_iter()
-            .map(|db_row| String::from_utf8_lossy(&db_row.to_vec()).into_owned())
+            .map(|db_row| {
+                let content = match fields.as_ref() {
+                    Some(fields) => fields.project_row(&db_row),
+                    None => db_row.to_vec(),
+                };
+
+                String::from_utf8_lossy(&content).into_owned()
+            })
             .collect();
 
         Ok(GetRowsResponse {
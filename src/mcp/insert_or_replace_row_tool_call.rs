@@ -7,6 +7,7 @@ use serde::*;
 
 use crate::{
     app::AppContext,
+    audit_log::AuditRecord,
     db_operations,
     db_sync::{DataSynchronizationPeriod, EventSource},
 };
@@ -77,6 +78,11 @@ impl McpToolCall<InsertOrReplaceRowInputData, InsertOrReplaceRowResponse>
         let db_row = crate::operations::parse_db_json_entity(model.entity_json.as_bytes(), &now)
             .map_err(|err| format!("{:?}", err))?;
 
+        let audit_record = AuditRecord::new("InsertOrReplaceRow")
+            .with_table(db_namespace.name.as_str(), db_table.name.as_str())
+            .with_partition_keys(std::iter::once(db_row.get_partition_key().to_string()))
+            .with_rows(1);
+
         db_operations::write::insert_or_replace::execute(
             self.app.as_ref(),
             &db_namespace,
@@ -89,6 +95,8 @@ impl McpToolCall<InsertOrReplaceRowInputData, InsertOrReplaceRowResponse>
         .await
         .map_err(|err| format!("{:?}", err))?;
 
+        super::write_gate::write_audit(self.app.as_ref(), audit_record).await;
+
         Ok(InsertOrReplaceRowResponse {
             status: "ok".into(),
         })
@@ -2,6 +2,8 @@ mod get_rows_tool_call;
 pub use get_rows_tool_call::*;
 mod get_list_of_tables_tool_call;
 pub use get_list_of_tables_tool_call::*;
+mod aggregate_rows_tool_call;
+pub use aggregate_rows_tool_call::*;
 mod delete_row_tool_call;
 mod write_gate;
 pub use delete_row_tool_call::*;
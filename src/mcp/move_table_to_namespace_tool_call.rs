@@ -4,7 +4,7 @@ use mcp_server_middleware::*;
 use my_ai_agent::macros::ApplyJsonSchema;
 use serde::*;
 
-use crate::{app::AppContext, db_operations, db_sync::EventSource};
+use crate::{app::AppContext, audit_log::AuditRecord, db_operations, db_sync::EventSource};
 
 #[derive(ApplyJsonSchema, Debug, Serialize, Deserialize)]
 pub struct MoveTableToNamespaceInputData {
@@ -90,6 +90,14 @@ impl McpToolCall<MoveTableToNamespaceInputData, MoveTableToNamespaceResponse>
         .await
         .map_err(|err| format!("{:?}", err))?;
 
+        super::write_gate::write_audit(
+            self.app.as_ref(),
+            AuditRecord::new("MoveTableToNamespace")
+                .with_table(from.name.as_str(), model.table_name.as_str())
+                .with_details(format!("To: {}", to.name)),
+        )
+        .await;
+
         Ok(MoveTableToNamespaceResponse {
             status: format!(
                 "Table '{}' moved from '{}' to '{}'",
@@ -5,6 +5,7 @@ use my_ai_agent::macros::ApplyJsonSchema;
 use serde::*;
 
 use crate::app::AppContext;
+use crate::audit_log::AuditRecord;
 
 #[derive(ApplyJsonSchema, Debug, Serialize, Deserialize)]
 pub struct RestoreBackupInputData {
@@ -78,6 +79,19 @@ impl McpToolCall<RestoreBackupInputData, RestoreBackupResponse> for RestoreBacku
         .await
         .map_err(|err| err.into_message())?;
 
+        super::write_gate::write_audit(
+            self.app.as_ref(),
+            AuditRecord::new("RestoreFromBackup")
+                .with_namespace(db_namespace.name.as_str())
+                .with_details(format!(
+                    "File: {}, Table: {}, CleanTable: {}",
+                    model.file_name,
+                    table_name.unwrap_or("*"),
+                    clean_table
+                )),
+        )
+        .await;
+
         let status = match table_name {
             Some(table) => format!(
                 "Table '{}' has been restored from snapshot '{}'.",
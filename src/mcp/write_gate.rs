@@ -1,4 +1,7 @@
-use crate::app::AppContext;
+use crate::{
+    app::AppContext,
+    audit_log::{AuditActorKind, AuditRecord},
+};
 
 /// Gate for the MCP writer tools. MCP writes must be explicitly enabled
 /// by the admin on the UI Settings page; the enable window lasts 10
@@ -16,3 +19,10 @@ pub fn ensure_mcp_writes_enabled(app: &AppContext) -> Result<(), String> {
             .into(),
     )
 }
+
+/// Records what an MCP writer tool did. The MCP endpoint is the only thing which
+/// knows the caller, so every tool call is attributed to MCP as a whole.
+pub async fn write_audit(app: &AppContext, record: AuditRecord) {
+    app.write_audit(record.with_actor(AuditActorKind::Mcp, None))
+        .await;
+}
@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
-use my_logger::LogEventCtx;
-
-use crate::app::{AppContext, DbNamespace};
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext, DbNamespace,
+};
 
 use super::utils::compile_backup_file;
 use super::SnapshotFileModel;
@@ -24,16 +25,20 @@ pub async fn gc_backups(app: &AppContext) {
 pub async fn gc_namespace_backups(app: &AppContext, db_namespace: &Arc<DbNamespace>) {
     let files = super::get_list_of_files(app, db_namespace).await;
 
-    for file_name in select_backups_to_delete(&files, app.settings.max_backups_to_keep) {
+    for file_name in select_backups_to_delete(&files, app.settings.load().max_backups_to_keep) {
         // Logged, not printed: whether MaxBackupsToKeep is being enforced at all
         // is the first question asked when a backup folder looks wrong, and the
         // answer belongs where the rest of the server's log is.
-        my_logger::LOGGER.write_info(
-            "GcBackups",
-            format!("Deleting backup file {}", file_name),
-            LogEventCtx::new()
-                .add("namespace", db_namespace.name.to_string())
-                .add("keeping", app.settings.max_backups_to_keep.to_string()),
+        app.logs.write(
+            LogItem::info(
+                SystemProcess::Backup,
+                format!("Deleting backup file {}", file_name),
+            )
+            .with_namespace(db_namespace.name.as_str())
+            .with_context(
+                "keeping",
+                app.settings.load().max_backups_to_keep.to_string(),
+            ),
         );
         delete_backup(app, db_namespace, file_name.as_str()).await;
     }
@@ -85,10 +90,13 @@ async fn delete_backup(app: &AppContext, db_namespace: &Arc<DbNamespace>, file_n
         // A snapshot that can not be deleted is not worth taking the server
         // down for: it is reported and stays in the folder, and the next tick
         // tries again.
-        my_logger::LOGGER.write_error(
-            "GcBackups",
-            format!("Can not delete backup file. Err: {}", err),
-            LogEventCtx::new().add("fileName", file_full_path),
+        app.logs.write(
+            LogItem::error(
+                SystemProcess::Backup,
+                format!("Can not delete backup file. Err: {}", err),
+            )
+            .with_namespace(db_namespace.name.as_str())
+            .with_context("fileName", file_full_path),
         );
     }
 }
@@ -1,7 +1,10 @@
 use my_logger::LogEventCtx;
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 
-use crate::app::AppContext;
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext, OperationKind, OperationTimer,
+};
 
 use super::utils::*;
 use super::SnapshotFileModel;
@@ -77,7 +80,7 @@ async fn save_namespace_backup(
 
     if !force_write {
         if let Some(last_backup_time) = get_last_backup_time(app, db_namespace).await {
-            let backup_interval_seconds = app.settings.backup_interval_hours * 60 * 60;
+            let backup_interval_seconds = app.settings.load().backup_interval_hours * 60 * 60;
 
             if now
                 .duration_since(last_backup_time)
@@ -90,6 +93,29 @@ async fn save_namespace_backup(
         }
     }
 
+    let timer = OperationTimer::new(
+        app,
+        OperationKind::Backup,
+        "SaveBackup",
+        db_namespace.name.as_str(),
+        "",
+    );
+
+    let result = write_namespace_backup(app, db_namespace, now).await;
+
+    if result.is_none() {
+        timer.succeeded();
+    }
+
+    result
+}
+
+/// The part of `save_namespace_backup` which runs once the interval has passed.
+async fn write_namespace_backup(
+    app: &AppContext,
+    db_namespace: &Arc<DbNamespace>,
+    now: DateTimeAsMicroseconds,
+) -> Option<&'static str> {
     // A namespace with no tables produces an empty archive — 22 bytes, just the
     // zip end-of-central-directory record. Written every interval it is
     // indistinguishable from a real snapshot to the collector, so it occupies a
@@ -99,10 +125,12 @@ async fn save_namespace_backup(
     // The timestamp is still moved forward, so an empty namespace is
     // re-checked once per interval rather than on every single tick.
     if db_namespace.tables_amount() == 0 {
-        my_logger::LOGGER.write_info(
-            "Backup",
-            "Namespace holds no tables - skipping the backup",
-            LogEventCtx::new().add("namespace", db_namespace.name.to_string()),
+        app.logs.write(
+            LogItem::info(
+                SystemProcess::Backup,
+                "Namespace holds no tables - skipping the backup",
+            )
+            .with_namespace(db_namespace.name.as_str()),
         );
 
         return save_last_backup_time(app, db_namespace, now).await;
@@ -123,10 +151,12 @@ async fn save_namespace_backup(
         // Reported rather than unwrapped, as everything else here: a panic takes
         // the whole tick down, and with it the backup of every namespace after
         // this one, which is the failure this module exists to not have.
-        my_logger::LOGGER.write_error(
-            "Backup",
-            format!("Can not write the snapshot. Err: {}", err),
-            LogEventCtx::new().add("namespace", db_namespace.name.to_string()),
+        app.logs.write(
+            LogItem::error(
+                SystemProcess::Backup,
+                format!("Can not write the snapshot. Err: {}", err),
+            )
+            .with_namespace(db_namespace.name.as_str()),
         );
 
         // The time stamp is deliberately left where it was: the next tick
@@ -219,10 +249,13 @@ async fn read_last_backup_time(
         Ok(content) => content,
         Err(err) => {
             if err.kind() != std::io::ErrorKind::NotFound {
-                my_logger::LOGGER.write_error(
-                    "Backup",
-                    format!("Can not read the last backup time. Err: {}", err),
-                    LogEventCtx::new().add("fileName", file_name),
+                app.logs.write(
+                    LogItem::error(
+                        SystemProcess::Backup,
+                        format!("Can not read the last backup time. Err: {}", err),
+                    )
+                    .with_namespace(db_namespace.name.as_str())
+                    .with_context("fileName", file_name),
                 );
             }
 
@@ -233,10 +266,13 @@ async fn read_last_backup_time(
     let content = match String::from_utf8(content) {
         Ok(content) => content,
         Err(err) => {
-            my_logger::LOGGER.write_error(
-                "Backup",
-                format!("Can not read the last backup time. Err: {}", err),
-                LogEventCtx::new().add("fileName", file_name),
+            app.logs.write(
+                LogItem::error(
+                    SystemProcess::Backup,
+                    format!("Can not read the last backup time. Err: {}", err),
+                )
+                .with_namespace(db_namespace.name.as_str())
+                .with_context("fileName", file_name),
             );
 
             return None;
@@ -246,12 +282,11 @@ async fn read_last_backup_time(
     let result = DateTimeAsMicroseconds::from_str(content.as_str());
 
     if result.is_none() {
-        my_logger::LOGGER.write_error(
-            "Backup",
-            "Can not parse the last backup time",
-            LogEventCtx::new()
-                .add("fileName", file_name)
-                .add("content", content),
+        app.logs.write(
+            LogItem::error(SystemProcess::Backup, "Can not parse the last backup time")
+                .with_namespace(db_namespace.name.as_str())
+                .with_context("fileName", file_name)
+                .with_context("content", content),
         );
     }
 
@@ -1,7 +1,10 @@
 use my_no_sql_sdk::core::db::DbNamespaceName;
 use my_no_sql_sdk::DEFAULT_NAMESPACE;
 
-use crate::app::AppContext;
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext,
+};
 
 pub const LAST_TIME_BACKUP_FILE_NAME: &str = ".last_backup_time";
 
@@ -9,7 +12,8 @@ pub const LAST_TIME_BACKUP_FILE_NAME: &str = ".last_backup_time";
 /// namespace, the default one included. Each folder keeps its own snapshots and
 /// its own `.last_backup_time`, so `MaxBackupsToKeep` is counted per namespace.
 pub fn get_backup_folder(app: &AppContext, namespace: &DbNamespaceName) -> String {
-    let backup_folder = app.settings.get_backup_folder();
+    let settings = app.settings.load();
+    let backup_folder = settings.get_backup_folder();
 
     format!(
         "{}/{}",
@@ -44,7 +48,7 @@ pub fn backup_file_name_is_valid(file_name: &str) -> bool {
 /// folder still holds files", which makes it idempotent and lets a run
 /// interrupted half way finish on the next start.
 pub async fn migrate_legacy_backup_folder(app: &AppContext) {
-    let backup_folder = app.settings.get_backup_folder().to_string();
+    let backup_folder = app.settings.load().get_backup_folder().to_string();
     let backup_folder = backup_folder.trim_end_matches(['/', '\\']);
 
     let mut read_dir = match tokio::fs::read_dir(backup_folder).await {
@@ -72,17 +76,28 @@ pub async fn migrate_legacy_backup_folder(app: &AppContext) {
 
     let default_folder = format!("{}/{}", backup_folder, DEFAULT_NAMESPACE);
 
-    println!(
-        "Backup folder {} holds {} file(s) of the pre-namespace layout. Moving them into {}",
-        backup_folder,
-        files_to_move.len(),
-        default_folder
+    app.logs.write(
+        LogItem::info(
+            SystemProcess::Backup,
+            format!(
+                "Backup folder holds {} file(s) of the pre-namespace layout. Moving them into {}",
+                files_to_move.len(),
+                default_folder
+            ),
+        )
+        .with_context("folder", backup_folder),
     );
 
     if let Err(err) = tokio::fs::create_dir_all(default_folder.as_str()).await {
-        println!(
-            "Can not create the default namespace backup folder {}. Err: {}",
-            default_folder, err
+        app.logs.write(
+            LogItem::error(
+                SystemProcess::Backup,
+                format!(
+                    "Can not create the default namespace backup folder. Err: {}",
+                    err
+                ),
+            )
+            .with_context("folder", default_folder),
         );
         return;
     }
@@ -92,10 +107,16 @@ pub async fn migrate_legacy_backup_folder(app: &AppContext) {
         let dest = format!("{}/{}", default_folder, file_name);
 
         match tokio::fs::rename(src.as_str(), dest.as_str()).await {
-            Ok(_) => println!("Moved {} -> {}", src, dest),
+            Ok(_) => app.logs.write(LogItem::info(
+                SystemProcess::Backup,
+                format!("Moved {} -> {}", src, dest),
+            )),
             // A backup which can not be moved is not worth refusing to start
             // over: it stays where it is and is simply not listed any more.
-            Err(err) => println!("Can not move {} into {}. Err: {}", src, dest, err),
+            Err(err) => app.logs.write(LogItem::error(
+                SystemProcess::Backup,
+                format!("Can not move {} into {}. Err: {}", src, dest, err),
+            )),
         }
     }
 }
@@ -133,9 +133,11 @@ pub async fn build_db_snapshot_as_zip_archive(
     let tables = db_namespace.db.get_tables();
 
     let mut zip_builder = DbZipBuilder::in_memory();
+    let mut rows = 0;
 
     for db_table in tables.iter() {
         let table_snapshot = db_table.get_table_snapshot();
+        rows += db_table.data.read().get_rows_amount();
 
         if let Err(err) = zip_builder.add_table(db_table.name.as_str(), &table_snapshot) {
             return Err(format!(
@@ -145,6 +147,8 @@ pub async fn build_db_snapshot_as_zip_archive(
         }
     }
 
+    crate::app::report_rows(rows);
+
     zip_builder
         .get_payload()
         .map_err(|err| format!("Can not compile the archive. Err: {}", err))
@@ -5,8 +5,11 @@ use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
 use my_no_sql_sdk::server::DbTable;
 
 use crate::{
-    app::{AppContext, DbNamespace},
-    data_readers::DataReader,
+    app::{
+        logs::{LogItem, SystemProcess},
+        AppContext, DbNamespace,
+    },
+    data_readers::{DataReader, DataReaderConnection, PartitionScope, SlowReaderAction},
     db_operations::DbOperationError,
     db_sync::{states::TableFirstInitSyncData, SyncEvent},
 };
@@ -16,7 +19,16 @@ pub async fn subscribe(
     db_namespace: &Arc<DbNamespace>,
     data_reader: Arc<DataReader>,
     table_name: &str,
+    scope: Option<PartitionScope>,
+    resume_from: Option<u64>,
 ) -> Result<(), DbOperationError> {
+    check_read_access(
+        app,
+        &data_reader,
+        db_namespace.name.as_str(),
+        Some(table_name),
+    )?;
+
     // The reader is routed by the namespace recorded on it, so it has to be
     // recorded here rather than by whoever happened to open the connection: a
     // TCP reader gets it from the SetNamespace packet, but an HTTP reader names
@@ -30,11 +42,14 @@ pub async fn subscribe(
     let mut table = db_namespace.db.get_table(table_name);
 
     if table.is_none() {
-        if app.settings.auto_create_table_on_reader_subscribe {
-            println!(
-                "Table {} does not exist. Creating it now on reader {:?} subscribe",
-                table_name,
-                data_reader.get_name()
+        if app.settings.load().auto_create_table_on_reader_subscribe {
+            app.logs.write(
+                LogItem::info(
+                    SystemProcess::DataReaders,
+                    "Table does not exist. Creating it now on reader subscribe",
+                )
+                .with_table(db_namespace.name.as_str(), table_name)
+                .with_context("name", data_reader.get_name().to_string()),
             );
 
             table = crate::db_operations::write::table::create_if_not_exist(
@@ -51,12 +66,7 @@ pub async fn subscribe(
             .into();
         } else {
             // Table does not exist and auto-create is disabled.
-            send_empty_snapshot(
-                app,
-                db_namespace.name.clone(),
-                data_reader,
-                table_name,
-            );
+            send_empty_snapshot(app, db_namespace.name.clone(), data_reader, table_name);
 
             return Ok(());
         }
@@ -64,7 +74,10 @@ pub async fn subscribe(
 
     let db_table = table.unwrap();
 
-    data_reader.subscribe(&db_table).await;
+    // A reader which resumes keeps count of the sequence - it is sent with every event.
+    data_reader
+        .subscribe(&db_table, scope, resume_from.is_some())
+        .await;
 
     crate::operations::sync::dispatch(
         app,
@@ -72,12 +85,108 @@ pub async fn subscribe(
         SyncEvent::TableFirstInit(TableFirstInitSyncData {
             db_table,
             data_reader,
+            resume_from,
         }),
     );
 
     Ok(())
 }
 
+/// Gives the reader the credential its token stands for. A reader with no token,
+/// or one the `ReaderAclFile` does not know, stays connected - and is refused
+/// everything it tries to subscribe to.
+pub fn authenticate_reader(app: &AppContext, data_reader: &Arc<DataReader>, token: Option<&str>) {
+    let Some(reader_acl) = app.reader_acl.as_ref() else {
+        return;
+    };
+
+    let credential = token.and_then(|token| reader_acl.authenticate(token));
+
+    match credential {
+        Some(credential) => {
+            app.logs.write(
+                LogItem::info(SystemProcess::DataReaders, "Reader is authenticated")
+                    .with_context("id", data_reader.id.to_string())
+                    .with_context("name", data_reader.get_name().to_string())
+                    .with_context("credential", credential.name.to_string()),
+            );
+
+            data_reader.set_credential(credential);
+        }
+        None => {
+            let message = if token.is_some() {
+                "Reader presented an unknown token"
+            } else {
+                "Reader presented no token"
+            };
+
+            app.logs.write(
+                LogItem::info(SystemProcess::DataReaders, message)
+                    .with_context("id", data_reader.id.to_string())
+                    .with_context("name", data_reader.get_name().to_string()),
+            );
+        }
+    }
+}
+
+/// Whether the credential of the reader covers the namespace - and the table, when
+/// one is given. Everything is allowed when no `ReaderAclFile` is set.
+pub fn check_read_access(
+    app: &AppContext,
+    data_reader: &DataReader,
+    namespace: &str,
+    table_name: Option<&str>,
+) -> Result<(), DbOperationError> {
+    let Some(reader_acl) = app.reader_acl.as_ref() else {
+        return Ok(());
+    };
+
+    reader_acl
+        .check_read_access(
+            data_reader.get_credential().as_deref(),
+            namespace,
+            table_name,
+        )
+        .map_err(DbOperationError::AccessDenied)
+}
+
+/// Changes the partitions of a subscribed table the reader gets, without it having
+/// to reconnect. The reader is then initialized with the table again - holding just
+/// the partitions of the new scope - the same way it was on subscribe, so it drops
+/// partitions which went out of scope and picks up the ones which came in. The
+/// snapshot goes through the sync queue, behind every update already dispatched.
+///
+/// Returns the new scope - `None` when the reader gets the whole table again.
+pub async fn update_partition_scope(
+    app: &AppContext,
+    data_reader: Arc<DataReader>,
+    table_name: &str,
+    update: impl FnOnce(&mut PartitionScope),
+    whole_table: bool,
+) -> Result<Option<PartitionScope>, DbOperationError> {
+    let Some(db_table) = data_reader
+        .update_partition_scope(table_name, update, whole_table)
+        .await
+    else {
+        // Only a subscription has a scope to change.
+        return Err(DbOperationError::TableNotFound(table_name.to_string()));
+    };
+
+    let scope = data_reader.get_partition_scope(table_name).await;
+
+    crate::operations::sync::dispatch_by_namespace_name(
+        app,
+        data_reader.get_namespace(),
+        SyncEvent::TableFirstInit(TableFirstInitSyncData {
+            db_table,
+            data_reader,
+            resume_from: None,
+        }),
+    );
+
+    Ok(scope)
+}
+
 /// Answers a reader which subscribed to something that is not there: a table
 /// which was never created, or a whole namespace nobody has written to yet.
 ///
@@ -117,6 +226,130 @@ pub fn send_empty_snapshot(
         SyncEvent::TableFirstInit(TableFirstInitSyncData {
             db_table: empty_table,
             data_reader,
+            resume_from: None,
         }),
     );
 }
+
+/// Applies the `SlowReader` policy before an event of `table_name` is sent to the
+/// reader. `true` - the event is not to be sent: the reader is disconnected, or is
+/// behind in the table and is to get it in full once it catches up.
+pub async fn skip_slow_reader(
+    app: &AppContext,
+    data_reader: &Arc<DataReader>,
+    table_name: &str,
+) -> bool {
+    if data_reader.is_disconnecting() || data_reader.is_table_behind(table_name) {
+        return true;
+    }
+
+    let settings = app.settings.load_full();
+
+    let Some(policy) = settings.slow_reader.as_ref() else {
+        return false;
+    };
+
+    let Some(reason) = policy.get_exceeded(
+        data_reader.get_pending_to_send(),
+        data_reader.get_pending_events(),
+    ) else {
+        return false;
+    };
+
+    app.logs.write(
+        LogItem::info(
+            SystemProcess::Sync,
+            format!("Reader is too far behind: {}", reason),
+        )
+        .with_table(data_reader.get_namespace().as_str(), table_name)
+        .with_context("id", data_reader.id.to_string())
+        .with_context("name", data_reader.get_name().to_string())
+        .with_context("action", policy.action.as_str()),
+    );
+
+    app.metrics.mark_slow_reader_action(policy.action);
+
+    match policy.action {
+        SlowReaderAction::Reinit => {
+            data_reader.mark_table_behind(table_name);
+            data_reader.drop_pending_of_table(table_name).await;
+        }
+        SlowReaderAction::Disconnect => {
+            disconnect(app, data_reader, reason).await;
+        }
+    }
+
+    true
+}
+
+/// Sends the tables a reader fell behind in once it is within the `SlowReader`
+/// limits again. The `InitTable` goes through the sync queue, and the reader is
+/// sent the changes of the table again from the moment it is processed.
+pub async fn reinit_caught_up_tables(app: &AppContext, data_reader: &Arc<DataReader>) {
+    let settings = app.settings.load_full();
+
+    let Some(policy) = settings.slow_reader.as_ref() else {
+        return;
+    };
+
+    if policy
+        .get_exceeded(
+            data_reader.get_pending_to_send(),
+            data_reader.get_pending_events(),
+        )
+        .is_some()
+    {
+        return;
+    }
+
+    for table_name in data_reader.take_tables_to_reinit() {
+        let data_reader = data_reader.clone();
+
+        let db_table = match data_reader.get_table(&table_name).await {
+            Some(db_table) => db_table,
+            None => {
+                // Unsubscribed in the meantime - nothing to catch up with.
+                data_reader.set_table_caught_up(&table_name);
+                continue;
+            }
+        };
+
+        crate::operations::sync::dispatch_by_namespace_name(
+            app,
+            data_reader.get_namespace(),
+            SyncEvent::TableFirstInit(TableFirstInitSyncData {
+                db_table,
+                data_reader,
+                resume_from: None,
+            }),
+        );
+    }
+}
+
+/// Closes the connection of a reader, whatever it is connected over. TCP and
+/// WebSocket readers are removed once their connection reports it is closed; the
+/// others have no such callback and are removed here.
+pub async fn disconnect(app: &AppContext, data_reader: &Arc<DataReader>, reason: String) {
+    if !data_reader.set_disconnecting() {
+        return;
+    }
+
+    match &data_reader.connection {
+        DataReaderConnection::Tcp(tcp_info) => {
+            tcp_info.disconnect().await;
+        }
+        DataReaderConnection::WebSocket(web_socket_info) => {
+            web_socket_info.disconnect().await;
+        }
+        DataReaderConnection::Grpc(grpc_info) => {
+            grpc_info.disconnect(reason);
+            app.data_readers.remove_grpc(grpc_info.get_id()).await;
+            app.metrics.remove_pending_to_sync(&data_reader.connection);
+        }
+        DataReaderConnection::Http(http_info) => {
+            http_info.disconnect().await;
+            app.data_readers.remove_http(data_reader).await;
+            app.metrics.remove_pending_to_sync(&data_reader.connection);
+        }
+    }
+}
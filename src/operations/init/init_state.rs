@@ -1,13 +1,195 @@
-use tokio::sync::Mutex;
+use std::{sync::Mutex, time::Duration};
 
-#[derive(Clone)]
+use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InitNamespaceStatus {
+    Pending,
+    /// Its partitions are being read from the persistence. How many there are and
+    /// how big they are is known once they are.
+    Reading,
+    Loading,
+    Loaded,
+}
+
+impl InitNamespaceStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InitNamespaceStatus::Pending => "pending",
+            InitNamespaceStatus::Reading => "reading",
+            InitNamespaceStatus::Loading => "loading",
+            InitNamespaceStatus::Loaded => "loaded",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SkippedPartition {
+    pub partition_key: String,
+    pub error: String,
+}
+
+/// Bytes are those of the partitions as they are persisted - compressed.
+#[derive(Clone, Debug)]
+pub struct InitTableProgress {
+    pub table_name: String,
+    pub partitions_total: usize,
+    pub partitions_loaded: usize,
+    pub bytes_total: usize,
+    pub bytes_loaded: usize,
+    pub skipped_partitions: Vec<SkippedPartition>,
+    pub loaded: bool,
+}
+
+impl InitTableProgress {
+    pub fn new(table_name: String, partitions_total: usize, bytes_total: usize) -> Self {
+        Self {
+            table_name,
+            partitions_total,
+            partitions_loaded: 0,
+            bytes_total,
+            bytes_loaded: 0,
+            skipped_partitions: Vec::new(),
+            loaded: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InitNamespaceProgress {
+    pub namespace: String,
+    pub status: InitNamespaceStatus,
+    pub tables: Vec<InitTableProgress>,
+}
+
+impl InitNamespaceProgress {
+    pub fn get_bytes_total(&self) -> usize {
+        self.tables.iter().map(|itm| itm.bytes_total).sum()
+    }
+
+    pub fn get_bytes_loaded(&self) -> usize {
+        self.tables.iter().map(|itm| itm.bytes_loaded).sum()
+    }
+
+    /// Until they are read, the size of the partitions of the namespace is not
+    /// known.
+    fn is_size_known(&self) -> bool {
+        match self.status {
+            InitNamespaceStatus::Pending | InitNamespaceStatus::Reading => false,
+            InitNamespaceStatus::Loading | InitNamespaceStatus::Loaded => true,
+        }
+    }
+
+    fn get_table_mut(&mut self, table_name: &str) -> Option<&mut InitTableProgress> {
+        self.tables
+            .iter_mut()
+            .find(|itm| itm.table_name == table_name)
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct InitStateInner {
     pub total_tables: usize,
     pub loaded: usize,
     pub current_table: Option<String>,
     pub error: Option<String>,
+    pub namespaces: Vec<InitNamespaceProgress>,
+    pub started: Option<DateTimeAsMicroseconds>,
+    pub finished: Option<DateTimeAsMicroseconds>,
 }
 
+impl InitStateInner {
+    /// Share of the bytes loaded, 0.0 to 1.0. A namespace which is not read yet is
+    /// taken for as big as the read ones are on average; without any bytes at all
+    /// - a server initialized from another one - the tables are counted instead.
+    pub fn get_progress(&self) -> f64 {
+        if self.finished.is_some() {
+            return 1.0;
+        }
+
+        let Some(bytes_total) = self.get_estimated_bytes_total() else {
+            return 0.0;
+        };
+
+        if bytes_total == 0 {
+            if self.total_tables == 0 {
+                return 0.0;
+            }
+
+            return self.loaded as f64 / self.total_tables as f64;
+        }
+
+        (self.get_bytes_loaded() as f64 / bytes_total as f64).min(1.0)
+    }
+
+    /// Time left at the rate the bytes were loaded at so far. `None` until there
+    /// is a rate to go by.
+    pub fn get_eta(&self, now: DateTimeAsMicroseconds) -> Option<Duration> {
+        if self.finished.is_some() {
+            return Some(Duration::ZERO);
+        }
+
+        let started = self.started?;
+
+        let progress = self.get_progress();
+
+        if progress <= 0.0 {
+            return None;
+        }
+
+        let elapsed = now.duration_since(started).as_positive_or_zero();
+
+        Some(elapsed.mul_f64((1.0 - progress) / progress))
+    }
+
+    pub fn get_bytes_loaded(&self) -> usize {
+        self.namespaces
+            .iter()
+            .map(|itm| itm.get_bytes_loaded())
+            .sum()
+    }
+
+    pub fn get_skipped_partitions_amount(&self) -> usize {
+        self.namespaces
+            .iter()
+            .flat_map(|itm| itm.tables.iter())
+            .map(|itm| itm.skipped_partitions.len())
+            .sum()
+    }
+
+    fn get_estimated_bytes_total(&self) -> Option<usize> {
+        let known: Vec<&InitNamespaceProgress> = self
+            .namespaces
+            .iter()
+            .filter(|itm| itm.is_size_known())
+            .collect();
+
+        if known.is_empty() {
+            return None;
+        }
+
+        let known_bytes: usize = known.iter().map(|itm| itm.get_bytes_total()).sum();
+        let unknown = self.namespaces.len() - known.len();
+
+        Some(known_bytes + known_bytes / known.len() * unknown)
+    }
+
+    fn get_namespace_mut(&mut self, namespace: &str) -> Option<&mut InitNamespaceProgress> {
+        self.namespaces
+            .iter_mut()
+            .find(|itm| itm.namespace == namespace)
+    }
+
+    fn get_table_mut(
+        &mut self,
+        namespace: &str,
+        table_name: &str,
+    ) -> Option<&mut InitTableProgress> {
+        self.get_namespace_mut(namespace)?.get_table_mut(table_name)
+    }
+}
+
+/// How far the load of the tables on start up got - by namespace and by table.
 pub struct InitState {
     inner: Mutex<InitStateInner>,
 }
@@ -20,12 +202,182 @@ impl InitState {
                 loaded: 0,
                 current_table: None,
                 error: None,
+                namespaces: Vec::new(),
+                started: None,
+                finished: None,
             }),
         }
     }
 
-    pub async fn clone(&self) -> InitStateInner {
-        let inner = self.inner.lock().await;
-        inner.clone()
+    pub fn get(&self) -> InitStateInner {
+        self.inner.lock().unwrap().clone()
+    }
+
+    pub fn get_progress(&self) -> f64 {
+        self.inner.lock().unwrap().get_progress()
+    }
+
+    pub fn get_skipped_partitions_amount(&self) -> usize {
+        self.inner.lock().unwrap().get_skipped_partitions_amount()
+    }
+
+    pub fn start(&self, namespaces: impl Iterator<Item = String>) {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.started = Some(DateTimeAsMicroseconds::now());
+        inner.namespaces = namespaces
+            .map(|namespace| InitNamespaceProgress {
+                namespace,
+                status: InitNamespaceStatus::Pending,
+                tables: Vec::new(),
+            })
+            .collect();
+    }
+
+    pub fn start_reading(&self, namespace: &str) {
+        let mut inner = self.inner.lock().unwrap();
+
+        if let Some(progress) = inner.get_namespace_mut(namespace) {
+            progress.status = InitNamespaceStatus::Reading;
+        }
+    }
+
+    /// The partitions of the namespace are read - these are its tables, to be
+    /// loaded one by one.
+    pub fn set_tables(&self, namespace: &str, tables: Vec<InitTableProgress>) {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.total_tables += tables.len();
+
+        if let Some(progress) = inner.get_namespace_mut(namespace) {
+            progress.status = InitNamespaceStatus::Loading;
+            progress.tables = tables;
+        }
+    }
+
+    pub fn start_table(&self, table_name: &str) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.current_table = Some(table_name.to_string());
+    }
+
+    pub fn partition_loaded(&self, namespace: &str, table_name: &str, size: usize) {
+        let mut inner = self.inner.lock().unwrap();
+
+        if let Some(table) = inner.get_table_mut(namespace, table_name) {
+            table.partitions_loaded += 1;
+            table.bytes_loaded += size;
+        }
+    }
+
+    /// A broken partition skipped under `SkipBrokenPartitions` - counted as
+    /// loaded, as there is nothing left to wait for about it.
+    pub fn partition_skipped(
+        &self,
+        namespace: &str,
+        table_name: &str,
+        size: usize,
+        skipped: SkippedPartition,
+    ) {
+        let mut inner = self.inner.lock().unwrap();
+
+        if let Some(table) = inner.get_table_mut(namespace, table_name) {
+            table.partitions_loaded += 1;
+            table.bytes_loaded += size;
+            table.skipped_partitions.push(skipped);
+        }
+    }
+
+    pub fn table_loaded(&self, namespace: &str, table_name: &str) {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.loaded += 1;
+        inner.current_table = None;
+
+        if let Some(table) = inner.get_table_mut(namespace, table_name) {
+            table.loaded = true;
+            table.partitions_loaded = table.partitions_total;
+            table.bytes_loaded = table.bytes_total;
+        }
+    }
+
+    pub fn namespace_loaded(&self, namespace: &str) {
+        let mut inner = self.inner.lock().unwrap();
+
+        if let Some(progress) = inner.get_namespace_mut(namespace) {
+            progress.status = InitNamespaceStatus::Loaded;
+        }
+    }
+
+    pub fn finish(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.finished = Some(DateTimeAsMicroseconds::now());
+        inner.current_table = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_takes_unread_namespaces_for_average_ones() {
+        let state = InitState::new();
+        state.start(["default".to_string(), "alpha".to_string()].into_iter());
+
+        assert_eq!(0.0, state.get().get_progress());
+
+        state.start_reading("default");
+        state.set_tables(
+            "default",
+            vec![
+                InitTableProgress::new("orders".to_string(), 2, 300),
+                InitTableProgress::new("users".to_string(), 1, 100),
+            ],
+        );
+
+        state.partition_loaded("default", "orders", 100);
+        state.partition_skipped(
+            "default",
+            "orders",
+            200,
+            SkippedPartition {
+                partition_key: "2024".to_string(),
+                error: "Corrupt".to_string(),
+            },
+        );
+        state.table_loaded("default", "orders");
+
+        let snapshot = state.get();
+        // 300 bytes of 400 read, and as many are expected of "alpha".
+        assert_eq!(300.0 / 800.0, snapshot.get_progress());
+        assert_eq!(1, snapshot.get_skipped_partitions_amount());
+        assert_eq!(1, snapshot.loaded);
+        assert_eq!(2, snapshot.total_tables);
+
+        let started = snapshot.started.unwrap();
+        let mut now = started;
+        now.add_seconds(3);
+        assert_eq!(Some(Duration::from_secs(5)), snapshot.get_eta(now));
+
+        state.finish();
+        assert_eq!(1.0, state.get().get_progress());
+    }
+
+    #[test]
+    fn test_progress_without_bytes_counts_tables() {
+        let state = InitState::new();
+        state.start(["default".to_string()].into_iter());
+        state.start_reading("default");
+        state.set_tables(
+            "default",
+            vec![
+                InitTableProgress::new("orders".to_string(), 0, 0),
+                InitTableProgress::new("users".to_string(), 0, 0),
+            ],
+        );
+
+        state.table_loaded("default", "orders");
+
+        assert_eq!(0.5, state.get().get_progress());
     }
 }
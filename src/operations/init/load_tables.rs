@@ -1,28 +1,44 @@
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 
 use my_no_sql_sdk::core::db::DbTableAttributes;
 use my_no_sql_sdk::server::rust_extensions::StopWatch;
 
-use crate::app::{AppContext, DbNamespace};
-use crate::persist_repo::LoadedTableAttrs;
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext, DbNamespace,
+};
+use crate::persist_repo::{LoadedPartition, LoadedTableAttrs};
 
-use super::partitions_init_reader::PartitionsInitReader;
+use super::{partitions_init_reader::PartitionsInitReader, InitTableProgress};
 
 pub async fn load_tables(app: Arc<AppContext>) {
     let sw = StopWatch::new();
 
-    if let Some(server_url) = app.settings.get_init_from_other_server_url() {
+    let settings = app.settings.load_full();
+
+    if let Some(server_url) = settings.get_init_from_other_server_url() {
         load_from_other_instance(&app, server_url).await;
     } else {
         // Every namespace found on disk at start up is loaded — a reader of one
         // of them must not have to wait for somebody to touch it first.
-        for db_namespace in app.namespaces.get_all() {
+        let namespaces = app.namespaces.get_all();
+
+        app.init_state
+            .start(namespaces.iter().map(|itm| itm.name.to_string()));
+
+        for db_namespace in namespaces {
             load_namespace(&app, &db_namespace).await;
         }
     }
 
-    println!("Tables are loaded in {:?}", sw.duration());
+    app.init_state.finish();
+    app.metrics.update_init_progress(&app.init_state);
+
+    app.logs.write(LogItem::info(
+        SystemProcess::Init,
+        format!("Tables are loaded in {:?}", sw.duration()),
+    ));
 
     app.states.set_initialized();
 }
@@ -46,34 +62,54 @@ async fn load_from_other_instance(app: &Arc<AppContext>, server_url: &str) {
 
     let server_url = connection_string.host.as_str();
 
+    app.init_state
+        .start(std::iter::once(db_namespace.name.to_string()));
+    app.init_state.start_reading(db_namespace.name.as_str());
+
     // The import below writes without the normal local-load having run;
     // the Files backend must scan its page-files first (see
     // PersistRepo::prime_for_writes) or the import would be silently
     // reverted on the next restart.
     db_namespace
         .repo
-        .prime_for_writes(app.settings.skip_broken_partitions)
+        .prime_for_writes(app.settings.load().skip_broken_partitions)
         .await;
 
     let tables = super::from_other_instance::load_tables(server_url).await;
     let tables_amount = tables.len();
 
+    // Rows come table by table over HTTP - there is nothing to count in bytes.
+    app.init_state.set_tables(
+        db_namespace.name.as_str(),
+        tables
+            .iter()
+            .map(|itm| InitTableProgress::new(itm.name.to_string(), 0, 0))
+            .collect(),
+    );
+
     let entities_reader =
         super::from_other_instance::EntitiesInitReaderForOtherInstance::new(server_url.to_string());
 
     super::scripts::init_tables(app, &db_namespace, tables, entities_reader, true).await;
 
-    println!(
-        "Loaded {} table(s) of the namespace '{}' from {}",
-        tables_amount, db_namespace.name, server_url
+    app.init_state.namespace_loaded(db_namespace.name.as_str());
+
+    app.logs.write(
+        LogItem::info(
+            SystemProcess::Init,
+            format!("Loaded {} table(s) from {}", tables_amount, server_url),
+        )
+        .with_namespace(db_namespace.name.as_str()),
     );
 }
 
 async fn load_namespace(app: &Arc<AppContext>, db_namespace: &Arc<DbNamespace>) {
+    app.init_state.start_reading(db_namespace.name.as_str());
+
     let mut tables = db_namespace.repo.get_tables().await;
     let partitions = db_namespace
         .repo
-        .load_all_partitions(app.settings.skip_broken_partitions)
+        .load_all_partitions(app.settings.load().skip_broken_partitions)
         .await;
 
     // Data-safety net: a partition can only exist without a metadata record
@@ -90,9 +126,15 @@ async fn load_namespace(app: &Arc<AppContext>, db_namespace: &Arc<DbNamespace>)
     }
     drop(known);
     for (table_name, partitions_amount) in orphans {
-        println!(
-            "WARNING: Table '{}' of the namespace '{}' has {} persisted partition(s) but no metadata record. Recreating it with default attributes so its data is not lost.",
-            table_name, db_namespace.name, partitions_amount
+        app.logs.write(
+            LogItem::error(
+                SystemProcess::Init,
+                format!(
+                    "Table has {} persisted partition(s) but no metadata record. Recreating it with default attributes so its data is not lost.",
+                    partitions_amount
+                ),
+            )
+            .with_table(db_namespace.name.as_str(), table_name.as_str()),
         );
         tables.push(LoadedTableAttrs {
             table_name: table_name.into(),
@@ -101,12 +143,53 @@ async fn load_namespace(app: &Arc<AppContext>, db_namespace: &Arc<DbNamespace>)
     }
 
     let tables_amount = tables.len();
-    let entities_reader =
-        PartitionsInitReader::new(partitions, app.settings.skip_broken_partitions);
+
+    app.init_state.set_tables(
+        db_namespace.name.as_str(),
+        get_tables_progress(&tables, &partitions),
+    );
+    app.metrics.update_init_progress(&app.init_state);
+
+    let entities_reader = PartitionsInitReader::new(
+        app.clone(),
+        db_namespace.name.to_string(),
+        partitions,
+        app.settings.load().skip_broken_partitions,
+    );
     super::scripts::init_tables(app, db_namespace, tables, entities_reader, false).await;
 
-    println!(
-        "Loaded {} table(s) of the namespace '{}'",
-        tables_amount, db_namespace.name
+    app.init_state.namespace_loaded(db_namespace.name.as_str());
+
+    app.logs.write(
+        LogItem::info(
+            SystemProcess::Init,
+            format!("Loaded {} table(s)", tables_amount),
+        )
+        .with_namespace(db_namespace.name.as_str()),
     );
 }
+
+fn get_tables_progress(
+    tables: &[LoadedTableAttrs],
+    partitions: &[LoadedPartition],
+) -> Vec<InitTableProgress> {
+    let mut by_table: HashMap<&str, (usize, usize)> = HashMap::new();
+
+    for partition in partitions {
+        let (amount, size) = by_table.entry(partition.table_name.as_str()).or_default();
+        *amount += 1;
+        *size += partition.compressed.len();
+    }
+
+    tables
+        .iter()
+        .map(|table| {
+            let (amount, size) = by_table
+                .get(table.table_name.as_str())
+                .copied()
+                .unwrap_or_default();
+
+            InitTableProgress::new(table.table_name.as_str().to_string(), amount, size)
+        })
+        .collect()
+}
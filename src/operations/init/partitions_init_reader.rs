@@ -3,20 +3,28 @@ use std::sync::Arc;
 
 use my_no_sql_sdk::core::{db::DbRow, db_json_entity::DbJsonEntity};
 
-use crate::persist_repo::LoadedPartition;
+use crate::{app::AppContext, persist_repo::LoadedPartition};
 
-use super::EntitiesInitReader;
+use super::{EntitiesInitReader, SkippedPartition};
 
 /// Init reader for the local persistence backend. Decompresses
 /// each partition blob and parses it into rows. A broken partition is either
-/// skipped (with a log) or fatal, per `SkipBrokenPartitions`.
+/// skipped (with a log) or fatal, per `SkipBrokenPartitions`. Every partition
+/// counts towards the init progress of its table as it is parsed.
 pub struct PartitionsInitReader {
+    app: Arc<AppContext>,
+    namespace: String,
     by_table: HashMap<String, Vec<LoadedPartition>>,
     skip_errors: bool,
 }
 
 impl PartitionsInitReader {
-    pub fn new(partitions: Vec<LoadedPartition>, skip_errors: bool) -> Self {
+    pub fn new(
+        app: Arc<AppContext>,
+        namespace: String,
+        partitions: Vec<LoadedPartition>,
+        skip_errors: bool,
+    ) -> Self {
         let mut by_table: HashMap<String, Vec<LoadedPartition>> = HashMap::new();
         for partition in partitions {
             by_table
@@ -26,6 +34,8 @@ impl PartitionsInitReader {
         }
 
         Self {
+            app,
+            namespace,
             by_table,
             skip_errors,
         }
@@ -40,18 +50,25 @@ impl EntitiesInitReader for PartitionsInitReader {
         let mut result = Vec::new();
 
         for partition in partitions {
+            let size = partition.compressed.len();
+
             let json = match crate::persist_compression::decompress(&partition.compressed) {
                 Ok(json) => json,
                 Err(err) => {
-                    self.report_broken(table_name, &partition.partition_key, format!("{:?}", err));
+                    self.report_broken(table_name, partition, format!("{:?}", err));
                     continue;
                 }
             };
 
             match DbJsonEntity::restore_as_vec(json.as_slice()) {
-                Ok(rows) => result.extend(rows),
+                Ok(rows) => {
+                    result.extend(rows);
+                    self.app
+                        .init_state
+                        .partition_loaded(&self.namespace, table_name, size);
+                }
                 Err(err) => {
-                    self.report_broken(table_name, &partition.partition_key, format!("{:?}", err));
+                    self.report_broken(table_name, partition, format!("{:?}", err));
                 }
             }
         }
@@ -61,12 +78,24 @@ impl EntitiesInitReader for PartitionsInitReader {
 }
 
 impl PartitionsInitReader {
-    fn report_broken(&self, table_name: &str, partition_key: &str, err: String) {
+    fn report_broken(&self, table_name: &str, partition: LoadedPartition, err: String) {
+        let partition_key = partition.partition_key.as_str();
+
         if self.skip_errors {
             println!(
                 "Can not restore partition Table:{}. PartitionKey: {}. Err: {}",
                 table_name, partition_key, err
             );
+
+            self.app.init_state.partition_skipped(
+                &self.namespace,
+                table_name,
+                partition.compressed.len(),
+                SkippedPartition {
+                    partition_key: partition.partition_key,
+                    error: err,
+                },
+            );
         } else {
             panic!(
                 "Can not restore partition Table:{}. PartitionKey: {}. Err: {}",
@@ -17,6 +17,8 @@ pub async fn init_tables(
         let (table_name, attr) = table_init_contract.into();
         let mut db_table = DbTableInner::new(table_name, attr);
 
+        app.init_state.start_table(db_table.name.as_str());
+
         let db_rows = entities_reader.get_entities(db_table.name.as_str()).await;
 
         if let Some(db_rows) = db_rows {
@@ -54,5 +56,9 @@ pub async fn init_tables(
             )
             .await;
         }
+
+        app.init_state
+            .table_loaded(db_namespace.name.as_str(), db_table.name.as_str());
+        app.metrics.update_init_progress(&app.init_state);
     }
 }
@@ -13,3 +13,5 @@ pub mod backup;
 mod parse_db_json_entity;
 pub use parse_db_json_entity::*;
 pub mod init;
+mod reload_settings;
+pub use reload_settings::*;
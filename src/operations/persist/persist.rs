@@ -1,9 +1,13 @@
 use std::sync::Arc;
 
 use my_no_sql_sdk::core::rust_extensions::date_time::DateTimeAsMicroseconds;
+use opentelemetry::{context::FutureExt, trace::SpanKind, Context};
 
 use crate::{
-    app::{AppContext, DbNamespace},
+    app::{
+        logs::{LogItem, SystemProcess},
+        AppContext, DbNamespace, OperationKind, OperationTimer,
+    },
     persist_markers::PersistTask,
 };
 
@@ -33,7 +37,56 @@ pub async fn persist(app: &Arc<AppContext>) -> bool {
             None => continue,
         };
 
-        execute_persist_task(&db_namespace, persist_task, start_time).await;
+        let namespace_name = db_namespace.name.to_string();
+
+        let table_name = persist_task.get_table_name().as_str().to_string();
+
+        let mut timer = OperationTimer::new(
+            app,
+            OperationKind::Persist,
+            persist_task.get_name(),
+            namespace_name.as_str(),
+            table_name.as_str(),
+        )
+        .with_partition_key(persist_task.get_partition_key());
+
+        if let Some(rows_amount) = persist_task.get_rows_amount() {
+            timer.set_rows(rows_amount);
+        }
+
+        // A trace of its own: a task persists what any number of writes left behind.
+        let span = crate::telemetry::start_span_with_parent(
+            "persist::execute_persist_task",
+            &Context::new(),
+            SpanKind::Internal,
+        );
+        span.set_attribute("db.namespace", namespace_name.to_string());
+        span.set_attribute("db.table", table_name.to_string());
+        span.set_attribute("persist.task", persist_task.get_name());
+
+        // The repo panics on a write it can not do. Run apart, so the panic is
+        // counted as the fatal error it is instead of silently killing the tick.
+        let result = tokio::spawn(
+            async move {
+                execute_persist_task(&db_namespace, persist_task, start_time).await;
+            }
+            .with_context(span.context()),
+        )
+        .await;
+
+        if let Err(err) = result {
+            span.set_error(format!("Persist task panicked. Err: {}", err));
+
+            app.logs.write(
+                LogItem::fatal_error(
+                    SystemProcess::Persist,
+                    format!("Persist task panicked. Err: {}", err),
+                )
+                .with_table(namespace_name.as_str(), table_name.as_str()),
+            );
+        } else {
+            timer.succeeded();
+        }
 
         return true;
     }
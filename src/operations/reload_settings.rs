@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use crate::{
+    app::{
+        logs::{LogItem, SystemProcess},
+        AppContext,
+    },
+    settings_reader::SettingsModel,
+    settings_reload::SettingsReloadResult,
+};
+
+/// Reads the settings from where they were read on start and puts the runtime ones
+/// of them in effect. The settings are checked in full first: with an error nothing
+/// is changed.
+pub async fn reload_settings(app: &AppContext) -> Result<SettingsReloadResult, String> {
+    let read = crate::settings_reader::read_settings(&app.settings_sources).await?;
+
+    let current = app.settings.load_full();
+
+    let (merged, result) = crate::settings_reload::merge_runtime_settings(
+        &serde_yaml::to_value(current.as_ref()).map_err(|err| err.to_string())?,
+        &serde_yaml::to_value(&read).map_err(|err| err.to_string())?,
+    );
+
+    if !result.applied.is_empty() {
+        let merged: SettingsModel =
+            serde_yaml::from_value(merged).map_err(|err| err.to_string())?;
+        app.settings.store(Arc::new(merged));
+
+        app.logs.write(
+            LogItem::info(SystemProcess::Settings, "Settings are reloaded")
+                .with_context("applied", result.applied.join(", ")),
+        );
+    }
+
+    let mut restart_required = app.settings_restart_required.lock().unwrap();
+
+    if *restart_required != result.restart_required {
+        if !result.restart_required.is_empty() {
+            app.logs.write(
+                LogItem::info(
+                    SystemProcess::Settings,
+                    "Settings are changed which only a restart applies",
+                )
+                .with_context("settings", result.restart_required.join(", ")),
+            );
+        }
+
+        *restart_required = result.restart_required.clone();
+    }
+
+    Ok(result)
+}
@@ -1,11 +1,17 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use my_no_sql_sdk::tcp_contracts::MyNoSqlTcpContract;
 
 use crate::{
-    app::{AppContext, DbNamespace},
-    data_readers::DataReaderConnection,
-    db_sync::{NamespaceSyncEvent, SyncEvent},
+    app::{
+        logs::{LogItem, SystemProcess},
+        AppContext, DbNamespace,
+    },
+    data_readers::{
+        tcp_connection::TcpConnectionInfo, DataReader, DataReaderConnection, PartitionScope,
+    },
+    db_sync::{states::TableFirstInitSyncData, NamespaceSyncEvent, SyncBatchFlush, SyncEvent},
+    mynosqlserver_grpc::ReaderGrpcEvent,
 };
 
 pub fn dispatch(app: &AppContext, db_namespace: &Arc<DbNamespace>, sync_event: SyncEvent) {
@@ -24,8 +30,50 @@ pub fn dispatch_by_namespace_name(
     namespace: my_no_sql_sdk::core::db::DbNamespaceName,
     sync_event: SyncEvent,
 ) {
-    app.sync
-        .send(NamespaceSyncEvent::new(namespace, sync_event));
+    let span = crate::telemetry::start_span("operations::sync::dispatch");
+    span.set_attribute("db.namespace", namespace.as_str().to_string());
+    span.set_attribute("db.table", sync_event.get_table_name().to_string());
+    let _context = span.attach();
+
+    let settings = app.settings.load();
+
+    let Some(sync_batching) = settings.sync_batching.as_ref() else {
+        app.sync
+            .send(NamespaceSyncEvent::new(namespace, sync_event));
+        return;
+    };
+
+    let window = sync_batching.get_window(sync_event.get_table_name());
+
+    app.sync_batches
+        .add(namespace, sync_event, window, |flush| {
+            send_flush(app, flush)
+        });
+}
+
+/// Sends the batches whose window is over. Called by the `SyncBatches` timer.
+pub fn flush_sync_batches(app: &AppContext) {
+    app.sync_batches
+        .flush_expired(Instant::now(), |flush| send_flush(app, flush));
+}
+
+fn send_flush(app: &AppContext, flush: SyncBatchFlush) {
+    if flush.merged > 0 {
+        if let Some(sync_event) = flush.events.first() {
+            app.metrics.mark_sync_events_merged(
+                flush.namespace.as_str(),
+                sync_event.get_table_name(),
+                flush.merged,
+            );
+        }
+    }
+
+    for sync_event in flush.events {
+        app.sync.send(
+            NamespaceSyncEvent::new(flush.namespace.clone(), sync_event)
+                .with_trace_parent(flush.trace_parent.clone()),
+        );
+    }
 }
 
 pub async fn sync(app: &AppContext, model: &NamespaceSyncEvent) {
@@ -34,20 +82,57 @@ pub async fn sync(app: &AppContext, model: &NamespaceSyncEvent) {
     if let SyncEvent::TableFirstInit(data) = sync_event {
         data.data_reader.set_first_init();
 
-        match &data.data_reader.connection {
-            DataReaderConnection::Tcp(tcp_info) => {
-                let compressed = tcp_info.is_compressed_data();
-                let payloads = crate::data_readers::tcp_connection::tcp_payload_to_send::serialize(
-                    sync_event, compressed,
-                )
-                .await;
+        let _span = start_reader_span(&data.data_reader);
 
-                if payloads.len() > 0 {
-                    tcp_info.send(payloads.as_slice()).await;
+        let table_name = data.db_table.name.as_str();
+
+        // The snapshot is taken right now, so from here on the reader is up to date
+        // with the table even if it fell behind in it before.
+        data.data_reader.set_table_caught_up(table_name);
+
+        let scope = data.data_reader.get_partition_scope(table_name).await;
+
+        // A reader which resumes is sent what it missed, as long as the history
+        // still has all of it. Everything it missed is behind this event in the
+        // history, and everything after it is yet to come through this very loop,
+        // so nothing falls in between.
+        let missed_events = data.resume_from.and_then(|sequence| {
+            app.sync_history
+                .get_since(model.namespace.as_str(), table_name, sequence)
+        });
+
+        match missed_events {
+            Some(missed_events) => {
+                for missed_event in missed_events {
+                    send_to_reader(
+                        app,
+                        &data.data_reader,
+                        &missed_event.event,
+                        missed_event.sequence,
+                        scope.as_ref(),
+                    )
+                    .await;
                 }
             }
-            DataReaderConnection::Http(http_info) => {
-                http_info.send(&sync_event).await;
+            None => {
+                let sequence = app
+                    .sync_history
+                    .get_last_sequence(model.namespace.as_str(), table_name);
+
+                let sent_from_cache = scope.is_none()
+                    && !data.data_reader.is_sequenced(table_name).await
+                    && send_cached_init_table(app, model.namespace.as_str(), data, sequence).await;
+
+                if !sent_from_cache {
+                    send_to_reader(
+                        app,
+                        &data.data_reader,
+                        sync_event,
+                        Some(sequence),
+                        scope.as_ref(),
+                    )
+                    .await;
+                }
             }
         }
 
@@ -62,55 +147,83 @@ pub async fn sync(app: &AppContext, model: &NamespaceSyncEvent) {
             .get_subscribed_to_table(&model.namespace, sync_event.get_table_name())
             .await;
 
+        app.init_table_cache
+            .remove(model.namespace.as_str(), sync_event.get_table_name());
+        app.metrics
+            .update_init_table_cache_size(app.init_table_cache.get_size());
+
         if data_readers.is_none() {
             return;
         }
         let data_readers = data_readers.unwrap();
 
-        let mut tcp_contracts_non_compressed: Option<Vec<MyNoSqlTcpContract>> = None;
-        let mut tcp_contracts_compressed: Option<Vec<MyNoSqlTcpContract>> = None;
+        let mut shared_tcp_contracts = SharedTcpContracts::new();
+        let mut grpc_events: Option<Vec<ReaderGrpcEvent>> = None;
 
         for data_reader in &data_readers {
             if !data_reader.has_first_init() {
                 continue;
             }
 
+            if crate::operations::data_readers::skip_slow_reader(
+                app,
+                data_reader,
+                sync_event.get_table_name(),
+            )
+            .await
+            {
+                continue;
+            }
+
+            let _span = start_reader_span(data_reader);
+
+            // A reader subscribed to some partitions, or sent the sequences, only gets a
+            // payload of its own; the shared ones below are for the other readers.
+            let scope = data_reader
+                .get_partition_scope(sync_event.get_table_name())
+                .await;
+
+            if scope.is_some() || data_reader.is_sequenced(sync_event.get_table_name()).await {
+                send_to_reader(app, data_reader, sync_event, model.sequence, scope.as_ref()).await;
+
+                app.metrics.update_pending_to_sync(&data_reader.connection);
+                continue;
+            }
+
             match &data_reader.connection {
                 DataReaderConnection::Tcp(connection_info) => {
-                    if connection_info.is_compressed_data() {
-                        if let Some(payloads) = &tcp_contracts_compressed {
-                            connection_info.send(payloads).await;
-                        } else {
-                            let payloads =
-                                crate::data_readers::tcp_connection::tcp_payload_to_send::serialize(
-                                    sync_event, true,
-                                )
-                                .await;
-
-                            if payloads.len() > 0 {
-                                connection_info.send(payloads.as_slice()).await;
-                                tcp_contracts_compressed = Some(payloads);
-                            }
-                        }
-                    } else {
-                        if let Some(to_send) = &tcp_contracts_non_compressed {
-                            connection_info.send(to_send).await;
-                        } else {
-                            let payloads =
-                                crate::data_readers::tcp_connection::tcp_payload_to_send::serialize(
-                                    sync_event, false,
-                                )
-                                .await;
-
-                            if payloads.len() > 0 {
-                                connection_info.send(&payloads).await;
-                                tcp_contracts_non_compressed = Some(payloads);
-                            }
-                        }
+                    let payloads = shared_tcp_contracts
+                        .get(sync_event, connection_info.is_compressed_data())
+                        .await;
+
+                    if payloads.len() > 0 {
+                        send_to_tcp(app, data_reader, connection_info, payloads).await;
                     }
                 }
+                DataReaderConnection::WebSocket(web_socket_info) => {
+                    let payloads = shared_tcp_contracts
+                        .get(sync_event, web_socket_info.is_compressed_data())
+                        .await;
+
+                    if payloads.len() > 0 {
+                        web_socket_info.send(payloads).await;
+                    }
+                }
+                DataReaderConnection::Grpc(grpc_info) => {
+                    let events = grpc_events.get_or_insert_with(|| {
+                        crate::data_readers::grpc_connection::grpc_payload_to_send::serialize(
+                            sync_event,
+                            None,
+                            model.sequence,
+                        )
+                    });
+
+                    grpc_info.send(events.clone());
+                }
                 DataReaderConnection::Http(http_info) => {
-                    http_info.send(&sync_event).await;
+                    if let Err(err) = http_info.send(&sync_event, model.sequence, None).await {
+                        write_delivery_error(app, data_reader, sync_event, err);
+                    }
                 }
             }
 
@@ -118,3 +231,221 @@ pub async fn sync(app: &AppContext, model: &NamespaceSyncEvent) {
         }
     }
 }
+
+/// Sends a reader which gets the whole table the `InitTable` the readers which
+/// subscribed before it since the last change of the table got - serialized and
+/// compressed once for all of them. `false` when the connection is not fed TCP
+/// contracts and gets a payload of its own.
+async fn send_cached_init_table(
+    app: &AppContext,
+    namespace: &str,
+    data: &TableFirstInitSyncData,
+    sequence: u64,
+) -> bool {
+    let compress = match &data.data_reader.connection {
+        DataReaderConnection::Tcp(tcp_info) => tcp_info.is_compressed_data(),
+        DataReaderConnection::WebSocket(web_socket_info) => web_socket_info.is_compressed_data(),
+        DataReaderConnection::Grpc(_) | DataReaderConnection::Http(_) => return false,
+    };
+
+    let table_name = data.db_table.name.as_str();
+
+    let contract = match app
+        .init_table_cache
+        .get(namespace, table_name, sequence, compress)
+    {
+        Some(contract) => {
+            app.metrics.mark_init_table_cache_hit();
+            contract
+        }
+        None => {
+            let table_snapshot = data.db_table.get_table_snapshot();
+            let payload =
+                crate::data_readers::table_snapshot_as_json(&table_snapshot, None).into_bytes();
+            let size = payload.len();
+
+            let contract = Arc::new(
+                crate::data_readers::tcp_connection::tcp_payload_to_send::serialize_init_table(
+                    table_name.to_string(),
+                    payload,
+                    compress,
+                ),
+            );
+
+            app.init_table_cache.insert(
+                namespace,
+                table_name,
+                sequence,
+                compress,
+                contract.clone(),
+                size,
+            );
+
+            app.metrics
+                .mark_init_table_cache_miss(app.init_table_cache.get_size());
+
+            contract
+        }
+    };
+
+    match &data.data_reader.connection {
+        DataReaderConnection::Tcp(tcp_info) => {
+            send_to_tcp(
+                app,
+                &data.data_reader,
+                tcp_info,
+                std::slice::from_ref(contract.as_ref()),
+            )
+            .await;
+        }
+        DataReaderConnection::WebSocket(web_socket_info) => {
+            web_socket_info
+                .send(std::slice::from_ref(contract.as_ref()))
+                .await;
+        }
+        DataReaderConnection::Grpc(_) | DataReaderConnection::Http(_) => {}
+    }
+
+    true
+}
+
+/// Hands the payloads to a TCP reader, which is disconnected once what it was sent
+/// before waits longer than `TcpSendTimeoutSec` to be written out. Read on every
+/// send, so a reload of the setting applies to the very next one.
+async fn send_to_tcp(
+    app: &AppContext,
+    data_reader: &Arc<DataReader>,
+    tcp_info: &TcpConnectionInfo,
+    payloads: &[MyNoSqlTcpContract],
+) {
+    let send_timeout = app.settings.load().get_tcp_send_timeout();
+
+    if tcp_info.send(payloads, send_timeout).await {
+        return;
+    }
+
+    crate::operations::data_readers::disconnect(
+        app,
+        data_reader,
+        "TcpSendTimeoutSec is over".to_string(),
+    )
+    .await;
+}
+
+/// Serializing the event for the reader and handing it to its connection.
+fn start_reader_span(data_reader: &DataReader) -> crate::telemetry::TraceSpan {
+    let span = crate::telemetry::start_span("operations::sync::send_to_reader");
+    span.set_attribute("reader.name", data_reader.get_name().to_string());
+    span
+}
+
+/// Serializes the event for one reader alone - the shared payloads `sync` builds
+/// are only for readers which get the whole table with no sequence, and only for
+/// the live stream.
+async fn send_to_reader(
+    app: &AppContext,
+    data_reader: &Arc<DataReader>,
+    sync_event: &SyncEvent,
+    sequence: Option<u64>,
+    scope: Option<&PartitionScope>,
+) {
+    // The TCP contracts carry the sequence in the table name - only for a reader
+    // which asked for it.
+    let tcp_sequence = if data_reader.is_sequenced(sync_event.get_table_name()).await {
+        sequence
+    } else {
+        None
+    };
+
+    match &data_reader.connection {
+        DataReaderConnection::Tcp(tcp_info) => {
+            let payloads = crate::data_readers::tcp_connection::tcp_payload_to_send::serialize(
+                sync_event,
+                tcp_info.is_compressed_data(),
+                scope,
+                tcp_sequence,
+            )
+            .await;
+
+            if payloads.len() > 0 {
+                send_to_tcp(app, data_reader, tcp_info, payloads.as_slice()).await;
+            }
+        }
+        DataReaderConnection::WebSocket(web_socket_info) => {
+            let payloads = crate::data_readers::tcp_connection::tcp_payload_to_send::serialize(
+                sync_event,
+                web_socket_info.is_compressed_data(),
+                scope,
+                tcp_sequence,
+            )
+            .await;
+
+            if payloads.len() > 0 {
+                web_socket_info.send(payloads.as_slice()).await;
+            }
+        }
+        DataReaderConnection::Grpc(grpc_info) => {
+            grpc_info.send(
+                crate::data_readers::grpc_connection::grpc_payload_to_send::serialize(
+                    sync_event, scope, sequence,
+                ),
+            );
+        }
+        DataReaderConnection::Http(http_info) => {
+            if let Err(err) = http_info.send(sync_event, sequence, scope).await {
+                write_delivery_error(app, data_reader, sync_event, err);
+            }
+        }
+    }
+}
+
+fn write_delivery_error(
+    app: &AppContext,
+    data_reader: &DataReader,
+    sync_event: &SyncEvent,
+    err: String,
+) {
+    app.logs.write(
+        LogItem::error(SystemProcess::Sync, err)
+            .with_table(
+                data_reader.get_namespace().as_str(),
+                sync_event.get_table_name(),
+            )
+            .with_context("name", data_reader.get_name().to_string()),
+    );
+}
+
+/// TCP contracts of one event, serialized once for all the readers which get the
+/// whole table - TCP and WebSocket alike.
+struct SharedTcpContracts {
+    compressed: Option<Vec<MyNoSqlTcpContract>>,
+    non_compressed: Option<Vec<MyNoSqlTcpContract>>,
+}
+
+impl SharedTcpContracts {
+    fn new() -> Self {
+        Self {
+            compressed: None,
+            non_compressed: None,
+        }
+    }
+
+    async fn get(&mut self, sync_event: &SyncEvent, compress: bool) -> &[MyNoSqlTcpContract] {
+        let payloads = if compress {
+            &mut self.compressed
+        } else {
+            &mut self.non_compressed
+        };
+
+        if payloads.is_none() {
+            let result = crate::data_readers::tcp_connection::tcp_payload_to_send::serialize(
+                sync_event, compress, None, None,
+            )
+            .await;
+
+            *payloads = Some(result);
+        }
+
+        payloads.as_ref().unwrap().as_slice()
+    }
+}
@@ -19,3 +19,40 @@ pub enum PersistTask {
         jobs: Vec<SyncRowJobDescription>,
     },
 }
+
+impl PersistTask {
+    pub fn get_table_name(&self) -> &DbTableName {
+        match self {
+            PersistTask::SaveTableAttributes(table_name) => table_name,
+            PersistTask::SyncTable(table_name) => table_name,
+            PersistTask::SyncPartition { table_name, .. } => table_name,
+            PersistTask::SyncRows { table_name, .. } => table_name,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            PersistTask::SaveTableAttributes(_) => "SaveTableAttributes",
+            PersistTask::SyncTable(_) => "SyncTable",
+            PersistTask::SyncPartition { .. } => "SyncPartition",
+            PersistTask::SyncRows { .. } => "SyncRows",
+        }
+    }
+
+    pub fn get_partition_key(&self) -> Option<&str> {
+        match self {
+            PersistTask::SyncPartition { partition_key, .. } => Some(partition_key.as_str()),
+            _ => None,
+        }
+    }
+
+    /// How many rows the task writes - known for `SyncRows` only.
+    pub fn get_rows_amount(&self) -> Option<usize> {
+        match self {
+            PersistTask::SyncRows { jobs, .. } => {
+                Some(jobs.iter().map(|job| job.items.len()).sum())
+            }
+            _ => None,
+        }
+    }
+}
@@ -1,9 +1,21 @@
+use std::{net::SocketAddr, sync::Arc};
+
 use my_no_sql_sdk::core::rust_extensions;
 
 use serde::{Deserialize, Serialize};
 
+use crate::app::logs::Logs;
+use crate::app::{OperationKind, SlowOperationsSettings};
+use crate::audit_log::AuditLogSettings;
+use crate::data_readers::SlowReaderPolicy;
+use crate::db_sync::SyncBatchingSettings;
 use crate::files_repo::FilesRepo;
 use crate::persist_repo::PersistRepo;
+use crate::settings_sources::SettingsSources;
+use crate::telemetry::TracingSettings;
+use crate::tls::TlsSettings;
+
+const DEFAULT_DEAD_READER_TIMEOUT_SEC: u64 = 60;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SettingsModel {
@@ -42,6 +54,51 @@ pub struct SettingsModel {
 
     #[serde(rename = "InitFromOtherServerUrl")]
     pub init_from_other_server_url: Option<String>,
+
+    #[serde(rename = "SyncHistorySize", default)]
+    pub sync_history_size: Option<usize>,
+
+    #[serde(rename = "SyncHistoryMaxSizeMb", default)]
+    pub sync_history_max_size_mb: Option<usize>,
+
+    #[serde(rename = "SlowReader", default)]
+    pub slow_reader: Option<SlowReaderPolicy>,
+
+    #[serde(rename = "SyncBatching", default)]
+    pub sync_batching: Option<SyncBatchingSettings>,
+
+    #[serde(rename = "InitTableCacheMaxSizeMb", default)]
+    pub init_table_cache_max_size_mb: Option<usize>,
+
+    #[serde(rename = "ReaderAclFile", default)]
+    pub reader_acl_file: Option<String>,
+
+    #[serde(rename = "ApiKeysFile", default)]
+    pub api_keys_file: Option<String>,
+
+    #[serde(rename = "Tls", default)]
+    pub tls: Option<TlsSettings>,
+
+    #[serde(rename = "DeadReaderTimeoutSec", default)]
+    pub dead_reader_timeout_sec: Option<u64>,
+
+    #[serde(rename = "AuditLog", default)]
+    pub audit_log: Option<AuditLogSettings>,
+
+    #[serde(rename = "SlowOperations", default)]
+    pub slow_operations: Option<SlowOperationsSettings>,
+
+    #[serde(rename = "Tracing", default)]
+    pub tracing: Option<TracingSettings>,
+
+    #[serde(rename = "HttpListenAddr", default)]
+    pub http_listen_addr: Option<SocketAddr>,
+
+    #[serde(rename = "GrpcListenAddr", default)]
+    pub grpc_listen_addr: Option<SocketAddr>,
+
+    #[serde(rename = "ReaderTcpListenAddr", default)]
+    pub reader_tcp_listen_addr: Option<SocketAddr>,
 }
 
 impl SettingsModel {
@@ -56,18 +113,78 @@ impl SettingsModel {
     /// Opens the persistence backend of a single namespace. `PersistenceDest` is
     /// a directory, and every namespace is a folder of its own inside it — the
     /// default one included.
-    pub async fn open_persist_repo(&self, namespace: &str) -> PersistRepo {
+    pub async fn open_persist_repo(&self, namespace: &str, logs: Arc<Logs>) -> PersistRepo {
         let dest = self.get_persistence_dest();
 
         let folder = crate::persist_repo::get_namespace_folder(dest.as_str(), namespace);
 
-        PersistRepo::new(FilesRepo::open(folder, self.skip_broken_partitions).await)
+        PersistRepo::new(FilesRepo::open(folder, self.skip_broken_partitions, logs).await)
     }
 
     pub fn get_backup_folder<'s>(&'s self) -> rust_extensions::StrOrString<'s> {
         rust_extensions::file_utils::format_path(self.backup_folder.as_str())
     }
 
+    /// How many recent changes of every table are kept for readers which resume.
+    pub fn get_sync_history_size(&self) -> usize {
+        self.sync_history_size
+            .unwrap_or(crate::db_sync::DEFAULT_SYNC_HISTORY_SIZE)
+    }
+
+    /// How much memory the recent changes of every table may take, in bytes.
+    pub fn get_sync_history_max_size(&self) -> usize {
+        self.sync_history_max_size_mb
+            .unwrap_or(crate::db_sync::DEFAULT_SYNC_HISTORY_MAX_SIZE_MB)
+            * 1024
+            * 1024
+    }
+
+    /// How much memory the `InitTable` payloads shared by the subscribing readers may
+    /// take, in bytes.
+    pub fn get_init_table_cache_max_size(&self) -> usize {
+        self.init_table_cache_max_size_mb
+            .unwrap_or(crate::data_readers::DEFAULT_INIT_TABLE_CACHE_MAX_SIZE_MB)
+            * 1024
+            * 1024
+    }
+
+    /// How long a TCP reader may stay silent before it is taken for dead. `None` - readers
+    /// are never disconnected for it.
+    pub fn get_dead_reader_timeout(&self) -> Option<std::time::Duration> {
+        match self
+            .dead_reader_timeout_sec
+            .unwrap_or(DEFAULT_DEAD_READER_TIMEOUT_SEC)
+        {
+            0 => None,
+            timeout => Some(std::time::Duration::from_secs(timeout)),
+        }
+    }
+
+    /// How long what is sent to a TCP reader may wait to be written out before the
+    /// reader is disconnected. `None` - it may wait for as long as it takes.
+    pub fn get_tcp_send_timeout(&self) -> Option<std::time::Duration> {
+        match self.tcp_send_time_out {
+            0 => None,
+            timeout => Some(std::time::Duration::from_secs(timeout)),
+        }
+    }
+
+    /// How long an operation of the kind may take before it is recorded as a slow
+    /// one. `None` - it is not recorded, however long it takes.
+    pub fn get_slow_operation_threshold(&self, kind: OperationKind) -> Option<std::time::Duration> {
+        match self.slow_operations.as_ref() {
+            Some(slow_operations) => slow_operations.get_threshold(kind),
+            None => SlowOperationsSettings::default().get_threshold(kind),
+        }
+    }
+
+    pub fn get_slow_operations_max_records(&self) -> usize {
+        self.slow_operations
+            .as_ref()
+            .and_then(|itm| itm.max_records)
+            .unwrap_or(crate::app::DEFAULT_SLOW_OPERATIONS_MAX_RECORDS)
+    }
+
     pub fn get_init_from_other_server_url(&self) -> Option<&str> {
         if let Some(url) = &self.init_from_other_server_url {
             return Some(url.as_str());
@@ -75,36 +192,151 @@ impl SettingsModel {
 
         None
     }
-}
 
-pub async fn read_settings() -> SettingsModel {
-    let file_name = rust_extensions::file_utils::format_path("~/.mynosqlserver");
+    pub fn get_http_listen_addr(&self) -> SocketAddr {
+        self.http_listen_addr.unwrap_or(SocketAddr::from((
+            [0, 0, 0, 0],
+            crate::http_server::start_up::DEFAULT_HTTP_PORT,
+        )))
+    }
 
-    let file_content = tokio::fs::read(file_name.as_str()).await;
+    pub fn get_grpc_listen_addr(&self) -> SocketAddr {
+        self.grpc_listen_addr.unwrap_or(SocketAddr::from((
+            [0, 0, 0, 0],
+            crate::grpc::server::DEFAULT_GRPC_PORT,
+        )))
+    }
 
-    if let Err(err) = &file_content {
-        panic!(
-            "Can't open settings file [{}]. Err: {}",
-            file_name.as_str(),
-            err
-        );
+    pub fn get_reader_tcp_listen_addr(&self) -> SocketAddr {
+        self.reader_tcp_listen_addr.unwrap_or(SocketAddr::from((
+            [0, 0, 0, 0],
+            crate::tcp::DEFAULT_READER_TCP_PORT,
+        )))
     }
 
-    let file_content = file_content.unwrap();
+    /// What serde does not check. Every problem found is reported, not just the first.
+    fn validate(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
 
-    let result: SettingsModel = serde_yaml::from_slice(file_content.as_slice()).unwrap();
+        if self.persistence_dest.is_empty() {
+            errors.push("PersistenceDest is empty".to_string());
+        }
 
-    result
-}
+        if self.init_threads_amount == 0 {
+            errors.push("InitThreadsAmount must be above 0".to_string());
+        }
+
+        // `true` - with Tls on the plain listener moves up, behind the terminator. The
+        // gRPC one takes TLS off itself.
+        let listeners = [
+            ("HttpListenAddr", self.get_http_listen_addr(), true),
+            ("GrpcListenAddr", self.get_grpc_listen_addr(), false),
+            (
+                "ReaderTcpListenAddr",
+                self.get_reader_tcp_listen_addr(),
+                true,
+            ),
+        ];
+
+        if let Some(tls) = self.tls.as_ref() {
+            if tls.get_internal_port_offset() == 0 {
+                errors.push("Tls InternalPortOffset must be above 0".to_string());
+            }
+        }
+
+        for (i, (name, addr, moves_up)) in listeners.iter().enumerate() {
+            if let Some(tls) = self.tls.as_ref().filter(|_| *moves_up) {
+                let offset = tls.get_internal_port_offset();
 
-/*
-fn get_settings_filename() -> String {
-    let path = env!("HOME");
+                if addr.port() == 0 || addr.port() > u16::MAX - offset {
+                    errors.push(format!(
+                        "{} {}: with Tls the port must be from 1 to {} - the plain listener moves {} ports up",
+                        name,
+                        addr,
+                        u16::MAX - offset,
+                        offset
+                    ));
+                }
+            }
+
+            for (other_name, other_addr, _) in listeners.iter().skip(i + 1) {
+                if addr.port() != 0 && addr.port() == other_addr.port() {
+                    errors.push(format!(
+                        "{} and {} share the port {}",
+                        name,
+                        other_name,
+                        addr.port()
+                    ));
+                }
+            }
+        }
 
-    if path.ends_with('/') {
-        return format!("{}{}", path, ".mynosqlserver");
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(errors.join("\n"))
     }
+}
+
+/// Reads the settings file with the overrides of the environment variables and the
+/// command line arguments over it. The error is ready to be shown as it is.
+pub async fn read_settings(sources: &SettingsSources) -> Result<SettingsModel, String> {
+    let file_name = rust_extensions::file_utils::format_path(sources.get_file_name());
+
+    let file_content = match tokio::fs::read_to_string(file_name.as_str()).await {
+        Ok(content) => Some(content),
+        // Everything may come from the overrides - as long as no file is asked for.
+        Err(err)
+            if err.kind() == std::io::ErrorKind::NotFound
+                && sources.file_name.is_none()
+                && !sources.overrides.is_empty() =>
+        {
+            None
+        }
+        Err(err) => {
+            return Err(format!(
+                "Can not read settings file {}. Err: {}. See --help",
+                file_name.as_str(),
+                err
+            ));
+        }
+    };
+
+    let result: SettingsModel = if sources.overrides.is_empty() {
+        // Parsed as it is - the errors point at the lines of the file.
+        serde_yaml::from_str(file_content.as_deref().unwrap_or_default())
+            .map_err(|err| format!("Invalid settings file {}. {}", file_name.as_str(), err))?
+    } else {
+        let mut settings = match file_content {
+            Some(content) => serde_yaml::from_str(content.as_str())
+                .map_err(|err| format!("Invalid settings file {}. {}", file_name.as_str(), err))?,
+            None => serde_yaml::Value::Null,
+        };
+
+        sources.apply(&mut settings)?;
+
+        // Through the text rather than from the value, so that `TableApiKey=123` is
+        // taken for the text it is.
+        let settings = serde_yaml::to_string(&settings).map_err(|err| err.to_string())?;
+
+        let result = serde_yaml::from_str(settings.as_str()).map_err(|err| {
+            format!(
+                "Invalid settings - file {} with the overrides. {}",
+                file_name.as_str(),
+                err
+            )
+        })?;
+
+        let effective = serde_yaml::to_value(&result).map_err(|err| err.to_string())?;
+        sources.check_known(&effective)?;
+
+        result
+    };
+
+    result
+        .validate()
+        .map_err(|err| format!("Invalid settings - file {}:\n{}", file_name.as_str(), err))?;
 
-    return format!("{}{}", path, "/.mynosqlserver");
+    Ok(result)
 }
- */
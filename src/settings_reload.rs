@@ -0,0 +1,90 @@
+use serde_yaml::Value;
+
+/// Settings a reload applies while the server runs. The rest are read once, on
+/// start - a change of them is reported as one a restart is needed for.
+pub const RUNTIME_SETTINGS: [&str; 5] = [
+    "BackupIntervalHours",
+    "MaxBackupsToKeep",
+    "TcpSendTimeoutSec",
+    "CompressData",
+    "AutoCreateTableOnReaderSubscribe",
+];
+
+/// Settings whose value is not shown.
+pub const SECRET_SETTINGS: [&str; 1] = ["TableApiKey"];
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SettingsReloadResult {
+    /// Runtime settings which changed and are in effect now.
+    pub applied: Vec<String>,
+    /// Settings which differ from the ones the server runs with until it is
+    /// restarted.
+    pub restart_required: Vec<String>,
+}
+
+pub fn is_runtime_setting(name: &str) -> bool {
+    RUNTIME_SETTINGS.contains(&name)
+}
+
+/// The settings the server runs with (`current`) with the runtime settings of the
+/// ones just read (`read`) - both as they serialize.
+pub fn merge_runtime_settings(current: &Value, read: &Value) -> (Value, SettingsReloadResult) {
+    let mut merged = current.clone();
+    let mut result = SettingsReloadResult::default();
+
+    let (Value::Mapping(current), Value::Mapping(read)) = (current, read) else {
+        return (merged, result);
+    };
+
+    for (key, read_value) in read.iter() {
+        if current.get(key) == Some(read_value) {
+            continue;
+        }
+
+        let name = key.as_str().unwrap_or_default().to_string();
+
+        if is_runtime_setting(name.as_str()) {
+            merged[key] = read_value.clone();
+            result.applied.push(name);
+        } else {
+            result.restart_required.push(name);
+        }
+    }
+
+    (merged, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_runtime_settings_are_applied() {
+        let current: Value = serde_yaml::from_str(
+            "BackupIntervalHours: 24\nCompressData: true\nLocation: M1\nTls: null",
+        )
+        .unwrap();
+
+        let read: Value = serde_yaml::from_str(
+            "BackupIntervalHours: 12\nCompressData: true\nLocation: M2\nTls: {CertFile: a.crt}",
+        )
+        .unwrap();
+
+        let (merged, result) = merge_runtime_settings(&current, &read);
+
+        assert_eq!(vec!["BackupIntervalHours".to_string()], result.applied);
+        assert_eq!(
+            vec!["Location".to_string(), "Tls".to_string()],
+            result.restart_required
+        );
+
+        assert_eq!(Some(12), merged["BackupIntervalHours"].as_u64());
+        assert_eq!(Some("M1"), merged["Location"].as_str());
+        assert!(merged["Tls"].is_null());
+
+        // Reading the same file again reports the restart ones again, and applies nothing.
+        let (_, result) = merge_runtime_settings(&merged, &read);
+        assert!(result.applied.is_empty());
+        assert_eq!(2, result.restart_required.len());
+    }
+}
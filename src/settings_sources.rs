@@ -0,0 +1,292 @@
+use serde_yaml::{Mapping, Value};
+
+/// Environment variables of the settings start with it. The rest of the name is the
+/// setting in upper snake case - `BACKUP_INTERVAL_HOURS`, with `__` between a section
+/// and its setting - `TLS__CERT_FILE`.
+pub const SETTINGS_ENV_PREFIX: &str = "MYNOSQLSERVER_";
+
+/// Argument and environment variable (after the prefix) of the settings file.
+const SETTINGS_FILE_ARG: &str = "settings";
+
+const DEFAULT_SETTINGS_FILE_NAME: &str = "~/.mynosqlserver";
+
+/// One setting set over the settings file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingOverride {
+    /// The setting as it is named in the file - `Tls.CertFile` is `["Tls", "CertFile"]`.
+    pub path: Vec<String>,
+    pub value: String,
+    /// The argument or the environment variable it came from - for the errors.
+    pub source: String,
+}
+
+/// Where the settings are read from: the file, then the environment variables over
+/// it, then the command line arguments over both.
+#[derive(Debug, Default)]
+pub struct SettingsSources {
+    /// Set with `--settings` or `MYNOSQLSERVER_SETTINGS`. Not set - `~/.mynosqlserver`.
+    pub file_name: Option<String>,
+    pub overrides: Vec<SettingOverride>,
+    pub help: bool,
+}
+
+impl SettingsSources {
+    /// As it is given - with `~` and the environment variables not resolved.
+    pub fn get_file_name(&self) -> &str {
+        self.file_name
+            .as_deref()
+            .unwrap_or(DEFAULT_SETTINGS_FILE_NAME)
+    }
+
+    pub fn from_process() -> Result<Self, String> {
+        Self::parse(std::env::args().skip(1), std::env::vars())
+    }
+
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self, String> {
+        let mut result = Self::default();
+
+        // A section set as a whole goes before the settings inside it.
+        let mut vars: Vec<(String, String)> = vars
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(SETTINGS_ENV_PREFIX))
+            .collect();
+        vars.sort();
+
+        for (name, value) in vars {
+            let key = &name[SETTINGS_ENV_PREFIX.len()..];
+
+            if key.eq_ignore_ascii_case(SETTINGS_FILE_ARG) {
+                result.file_name = Some(value);
+                continue;
+            }
+
+            let path = parse_path(key.split("__"), '_')
+                .ok_or_else(|| format!("Invalid settings environment variable {}", name))?;
+
+            result.overrides.push(SettingOverride {
+                path,
+                value,
+                source: name,
+            });
+        }
+
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if arg == "-h" || arg == "--help" {
+                result.help = true;
+                continue;
+            }
+
+            let Some(key) = arg.strip_prefix("--") else {
+                return Err(format!("Unexpected argument {}. See --help", arg));
+            };
+
+            let (key, value) = match key.split_once('=') {
+                Some((key, value)) => (key.to_string(), value.to_string()),
+                None => match args.next() {
+                    Some(value) => (key.to_string(), value),
+                    None => return Err(format!("No value of the argument --{}", key)),
+                },
+            };
+
+            if key == SETTINGS_FILE_ARG {
+                result.file_name = Some(value);
+                continue;
+            }
+
+            let path = parse_path(key.split('.'), '-')
+                .ok_or_else(|| format!("Invalid argument --{}. See --help", key))?;
+
+            result.overrides.push(SettingOverride {
+                path,
+                value,
+                source: format!("--{}", key),
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// Sets the overrides into the settings as they are read from the file. A value
+    /// is YAML - `{CertFile: a.crt, KeyFile: a.key}` sets a section as a whole.
+    pub fn apply(&self, settings: &mut Value) -> Result<(), String> {
+        for item in self.overrides.iter() {
+            set_value(settings, &item.path, parse_value(item.value.as_str()))
+                .map_err(|err| format!("Can not apply {}. {}", item.source, err))?;
+        }
+
+        Ok(())
+    }
+
+    /// Settings the model does not have are dropped by serde without a word - a typo
+    /// in an argument is found by looking for it in the settings as they were read.
+    pub fn check_known(&self, effective: &Value) -> Result<(), String> {
+        for item in self.overrides.iter() {
+            let mut current = effective;
+
+            for key in item.path.iter() {
+                let Value::Mapping(mapping) = current else {
+                    break;
+                };
+
+                match mapping.get(key.as_str()) {
+                    Some(value) => current = value,
+                    None => {
+                        return Err(format!(
+                            "Unknown setting {} of {}. See --help",
+                            item.path.join("."),
+                            item.source
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn get_usage() -> String {
+    format!(
+        r#"Usage: my_no_sql_server [--settings <file>] [--<setting> <value>]...
+
+Settings are read from ~/.mynosqlserver, or the file of --settings or {prefix}SETTINGS.
+Every setting of the file may be set over it:
+  --backup-interval-hours 12          {prefix}BACKUP_INTERVAL_HOURS=12
+  --tls.cert-file /etc/server.crt     {prefix}TLS__CERT_FILE=/etc/server.crt
+  --http-listen-addr 127.0.0.1:6123   {prefix}HTTP_LISTEN_ADDR=127.0.0.1:6123
+
+Values are YAML - a section may be set as a whole with {{Key: value, ...}}. The arguments
+win over the environment variables, which win over the file."#,
+        prefix = SETTINGS_ENV_PREFIX
+    )
+}
+
+/// `backup-interval-hours` (split by `-`) or `BACKUP_INTERVAL_HOURS` (split by `_`) is
+/// `BackupIntervalHours`.
+fn parse_path<'s>(segments: impl Iterator<Item = &'s str>, separator: char) -> Option<Vec<String>> {
+    let mut result = Vec::new();
+
+    for segment in segments {
+        let mut key = String::new();
+
+        for word in segment.split(separator) {
+            let mut chars = word.chars();
+
+            let first = chars.next()?;
+
+            if !first.is_ascii_alphanumeric() || !chars.clone().all(|c| c.is_ascii_alphanumeric()) {
+                return None;
+            }
+
+            key.push(first.to_ascii_uppercase());
+            key.extend(chars.map(|c| c.to_ascii_lowercase()));
+        }
+
+        result.push(key);
+    }
+
+    Some(result)
+}
+
+/// An empty value is an empty text rather than `null` - `~` unsets a setting.
+fn parse_value(value: &str) -> Value {
+    if value.is_empty() {
+        return Value::String(String::new());
+    }
+
+    serde_yaml::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()))
+}
+
+fn set_value(settings: &mut Value, path: &[String], value: Value) -> Result<(), String> {
+    let Some((key, rest)) = path.split_first() else {
+        *settings = value;
+        return Ok(());
+    };
+
+    if settings.is_null() {
+        *settings = Value::Mapping(Mapping::new());
+    }
+
+    let Value::Mapping(mapping) = settings else {
+        return Err(format!("The setting above {} is not a section", key));
+    };
+
+    let key = Value::String(key.to_string());
+
+    if !mapping.contains_key(&key) {
+        mapping.insert(key.clone(), Value::Null);
+    }
+
+    set_value(mapping.get_mut(&key).unwrap(), rest, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|itm| itm.to_string()).collect()
+    }
+
+    #[test]
+    fn test_args_win_over_the_environment() {
+        let sources = SettingsSources::parse(
+            to_strings(&[
+                "--backup-interval-hours",
+                "12",
+                "--tls.cert-file=/etc/server.crt",
+                "--settings",
+                "/etc/mynosqlserver.yaml",
+            ]),
+            [
+                ("MYNOSQLSERVER_BACKUP_INTERVAL_HOURS", "6"),
+                ("MYNOSQLSERVER_TABLE_API_KEY", "0123"),
+                ("MYNOSQLSERVER_TLS", "{CertFile: a.crt, KeyFile: a.key}"),
+                ("PATH", "/usr/bin"),
+            ]
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.to_string())),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some("/etc/mynosqlserver.yaml"),
+            sources.file_name.as_deref()
+        );
+
+        let mut settings: Value =
+            serde_yaml::from_str("BackupIntervalHours: 24\nLocation: M1").unwrap();
+        sources.apply(&mut settings).unwrap();
+
+        assert_eq!(Some(12), settings["BackupIntervalHours"].as_u64());
+        assert_eq!(Some("M1"), settings["Location"].as_str());
+        assert_eq!(
+            Some("/etc/server.crt"),
+            settings["Tls"]["CertFile"].as_str()
+        );
+        assert_eq!(Some("a.key"), settings["Tls"]["KeyFile"].as_str());
+        assert_eq!(Some("0123"), settings["TableApiKey"].as_str());
+
+        assert!(sources.check_known(&settings).is_ok());
+
+        let settings: Value = serde_yaml::from_str("BackupIntervalHours: 12").unwrap();
+        assert!(sources.check_known(&settings).is_err());
+    }
+
+    #[test]
+    fn test_invalid_args() {
+        let no_vars = std::iter::empty::<(String, String)>;
+
+        assert!(SettingsSources::parse(to_strings(&["12"]), no_vars()).is_err());
+        assert!(SettingsSources::parse(to_strings(&["--location"]), no_vars()).is_err());
+        assert!(SettingsSources::parse(to_strings(&["--tls..cert-file", "a"]), no_vars()).is_err());
+
+        let sources = SettingsSources::parse(to_strings(&["--help"]), no_vars()).unwrap();
+        assert!(sources.help);
+    }
+}
@@ -1,33 +1,64 @@
 use std::{sync::Arc, time::Duration};
 
-use crate::app::AppContext;
+use my_no_sql_sdk::core::rust_extensions::{
+    date_time::DateTimeAsMicroseconds, MyTimerTick, RepeatTimerIteration,
+};
 
-pub async fn start(app: Arc<AppContext>) {
-    let timeout = Duration::from_secs(60);
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext,
+};
 
-    while !app.states.is_shutting_down() {
-        tokio::time::sleep(timeout).await;
-
-        let result = tokio::spawn(detect_dead_connections(app.clone(), timeout)).await;
+/// Disconnects the TCP readers nothing came from for `timeout`. A half-open
+/// connection is otherwise only noticed once a send to it times out - and a reader of
+/// tables which do not change is never sent anything.
+pub struct DeadConnectionsDetector {
+    app: Arc<AppContext>,
+    timeout: Duration,
+}
 
-        if let Err(err) = result {
-            app.logs
-                .add_fatal_error(
-                    crate::app::logs::SystemProcess::TcpSocket,
-                    "dead_connections_detector".to_string(),
-                    format!("{}", err),
-                )
-                .await;
-        }
+impl DeadConnectionsDetector {
+    pub fn new(app: Arc<AppContext>, timeout: Duration) -> Self {
+        Self { app, timeout }
     }
 }
 
-pub async fn detect_dead_connections(app: Arc<AppContext>, timeout: Duration) {
-    let dead_sessions = app.data_readers.get_dead_connections(timeout).await;
+#[async_trait::async_trait]
+impl MyTimerTick for DeadConnectionsDetector {
+    async fn tick(&self) -> RepeatTimerIteration {
+        let now = DateTimeAsMicroseconds::now();
+
+        let dead_readers = self
+            .app
+            .data_readers
+            .get_dead_connections(now, self.timeout)
+            .await;
 
-    if let Some(dead_sessions) = dead_sessions {
-        for dead_session in dead_sessions {
-            crate::operations::sessions::disconnect(dead_session).await;
+        for data_reader in dead_readers {
+            let silent_for = now
+                .duration_since(data_reader.get_last_incoming_moment())
+                .as_positive_or_zero();
+
+            self.app.logs.write(
+                LogItem::info(
+                    SystemProcess::DataReaders,
+                    format!("Reader is silent for {:?} - disconnecting", silent_for),
+                )
+                .with_namespace(data_reader.get_namespace().as_str())
+                .with_context("id", data_reader.id.to_string())
+                .with_context("name", data_reader.get_name()),
+            );
+
+            self.app.metrics.mark_dead_reader_disconnected();
+
+            crate::operations::data_readers::disconnect(
+                &self.app,
+                &data_reader,
+                "Dead connection".to_string(),
+            )
+            .await;
         }
+
+        RepeatTimerIteration::WithInterval
     }
 }
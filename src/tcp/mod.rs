@@ -1,2 +1,7 @@
+pub mod dead_connections_detector;
+pub mod reader_contracts;
 mod tcp_server_events;
 pub use tcp_server_events::{MyNoSqlTcpConnection, TcpServerEvents};
+
+/// Of `ReaderTcpListenAddr` when it is not set.
+pub const DEFAULT_READER_TCP_PORT: u16 = 5125;
@@ -0,0 +1,551 @@
+use std::sync::Arc;
+
+use my_logger::LogEventCtx;
+use my_no_sql_sdk::tcp_contracts::MyNoSqlTcpContract;
+
+use crate::{
+    app::{AppContext, DbNamespace, OperationKind, OperationTimer},
+    data_readers::{
+        get_subscribe_table_name, split_reader_token, split_subscribe_options,
+        tcp_connection::ReaderName, DataReader, SubscribeOptions,
+    },
+};
+
+/// Connection a reader speaks `MyNoSqlTcpContract` over - the TCP socket or a
+/// WebSocket. Everything a packet does is the same on both; only how the answer
+/// goes out and how the reader is registered differ.
+#[async_trait::async_trait]
+pub trait ReaderContractsSession: Send + Sync {
+    /// Id of the connection, as it is written to the logs.
+    fn get_session_id(&self) -> String;
+
+    async fn send(&self, contract: &MyNoSqlTcpContract);
+
+    async fn get_data_reader(&self, app: &AppContext) -> Option<Arc<DataReader>>;
+
+    async fn greeting(&self, app: &AppContext, name: ReaderName, compress: bool);
+}
+
+/// Namespace the connection works in, as long as it exists. A connection
+/// which never sent `SetNamespace` stays in the default one, which always
+/// does.
+///
+/// Resolved WITHOUT creating: every packet a reader connection can send reads,
+/// and a mistyped `SetNamespace` must not leave a namespace — and a
+/// `db/<ns>` folder — behind. `None` costs a reader nothing it was not
+/// getting already: the namespace which used to be conjured up for it held
+/// no tables either, so the answer was "table not found" either way.
+fn get_existing_namespace(
+    app: &AppContext,
+    data_reader: &Arc<DataReader>,
+) -> Option<Arc<DbNamespace>> {
+    app.namespaces.get(data_reader.get_namespace().as_str())
+}
+
+/// Namespace a `Subscribe` works in. Unlike everything else on this
+/// connection a subscribe MAY write — it creates the table it subscribes to
+/// when `AutoCreateTableOnReaderSubscribe` is on — and a packet allowed to
+/// create a table has to be allowed to create the namespace holding it.
+async fn get_namespace_of_subscribe(
+    app: &AppContext,
+    data_reader: &Arc<DataReader>,
+) -> Option<Arc<DbNamespace>> {
+    if app.settings.load().auto_create_table_on_reader_subscribe {
+        let namespace = data_reader.get_namespace();
+
+        return Some(app.namespaces.get_or_create(namespace.as_str()).await);
+    }
+
+    get_existing_namespace(app, data_reader)
+}
+
+async fn set_namespace(
+    app: &AppContext,
+    data_reader: &Arc<DataReader>,
+    namespace: &str,
+) -> Result<(), String> {
+    if let Err(err) = my_no_sql_sdk::validate_namespace_name(namespace) {
+        return Err(format!("Invalid namespace name. {}", err));
+    }
+
+    if let Err(err) =
+        crate::operations::data_readers::check_read_access(app, data_reader, namespace, None)
+    {
+        return Err(format!("{:?}", err));
+    }
+
+    data_reader.set_namespace(namespace.into()).await?;
+
+    // Deliberately NOT materialized here: naming a namespace is not writing
+    // to it, and a reader which connects before the first writer must not be
+    // the one that brings the namespace into existence. Whatever the reader
+    // does next resolves it — by then it either exists or the reader is
+    // answered that it does not.
+    Ok(())
+}
+
+/// Namespace and table addressed by one of the maintenance packets, resolved
+/// inside the namespace of the connection which sent it. `None` as soon as
+/// any of the three is not there — which is what the packets treat as
+/// "nothing to update".
+///
+/// `Err` when the credential of the reader does not cover the table: these
+/// packets change the expiration of rows, which is as good as deleting them,
+/// so they are held to the same `ReaderAclFile` a `Subscribe` is.
+async fn get_table_of_session(
+    app: &AppContext,
+    session: &impl ReaderContractsSession,
+    table_name: &str,
+) -> Result<Option<(Arc<DbNamespace>, Arc<my_no_sql_sdk::server::DbTable>)>, String> {
+    let Some(data_reader) = session.get_data_reader(app).await else {
+        return Ok(None);
+    };
+
+    if let Err(err) = crate::operations::data_readers::check_read_access(
+        app,
+        &data_reader,
+        data_reader.get_namespace().as_str(),
+        Some(table_name),
+    ) {
+        return Err(format!("Table {} is not available. Err: {:?}", table_name, err));
+    }
+
+    let Some(db_namespace) = get_existing_namespace(app, &data_reader) else {
+        return Ok(None);
+    };
+
+    Ok(db_namespace
+        .db
+        .get_table(table_name)
+        .map(|db_table| (db_namespace, db_table)))
+}
+
+/// A token presented with the greeting. Only ever looked at when `ReaderAclFile`
+/// is set.
+async fn authenticate(
+    app: &AppContext,
+    session: &impl ReaderContractsSession,
+    token: Option<String>,
+) {
+    if let Some(data_reader) = session.get_data_reader(app).await {
+        crate::operations::data_readers::authenticate_reader(app, &data_reader, token.as_deref());
+    }
+}
+
+/// Name of a packet and the table it addresses, as the reader operations are
+/// labeled with.
+fn get_operation(contract: &MyNoSqlTcpContract) -> (&'static str, &str) {
+    match contract {
+        MyNoSqlTcpContract::Ping => ("Ping", ""),
+        MyNoSqlTcpContract::Greeting { .. } => ("Greeting", ""),
+        MyNoSqlTcpContract::GreetingFromNode { .. } => ("GreetingFromNode", ""),
+        MyNoSqlTcpContract::SetNamespace { .. } => ("SetNamespace", ""),
+        MyNoSqlTcpContract::Subscribe { table_name } => {
+            ("Subscribe", get_subscribe_table_name(table_name.as_str()))
+        }
+        MyNoSqlTcpContract::SubscribeAsNode(table_name) => {
+            ("SubscribeAsNode", get_subscribe_table_name(table_name.as_str()))
+        }
+        MyNoSqlTcpContract::Unsubscribe(table_name) => ("Unsubscribe", table_name.as_str()),
+        MyNoSqlTcpContract::UpdatePartitionsLastReadTime { table_name, .. } => {
+            ("UpdatePartitionsLastReadTime", table_name.as_str())
+        }
+        MyNoSqlTcpContract::UpdateRowsLastReadTime { table_name, .. } => {
+            ("UpdateRowsLastReadTime", table_name.as_str())
+        }
+        MyNoSqlTcpContract::UpdatePartitionsExpirationTime { table_name, .. } => {
+            ("UpdatePartitionsExpirationTime", table_name.as_str())
+        }
+        MyNoSqlTcpContract::UpdateRowsExpirationTime { table_name, .. } => {
+            ("UpdateRowsExpirationTime", table_name.as_str())
+        }
+        _ => ("Other", ""),
+    }
+}
+
+pub async fn handle(
+    app: &AppContext,
+    session: &impl ReaderContractsSession,
+    contract: MyNoSqlTcpContract,
+) {
+    let namespace = match session.get_data_reader(app).await {
+        Some(data_reader) => data_reader.get_namespace().as_str().to_string(),
+        None => String::new(),
+    };
+
+    let (operation, table_name) = get_operation(&contract);
+
+    let timer = OperationTimer::new(
+        app,
+        OperationKind::Reader,
+        operation,
+        namespace.as_str(),
+        table_name,
+    );
+
+    handle_contract(app, session, contract).await;
+
+    timer.succeeded();
+}
+
+/// `Subscribe` of a reader which names the options of the subscription in fields
+/// of its own - a gRPC one - rather than in the table name.
+pub async fn handle_subscribe(
+    app: &AppContext,
+    session: &impl ReaderContractsSession,
+    table_name: String,
+    options: SubscribeOptions,
+) {
+    let namespace = match session.get_data_reader(app).await {
+        Some(data_reader) => data_reader.get_namespace().as_str().to_string(),
+        None => String::new(),
+    };
+
+    let timer = OperationTimer::new(
+        app,
+        OperationKind::Reader,
+        "Subscribe",
+        namespace.as_str(),
+        table_name.as_str(),
+    );
+
+    subscribe(app, session, table_name, options).await;
+
+    timer.succeeded();
+}
+
+async fn subscribe(
+    app: &AppContext,
+    session: &impl ReaderContractsSession,
+    table_name: String,
+    options: SubscribeOptions,
+) {
+    if let Some(data_reader) = session.get_data_reader(app).await {
+        let db_namespace = match get_namespace_of_subscribe(app, &data_reader).await {
+            Some(db_namespace) => db_namespace,
+            None => {
+                if let Err(err) = crate::operations::data_readers::check_read_access(
+                    app,
+                    &data_reader,
+                    data_reader.get_namespace().as_str(),
+                    Some(table_name.as_str()),
+                ) {
+                    let message = format!("Subscribe to table error. Err: {:?}", err);
+                    session.send(&MyNoSqlTcpContract::Error { message }).await;
+                    return;
+                }
+
+                // No namespace means no table in it — answered with
+                // the empty snapshot a missing table is answered
+                // with, and NOT with an Error contract, which panics
+                // the SDK reader. Subscribing before the first write
+                // is how a reader which starts first behaves.
+                crate::operations::data_readers::send_empty_snapshot(
+                    app,
+                    data_reader.get_namespace(),
+                    data_reader,
+                    table_name.as_str(),
+                );
+
+                return;
+            }
+        };
+
+        let result = crate::operations::data_readers::subscribe(
+            app,
+            &db_namespace,
+            data_reader,
+            &table_name,
+            options.scope,
+            options.from_sequence,
+        )
+        .await;
+
+        if let Err(err) = result {
+            let data_reader = session.get_data_reader(app).await;
+
+            let session_name = if let Some(data_reader) = data_reader {
+                data_reader.get_name().to_string()
+            } else {
+                "".to_string()
+            };
+
+            let message = format!("Subscribe to table error. Err: {:?}", err);
+
+            my_logger::LOGGER.write_info(
+                "GreetingTcpMessage",
+                message.as_str(),
+                LogEventCtx::new()
+                    .add("sessionId", session.get_session_id())
+                    .add("Name", session_name)
+                    .add("TableName", table_name),
+            );
+
+            session.send(&MyNoSqlTcpContract::Error { message }).await;
+        }
+    }
+}
+
+async fn handle_contract(
+    app: &AppContext,
+    session: &impl ReaderContractsSession,
+    contract: MyNoSqlTcpContract,
+) {
+    match contract {
+        MyNoSqlTcpContract::Ping => {
+            session.send(&MyNoSqlTcpContract::Pong).await;
+        }
+        MyNoSqlTcpContract::Greeting { name } => {
+            let (name, token) = split_reader_token(name);
+
+            my_logger::LOGGER.write_info(
+                "GreetingTcpMessage",
+                "New reader connection",
+                LogEventCtx::new()
+                    .add("Id", session.get_session_id())
+                    .add("Name", name.as_str()),
+            );
+
+            session
+                .greeting(app, ReaderName::AsReader(name), false)
+                .await;
+
+            authenticate(app, session, token).await;
+        }
+
+        MyNoSqlTcpContract::GreetingFromNode {
+            node_location,
+            node_version,
+            compress,
+        } => {
+            let (node_location, token) = split_reader_token(node_location);
+
+            let name = ReaderName::AsNode {
+                location: node_location,
+                version: node_version,
+            };
+            session.greeting(app, name, compress).await;
+
+            authenticate(app, session, token).await;
+        }
+
+        MyNoSqlTcpContract::SetNamespace { namespace } => {
+            let data_reader = session.get_data_reader(app).await;
+
+            let data_reader = match data_reader {
+                Some(data_reader) => data_reader,
+                None => {
+                    session.send(&MyNoSqlTcpContract::Error {
+                        message: "SetNamespace is sent before the Greeting".to_string(),
+                    }).await;
+                    return;
+                }
+            };
+
+            if let Err(message) = set_namespace(app, &data_reader, namespace.as_str()).await {
+                my_logger::LOGGER.write_info(
+                    "SetNamespaceTcpMessage",
+                    message.as_str(),
+                    LogEventCtx::new()
+                        .add("sessionId", session.get_session_id())
+                        .add("name", data_reader.get_name().to_string())
+                        .add("namespace", namespace),
+                );
+
+                session.send(&MyNoSqlTcpContract::Error { message }).await;
+            }
+        }
+
+        MyNoSqlTcpContract::Subscribe { table_name } => {
+            let (table_name, options) = match split_subscribe_options(table_name) {
+                Ok(result) => result,
+                Err(message) => {
+                    session.send(&MyNoSqlTcpContract::Error { message }).await;
+                    return;
+                }
+            };
+
+            subscribe(app, session, table_name, options).await;
+        }
+
+        MyNoSqlTcpContract::SubscribeAsNode(table_name) => {
+            let (table_name, options) = match split_subscribe_options(table_name) {
+                Ok(result) => result,
+                Err(message) => {
+                    session.send(&MyNoSqlTcpContract::Error { message }).await;
+                    return;
+                }
+            };
+
+            if let Some(data_reader) = session.get_data_reader(app).await
+            {
+                // Resolved without creating even when
+                // `AutoCreateTableOnReaderSubscribe` is on: this path refuses
+                // a missing table below instead of creating it, so it has no
+                // business creating the namespace either. A namespace which
+                // does not exist holds no table, which is the very same
+                // TableNotFound the node already knows how to handle.
+                let db_namespace = match get_existing_namespace(app, &data_reader) {
+                    Some(db_namespace) => db_namespace,
+                    None => {
+                        session.send(&MyNoSqlTcpContract::TableNotFound(table_name)).await;
+
+                        return;
+                    }
+                };
+
+                let table = db_namespace.db.get_table(table_name.as_str());
+
+                if table.is_none() {
+                    session.send(&MyNoSqlTcpContract::TableNotFound(table_name)).await;
+
+                    return;
+                }
+
+                let result = crate::operations::data_readers::subscribe(
+                    app,
+                    &db_namespace,
+                    data_reader.clone(),
+                    &table_name,
+                    options.scope,
+                    options.from_sequence,
+                )
+                .await;
+
+                if let Err(err) = result {
+                    let data_reader = session.get_data_reader(app).await;
+
+                    let session_name = if let Some(data_reader) = data_reader {
+                        data_reader.get_name().to_string()
+                    } else {
+                        "".to_string()
+                    };
+
+                    let message =
+                        format!("Subscribe to table {} error. Err: {:?}", table_name, err);
+
+                    my_logger::LOGGER.write_info(
+                        "SubscribeToTableAsNode",
+                        message,
+                        LogEventCtx::new()
+                            .add("sessionId", session.get_session_id())
+                            .add("name", session_name)
+                            .add("tableName", table_name),
+                    );
+                }
+            }
+        }
+
+        MyNoSqlTcpContract::Unsubscribe(table_name) => {
+            if let Some(data_reader) = session.get_data_reader(app).await
+            {
+                data_reader.unsubscribe(table_name.as_str()).await;
+            }
+        }
+
+        MyNoSqlTcpContract::UpdatePartitionsLastReadTime {
+            confirmation_id,
+            table_name,
+            partitions,
+        } => {
+            let db_table = match get_table_of_session(app, session, table_name.as_str()).await {
+                Ok(db_table) => db_table,
+                Err(message) => {
+                    session.send(&MyNoSqlTcpContract::Error { message }).await;
+                    return;
+                }
+            };
+
+            if let Some((_, db_table)) = db_table {
+                crate::db_operations::update_partitions_last_read_time(
+                    &db_table,
+                    partitions.iter().map(|x| x.as_str()),
+                )
+                .await;
+            }
+
+            session.send(&MyNoSqlTcpContract::Confirmation { confirmation_id }).await;
+        }
+
+        MyNoSqlTcpContract::UpdateRowsLastReadTime {
+            confirmation_id,
+            table_name,
+            partition_key,
+            row_keys,
+        } => {
+            let db_table = match get_table_of_session(app, session, table_name.as_str()).await {
+                Ok(db_table) => db_table,
+                Err(message) => {
+                    session.send(&MyNoSqlTcpContract::Error { message }).await;
+                    return;
+                }
+            };
+
+            if let Some((_, db_table)) = db_table {
+                crate::db_operations::update_row_keys_last_read_access_time(
+                    &db_table,
+                    &partition_key,
+                    row_keys.iter().map(|x| x.as_str()),
+                )
+                .await;
+            }
+
+            session.send(&MyNoSqlTcpContract::Confirmation { confirmation_id }).await;
+        }
+
+        MyNoSqlTcpContract::UpdatePartitionsExpirationTime {
+            confirmation_id,
+            table_name,
+            partitions,
+        } => {
+            let db_table = match get_table_of_session(app, session, table_name.as_str()).await {
+                Ok(db_table) => db_table,
+                Err(message) => {
+                    session.send(&MyNoSqlTcpContract::Error { message }).await;
+                    return;
+                }
+            };
+
+            if let Some((_, db_table)) = &db_table {
+                for (partition_key, set_expiration_time) in partitions {
+                    crate::db_operations::update_partition_expiration_time(
+                        db_table,
+                        partition_key,
+                        set_expiration_time,
+                    )
+                }
+            }
+
+            session.send(&MyNoSqlTcpContract::Confirmation { confirmation_id }).await;
+        }
+        MyNoSqlTcpContract::UpdateRowsExpirationTime {
+            confirmation_id,
+            table_name,
+            partition_key,
+            row_keys,
+            expiration_time,
+        } => {
+            let db_table = match get_table_of_session(app, session, table_name.as_str()).await {
+                Ok(db_table) => db_table,
+                Err(message) => {
+                    session.send(&MyNoSqlTcpContract::Error { message }).await;
+                    return;
+                }
+            };
+
+            if let Some((db_namespace, db_table)) = &db_table {
+                crate::db_operations::update_rows_expiration_time(
+                    db_namespace,
+                    db_table,
+                    &partition_key,
+                    row_keys.iter().map(|x| x.as_str()),
+                    expiration_time,
+                )
+            }
+
+            session.send(&MyNoSqlTcpContract::Confirmation { confirmation_id }).await;
+        }
+
+        _ => {}
+    }
+}
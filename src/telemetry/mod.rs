@@ -0,0 +1,6 @@
+mod tracing_settings;
+pub use tracing_settings::*;
+mod trace_span;
+pub use trace_span::*;
+mod traced_grpc_service;
+pub use traced_grpc_service::*;
@@ -0,0 +1,200 @@
+use std::{borrow::Cow, collections::HashMap, sync::OnceLock};
+
+use opentelemetry::{
+    propagation::{Extractor, TextMapPropagator},
+    trace::{SpanContext, SpanKind, Status, TraceContextExt, Tracer, TracerProvider},
+    Context, ContextGuard, KeyValue, Value,
+};
+use opentelemetry_sdk::{
+    propagation::TraceContextPropagator,
+    trace::{SdkTracer, SdkTracerProvider},
+};
+
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext,
+};
+
+use super::{TracingSettings, DEFAULT_TRACING_SERVICE_NAME};
+
+static TRACER: OnceLock<SdkTracer> = OnceLock::new();
+
+/// Turns the spans on. Until it is called - and it is only called with
+/// `Tracing` set - every span below is nothing but an empty struct. The provider
+/// is shut down once the server is, so the last batch of spans gets out.
+pub fn start_tracing(app: &AppContext, settings: &TracingSettings) -> Option<SdkTracerProvider> {
+    let provider = match settings.create_tracer_provider() {
+        Ok(provider) => provider,
+        Err(err) => {
+            app.logs.write(LogItem::error(
+                SystemProcess::Init,
+                format!("Traces are not exported. {}", err),
+            ));
+            return None;
+        }
+    };
+
+    let _ = TRACER.set(provider.tracer(DEFAULT_TRACING_SERVICE_NAME));
+
+    app.logs.write(
+        LogItem::info(SystemProcess::Init, "Exporting traces")
+            .with_context("endpoint", settings.get_endpoint()),
+    );
+
+    Some(provider)
+}
+
+pub fn is_enabled() -> bool {
+    TRACER.get().is_some()
+}
+
+/// A span which ends once dropped - or once the last copy of its context is,
+/// should the span be handed on with `context`.
+pub struct TraceSpan {
+    context: Option<Context>,
+}
+
+impl TraceSpan {
+    fn disabled() -> Self {
+        Self { context: None }
+    }
+
+    /// The context to run what is under the span in - `with_context` of a future.
+    pub fn context(&self) -> Context {
+        match self.context.as_ref() {
+            Some(context) => context.clone(),
+            None => Context::current(),
+        }
+    }
+
+    /// Makes the span the current one until the guard is dropped - for what is
+    /// done without an `.await`, as the guard can not be held across one.
+    pub fn attach(&self) -> Option<ContextGuard> {
+        self.context
+            .as_ref()
+            .map(|context| context.clone().attach())
+    }
+
+    pub fn set_attribute(&self, key: &'static str, value: impl Into<Value>) {
+        if let Some(context) = self.context.as_ref() {
+            context.span().set_attribute(KeyValue::new(key, value));
+        }
+    }
+
+    pub fn set_error(&self, message: impl Into<Cow<'static, str>>) {
+        if let Some(context) = self.context.as_ref() {
+            context.span().set_status(Status::error(message));
+        }
+    }
+}
+
+/// A span under whatever the current one is - the request, mostly.
+pub fn start_span(name: &'static str) -> TraceSpan {
+    if !is_enabled() {
+        return TraceSpan::disabled();
+    }
+
+    start_span_with_parent(name, &Context::current(), SpanKind::Internal)
+}
+
+/// A span of an entry point or of a background job - `parent` is what the caller
+/// sent, or `Context::new()` to start a trace of its own.
+pub fn start_span_with_parent(
+    name: impl Into<Cow<'static, str>>,
+    parent: &Context,
+    kind: SpanKind,
+) -> TraceSpan {
+    let Some(tracer) = TRACER.get() else {
+        return TraceSpan::disabled();
+    };
+
+    let span = tracer
+        .span_builder(name)
+        .with_kind(kind)
+        .start_with_context(tracer, parent);
+
+    TraceSpan {
+        context: Some(parent.with_span(span)),
+    }
+}
+
+/// The span the current operation runs under, to be carried to where the rest
+/// of it is done - the sync loop, for a change. `None` with tracing off.
+pub fn get_current_span_context() -> Option<SpanContext> {
+    if !is_enabled() {
+        return None;
+    }
+
+    let context = Context::current();
+    let span_context = context.span().span_context().clone();
+
+    if span_context.is_valid() {
+        Some(span_context)
+    } else {
+        None
+    }
+}
+
+/// The parent `get_current_span_context` gave, as a context to start a span under.
+pub fn as_parent(span_context: Option<&SpanContext>) -> Context {
+    match span_context {
+        Some(span_context) => Context::new().with_remote_span_context(span_context.clone()),
+        None => Context::new(),
+    }
+}
+
+/// Context of the caller, by the W3C `traceparent` and `tracestate` headers of
+/// its request. Without them - a trace of our own.
+pub fn extract_parent(traceparent: Option<&str>, tracestate: Option<&str>) -> Context {
+    let Some(traceparent) = traceparent else {
+        return Context::new();
+    };
+
+    let mut headers = HashMap::new();
+    headers.insert("traceparent", traceparent);
+
+    if let Some(tracestate) = tracestate {
+        headers.insert("tracestate", tracestate);
+    }
+
+    TraceContextPropagator::new().extract(&TraceHeaders(headers))
+}
+
+struct TraceHeaders<'s>(HashMap<&'static str, &'s str>);
+
+impl Extractor for TraceHeaders<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).copied()
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_parent() {
+        let context = extract_parent(
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            Some("vendor=value"),
+        );
+
+        let span_context = context.span().span_context().clone();
+        assert!(span_context.is_remote());
+        assert_eq!(
+            "4bf92f3577b34da6a3ce929d0e0e4736",
+            span_context.trace_id().to_string()
+        );
+        assert!(span_context.is_sampled());
+
+        let context = extract_parent(Some("not-a-traceparent"), None);
+        assert!(!context.span().span_context().is_valid());
+
+        let context = extract_parent(None, None);
+        assert!(!context.span().span_context().is_valid());
+    }
+}
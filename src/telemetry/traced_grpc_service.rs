@@ -0,0 +1,69 @@
+use std::task::{Context as TaskContext, Poll};
+
+use opentelemetry::{
+    context::{FutureExt, WithContext},
+    trace::SpanKind,
+    Context,
+};
+use tonic::{
+    codegen::{http, Service},
+    server::NamedService,
+};
+
+/// Runs every call of a gRPC service under a span of its own - a child of the
+/// `traceparent` of the call, when there is one. The span ends with the future
+/// of the response, which is all of a unary call.
+#[derive(Clone)]
+pub struct TracedGrpcService<S> {
+    inner: S,
+}
+
+impl<S> TracedGrpcService<S> {
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+}
+
+impl<S: NamedService> NamedService for TracedGrpcService<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S, B> Service<http::Request<B>> for TracedGrpcService<S>
+where
+    S: Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = WithContext<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        if !super::is_enabled() {
+            return self.inner.call(request).with_context(Context::current());
+        }
+
+        let headers = request.headers();
+
+        let parent = super::extract_parent(
+            headers
+                .get("traceparent")
+                .and_then(|value| value.to_str().ok()),
+            headers
+                .get("tracestate")
+                .and_then(|value| value.to_str().ok()),
+        );
+
+        let span = super::start_span_with_parent(
+            request.uri().path().to_string(),
+            &parent,
+            SpanKind::Server,
+        );
+        span.set_attribute("rpc.system", "grpc");
+
+        let context = span.context();
+        self.inner.call(request).with_context(context)
+    }
+}
@@ -0,0 +1,124 @@
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    trace::{Sampler, SdkTracerProvider},
+    Resource,
+};
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_TRACING_SERVICE_NAME: &str = "my-no-sql-server";
+
+/// How the spans get to the collector.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TracingProtocol {
+    /// OTLP over gRPC - port 4317 of a collector.
+    #[default]
+    #[serde(rename = "grpc")]
+    Grpc,
+    /// OTLP over HTTP with protobuf - port 4318 of a collector.
+    #[serde(rename = "http")]
+    Http,
+}
+
+/// `Tracing` of the settings file. Not set - no span is made at all.
+///
+/// ```yaml
+/// Tracing:
+///   Endpoint: http://localhost:4317
+///   Protocol: grpc
+///   ServiceName: my-no-sql-server
+///   SampleRatio: 0.1
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TracingSettings {
+    /// The collector. Over HTTP it is the collector itself as well - the
+    /// `/v1/traces` path is added unless it is there already.
+    #[serde(rename = "Endpoint")]
+    pub endpoint: String,
+    #[serde(rename = "Protocol", default)]
+    pub protocol: TracingProtocol,
+    #[serde(rename = "ServiceName", default)]
+    pub service_name: Option<String>,
+    /// Share of the traces started here which are recorded, 1.0 when not set. A
+    /// request which comes with a `traceparent` is recorded if its caller's is.
+    #[serde(rename = "SampleRatio", default)]
+    pub sample_ratio: Option<f64>,
+}
+
+impl TracingSettings {
+    pub fn get_service_name(&self) -> String {
+        match self.service_name.as_ref() {
+            Some(service_name) => service_name.to_string(),
+            None => DEFAULT_TRACING_SERVICE_NAME.to_string(),
+        }
+    }
+
+    pub fn get_endpoint(&self) -> String {
+        let endpoint = self.endpoint.trim_end_matches('/');
+
+        match self.protocol {
+            TracingProtocol::Grpc => endpoint.to_string(),
+            TracingProtocol::Http => {
+                if endpoint.ends_with("/v1/traces") {
+                    endpoint.to_string()
+                } else {
+                    format!("{}/v1/traces", endpoint)
+                }
+            }
+        }
+    }
+
+    pub fn get_sample_ratio(&self) -> f64 {
+        self.sample_ratio.unwrap_or(1.0).clamp(0.0, 1.0)
+    }
+
+    pub fn create_tracer_provider(&self) -> Result<SdkTracerProvider, String> {
+        let exporter = match self.protocol {
+            TracingProtocol::Grpc => opentelemetry_otlp::SpanExporter::builder()
+                .with_tonic()
+                .with_endpoint(self.get_endpoint())
+                .build(),
+            TracingProtocol::Http => opentelemetry_otlp::SpanExporter::builder()
+                .with_http()
+                .with_endpoint(self.get_endpoint())
+                .build(),
+        }
+        .map_err(|err| format!("Can not create the OTLP exporter. Err: {}", err))?;
+
+        let sampler = Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            self.get_sample_ratio(),
+        )));
+
+        let resource = Resource::builder()
+            .with_service_name(self.get_service_name())
+            .build();
+
+        let provider = SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_sampler(sampler)
+            .with_resource(resource)
+            .build();
+
+        Ok(provider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_by_protocol() {
+        let settings: TracingSettings =
+            serde_yaml::from_str("Endpoint: http://localhost:4318/\nProtocol: http\n").unwrap();
+
+        assert_eq!("http://localhost:4318/v1/traces", settings.get_endpoint());
+        assert_eq!(DEFAULT_TRACING_SERVICE_NAME, settings.get_service_name());
+
+        let settings: TracingSettings =
+            serde_yaml::from_str("Endpoint: http://localhost:4317\nSampleRatio: 2\n").unwrap();
+
+        assert_eq!(TracingProtocol::Grpc, settings.protocol);
+        assert_eq!("http://localhost:4317", settings.get_endpoint());
+        assert_eq!(1.0, settings.get_sample_ratio());
+    }
+}
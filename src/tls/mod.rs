@@ -0,0 +1,6 @@
+mod tls_config;
+pub use tls_config::*;
+mod tls_peers;
+pub use tls_peers::*;
+mod tls_terminator;
+pub use tls_terminator::*;
@@ -0,0 +1,222 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+use arc_swap::ArcSwap;
+use serde::{Deserialize, Serialize};
+use tokio_rustls::rustls::{
+    pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
+    server::WebPkiClientVerifier,
+    RootCertStore, ServerConfig,
+};
+
+/// Of `InternalPortOffset` when it is not set.
+pub const DEFAULT_INTERNAL_PORT_OFFSET: u16 = 10000;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TlsSettings {
+    #[serde(rename = "CertFile")]
+    pub cert_file: String,
+    #[serde(rename = "KeyFile")]
+    pub key_file: String,
+    /// CA bundle the clients' certificates are checked against. Not set - clients
+    /// are not asked for one.
+    #[serde(rename = "ClientCaFile", default)]
+    pub client_ca_file: Option<String>,
+    /// With TLS on, the plain HTTP and reader TCP listeners move this many ports up
+    /// and to the loopback interface - their own ports are taken by TLS, which
+    /// forwards to them.
+    #[serde(rename = "InternalPortOffset", default)]
+    pub internal_port_offset: Option<u16>,
+}
+
+impl TlsSettings {
+    pub fn get_internal_port_offset(&self) -> u16 {
+        self.internal_port_offset
+            .unwrap_or(DEFAULT_INTERNAL_PORT_OFFSET)
+    }
+
+    fn get_files(&self) -> Vec<String> {
+        let mut result = vec![format_path(&self.cert_file), format_path(&self.key_file)];
+
+        if let Some(client_ca_file) = self.client_ca_file.as_ref() {
+            result.push(format_path(client_ca_file));
+        }
+
+        result
+    }
+}
+
+/// Address the plain HTTP or reader TCP listener configured at `listen_addr` binds.
+pub fn get_listen_addr(tls: Option<&TlsSettings>, listen_addr: SocketAddr) -> SocketAddr {
+    match tls {
+        Some(tls) => get_internal_addr(tls, listen_addr),
+        None => listen_addr,
+    }
+}
+
+/// Where TLS at `listen_addr` forwards what it decrypts. The settings are checked for
+/// the port to leave room for the offset.
+pub fn get_internal_addr(tls: &TlsSettings, listen_addr: SocketAddr) -> SocketAddr {
+    SocketAddr::from((
+        [127, 0, 0, 1],
+        listen_addr.port() + tls.get_internal_port_offset(),
+    ))
+}
+
+/// What the connections of a listener speak inside TLS.
+#[derive(Clone, Copy)]
+pub enum TlsProtocol {
+    /// HTTP/1.1 or HTTP/2 - gRPC included - negotiated with ALPN.
+    Http,
+    /// The reader TCP protocol.
+    Tcp,
+}
+
+struct TlsServerConfigs {
+    http: Arc<ServerConfig>,
+    tcp: Arc<ServerConfig>,
+}
+
+/// Certificate, key and client CA of the listeners - as of the last time they were
+/// read. A connection gets the configuration current when it is accepted, so the
+/// files may be replaced while the server runs.
+pub struct TlsConfig {
+    settings: TlsSettings,
+    server_configs: ArcSwap<TlsServerConfigs>,
+    files_modified: Mutex<Vec<Option<SystemTime>>>,
+    pub peers: super::TlsPeers,
+}
+
+impl TlsConfig {
+    pub async fn load(settings: TlsSettings) -> Result<Self, String> {
+        let files_modified = get_files_modified(&settings).await;
+
+        let server_configs = match build_server_configs(&settings).await {
+            Ok(server_configs) => server_configs,
+            Err(err) => return Err(format!("Can not load TLS certificates. Err: {}", err)),
+        };
+
+        Ok(Self {
+            settings,
+            server_configs: ArcSwap::from_pointee(server_configs),
+            files_modified: Mutex::new(files_modified),
+            peers: super::TlsPeers::new(),
+        })
+    }
+
+    pub fn get_settings(&self) -> &TlsSettings {
+        &self.settings
+    }
+
+    pub fn get_server_config(&self, protocol: TlsProtocol) -> Arc<ServerConfig> {
+        let server_configs = self.server_configs.load();
+
+        match protocol {
+            TlsProtocol::Http => server_configs.http.clone(),
+            TlsProtocol::Tcp => server_configs.tcp.clone(),
+        }
+    }
+
+    /// Reads the files again if any of them changed since they were read, and tells
+    /// whether they did. On an error what was read before stays in use, and the files
+    /// are read again on the next call.
+    pub async fn reload_if_changed(&self) -> Result<bool, String> {
+        let files_modified = get_files_modified(&self.settings).await;
+
+        if *self.files_modified.lock().unwrap() == files_modified {
+            return Ok(false);
+        }
+
+        let server_configs = build_server_configs(&self.settings).await?;
+
+        self.server_configs.store(Arc::new(server_configs));
+        *self.files_modified.lock().unwrap() = files_modified;
+
+        Ok(true)
+    }
+}
+
+async fn get_files_modified(settings: &TlsSettings) -> Vec<Option<SystemTime>> {
+    let mut result = Vec::new();
+
+    for file_name in settings.get_files() {
+        let modified = match tokio::fs::metadata(file_name.as_str()).await {
+            Ok(metadata) => metadata.modified().ok(),
+            Err(_) => None,
+        };
+
+        result.push(modified);
+    }
+
+    result
+}
+
+async fn build_server_configs(settings: &TlsSettings) -> Result<TlsServerConfigs, String> {
+    let certificates = read_certificates(settings.cert_file.as_str()).await?;
+
+    let key_file = format_path(&settings.key_file);
+    let key = PrivateKeyDer::from_pem_slice(read_file(key_file.as_str()).await?.as_slice())
+        .map_err(|err| format!("Invalid private key in [{}]. Err: {}", key_file, err))?;
+
+    let builder = ServerConfig::builder();
+
+    let builder = match settings.client_ca_file.as_ref() {
+        Some(client_ca_file) => {
+            let mut roots = RootCertStore::empty();
+
+            for certificate in read_certificates(client_ca_file).await? {
+                roots.add(certificate).map_err(|err| {
+                    format!("Invalid client CA in [{}]. Err: {}", client_ca_file, err)
+                })?;
+            }
+
+            let verifier = WebPkiClientVerifier::builder(Arc::new(roots))
+                .build()
+                .map_err(|err| format!("Invalid client CA bundle. Err: {}", err))?;
+
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let tcp = builder
+        .with_single_cert(certificates, key)
+        .map_err(|err| format!("Certificate does not match the key. Err: {}", err))?;
+
+    let mut http = tcp.clone();
+    http.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(TlsServerConfigs {
+        http: Arc::new(http),
+        tcp: Arc::new(tcp),
+    })
+}
+
+async fn read_certificates(file_name: &str) -> Result<Vec<CertificateDer<'static>>, String> {
+    let file_name = format_path(file_name);
+
+    let content = read_file(file_name.as_str()).await?;
+
+    let certificates = CertificateDer::pem_slice_iter(content.as_slice())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("Invalid certificates in [{}]. Err: {}", file_name, err))?;
+
+    if certificates.is_empty() {
+        return Err(format!("No certificates in [{}]", file_name));
+    }
+
+    Ok(certificates)
+}
+
+async fn read_file(file_name: &str) -> Result<Vec<u8>, String> {
+    tokio::fs::read(file_name)
+        .await
+        .map_err(|err| format!("Can not read [{}]. Err: {}", file_name, err))
+}
+
+fn format_path(file_name: &str) -> String {
+    my_no_sql_sdk::core::rust_extensions::file_utils::format_path(file_name).to_string()
+}
@@ -0,0 +1,45 @@
+use std::{collections::HashMap, net::SocketAddr, sync::Mutex};
+
+use crate::app::AppContext;
+
+/// Clients of the HTTP and the reader TCP TLS listeners, by the address the plain connection forwarding one
+/// of them to the internal listener comes from. An internal listener sees the
+/// terminator as the peer of every connection; this is how it learns who is behind
+/// it - and which connections did not come through TLS at all.
+#[derive(Default)]
+pub struct TlsPeers {
+    peers: Mutex<HashMap<SocketAddr, SocketAddr>>,
+}
+
+impl TlsPeers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&self, internal_addr: SocketAddr, peer_addr: SocketAddr) {
+        self.peers.lock().unwrap().insert(internal_addr, peer_addr);
+    }
+
+    pub fn remove(&self, internal_addr: &SocketAddr) {
+        self.peers.lock().unwrap().remove(internal_addr);
+    }
+
+    pub fn get(&self, internal_addr: &SocketAddr) -> Option<SocketAddr> {
+        self.peers.lock().unwrap().get(internal_addr).copied()
+    }
+}
+
+/// The client behind a connection to one of the TCP listeners, as `addr` is what the
+/// listener sees. With TLS on the listeners are on the loopback interface, and a
+/// connection which is not one the terminator made is refused: a local process would
+/// skip the client certificate check by connecting to the internal port directly.
+pub fn get_peer_addr(app: &AppContext, addr: SocketAddr) -> Result<SocketAddr, String> {
+    let Some(tls) = app.tls.as_ref() else {
+        return Ok(addr);
+    };
+
+    match tls.peers.get(&addr) {
+        Some(peer_addr) => Ok(peer_addr),
+        None => Err(format!("Connection from {} did not come through TLS", addr)),
+    }
+}
@@ -0,0 +1,115 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use tokio::net::{TcpListener, TcpSocket, TcpStream};
+use tokio_rustls::TlsAcceptor;
+
+use crate::app::{
+    logs::{LogItem, SystemProcess},
+    AppContext,
+};
+
+use super::TlsProtocol;
+
+/// Takes the TLS connections of `addr` and forwards what they carry to the plain HTTP
+/// or reader TCP listener behind it, on the loopback interface - those are bound by
+/// the libraries which serve them, and take plain connections only. The address every forwarding
+/// connection comes from is registered in `TlsPeers` before it connects, so the
+/// listener knows the client behind it from the first byte on.
+pub async fn start_tls_terminator(app: Arc<AppContext>, addr: SocketAddr, protocol: TlsProtocol) {
+    let listener = match TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(err) => panic!("Can not listen TLS at {}. Err: {}", addr, err),
+    };
+
+    println!("Listening TLS at {}", addr);
+
+    let Some(tls) = app.tls.as_ref() else {
+        return;
+    };
+
+    let internal_addr = super::get_internal_addr(tls.get_settings(), addr);
+
+    loop {
+        let (stream, peer_addr) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(err) => {
+                app.logs.write(
+                    LogItem::error(
+                        SystemProcess::Tls,
+                        format!("Can not accept a connection. Err: {}", err),
+                    )
+                    .with_context("addr", addr.to_string()),
+                );
+                continue;
+            }
+        };
+
+        tokio::spawn(forward(
+            app.clone(),
+            stream,
+            peer_addr,
+            internal_addr,
+            protocol,
+        ));
+    }
+}
+
+async fn forward(
+    app: Arc<AppContext>,
+    stream: TcpStream,
+    peer_addr: SocketAddr,
+    internal_addr: SocketAddr,
+    protocol: TlsProtocol,
+) {
+    let Some(tls) = app.tls.as_ref() else {
+        return;
+    };
+
+    let acceptor = TlsAcceptor::from(tls.get_server_config(protocol));
+
+    // A failed handshake - a plain client, a scanner, a client certificate the CA did
+    // not sign - is not worth a log record of its own.
+    let Ok(mut tls_stream) = acceptor.accept(stream).await else {
+        return;
+    };
+
+    let (socket, local_addr) = match bind_internal_socket() {
+        Ok(bound) => bound,
+        Err(err) => {
+            app.logs.write(LogItem::error(
+                SystemProcess::Tls,
+                format!("Can not open a loopback socket. Err: {}", err),
+            ));
+            return;
+        }
+    };
+
+    tls.peers.add(local_addr, peer_addr);
+
+    match socket.connect(internal_addr).await {
+        Ok(mut internal_stream) => {
+            let _ = tokio::io::copy_bidirectional(&mut tls_stream, &mut internal_stream).await;
+        }
+        Err(err) => {
+            app.logs.write(
+                LogItem::error(
+                    SystemProcess::Tls,
+                    format!("Can not connect to {}. Err: {}", internal_addr, err),
+                )
+                .with_context("peer", peer_addr.to_string()),
+            );
+        }
+    }
+
+    tls.peers.remove(&local_addr);
+}
+
+/// A socket on the loopback interface with its port already taken, so the address the
+/// listener is going to see is known before the connection is made.
+fn bind_internal_socket() -> std::io::Result<(TcpSocket, SocketAddr)> {
+    let socket = TcpSocket::new_v4()?;
+    socket.bind(SocketAddr::from(([127, 0, 0, 1], 0)))?;
+    let local_addr = socket.local_addr()?;
+
+    Ok((socket, local_addr))
+}
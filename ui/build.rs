@@ -7,5 +7,6 @@ fn main() {
         .add_file("05-data.css")
         .add_file("06-connections.css")
         .add_file("07-mini-chart.css")
+        .add_file("08-logs.css")
         .compile("./public/assets/app.css");
 }
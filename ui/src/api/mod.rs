@@ -35,12 +35,17 @@ fn get_base_url() -> String {
 /// the pre-namespace behaviour is preserved byte for byte.
 const NAMESPACE_HEADER: &str = "ns";
 
+/// Tells the server's audit log that a request comes from the UI.
+const CLIENT_HEADER: &str = "x-client";
+
 /// Every request is built through here so the namespace can never be forgotten
 /// at a call site. The value is read from localStorage on each call rather than
 /// threaded through a `Signal`: these are free `async fn`s, not components, so
 /// they cannot reach into the Dioxus context.
 fn request(method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
-    let builder = reqwest::Client::new().request(method, url);
+    let builder = reqwest::Client::new()
+        .request(method, url)
+        .header(CLIENT_HEADER, "ui");
 
     match crate::storage::load_namespace() {
         Some(namespace) => builder.header(NAMESPACE_HEADER, namespace),
@@ -53,7 +58,9 @@ fn request(method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
 /// in the header. Sending a stale value here would conjure the very namespace we
 /// are trying to check for.
 fn request_without_namespace(method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
-    reqwest::Client::new().request(method, url)
+    reqwest::Client::new()
+        .request(method, url)
+        .header(CLIENT_HEADER, "ui")
 }
 
 pub fn download_rows_url(table_name: &str, partition_key: &str) -> String {
@@ -117,6 +124,22 @@ pub async fn get_status() -> Result<StatusApiModel, RequestError> {
     Ok(result)
 }
 
+/// Progress of the load of the tables on start up. The server answers `503`
+/// until it is over - with the same body.
+pub async fn get_ready() -> Result<ReadyApiModel, RequestError> {
+    let url = format!("{}/api/Ready", get_base_url());
+    let response = request(reqwest::Method::GET, &url).send().await?;
+    if !response.status().is_success()
+        && response.status() != reqwest::StatusCode::SERVICE_UNAVAILABLE
+    {
+        return Err(RequestError {
+            message: format!("Failed to load readiness: {}", response.status()),
+        });
+    }
+    let result: ReadyApiModel = response.json().await?;
+    Ok(result)
+}
+
 pub async fn get_connections() -> Result<ConnectionsApiModel, RequestError> {
     let url = format!("{}/api/Connections", get_base_url());
     let response = request(reqwest::Method::GET, &url).send().await?;
@@ -129,6 +152,55 @@ pub async fn get_connections() -> Result<ConnectionsApiModel, RequestError> {
     Ok(result)
 }
 
+/// Latest operations which took longer than their threshold, the newest first.
+pub async fn get_slow_operations() -> Result<SlowOperationsApiModel, RequestError> {
+    let url = format!("{}/api/Debug/SlowOperations", get_base_url());
+    let response = request(reqwest::Method::GET, &url).send().await?;
+    if !response.status().is_success() {
+        return Err(RequestError {
+            message: format!("Failed to load slow operations: {}", response.status()),
+        });
+    }
+    let result: SlowOperationsApiModel = response.json().await?;
+    Ok(result)
+}
+
+/// Latest log entries of the server, the newest first. `table` - of a table of
+/// the selected namespace only, `process` - of one process only, `fatal_errors` -
+/// the fatal errors only.
+pub async fn get_logs(
+    table: Option<&str>,
+    process: Option<&str>,
+    fatal_errors: bool,
+) -> Result<LogsApiModel, RequestError> {
+    let url = if let Some(table) = table {
+        format!(
+            "{}/api/Logs/Table?tableName={}",
+            get_base_url(),
+            url_escape(table)
+        )
+    } else if let Some(process) = process {
+        format!(
+            "{}/api/Logs/Process?process={}",
+            get_base_url(),
+            url_escape(process)
+        )
+    } else if fatal_errors {
+        format!("{}/api/Logs/FatalErrors", get_base_url())
+    } else {
+        format!("{}/api/Logs", get_base_url())
+    };
+
+    let response = request(reqwest::Method::GET, &url).send().await?;
+    if !response.status().is_success() {
+        return Err(RequestError {
+            message: format!("Failed to load logs: {}", response.status()),
+        });
+    }
+    let result: LogsApiModel = response.json().await?;
+    Ok(result)
+}
+
 pub async fn get_tables_list() -> Result<Vec<TableListItemApiModel>, RequestError> {
     let url = format!("{}/api/Tables/List", get_base_url());
     let response = request(reqwest::Method::GET, &url).send().await?;
@@ -370,6 +442,34 @@ pub async fn set_ui_writes(enabled: bool) -> Result<(), RequestError> {
     Ok(())
 }
 
+/// Settings the server runs with - read-only, they are changed in the settings file.
+pub async fn get_effective_settings() -> Result<EffectiveSettingsApiModel, RequestError> {
+    let url = format!("{}/api/Settings/Effective", get_base_url());
+    let response = request(reqwest::Method::GET, &url).send().await?;
+    if !response.status().is_success() {
+        return Err(RequestError {
+            message: format!("Failed to load server settings: {}", response.status()),
+        });
+    }
+    let result: EffectiveSettingsApiModel = response.json().await?;
+    Ok(result)
+}
+
+/// Has the server read its settings file again via POST `/api/Settings/Reload`.
+pub async fn reload_settings() -> Result<SettingsReloadApiModel, RequestError> {
+    let url = format!("{}/api/Settings/Reload", get_base_url());
+    let response = request(reqwest::Method::POST, &url).send().await?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(RequestError {
+            message: format!("Reload failed ({}): {}", status, body),
+        });
+    }
+    let result: SettingsReloadApiModel = response.json().await?;
+    Ok(result)
+}
+
 /// Message shown when a write is attempted while write access is off.
 pub const WRITE_ACCESS_DISABLED: &str = "Write access is DISABLED. \
     Open Settings \u{2192} Write access and click \"Enable\" to allow writes for 10 minutes.";
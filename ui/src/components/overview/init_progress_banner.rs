@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+use crate::api::get_ready;
+use crate::components::atoms::{Badge, BadgeTone, Icon, IconKind};
+use crate::models::{ReadyApiModel, ReadyNamespaceApiModel};
+use crate::utils::format_bytes;
+
+#[derive(Default)]
+struct InitProgressState {
+    started: bool,
+    ready: Option<ReadyApiModel>,
+}
+
+/// How far the server got loading its tables on start up - shown instead of the
+/// overview until it is done. Polled every second.
+#[component]
+pub fn InitProgressBanner() -> Element {
+    let mut state = use_signal(InitProgressState::default);
+
+    let started_val = state.read().started;
+    let on_mount = move |_| {
+        if started_val {
+            return;
+        }
+        state.write().started = true;
+        spawn(async move {
+            loop {
+                match get_ready().await {
+                    Ok(result) => {
+                        let ready = result.ready;
+                        state.write().ready = Some(result);
+                        if ready {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        dioxus_utils::console_log(&format!("Ready error: {}", err));
+                    }
+                }
+                dioxus_utils::js::sleep(Duration::from_secs(1)).await;
+            }
+        });
+    };
+
+    let Some(ready) = state.read().ready.clone() else {
+        return rsx! {
+            div { class: "empty-state", onmounted: on_mount,
+                div { class: "empty-state__title", "Server is initializing…" }
+            }
+        };
+    };
+
+    let percent = (ready.progress * 100.0).clamp(0.0, 100.0);
+    let headline = format!("Loading tables · {:.0}%", percent);
+
+    let mut sub = format!("{} of {} tables", ready.tables_loaded, ready.tables_total);
+    if let Some(current_table) = ready.current_table.as_ref() {
+        sub.push_str(&format!(" · now {}", current_table));
+    }
+    if let Some(eta_seconds) = ready.eta_seconds {
+        sub.push_str(&format!(" · about {} left", format_seconds(eta_seconds)));
+    }
+    if ready.skipped_partitions > 0 {
+        sub.push_str(&format!(
+            " · {} broken partition{} skipped",
+            ready.skipped_partitions,
+            if ready.skipped_partitions == 1 {
+                ""
+            } else {
+                "s"
+            }
+        ));
+    }
+
+    let tone = if ready.skipped_partitions > 0 {
+        "health health--bad"
+    } else {
+        "health health--warn"
+    };
+
+    let elapsed = format_seconds(ready.initializing_seconds.max(0) as u64);
+    let bar_style = format!("width:{:.1}%", percent);
+
+    let namespaces_amount = ready.namespaces.len();
+    let rows = ready.namespaces.iter().map(render_namespace);
+
+    rsx! {
+        section { class: tone, onmounted: on_mount,
+            div { class: "health__icon",
+                Icon { kind: IconKind::Database }
+            }
+            div { class: "health__text",
+                div { class: "health__headline", "{headline}" }
+                div { class: "health__sub", "{sub}" }
+                div { class: "init-progress",
+                    div { class: "init-progress__bar", style: "{bar_style}" }
+                }
+            }
+            div { class: "health__uptime",
+                span { class: "health__uptime-label", "Loading for" }
+                "{elapsed}"
+            }
+        }
+        div { class: "card",
+            div { class: "card__header",
+                span { class: "card__title", "Namespaces" }
+                span { class: "card__subtitle", "{namespaces_amount}" }
+            }
+            table { class: "dt",
+                thead {
+                    tr {
+                        th { "Namespace" }
+                        th { "Status" }
+                        th { "Tables" }
+                        th { "Loaded" }
+                        th { "Skipped partitions" }
+                    }
+                }
+                tbody { {rows} }
+            }
+        }
+    }
+}
+
+fn render_namespace(namespace: &ReadyNamespaceApiModel) -> Element {
+    let tables_loaded = namespace.tables.iter().filter(|itm| itm.loaded).count();
+    let tables_total = namespace.tables.len();
+
+    let bytes = if namespace.bytes_total > 0 {
+        format!(
+            "{} of {}",
+            format_bytes(namespace.bytes_loaded as f64),
+            format_bytes(namespace.bytes_total as f64)
+        )
+    } else {
+        "—".to_string()
+    };
+
+    let skipped: Vec<String> = namespace
+        .tables
+        .iter()
+        .flat_map(|table| {
+            table
+                .skipped_partitions
+                .iter()
+                .map(move |itm| format!("{}/{}", table.table_name, itm.partition_key))
+        })
+        .collect();
+    let skipped = skipped.join(" ");
+
+    let tone = match namespace.status.as_str() {
+        "loaded" => BadgeTone::Ok,
+        "loading" | "reading" => BadgeTone::Warn,
+        _ => BadgeTone::Neutral,
+    };
+
+    rsx! {
+        tr {
+            td { class: "mono", "{namespace.namespace}" }
+            td {
+                Badge { text: namespace.status.clone(), tone }
+            }
+            td { class: "mono", "{tables_loaded}/{tables_total}" }
+            td { class: "mono", "{bytes}" }
+            td { class: "mono muted", "{skipped}" }
+        }
+    }
+}
+
+fn format_seconds(seconds: u64) -> String {
+    if seconds < 60 {
+        return format!("{}s", seconds);
+    }
+    if seconds < 3600 {
+        return format!("{}m {}s", seconds / 60, seconds % 60);
+    }
+    format!("{}h {}m", seconds / 3600, seconds % 3600 / 60)
+}
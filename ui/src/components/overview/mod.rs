@@ -15,3 +15,12 @@ pub use writers_table::*;
 
 mod readers_table;
 pub use readers_table::*;
+
+mod operations_chart;
+pub use operations_chart::*;
+
+mod slow_operations_panel;
+pub use slow_operations_panel::*;
+
+mod init_progress_banner;
+pub use init_progress_banner::*;
@@ -0,0 +1,170 @@
+use dioxus::prelude::*;
+
+use crate::components::atoms::{MiniChart, MiniChartSeries};
+use crate::models::OperationStatsApiModel;
+use crate::utils::format_duration;
+
+/// Operation totals of one poll. Two in a row make the rates charted below.
+#[derive(Clone, PartialEq)]
+pub struct OperationsSample {
+    pub operations: Vec<OperationStatsApiModel>,
+}
+
+#[derive(Clone, Copy, Default)]
+struct Delta {
+    count: u64,
+    errors: u64,
+    duration_micros: u64,
+}
+
+impl Delta {
+    fn add(&mut self, other: Delta) {
+        self.count += other.count;
+        self.errors += other.errors;
+        self.duration_micros += other.duration_micros;
+    }
+
+    fn avg_latency_micros(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.duration_micros as f64 / self.count as f64
+    }
+}
+
+/// What happened between two polls, per kind. The totals only grow, so a
+/// smaller one means the server was restarted - that step counts as nothing.
+fn get_deltas(prev: &OperationsSample, next: &OperationsSample) -> Vec<(String, Delta)> {
+    next.operations
+        .iter()
+        .map(|itm| {
+            let delta = match prev.operations.iter().find(|p| p.kind == itm.kind) {
+                Some(p) if p.count <= itm.count => Delta {
+                    count: itm.count - p.count,
+                    errors: itm.errors.saturating_sub(p.errors),
+                    duration_micros: itm.duration_micros.saturating_sub(p.duration_micros),
+                },
+                _ => Delta::default(),
+            };
+            (itm.kind.clone(), delta)
+        })
+        .collect()
+}
+
+/// Operations per second and their average latency, all kinds together on the
+/// chart and every kind on its own below it. Polled once a second.
+#[component]
+pub fn OperationsChart(history: Vec<OperationsSample>) -> Element {
+    let steps: Vec<Vec<(String, Delta)>> = history
+        .windows(2)
+        .map(|pair| get_deltas(&pair[0], &pair[1]))
+        .collect();
+
+    let totals: Vec<Delta> = steps
+        .iter()
+        .map(|step| {
+            let mut total = Delta::default();
+            for (_, delta) in step {
+                total.add(*delta);
+            }
+            total
+        })
+        .collect();
+
+    let ops_series = vec![
+        MiniChartSeries::new(
+            totals.iter().map(|t| t.count as f64).collect(),
+            "mini-chart__line--in",
+        ),
+        MiniChartSeries::new(
+            totals.iter().map(|t| t.errors as f64).collect(),
+            "mini-chart__line--write",
+        ),
+    ];
+    let ops_max = totals.iter().map(|t| t.count).max().unwrap_or(0).max(1) as f64;
+
+    let latency_series = vec![MiniChartSeries::new(
+        totals
+            .iter()
+            .map(|t| t.avg_latency_micros() / 1_000.0)
+            .collect(),
+        "mini-chart__line--read",
+    )];
+    let latency_max = totals
+        .iter()
+        .map(|t| t.avg_latency_micros() / 1_000.0)
+        .fold(0.0, f64::max)
+        .max(1.0);
+
+    let last = totals.last().copied().unwrap_or_default();
+    let last_latency = format_duration(last.avg_latency_micros());
+
+    let rows = steps
+        .last()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(kind, delta)| {
+            let latency = format_duration(delta.avg_latency_micros());
+            rsx! {
+                tr {
+                    td { "{kind}" }
+                    td { class: "mono", "{delta.count}/s" }
+                    td { class: "mono", "{latency}" }
+                    td { class: "mono muted", "{delta.errors}" }
+                }
+            }
+        });
+
+    rsx! {
+        div { class: "card",
+            div { class: "card__header",
+                span { class: "card__title", "Operations" }
+                div { class: "conn-legend",
+                    span { class: "conn-legend__item",
+                        span { class: "conn-legend__dot conn-legend__dot--in" }
+                        "Ops "
+                        b { "{last.count}/s" }
+                    }
+                    span { class: "conn-legend__item",
+                        span { class: "conn-legend__dot conn-legend__dot--write" }
+                        "Errors "
+                        b { "{last.errors}/s" }
+                    }
+                    span { class: "conn-legend__item",
+                        span { class: "conn-legend__dot conn-legend__dot--out" }
+                        "Avg latency "
+                        b { "{last_latency}" }
+                    }
+                }
+            }
+            div { class: "card__body",
+                div { class: "conn-chart-label", "Operations (ops/s)" }
+                MiniChart {
+                    series: ops_series,
+                    max: ops_max,
+                    label: format!("{} ops/s", ops_max),
+                    height: 140.0,
+                }
+                div { class: "conn-chart-label", "Average latency (ms)" }
+                MiniChart {
+                    series: latency_series,
+                    max: latency_max,
+                    label: format!("{:.1} ms", latency_max),
+                    height: 140.0,
+                }
+            }
+            table { class: "dt",
+                thead {
+                    tr {
+                        th { "Kind" }
+                        th { "Rate" }
+                        th { "Avg latency" }
+                        th { "Errors" }
+                    }
+                }
+                tbody { {rows} }
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+use crate::api::get_slow_operations;
+use crate::components::atoms::{Badge, BadgeTone};
+use crate::models::{DEFAULT_NAMESPACE, SlowOperationApiModel};
+use crate::utils::{format_bytes, format_duration};
+
+/// How many of the latest slow operations the panel shows.
+const MAX_ROWS: usize = 20;
+
+#[derive(Default)]
+struct SlowOperationsState {
+    started: bool,
+    items: Option<Vec<SlowOperationApiModel>>,
+}
+
+/// Latest operations which took longer than their threshold, with whatever the
+/// server knew about them. Polled every few seconds.
+#[component]
+pub fn SlowOperationsPanel() -> Element {
+    let mut state = use_signal(SlowOperationsState::default);
+
+    let started_val = state.read().started;
+    let on_mount = move |_| {
+        if started_val {
+            return;
+        }
+        state.write().started = true;
+        spawn(async move {
+            loop {
+                match get_slow_operations().await {
+                    Ok(result) => state.write().items = Some(result.items),
+                    Err(err) => {
+                        dioxus_utils::console_log(&format!("Slow operations error: {}", err));
+                    }
+                }
+                dioxus_utils::js::sleep(Duration::from_secs(5)).await;
+            }
+        });
+    };
+
+    let items = state.read().items.clone();
+
+    let (subtitle, content) = match items {
+        Some(items) if items.is_empty() => (
+            "none".to_string(),
+            rsx! {
+                div { style: "color:var(--text-muted); font-size:12px; text-align:center; padding:14px;",
+                    "No operation went over its threshold"
+                }
+            },
+        ),
+        Some(items) => (
+            format!("latest {}", items.len().min(MAX_ROWS)),
+            render_items(&items),
+        ),
+        None => (
+            "loading".to_string(),
+            rsx! {
+                div { style: "color:var(--text-muted); font-size:12px; text-align:center; padding:14px;",
+                    "Loading…"
+                }
+            },
+        ),
+    };
+
+    rsx! {
+        div { class: "card", onmounted: on_mount,
+            div { class: "card__header",
+                span { class: "card__title", "Slow operations" }
+                span { class: "card__subtitle", "{subtitle}" }
+            }
+            {content}
+        }
+    }
+}
+
+fn render_items(items: &[SlowOperationApiModel]) -> Element {
+    let rows = items.iter().take(MAX_ROWS).map(|item| {
+        let table = if item.table_name.is_empty() {
+            item.namespace.to_string()
+        } else if item.namespace == DEFAULT_NAMESPACE || item.namespace.is_empty() {
+            item.table_name.to_string()
+        } else {
+            format!("{}/{}", item.namespace, item.table_name)
+        };
+
+        let mut details = Vec::new();
+        if let Some(partition_key) = item.partition_key.as_ref() {
+            details.push(format!("partition={}", partition_key));
+        }
+        if let Some(rows) = item.rows {
+            details.push(format!("rows={}", rows));
+        }
+        if let Some(payload_size) = item.payload_size {
+            details.push(format!("payload={}", format_bytes(payload_size as f64)));
+        }
+        if let Some(session) = item.session.as_ref() {
+            details.push(format!("session={}", session));
+        }
+        let details = details.join(" ");
+
+        let duration = format_duration(item.duration_micros as f64);
+        let (result, tone) = if item.succeeded {
+            ("ok", BadgeTone::Neutral)
+        } else {
+            ("error", BadgeTone::Bad)
+        };
+
+        rsx! {
+            tr {
+                td { class: "mono muted", "{item.date}" }
+                td { "{item.kind}" }
+                td { class: "mono", "{item.operation}" }
+                td { class: "mono", "{table}" }
+                td { class: "mono", "{duration}" }
+                td {
+                    Badge { text: result.to_string(), tone }
+                }
+                td { class: "mono muted", "{details}" }
+            }
+        }
+    });
+
+    rsx! {
+        table { class: "dt",
+            thead {
+                tr {
+                    th { "Date" }
+                    th { "Kind" }
+                    th { "Operation" }
+                    th { "Table" }
+                    th { "Duration" }
+                    th { "Result" }
+                    th { "Details" }
+                }
+            }
+            tbody { {rows} }
+        }
+    }
+}
@@ -82,6 +82,12 @@ pub fn Sidebar(
                     span { class: "sidebar__nav-label", "Connections" }
                     span { class: "sidebar__nav-count", "{clients_in_current_ns}" }
                 }
+                Link {
+                    to: AppRoute::Logs {},
+                    class: nav_class(active == SidebarSection::Logs),
+                    Icon { kind: IconKind::AlertTriangle, class: "sidebar__nav-icon".to_string() }
+                    span { class: "sidebar__nav-label", "Logs" }
+                }
                 Link {
                     to: AppRoute::Snapshots {},
                     class: nav_class(active == SidebarSection::Snapshots),
@@ -123,6 +129,7 @@ pub enum SidebarSection {
     Overview,
     Tables,
     Connections,
+    Logs,
     Snapshots,
     Settings,
 }
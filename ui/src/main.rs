@@ -34,6 +34,8 @@ pub enum AppRoute {
     #[end_layout]
     #[route("/connections")]
     Connections {},
+    #[route("/logs")]
+    Logs {},
     #[layout(SnapshotsLayout)]
     #[route("/snapshots")]
     Snapshots {},
@@ -165,6 +167,7 @@ fn Shell() -> Element {
         | AppRoute::DataPartition { .. }
         | AppRoute::DataRow { .. } => SidebarSection::Tables,
         AppRoute::Connections {} => SidebarSection::Connections,
+        AppRoute::Logs {} => SidebarSection::Logs,
         AppRoute::Snapshots {}
         | AppRoute::SnapshotFile { .. }
         | AppRoute::SnapshotTable { .. }
@@ -184,6 +187,16 @@ fn Shell() -> Element {
                 active: true,
             },
         ],
+        AppRoute::Logs {} => vec![
+            Crumb {
+                label: "MyNoSql".to_string(),
+                active: false,
+            },
+            Crumb {
+                label: "Logs".to_string(),
+                active: true,
+            },
+        ],
         AppRoute::Snapshots {}
         | AppRoute::SnapshotFile { .. }
         | AppRoute::SnapshotTable { .. }
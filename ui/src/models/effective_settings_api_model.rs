@@ -0,0 +1,32 @@
+use serde::*;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct EffectiveSettingApiModel {
+    pub name: String,
+    /// `None` - not set.
+    #[serde(default)]
+    pub value: Option<String>,
+    /// Applied by a reload, with no restart.
+    #[serde(default)]
+    pub runtime: bool,
+    /// Changed in the file, waiting for a restart.
+    #[serde(rename = "restartRequired", default)]
+    pub restart_required: bool,
+}
+
+/// Settings the server runs with, as `/api/Settings/Effective` returns them.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct EffectiveSettingsApiModel {
+    #[serde(default)]
+    pub file: String,
+    #[serde(default)]
+    pub settings: Vec<EffectiveSettingApiModel>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct SettingsReloadApiModel {
+    #[serde(default)]
+    pub applied: Vec<String>,
+    #[serde(rename = "restartRequired", default)]
+    pub restart_required: Vec<String>,
+}
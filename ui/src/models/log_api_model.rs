@@ -0,0 +1,40 @@
+use serde::*;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LogContextApiModel {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LogItemApiModel {
+    pub date: String,
+    pub level: String,
+    pub process: String,
+    pub message: String,
+    #[serde(default)]
+    pub namespace: Option<String>,
+    #[serde(rename = "tableName", default)]
+    pub table_name: Option<String>,
+    #[serde(default)]
+    pub context: Vec<LogContextApiModel>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LogsApiModel {
+    pub items: Vec<LogItemApiModel>,
+}
+
+/// Processes the server tags its log entries with - the values `/api/Logs/Process`
+/// takes.
+pub const LOG_PROCESSES: [&str; 9] = [
+    "Init",
+    "Persist",
+    "Backup",
+    "Gc",
+    "Vacuum",
+    "Sync",
+    "DataReaders",
+    "Tls",
+    "Settings",
+];
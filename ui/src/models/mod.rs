@@ -27,3 +27,15 @@ pub use connections_api_model::*;
 
 mod namespace_api_model;
 pub use namespace_api_model::*;
+
+mod log_api_model;
+pub use log_api_model::*;
+
+mod slow_operation_api_model;
+pub use slow_operation_api_model::*;
+
+mod ready_api_model;
+pub use ready_api_model::*;
+
+mod effective_settings_api_model;
+pub use effective_settings_api_model::*;
@@ -0,0 +1,60 @@
+use serde::*;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SkippedPartitionApiModel {
+    #[serde(rename = "partitionKey")]
+    pub partition_key: String,
+    #[serde(default)]
+    pub error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReadyTableApiModel {
+    #[serde(rename = "tableName")]
+    pub table_name: String,
+    #[serde(default)]
+    pub loaded: bool,
+    #[serde(rename = "partitionsTotal", default)]
+    pub partitions_total: u64,
+    #[serde(rename = "partitionsLoaded", default)]
+    pub partitions_loaded: u64,
+    #[serde(rename = "bytesTotal", default)]
+    pub bytes_total: u64,
+    #[serde(rename = "bytesLoaded", default)]
+    pub bytes_loaded: u64,
+    #[serde(rename = "skippedPartitions", default)]
+    pub skipped_partitions: Vec<SkippedPartitionApiModel>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReadyNamespaceApiModel {
+    pub namespace: String,
+    pub status: String,
+    #[serde(rename = "bytesTotal", default)]
+    pub bytes_total: u64,
+    #[serde(rename = "bytesLoaded", default)]
+    pub bytes_loaded: u64,
+    #[serde(default)]
+    pub tables: Vec<ReadyTableApiModel>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReadyApiModel {
+    pub ready: bool,
+    #[serde(default)]
+    pub progress: f64,
+    #[serde(rename = "etaSeconds", default)]
+    pub eta_seconds: Option<u64>,
+    #[serde(rename = "initializingSeconds", default)]
+    pub initializing_seconds: i64,
+    #[serde(rename = "tablesTotal", default)]
+    pub tables_total: u64,
+    #[serde(rename = "tablesLoaded", default)]
+    pub tables_loaded: u64,
+    #[serde(rename = "currentTable", default)]
+    pub current_table: Option<String>,
+    #[serde(rename = "skippedPartitions", default)]
+    pub skipped_partitions: u64,
+    #[serde(default)]
+    pub namespaces: Vec<ReadyNamespaceApiModel>,
+}
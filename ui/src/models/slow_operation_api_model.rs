@@ -0,0 +1,29 @@
+use serde::*;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SlowOperationApiModel {
+    pub date: String,
+    pub kind: String,
+    pub operation: String,
+    #[serde(default)]
+    pub namespace: String,
+    #[serde(rename = "tableName", default)]
+    pub table_name: String,
+    #[serde(rename = "durationMicros", default)]
+    pub duration_micros: u64,
+    #[serde(default)]
+    pub succeeded: bool,
+    #[serde(rename = "partitionKey", default)]
+    pub partition_key: Option<String>,
+    #[serde(default)]
+    pub rows: Option<u64>,
+    #[serde(rename = "payloadSize", default)]
+    pub payload_size: Option<u64>,
+    #[serde(default)]
+    pub session: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SlowOperationsApiModel {
+    pub items: Vec<SlowOperationApiModel>,
+}
@@ -7,6 +7,17 @@ pub struct LocationApiModel {
     pub compress: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct OperationStatsApiModel {
+    pub kind: String,
+    #[serde(default)]
+    pub count: u64,
+    #[serde(default)]
+    pub errors: u64,
+    #[serde(rename = "durationMicros", default)]
+    pub duration_micros: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct StatusBarApiModel {
     #[serde(default)]
@@ -31,4 +42,6 @@ pub struct StatusBarApiModel {
     pub write_payloads_per_second: u64,
     #[serde(rename = "writeBytesPerSecond", default)]
     pub write_bytes_per_second: u64,
+    #[serde(default)]
+    pub operations: Vec<OperationStatsApiModel>,
 }
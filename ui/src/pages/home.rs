@@ -6,14 +6,18 @@ use crate::AppContext;
 use crate::api::get_status;
 use crate::components::atoms::{StateTone, classify_reader};
 use crate::components::overview::{
-    HealthBanner, HealthTone, ReaderHealthGrid, ReadersTable, StatsRow, TableCoverage, WritersTable,
+    HealthBanner, HealthTone, InitProgressBanner, OperationsChart, OperationsSample,
+    ReaderHealthGrid, ReadersTable, SlowOperationsPanel, StatsRow, TableCoverage, WritersTable,
 };
 use crate::models::{InitializedApiModel, ReaderApiModel, StatusApiModel};
 use crate::settings::HealthThresholds;
 
+const MAX_POINTS: usize = 120;
+
 #[component]
 pub fn Home() -> Element {
     let mut data = use_signal(|| None::<StatusApiModel>);
+    let mut operations = use_signal(Vec::<OperationsSample>::new);
     let mut started = use_signal(|| false);
     let tick = use_signal(|| 0u64);
     let app_ctx = use_context::<Signal<AppContext>>();
@@ -29,6 +33,16 @@ pub fn Home() -> Element {
             loop {
                 match get_status().await {
                     Ok(result) => {
+                        let mut history = operations.write();
+                        history.push(OperationsSample {
+                            operations: result.status_bar.operations.clone(),
+                        });
+                        if history.len() > MAX_POINTS {
+                            let overflow = history.len() - MAX_POINTS;
+                            history.drain(0..overflow);
+                        }
+                        drop(history);
+
                         ctx.write().status = Some(result.clone());
                         data.set(Some(result));
                     }
@@ -45,6 +59,7 @@ pub fn Home() -> Element {
     };
 
     let snapshot = data.read().clone();
+    let operations_history = operations.read().clone();
     let thresholds = *use_context::<Signal<HealthThresholds>>().read();
 
     let content = match snapshot {
@@ -59,8 +74,11 @@ pub fn Home() -> Element {
                     read_per_second,
                     write_payloads_per_second,
                     write_bytes_per_second,
+                    operations_history,
                 ),
-                None => render_loading_msg("Server is initializing…"),
+                None => rsx! {
+                    InitProgressBanner {}
+                },
             }
         }
         None => render_loading_msg("Connecting to server…"),
@@ -87,6 +105,7 @@ fn render_overview(
     read_per_second: u64,
     write_payloads_per_second: u64,
     write_bytes_per_second: u64,
+    operations_history: Vec<OperationsSample>,
 ) -> Element {
     let readers_only: Vec<ReaderApiModel> = init
         .readers
@@ -132,6 +151,8 @@ fn render_overview(
                 }
             }
         }
+        OperationsChart { history: operations_history }
+        SlowOperationsPanel {}
         WritersTable { writers: init.writers.clone() }
         ReadersTable { readers: readers_only }
     }
@@ -0,0 +1,224 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+use crate::api::{get_logs, get_tables_list};
+use crate::components::atoms::{Badge, BadgeTone};
+use crate::models::{DEFAULT_NAMESPACE, LOG_PROCESSES, LogItemApiModel};
+
+/// Value of the process filter which asks for the fatal errors only.
+const FATAL_ERRORS: &str = "FatalErrors";
+
+#[derive(Default)]
+struct LogsState {
+    started: bool,
+    items: Option<Vec<LogItemApiModel>>,
+    tables: Vec<String>,
+    /// Table of the selected namespace the entries are of. Empty - any.
+    table: String,
+    /// Process the entries are of, or `FATAL_ERRORS`. Empty - any.
+    process: String,
+}
+
+#[component]
+pub fn Logs() -> Element {
+    let mut ls = use_signal(LogsState::default);
+
+    let started_val = ls.read().started;
+    let on_mount = move |_| {
+        if started_val {
+            return;
+        }
+        ls.write().started = true;
+
+        spawn(async move {
+            if let Ok(tables) = get_tables_list().await {
+                ls.write().tables = tables.into_iter().map(|itm| itm.name).collect();
+            }
+        });
+
+        spawn(async move {
+            loop {
+                load(ls).await;
+                dioxus_utils::js::sleep(Duration::from_secs(3)).await;
+            }
+        });
+    };
+
+    // A filter is either a table or a process: picking one clears the other, so
+    // the page never shows an intersection the server has no view for.
+    let on_table_change = move |evt: Event<FormData>| {
+        {
+            let mut write = ls.write();
+            write.table = evt.value();
+            write.process = String::new();
+            write.items = None;
+        }
+        spawn(load(ls));
+    };
+
+    let on_process_change = move |evt: Event<FormData>| {
+        {
+            let mut write = ls.write();
+            write.process = evt.value();
+            write.table = String::new();
+            write.items = None;
+        }
+        spawn(load(ls));
+    };
+
+    let ls_ra = ls.read();
+    let items = ls_ra.items.clone();
+    let tables = ls_ra.tables.clone();
+    let table = ls_ra.table.clone();
+    let process = ls_ra.process.clone();
+    drop(ls_ra);
+
+    let table_options = tables.iter().map(|name| {
+        rsx! {
+            option { value: "{name}", selected: *name == table, "{name}" }
+        }
+    });
+
+    let process_options = LOG_PROCESSES.iter().map(|name| {
+        rsx! {
+            option { value: "{name}", selected: *name == process, "{name}" }
+        }
+    });
+
+    let content = match items {
+        Some(items) => render_items(&items),
+        None => rsx! {
+            div { class: "empty-state",
+                div { class: "empty-state__title", "Loading logs…" }
+            }
+        },
+    };
+
+    rsx! {
+        section { class: "page page--padded", onmounted: on_mount,
+            div { class: "logs",
+                div { class: "card",
+                    div { class: "card__header",
+                        span { class: "card__title", "Logs" }
+                        div { class: "logs__filters",
+                            select {
+                                class: "logs__filter",
+                                title: "Process",
+                                onchange: on_process_change,
+                                option { value: "", selected: process.is_empty(), "All processes" }
+                                option { value: FATAL_ERRORS, selected: process == FATAL_ERRORS, "Fatal errors" }
+                                {process_options}
+                            }
+                            select {
+                                class: "logs__filter",
+                                title: "Table of the selected namespace",
+                                onchange: on_table_change,
+                                option { value: "", selected: table.is_empty(), "All tables" }
+                                {table_options}
+                            }
+                        }
+                    }
+                    div { class: "card__body", {content} }
+                }
+            }
+        }
+    }
+}
+
+async fn load(mut ls: Signal<LogsState>) {
+    let (table, process) = {
+        let read = ls.read();
+        (read.table.clone(), read.process.clone())
+    };
+
+    let table_filter = if table.is_empty() {
+        None
+    } else {
+        Some(table.as_str())
+    };
+
+    let process_filter = if process.is_empty() || process == FATAL_ERRORS {
+        None
+    } else {
+        Some(process.as_str())
+    };
+
+    match get_logs(table_filter, process_filter, process == FATAL_ERRORS).await {
+        Ok(result) => {
+            let mut write = ls.write();
+
+            // The filter may have changed while the request was in flight.
+            if write.table == table && write.process == process {
+                write.items = Some(result.items);
+            }
+        }
+        Err(err) => {
+            dioxus_utils::console_log(&format!("Logs error: {}", err));
+        }
+    }
+}
+
+fn render_items(items: &[LogItemApiModel]) -> Element {
+    if items.is_empty() {
+        return rsx! {
+            div { class: "empty-state",
+                div { class: "empty-state__title", "No log entries" }
+            }
+        };
+    }
+
+    let rows = items.iter().map(|item| {
+        let tone = match item.level.as_str() {
+            "FatalError" => BadgeTone::Bad,
+            "Error" => BadgeTone::Warn,
+            _ => BadgeTone::Neutral,
+        };
+
+        let table = match (item.namespace.as_ref(), item.table_name.as_ref()) {
+            (Some(namespace), Some(table_name)) if namespace == DEFAULT_NAMESPACE => {
+                table_name.to_string()
+            }
+            (Some(namespace), Some(table_name)) => format!("{}/{}", namespace, table_name),
+            (Some(namespace), None) => namespace.to_string(),
+            _ => String::new(),
+        };
+
+        let context = item
+            .context
+            .iter()
+            .map(|itm| format!("{}={}", itm.key, itm.value))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        rsx! {
+            tr {
+                td { class: "conn-table__id", "{item.date}" }
+                td { Badge { text: item.level.clone(), tone } }
+                td { "{item.process}" }
+                td { class: "conn-table__id", "{table}" }
+                td { class: "logs__message",
+                    div { "{item.message}" }
+                    if !context.is_empty() {
+                        div { class: "logs__context", "{context}" }
+                    }
+                }
+            }
+        }
+    });
+
+    rsx! {
+        table { class: "conn-table",
+            thead {
+                tr {
+                    th { "Date" }
+                    th { "Level" }
+                    th { "Process" }
+                    th { "Table" }
+                    th { "Message" }
+                }
+            }
+            tbody { {rows} }
+        }
+    }
+}
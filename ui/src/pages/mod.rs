@@ -15,3 +15,6 @@ pub use connections::*;
 
 mod not_found;
 pub use not_found::*;
+
+mod logs;
+pub use logs::*;
@@ -3,6 +3,8 @@ use std::time::Duration;
 use dioxus::prelude::*;
 
 use crate::api;
+use crate::components::atoms::{Badge, BadgeTone};
+use crate::models::{EffectiveSettingApiModel, EffectiveSettingsApiModel};
 use crate::settings::{DEFAULT_BAD_MS, DEFAULT_WARN_MS, HealthThresholds};
 
 /// Remaining time of a write-enable window as `Nm SSs`.
@@ -44,6 +46,57 @@ struct UiWritesState {
     error: Option<String>,
 }
 
+#[derive(Default)]
+struct ServerSettingsState {
+    /// Set once, so the settings are read only on first render.
+    loaded: bool,
+    settings: Option<EffectiveSettingsApiModel>,
+    reloading: bool,
+    message: Option<String>,
+    error: Option<String>,
+}
+
+/// Has the server read its settings file again, then re-reads what is in effect.
+fn reload_server_settings(mut server: Signal<ServerSettingsState>) {
+    {
+        let mut w = server.write();
+        w.reloading = true;
+        w.error = None;
+        w.message = None;
+    }
+    spawn(async move {
+        match api::reload_settings().await {
+            Ok(result) => {
+                let settings = api::get_effective_settings().await.ok();
+                let mut w = server.write();
+                w.reloading = false;
+                if settings.is_some() {
+                    w.settings = settings;
+                }
+                let applied = if result.applied.is_empty() {
+                    "Nothing to apply.".to_string()
+                } else {
+                    format!("Applied: {}.", result.applied.join(", "))
+                };
+                w.message = Some(if result.restart_required.is_empty() {
+                    applied
+                } else {
+                    format!(
+                        "{} Waiting for a restart: {}.",
+                        applied,
+                        result.restart_required.join(", ")
+                    )
+                });
+            }
+            Err(err) => {
+                let mut w = server.write();
+                w.reloading = false;
+                w.error = Some(err.to_string());
+            }
+        }
+    });
+}
+
 /// Enables/disables UI writes on the server, then re-reads the authoritative
 /// state so the countdown reflects the real window.
 fn toggle_ui_writes(mut uiw: Signal<UiWritesState>, enabled: bool) {
@@ -534,7 +587,114 @@ pub fn Settings() -> Element {
                         }
                     }
                 }
+
+                ServerSettingsCard {}
+            }
+        }
+    }
+}
+
+/// The settings the server runs with. Read-only: they are changed in the settings
+/// file, which the server reads again on its own once it changes - or on "Reload".
+#[component]
+fn ServerSettingsCard() -> Element {
+    let mut server = use_signal(ServerSettingsState::default);
+
+    {
+        let loaded = server.read().loaded;
+        if !loaded {
+            server.write().loaded = true;
+            spawn(async move {
+                let result = api::get_effective_settings().await;
+                let mut w = server.write();
+                match result {
+                    Ok(settings) => w.settings = Some(settings),
+                    Err(err) => w.error = Some(err.to_string()),
+                }
+            });
+        }
+    }
+
+    let server_ra = server.read();
+    let settings = server_ra.settings.clone();
+    let reloading = server_ra.reloading;
+    let message = server_ra.message.clone();
+    let error = server_ra.error.clone();
+    drop(server_ra);
+
+    let reload = move |_| reload_server_settings(server);
+
+    let file = settings
+        .as_ref()
+        .map(|itm| itm.file.clone())
+        .unwrap_or_default();
+
+    let rows = settings
+        .iter()
+        .flat_map(|itm| itm.settings.iter())
+        .map(render_server_setting);
+
+    let footer = if let Some(m) = message {
+        rsx! { div { style: "color: var(--ok); font-size: 12px;", "{m}" } }
+    } else if let Some(e) = error {
+        rsx! { div { style: "color: var(--danger); font-size: 12px;", "{e}" } }
+    } else {
+        rsx! {}
+    };
+
+    rsx! {
+        div { class: "card",
+            div { class: "card__header",
+                span { class: "card__title", "Server settings" }
+                span { class: "card__subtitle", "{file}" }
+            }
+            div { class: "card__body", style: "display: flex; flex-direction: column; gap: 14px;",
+                p { style: "margin: 0; color: var(--text-muted); font-size: 12.5px;",
+                    "What the server runs with: the settings file, with the environment variables and the command line arguments over it. "
+                    "The ones marked "
+                    b { "runtime" }
+                    " are applied once the file changes; the rest wait for a restart."
+                }
+                table { class: "dt",
+                    thead {
+                        tr {
+                            th { "Setting" }
+                            th { "Value" }
+                            th { "" }
+                        }
+                    }
+                    tbody { {rows} }
+                }
+                {footer}
             }
+            div { class: "card__footer", style: "display: flex; justify-content: flex-end; gap: 6px; padding: 10px 14px;",
+                button {
+                    class: "btn btn--primary btn--sm",
+                    disabled: reloading,
+                    onclick: reload,
+                    if reloading { "Reloading…" } else { "Reload" }
+                }
+            }
+        }
+    }
+}
+
+fn render_server_setting(setting: &EffectiveSettingApiModel) -> Element {
+    let value = setting.value.clone().unwrap_or_else(|| "—".to_string());
+
+    let badge = if setting.restart_required {
+        rsx! { Badge { text: "restart required".to_string(), tone: BadgeTone::Warn } }
+    } else if setting.runtime {
+        rsx! { Badge { text: "runtime".to_string(), tone: BadgeTone::Ok } }
+    } else {
+        rsx! {}
+    };
+
+    rsx! {
+        tr {
+            td { class: "mono", "{setting.name}" }
+            td { class: "mono muted", style: "word-break: break-all;", "{value}" }
+            td { {badge} }
         }
     }
 }
@@ -21,7 +21,6 @@ pub fn format_bytes_per_sec(bytes_per_sec: f64) -> String {
     format!("{}/s", format_bytes(bytes_per_sec))
 }
 
-#[allow(dead_code)]
 pub fn format_duration(micros: f64) -> String {
     if micros == 0.0 {
         return "0".to_string();